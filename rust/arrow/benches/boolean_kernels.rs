@@ -51,6 +51,13 @@ fn bench_or(size: usize) {
     criterion::black_box(boolean_kernels::or(&buffer_a, &buffer_b).unwrap());
 }
 
+/// Benchmark for `XOR`
+fn bench_xor(size: usize) {
+    let buffer_a = create_boolean_array(size);
+    let buffer_b = create_boolean_array(size);
+    criterion::black_box(boolean_kernels::xor(&buffer_a, &buffer_b).unwrap());
+}
+
 /// Benchmark for `NOT`
 fn bench_not(size: usize) {
     let buffer = create_boolean_array(size);
@@ -60,6 +67,7 @@ fn bench_not(size: usize) {
 fn add_benchmark(c: &mut Criterion) {
     c.bench_function("and", |b| b.iter(|| bench_and(512)));
     c.bench_function("or", |b| b.iter(|| bench_or(512)));
+    c.bench_function("xor", |b| b.iter(|| bench_xor(512)));
     c.bench_function("not", |b| b.iter(|| bench_not(512)));
 }
 
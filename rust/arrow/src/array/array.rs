@@ -22,8 +22,11 @@ use std::{any::Any, convert::TryFrom};
 use super::ArrayDataRef;
 use super::*;
 use crate::array::equal_json::JsonEqual;
-use crate::error::Result;
+use crate::buffer::{buffer_bin_and, Buffer, MutableBuffer};
+use crate::datatypes::ToByteSlice;
+use crate::error::{ArrowError, Result};
 use crate::ffi;
+use crate::util::bit_util;
 
 /// Trait for dealing with different types of array at runtime when the type of the
 /// array is not known in advance.
@@ -214,6 +217,165 @@ pub trait Array: fmt::Debug + Send + Sync + JsonEqual {
 /// A reference-counted reference to a generic `Array`.
 pub type ArrayRef = Arc<Array>;
 
+impl<'a> dyn Array + 'a {
+    /// Downcasts this array to the concrete array type `T`.
+    ///
+    /// Unlike `as_any().downcast_ref::<T>().unwrap()`, this returns an
+    /// `ArrowError::InvalidArgumentError` naming the expected and actual types if the
+    /// array is not a `T`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{Array, ArrayRef, Int32Array, StringArray};
+    ///
+    /// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// assert_eq!(array.as_typed::<Int32Array>().unwrap().value(1), 2);
+    ///
+    /// let err = array.as_typed::<StringArray>().unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Invalid argument error: Expected array of type GenericStringArray<i32>, \
+    ///      got array of data type Int32"
+    /// );
+    /// ```
+    pub fn as_typed<T: Array + 'static>(&self) -> Result<&T> {
+        self.as_any().downcast_ref::<T>().ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Expected array of type {}, got array of data type {:?}",
+                short_type_name::<T>(),
+                self.data_type()
+            ))
+        })
+    }
+
+    /// Returns an array of the values of this array with the validity bitmap
+    /// `null_buffer`, whose bit `i` is set if the slot `i` is valid, or without nulls if
+    /// it is `None`. The buffers of the values are shared, not copied.
+    ///
+    /// Note that the values of the slots that become valid are those stored in the
+    /// array, which are unspecified for the slots that were null.
+    ///
+    /// # Errors
+    /// This function errors if `null_buffer` is shorter than the array, or if arrays of
+    /// the data type of this array have no validity bitmap, such as null and union
+    /// arrays.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{Array, ArrayRef, Int32Array};
+    /// use arrow::buffer::Buffer;
+    ///
+    /// let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let array = array.with_null_buffer(Some(Buffer::from([0b101]))).unwrap();
+    /// assert_eq!(
+    ///     array.as_typed::<Int32Array>().unwrap(),
+    ///     &Int32Array::from(vec![Some(1), None, Some(3)])
+    /// );
+    /// ```
+    pub fn with_null_buffer(&self, null_buffer: Option<Buffer>) -> Result<ArrayRef> {
+        let data = self.data_ref();
+        if let DataType::Null | DataType::Union(_) = data.data_type() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Arrays of type {:?} have no validity bitmap",
+                data.data_type()
+            )));
+        }
+        if let Some(buffer) = &null_buffer {
+            if buffer.len() * 8 < data.len() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "A validity bitmap of {} bytes is too short for an array of \
+                     length {}",
+                    buffer.len(),
+                    data.len()
+                )));
+            }
+        }
+
+        // the bitmap is shifted to the offset of the values
+        let null_buffer =
+            null_buffer.map(|buffer| shift_bitmap(&buffer, data.offset(), data.len()));
+        Ok(make_array(Arc::new(ArrayData::new(
+            data.data_type().clone(),
+            data.len(),
+            None,
+            null_buffer,
+            data.offset(),
+            data.buffers().to_vec(),
+            data.child_data().to_vec(),
+        ))))
+    }
+
+    /// Returns an array of the values of this array where the slots whose bits are
+    /// unset in the bitmap `validity` are also null, e.g. to introduce the nulls of an
+    /// outer join. The buffers of the values are shared, not copied.
+    ///
+    /// # Errors
+    /// This function errors like [`with_null_buffer`](#method.with_null_buffer).
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{Array, ArrayRef, Int32Array};
+    /// use arrow::buffer::Buffer;
+    ///
+    /// let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+    /// let array = array.nullify(&Buffer::from([0b011])).unwrap();
+    /// assert_eq!(
+    ///     array.as_typed::<Int32Array>().unwrap(),
+    ///     &Int32Array::from(vec![Some(1), None, None])
+    /// );
+    /// ```
+    pub fn nullify(&self, validity: &Buffer) -> Result<ArrayRef> {
+        let data = self.data_ref();
+        let null_buffer = match data.null_buffer() {
+            Some(nulls) if validity.len() * 8 >= data.len() => {
+                buffer_bin_and(nulls, data.offset(), validity, 0, data.len())
+            }
+            _ => validity.clone(),
+        };
+        self.with_null_buffer(Some(null_buffer))
+    }
+}
+
+/// Returns the first `len` bits of `bitmap` starting at bit `offset` of a new bitmap,
+/// whose previous bits are unset
+fn shift_bitmap(bitmap: &Buffer, offset: usize, len: usize) -> Buffer {
+    if offset == 0 {
+        return bitmap.clone();
+    }
+    if offset % 8 == 0 {
+        let mut shifted = MutableBuffer::new(bit_util::ceil(offset + len, 8))
+            .with_bitset(offset / 8, false);
+        shifted.extend_from_slice(&bitmap.data()[..bit_util::ceil(len, 8)]);
+        return shifted.freeze();
+    }
+    let mut shifted = MutableBuffer::new_null(offset + len);
+    let bits = bitmap.data();
+    let shifted_bits = shifted.data_mut();
+    (0..len)
+        .filter(|i| bit_util::get_bit(bits, *i))
+        .for_each(|i| bit_util::set_bit(shifted_bits, offset + i));
+    shifted.freeze()
+}
+
+/// Returns the name of `T` without the paths of the types it is made of,
+/// e.g. `PrimitiveArray<Int32Type>`
+fn short_type_name<T: ?Sized>() -> String {
+    let name = std::any::type_name::<T>();
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap());
+    short
+}
+
 /// Constructs an array using the input `data`.
 /// Returns a reference-counted `Array` instance.
 pub fn make_array(data: ArrayDataRef) -> ArrayRef {
@@ -323,6 +485,70 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
     }
 }
 
+/// Creates an array of length zero of the given data type, including for nested types.
+///
+/// ```
+/// use arrow::array::{new_empty_array, Array};
+/// use arrow::datatypes::{DataType, Field};
+///
+/// let data_type = DataType::List(Box::new(Field::new("item", DataType::Utf8, true)));
+/// let array = new_empty_array(&data_type);
+/// assert_eq!(0, array.len());
+/// assert_eq!(&data_type, array.data_type());
+/// ```
+pub fn new_empty_array(data_type: &DataType) -> ArrayRef {
+    let empty = || Buffer::from(&[]);
+    let (buffers, child_data) = match data_type {
+        DataType::Null => (vec![], vec![]),
+        DataType::Utf8 | DataType::Binary => {
+            (vec![Buffer::from(&[0i32].to_byte_slice()), empty()], vec![])
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            (vec![Buffer::from(&[0i64].to_byte_slice()), empty()], vec![])
+        }
+        DataType::List(field) => (
+            vec![Buffer::from(&[0i32].to_byte_slice())],
+            vec![new_empty_array(field.data_type()).data()],
+        ),
+        DataType::LargeList(field) => (
+            vec![Buffer::from(&[0i64].to_byte_slice())],
+            vec![new_empty_array(field.data_type()).data()],
+        ),
+        DataType::FixedSizeList(field, _) => {
+            (vec![], vec![new_empty_array(field.data_type()).data()])
+        }
+        DataType::Struct(fields) => (
+            vec![],
+            fields
+                .iter()
+                .map(|field| new_empty_array(field.data_type()).data())
+                .collect(),
+        ),
+        // the type ids of a sparse union
+        DataType::Union(fields) => (
+            vec![empty()],
+            fields
+                .iter()
+                .map(|field| new_empty_array(field.data_type()).data())
+                .collect(),
+        ),
+        DataType::Dictionary(_, value_type) => {
+            (vec![empty()], vec![new_empty_array(value_type).data()])
+        }
+        // the values of all other types are fixed-width
+        _ => (vec![empty()], vec![]),
+    };
+    make_array(Arc::new(ArrayData::new(
+        data_type.clone(),
+        0,
+        Some(0),
+        None,
+        0,
+        buffers,
+        child_data,
+    )))
+}
+
 /// Creates a new array from two FFI pointers. Used to import arrays from the C Data Interface
 /// # Safety
 /// Assumes that these pointers represent valid C Data Interfaces, both in memory
@@ -375,3 +601,67 @@ where
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_null_buffer_of_sliced_arrays() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+            None,
+        ]));
+        let validity = Buffer::from([0b0110]);
+        for offset in 0..2 {
+            let sliced = array.slice(offset, 4);
+            let replaced = sliced.with_null_buffer(Some(validity.clone())).unwrap();
+            assert_eq!(replaced.null_count(), 2);
+            assert_eq!(replaced.offset(), offset);
+            let replaced = replaced.as_typed::<StringArray>().unwrap();
+            assert!(replaced.is_null(0) && replaced.is_null(3));
+            assert_eq!(replaced.value(2), if offset == 0 { "c" } else { "d" });
+
+            let nullified = sliced.nullify(&validity).unwrap();
+            let expected = (0..4)
+                .map(|i| i == 1 || i == 2)
+                .zip(array.as_typed::<StringArray>().unwrap().iter().skip(offset))
+                .map(|(valid, value)| if valid { value } else { None })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                nullified.as_typed::<StringArray>().unwrap(),
+                &StringArray::from(expected)
+            );
+        }
+
+        let values = (0..100).map(|i| Some(i as i64)).collect::<Vec<_>>();
+        let array: ArrayRef = Arc::new(Int64Array::from(values));
+        let sliced = array.slice(8, 20);
+        let replaced = sliced
+            .with_null_buffer(Some(Buffer::from([0xff, 0x00, 0x0f])))
+            .unwrap();
+        assert_eq!(replaced.null_count(), 8);
+        assert!(replaced.is_valid(7) && replaced.is_null(8) && replaced.is_valid(19));
+    }
+
+    #[test]
+    fn test_with_null_buffer_removed() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let array = array.with_null_buffer(None).unwrap();
+        assert_eq!(array.null_count(), 0);
+        assert!(array.data_ref().null_buffer().is_none());
+    }
+
+    #[test]
+    fn test_with_null_buffer_errors() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1; 10]));
+        assert!(array.with_null_buffer(Some(Buffer::from([0xff]))).is_err());
+        assert!(array.nullify(&Buffer::from([0xff])).is_err());
+
+        let array: ArrayRef = Arc::new(NullArray::new(3));
+        assert!(array.with_null_buffer(None).is_err());
+    }
+}
@@ -89,6 +89,62 @@ impl BooleanArray {
         let offset = i + self.offset();
         unsafe { bit_util::get_bit_raw(self.raw_values.get() as *const u8, offset) }
     }
+
+    /// Returns the number of non-null values of this array that are true.
+    pub fn true_count(&self) -> usize {
+        self.true_count_range(0, self.len())
+    }
+
+    /// Returns the number of non-null values of this array that are false.
+    pub fn false_count(&self) -> usize {
+        self.false_count_range(0, self.len())
+    }
+
+    /// Returns the number of non-null values of the `len` slots starting at `offset`
+    /// that are true.
+    ///
+    /// The values are counted 64 at a time from the values and validity bitmaps.
+    ///
+    /// # Panics
+    /// This function panics if the range is out of bounds of the array.
+    pub fn true_count_range(&self, offset: usize, len: usize) -> usize {
+        assert!(
+            offset
+                .checked_add(len)
+                .map_or(false, |end| end <= self.len()),
+            "the range is out of bounds of the BooleanArray"
+        );
+        let offset = self.offset() + offset;
+        let values = &self.data.buffers()[0];
+        match self.data.null_buffer() {
+            None => values.count_set_bits_offset(offset, len),
+            Some(validity) => {
+                let values = values.bit_chunks(offset, len);
+                let validity = validity.bit_chunks(offset, len);
+                let count: usize = values
+                    .iter()
+                    .zip(validity.iter())
+                    .map(|(values, valid)| (values & valid).count_ones() as usize)
+                    .sum();
+                let remainder = values.remainder_bits() & validity.remainder_bits();
+                count + remainder.count_ones() as usize
+            }
+        }
+    }
+
+    /// Returns the number of non-null values of the `len` slots starting at `offset`
+    /// that are false.
+    ///
+    /// # Panics
+    /// This function panics if the range is out of bounds of the array.
+    pub fn false_count_range(&self, offset: usize, len: usize) -> usize {
+        let true_count = self.true_count_range(offset, len);
+        let valid_count = match self.data.null_buffer() {
+            None => len,
+            Some(validity) => validity.count_set_bits_offset(self.offset() + offset, len),
+        };
+        valid_count - true_count
+    }
 }
 
 impl Array for BooleanArray {
@@ -219,6 +275,50 @@ mod tests {
     use crate::buffer::Buffer;
     use crate::datatypes::DataType;
 
+    #[test]
+    fn test_boolean_array_true_false_count() {
+        let array: BooleanArray = (0..200)
+            .map(|i| match i % 3 {
+                0 => None,
+                1 => Some(true),
+                _ => Some(i % 2 == 0),
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let expected = |offset: usize, len: usize, value: bool| {
+            (offset..offset + len)
+                .filter(|i| {
+                    (i % 3 == 1 && value) || (i % 3 == 2 && (i % 2 == 0) == value)
+                })
+                .count()
+        };
+        assert_eq!(array.true_count(), expected(0, 200, true));
+        assert_eq!(array.false_count(), expected(0, 200, false));
+        assert_eq!(array.true_count_range(5, 130), expected(5, 130, true));
+        assert_eq!(array.false_count_range(5, 130), expected(5, 130, false));
+
+        let sliced = array.slice(7, 100);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(sliced.true_count(), expected(7, 100, true));
+        assert_eq!(sliced.false_count(), expected(7, 100, false));
+        assert_eq!(sliced.true_count_range(3, 0), 0);
+
+        let array = BooleanArray::from(vec![true, false, true]);
+        assert_eq!((array.true_count(), array.false_count()), (2, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "the range is out of bounds of the BooleanArray")]
+    fn test_boolean_array_true_count_out_of_bounds() {
+        BooleanArray::from(vec![true, false]).true_count_range(1, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the range is out of bounds of the BooleanArray")]
+    fn test_boolean_array_true_count_overflow() {
+        BooleanArray::from(vec![true, false]).true_count_range(1, usize::MAX);
+    }
+
     #[test]
     fn test_boolean_fmt_debug() {
         let arr = BooleanArray::from(vec![true, false, false]);
@@ -189,6 +189,11 @@ impl FixedSizeListArray {
         self.values.clone()
     }
 
+    /// Returns a reference to the values of this list, borrowed for as long as the list.
+    pub(crate) fn values_ref(&self) -> &ArrayRef {
+        &self.values
+    }
+
     /// Returns a clone of the value type of this list.
     pub fn value_type(&self) -> DataType {
         self.values.data_ref().data_type().clone()
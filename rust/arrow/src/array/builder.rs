@@ -353,6 +353,11 @@ impl BooleanBufferBuilder {
         self.buffer.capacity() * 8
     }
 
+    /// Returns the number of bytes allocated by the internal buffer
+    fn allocated_bytes(&self) -> usize {
+        self.buffer.capacity()
+    }
+
     #[inline]
     pub fn advance(&mut self, i: usize) -> Result<()> {
         let new_buffer_len = bit_util::ceil(self.len + i, 8);
@@ -444,6 +449,11 @@ impl<T: ArrowPrimitiveType> BufferBuilder<T> {
         self.buffer.extend_from_slice(bytes);
         self.len += len_added;
     }
+
+    /// Returns the number of bytes allocated by the internal buffer
+    fn allocated_bytes(&self) -> usize {
+        self.buffer.capacity()
+    }
 }
 
 /// Trait for dealing with different array builders at runtime
@@ -454,6 +464,22 @@ pub trait ArrayBuilder: Any {
     /// Returns whether number of array slots is zero
     fn is_empty(&self) -> bool;
 
+    /// Returns the number of array slots that can be appended without reallocating.
+    ///
+    /// For nested and variable-size builders, this only accounts for the slots of the
+    /// builder itself, not for the values of its child builders. Builders that do not
+    /// track their capacity return their length.
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+
+    /// Returns the number of bytes allocated by the builder, including the bytes
+    /// allocated by its child builders. Builders that do not track their allocations
+    /// return 0.
+    fn allocated_bytes(&self) -> usize {
+        0
+    }
+
     /// Builds the array
     fn finish(&mut self) -> ArrayRef;
 
@@ -581,6 +607,16 @@ impl ArrayBuilder for BooleanBuilder {
         self.values_builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.values_builder
+            .capacity()
+            .min(self.bitmap_builder.capacity())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values_builder.allocated_bytes() + self.bitmap_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -620,6 +656,16 @@ impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
         self.values_builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.values_builder
+            .capacity()
+            .min(self.bitmap_builder.capacity())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.values_builder.allocated_bytes() + self.bitmap_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -736,8 +782,7 @@ pub struct ListBuilder<T: ArrayBuilder> {
 impl<T: ArrayBuilder> ListBuilder<T> {
     /// Creates a new `ListArrayBuilder` from a given values array builder
     pub fn new(values_builder: T) -> Self {
-        let capacity = values_builder.len();
-        Self::with_capacity(values_builder, capacity)
+        Self::with_capacity(values_builder, 0)
     }
 
     /// Creates a new `ListArrayBuilder` from a given values array builder
@@ -783,6 +828,17 @@ where
         self.len == 0
     }
 
+    fn capacity(&self) -> usize {
+        let offsets_capacity = self.offsets_builder.capacity().saturating_sub(1);
+        offsets_capacity.min(self.bitmap_builder.capacity())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.offsets_builder.allocated_bytes()
+            + self.bitmap_builder.allocated_bytes()
+            + self.values_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -854,8 +910,7 @@ pub struct LargeListBuilder<T: ArrayBuilder> {
 impl<T: ArrayBuilder> LargeListBuilder<T> {
     /// Creates a new `LargeListArrayBuilder` from a given values array builder
     pub fn new(values_builder: T) -> Self {
-        let capacity = values_builder.len();
-        Self::with_capacity(values_builder, capacity)
+        Self::with_capacity(values_builder, 0)
     }
 
     /// Creates a new `LargeListArrayBuilder` from a given values array builder
@@ -901,6 +956,17 @@ where
         self.len == 0
     }
 
+    fn capacity(&self) -> usize {
+        let offsets_capacity = self.offsets_builder.capacity().saturating_sub(1);
+        offsets_capacity.min(self.bitmap_builder.capacity())
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.offsets_builder.allocated_bytes()
+            + self.bitmap_builder.allocated_bytes()
+            + self.values_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -973,16 +1039,13 @@ impl<T: ArrayBuilder> FixedSizeListBuilder<T> {
     /// Creates a new `FixedSizeListBuilder` from a given values array builder
     /// `length` is the number of values within each array
     pub fn new(values_builder: T, length: i32) -> Self {
-        let capacity = values_builder.len();
-        Self::with_capacity(values_builder, length, capacity)
+        Self::with_capacity(values_builder, length, 0)
     }
 
     /// Creates a new `FixedSizeListBuilder` from a given values array builder
     /// `length` is the number of values within each array
     /// `capacity` is the number of items to pre-allocate space for in this builder
     pub fn with_capacity(values_builder: T, length: i32, capacity: usize) -> Self {
-        let mut offsets_builder = Int32BufferBuilder::new(capacity + 1);
-        offsets_builder.append(0).unwrap();
         Self {
             bitmap_builder: BooleanBufferBuilder::new(capacity),
             values_builder,
@@ -1021,6 +1084,14 @@ where
         self.len == 0
     }
 
+    fn capacity(&self) -> usize {
+        self.bitmap_builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.bitmap_builder.allocated_bytes() + self.values_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1147,6 +1218,14 @@ impl ArrayBuilder for BinaryBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1179,6 +1258,14 @@ impl ArrayBuilder for LargeBinaryBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1211,6 +1298,14 @@ impl ArrayBuilder for StringBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1243,6 +1338,14 @@ impl ArrayBuilder for LargeStringBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1275,6 +1378,14 @@ impl ArrayBuilder for FixedSizeBinaryBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1307,6 +1418,14 @@ impl ArrayBuilder for DecimalBuilder {
         self.builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -1619,9 +1738,9 @@ impl fmt::Debug for StructBuilder {
 impl ArrayBuilder for StructBuilder {
     /// Returns the number of array slots in the builder.
     ///
-    /// Note that this always return the first child field builder's length, and it is
-    /// the caller's responsibility to maintain the consistency that all the child field
-    /// builder should have the equal number of elements.
+    /// Note that this is the number of calls to `append`, and it is the caller's
+    /// responsibility to maintain the consistency that all the child field builders
+    /// should have the same number of elements.
     fn len(&self) -> usize {
         self.len
     }
@@ -1631,6 +1750,19 @@ impl ArrayBuilder for StructBuilder {
         self.len == 0
     }
 
+    fn capacity(&self) -> usize {
+        self.bitmap_builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.bitmap_builder.allocated_bytes()
+            + self
+                .field_builders
+                .iter()
+                .map(|b| b.allocated_bytes())
+                .sum::<usize>()
+    }
+
     /// Builds the array.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -2163,6 +2295,14 @@ where
         self.keys_builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.keys_builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.keys_builder.allocated_bytes() + self.values_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -2313,6 +2453,14 @@ where
         self.keys_builder.is_empty()
     }
 
+    fn capacity(&self) -> usize {
+        self.keys_builder.capacity()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.keys_builder.allocated_bytes() + self.values_builder.allocated_bytes()
+    }
+
     /// Builds the array and reset this builder.
     fn finish(&mut self) -> ArrayRef {
         Arc::new(self.finish())
@@ -3134,6 +3282,61 @@ mod tests {
         assert_eq!(5, string_array.value_length(2));
     }
 
+    #[test]
+    fn test_builder_len_and_capacity() {
+        let mut builder = ListBuilder::new(Int32Builder::new(10));
+        builder.values().append_slice(&[1, 2, 3]).unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_value(4).unwrap();
+        builder.append(true).unwrap();
+        // the values of the lists are not counted
+        assert_eq!(2, builder.len());
+        assert!(builder.capacity() >= 2);
+
+        let mut builders: Vec<Box<ArrayBuilder>> = vec![
+            Box::new(builder),
+            Box::new(StringBuilder::with_capacity(4, 100)),
+            Box::new(FixedSizeListBuilder::new(Int32Builder::new(8), 2)),
+            Box::new(StructBuilder::from_fields(
+                vec![Field::new("a", DataType::Int64, true)],
+                10,
+            )),
+            make_builder(&DataType::Boolean, 10),
+        ];
+        for builder in &builders {
+            assert!(builder.allocated_bytes() > 0);
+        }
+        let string_builder = builders[1]
+            .as_any_mut()
+            .downcast_mut::<StringBuilder>()
+            .unwrap();
+        assert!(string_builder.capacity() >= 4);
+        string_builder.append_value("abc").unwrap();
+        assert_eq!(1, string_builder.len());
+        assert!(!string_builder.is_empty());
+
+        let fixed_size_builder = builders[2]
+            .as_any_mut()
+            .downcast_mut::<FixedSizeListBuilder<Int32Builder>>()
+            .unwrap();
+        assert!(fixed_size_builder.is_empty());
+        fixed_size_builder.values().append_slice(&[1, 2]).unwrap();
+        fixed_size_builder.append(true).unwrap();
+        assert_eq!(1, fixed_size_builder.len());
+
+        // includes the buffers of the Int64 field builder
+        assert!(builders[3].allocated_bytes() >= 10 * 8);
+
+        let mut builder = PrimitiveBuilder::<Int32Type>::new(2);
+        let initial_bytes = builder.allocated_bytes();
+        for i in 0..1000 {
+            builder.append_value(i).unwrap();
+        }
+        assert_eq!(1000, builder.len());
+        assert!(builder.capacity() >= 1000);
+        assert!(builder.allocated_bytes() > initial_bytes);
+    }
+
     #[test]
     fn test_struct_array_builder() {
         let string_builder = StringBuilder::new(4);
@@ -22,7 +22,9 @@ use std::mem;
 use std::sync::Arc;
 
 use crate::buffer::Buffer;
-use crate::datatypes::DataType;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
 use crate::{bitmap::Bitmap, datatypes::ArrowNativeType};
 
 use super::equal::equal;
@@ -232,6 +234,105 @@ impl ArrayData {
         new_data
     }
 
+    /// Validates that the buffers and children of this array data are large enough for
+    /// its data type, offset and length, so that accessing any of its elements is in
+    /// bounds.
+    ///
+    /// The contents of the buffers, such as offsets of variable sized types, are not
+    /// validated.
+    pub fn validate(&self) -> Result<()> {
+        let len = self.offset + self.len;
+
+        if let Some(bitmap) = &self.null_bitmap {
+            let required = bit_util::ceil(len, 8);
+            if bitmap.buffer_ref().len() < required {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Null bitmap of {} bytes is too small for offset {} and length {}, \
+                     expected at least {} bytes",
+                    bitmap.buffer_ref().len(),
+                    self.offset,
+                    self.len,
+                    required
+                )));
+            }
+        }
+
+        let check_buffer = |index: usize, required: usize| -> Result<()> {
+            match self.buffers.get(index) {
+                Some(buffer) if buffer.len() >= required => Ok(()),
+                Some(buffer) => Err(ArrowError::InvalidArgumentError(format!(
+                    "Buffer {} of {:?} array data has {} bytes, expected at least {} \
+                     bytes for offset {} and length {}",
+                    index,
+                    self.data_type,
+                    buffer.len(),
+                    required,
+                    self.offset,
+                    self.len
+                ))),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "{:?} array data expects at least {} buffers, found {}",
+                    self.data_type,
+                    index + 1,
+                    self.buffers.len()
+                ))),
+            }
+        };
+        let check_child = |index: usize, required: usize| -> Result<()> {
+            match self.child_data.get(index) {
+                Some(child) if child.len() >= required => Ok(()),
+                Some(child) => Err(ArrowError::InvalidArgumentError(format!(
+                    "Child {} of {:?} array data has length {}, expected at least {}",
+                    index,
+                    self.data_type,
+                    child.len(),
+                    required
+                ))),
+                None => Err(ArrowError::InvalidArgumentError(format!(
+                    "{:?} array data expects at least {} children, found {}",
+                    self.data_type,
+                    index + 1,
+                    self.child_data.len()
+                ))),
+            }
+        };
+        // offsets have one more slot than the array, but empty arrays may have no offsets
+        let offsets_len =
+            |width: usize| if self.len == 0 { 0 } else { (len + 1) * width };
+
+        match &self.data_type {
+            DataType::Null => Ok(()),
+            DataType::Boolean => check_buffer(0, bit_util::ceil(len, 8)),
+            DataType::Utf8 | DataType::Binary => {
+                check_buffer(0, offsets_len(4))?;
+                check_buffer(1, 0)
+            }
+            DataType::LargeUtf8 | DataType::LargeBinary => {
+                check_buffer(0, offsets_len(8))?;
+                check_buffer(1, 0)
+            }
+            DataType::FixedSizeBinary(size) => check_buffer(0, len * *size as usize),
+            DataType::List(_) => {
+                check_buffer(0, offsets_len(4))?;
+                check_child(0, 0)
+            }
+            DataType::LargeList(_) => {
+                check_buffer(0, offsets_len(8))?;
+                check_child(0, 0)
+            }
+            DataType::FixedSizeList(_, size) => check_child(0, len * *size as usize),
+            DataType::Struct(fields) => {
+                (0..fields.len()).try_for_each(|i| check_child(i, len))
+            }
+            DataType::Union(_) => Ok(()),
+            DataType::Dictionary(key_type, _) => {
+                check_buffer(0, len * primitive_byte_width(key_type)?)?;
+                check_child(0, 0)
+            }
+            data_type => check_buffer(0, len * primitive_byte_width(data_type)?),
+        }
+    }
+
     /// Returns the `buffer` as a slice of type `T` starting at self.offset
     /// # Panics
     /// This function panics if:
@@ -248,6 +349,38 @@ impl ArrayData {
     }
 }
 
+/// Returns the size in bytes of a value of a fixed width primitive data type, or an error
+/// for other data types
+fn primitive_byte_width(data_type: &DataType) -> Result<usize> {
+    let width = match data_type {
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "{:?} array data cannot be validated, as it is not a fixed width \
+                 primitive type",
+                data_type
+            )))
+        }
+    };
+    Ok(width)
+}
+
 impl PartialEq for ArrayData {
     fn eq(&self, other: &Self) -> bool {
         equal(self, other)
@@ -335,6 +468,35 @@ impl ArrayDataBuilder {
         );
         Arc::new(data)
     }
+
+    /// Builds the `ArrayData`, validating it with [`ArrayData::validate`].
+    ///
+    /// The null count is computed from the null bitmap if it was not set, and otherwise
+    /// must match the number of unset bits of the null bitmap.
+    pub fn try_build(self) -> Result<ArrayDataRef> {
+        let expected_null_count = self.null_count;
+        let mut data = ArrayData::new(
+            self.data_type,
+            self.len,
+            Some(0),
+            self.null_bit_buffer,
+            self.offset,
+            self.buffers,
+            self.child_data,
+        );
+        data.validate()?;
+
+        data.null_count = count_nulls(data.null_buffer(), data.offset, data.len);
+        match expected_null_count {
+            Some(null_count) if null_count != data.null_count => {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "Null count {} does not match the {} nulls of the null bitmap",
+                    null_count, data.null_count
+                )))
+            }
+            _ => Ok(Arc::new(data)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -456,4 +618,73 @@ mod tests {
         let float_data = ArrayData::builder(DataType::Float32).build();
         assert_ne!(int_data, float_data);
     }
+
+    #[test]
+    fn test_try_build() {
+        let arr_data = ArrayData::builder(DataType::Int32)
+            .len(3)
+            .offset(1)
+            .null_bit_buffer(Buffer::from([0b00001101]))
+            .add_buffer(Buffer::from([1i32, 2, 3, 4].to_byte_slice()))
+            .try_build()
+            .unwrap();
+        assert_eq!(1, arr_data.null_count());
+
+        let arr_data = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from([0i32, 1, 3].to_byte_slice()))
+            .add_buffer(Buffer::from(b"abc"))
+            .try_build()
+            .unwrap();
+        assert_eq!(0, arr_data.null_count());
+    }
+
+    #[test]
+    fn test_try_build_invalid() {
+        // the values buffer is too small
+        let result = ArrayData::builder(DataType::Int64)
+            .len(3)
+            .add_buffer(Buffer::from([1i64, 2].to_byte_slice()))
+            .try_build();
+        assert!(result.is_err());
+
+        // the values buffer is missing
+        let result = ArrayData::builder(DataType::Boolean).len(3).try_build();
+        assert!(result.is_err());
+
+        // the null bitmap is too small for the offset
+        let result = ArrayData::builder(DataType::Int8)
+            .len(3)
+            .offset(6)
+            .null_bit_buffer(Buffer::from([0xff]))
+            .add_buffer(Buffer::from(&[0u8; 9][..]))
+            .try_build();
+        assert!(result.is_err());
+
+        // the null count does not match the null bitmap
+        let result = ArrayData::builder(DataType::Int8)
+            .len(3)
+            .null_count(2)
+            .null_bit_buffer(Buffer::from([0b00000110]))
+            .add_buffer(Buffer::from(&[0u8; 3][..]))
+            .try_build();
+        assert!(result.is_err());
+
+        // the offsets buffer does not have an extra slot
+        let result = ArrayData::builder(DataType::Utf8)
+            .len(2)
+            .add_buffer(Buffer::from([0i32, 1].to_byte_slice()))
+            .add_buffer(Buffer::from(b"abc"))
+            .try_build();
+        assert!(result.is_err());
+
+        // the keys of dictionaries are integers
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Utf8), Box::new(DataType::Utf8));
+        let result = ArrayData::builder(dict_type)
+            .len(1)
+            .add_buffer(Buffer::from([0i32].to_byte_slice()))
+            .try_build();
+        assert!(result.is_err());
+    }
 }
@@ -507,6 +507,21 @@ mod tests {
         test_generic_string_equal::<i64>()
     }
 
+    #[test]
+    fn test_string_slice() {
+        let lhs =
+            StringArray::from(vec![Some("hello"), Some("world"), None, Some("arrow")])
+                .data();
+        let lhs = lhs.slice(1, 3);
+        let rhs = StringArray::from(vec![Some("world"), None, Some("arrow")]).data();
+        test_equal(&lhs, &rhs, true);
+
+        let rhs = StringArray::from(vec![Some("abcdefgh"), Some("world"), None]).data();
+        let rhs = rhs.slice(1, 2);
+        test_equal(&lhs.slice(0, 2), &rhs, true);
+        test_equal(&lhs.slice(1, 2), &rhs, false);
+    }
+
     fn test_generic_binary_equal<OffsetSize: BinaryOffsetSizeTrait>() {
         let cases = binary_cases();
 
@@ -130,6 +130,7 @@ pub use self::array_union::UnionArray;
 pub use self::null::NullArray;
 
 pub use self::array::make_array;
+pub use self::array::new_empty_array;
 
 pub type Int8Array = PrimitiveArray<Int8Type>;
 pub type Int16Array = PrimitiveArray<Int16Type>;
@@ -105,6 +105,36 @@ where
     })
 }
 
+/// Compares fixed size lists element by element, where null elements are less than any
+/// other value
+fn compare_fixed_size_list<'a>(
+    left: &'a Array,
+    right: &'a Array,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_typed::<FixedSizeListArray>()?;
+    let right = right.as_typed::<FixedSizeListArray>()?;
+    let left_values = left.values_ref().as_ref();
+    let right_values = right.values_ref().as_ref();
+    let compare = build_compare(left_values, right_values)?;
+    let length = left.value_length() as usize;
+    Ok(Box::new(move |i, j| {
+        let left_offset = left.value_offset(i) as usize;
+        let right_offset = right.value_offset(j) as usize;
+        (0..length)
+            .map(|k| {
+                let (a, b) = (left_offset + k, right_offset + k);
+                match (left_values.is_valid(a), right_values.is_valid(b)) {
+                    (true, true) => compare(a, b),
+                    (false, true) => Ordering::Less,
+                    (true, false) => Ordering::Greater,
+                    (false, false) => Ordering::Equal,
+                }
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
 /// returns a comparison function that compares two values at two different positions
 /// between the two arrays.
 /// The arrays' types must be equal.
@@ -192,6 +222,9 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
         }
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => {
+            compare_fixed_size_list(left, right)?
+        }
         (
             Dictionary(key_type_lhs, value_type_lhs),
             Dictionary(key_type_rhs, value_type_rhs),
@@ -307,4 +340,30 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(2, 3));
         Ok(())
     }
+
+    #[test]
+    fn test_fixed_size_list() -> Result<()> {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(0), 2);
+        for values in &[[Some(1), Some(2)], [Some(1), None], [Some(0), Some(5)]] {
+            for value in values {
+                match value {
+                    Some(v) => builder.values().append_value(*v)?,
+                    None => builder.values().append_null()?,
+                }
+            }
+            builder.append(true)?;
+        }
+        let array = builder.finish();
+        let sliced = array.slice(1, 2);
+
+        let cmp = build_compare(&array, sliced.as_ref())?;
+
+        // null elements are less than any other value
+        assert_eq!(Ordering::Greater, (cmp)(0, 0));
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Less, (cmp)(2, 0));
+        assert_eq!(Ordering::Equal, (cmp)(1, 0));
+        assert_eq!(Ordering::Equal, (cmp)(2, 1));
+        Ok(())
+    }
 }
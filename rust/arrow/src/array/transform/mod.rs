@@ -647,6 +647,29 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    /// tests extending from a sliced variable-sized array whose offset in values differs
+    /// from its offset in slots
+    #[test]
+    fn test_string_offsets_multibyte() {
+        let array = StringArray::from(vec!["hello", "bc", "defh"]).data();
+        let array = array.slice(1, 2);
+        let arrays = vec![&array];
+        let mut mutable = MutableArrayData::new(arrays, false, 0);
+        mutable.extend(0, 0, 2);
+        let result = StringArray::from(Arc::new(mutable.freeze()));
+        assert_eq!(result, StringArray::from(vec!["bc", "defh"]));
+
+        let array =
+            StringArray::from(vec![Some("hello"), Some("bc"), None, Some("defh")]).data();
+        let array = array.slice(1, 3);
+        let arrays = vec![&array];
+        let mut mutable = MutableArrayData::new(arrays, false, 0);
+        mutable.extend(0, 0, 3);
+        let result = StringArray::from(Arc::new(mutable.freeze()));
+        let expected = StringArray::from(vec![Some("bc"), None, Some("defh")]);
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn test_multiple_with_nulls() {
         let array1 = StringArray::from(vec!["hello", "world"]).data();
@@ -38,7 +38,8 @@ fn extend_offset_values<T: OffsetSizeTrait>(
 
 pub(super) fn build_extend<T: OffsetSizeTrait>(array: &ArrayData) -> Extend {
     let offsets = array.buffer::<T>(0);
-    let values = &array.buffers()[1].data()[array.offset()..];
+    // the offsets index the whole values buffer, regardless of the array's offset
+    let values = array.buffers()[1].data();
     if array.null_count() == 0 {
         // fast case where we can copy regions without null issues
         Box::new(
@@ -79,8 +80,7 @@ pub(super) fn build_extend<T: OffsetSizeTrait>(array: &ArrayData) -> Extend {
                         let length = length.to_usize().unwrap();
 
                         // append value
-                        let start = offsets[i].to_usize().unwrap()
-                            - offsets[0].to_usize().unwrap();
+                        let start = offsets[i].to_usize().unwrap();
                         let bytes = &values[start..(start + length)];
                         values_buffer.extend_from_slice(bytes);
                     }
@@ -168,6 +168,31 @@ impl Buffer {
         )
     }
 
+    /// Converts this buffer into a `MutableBuffer` without copying its memory, when this
+    /// buffer is the only reference to its memory region, is not sliced, and its memory
+    /// was allocated by this crate. Otherwise, returns this buffer unchanged.
+    ///
+    /// This allows kernels to reuse the memory of inputs that are no longer needed.
+    pub fn into_mutable(self) -> std::result::Result<MutableBuffer, Self> {
+        if self.offset != 0 {
+            return Err(self);
+        }
+        match Arc::try_unwrap(self.data) {
+            Ok(bytes) => match bytes.into_native_parts() {
+                Ok((data, len, capacity)) => Ok(MutableBuffer {
+                    data,
+                    len,
+                    capacity,
+                }),
+                Err(bytes) => Err(Buffer {
+                    data: Arc::new(bytes),
+                    offset: 0,
+                }),
+            },
+            Err(data) => Err(Buffer { data, offset: 0 }),
+        }
+    }
+
     /// Returns a slice of this buffer starting at a certain bit offset.
     /// If the offset is byte-aligned the returned buffer is a shallow clone,
     /// otherwise a new buffer is allocated and filled with a copy of the bits in the range.
@@ -314,7 +339,7 @@ where
 
 /// Apply a bitwise operation `op` to two inputs and return the result as a Buffer.
 /// The inputs are treated as bitmaps, meaning that offsets and length are specified in number of bits.
-fn bitwise_bin_op_helper<F>(
+pub(crate) fn bitwise_bin_op_helper<F>(
     left: &Buffer,
     left_offset_in_bits: usize,
     right: &Buffer,
@@ -350,7 +375,7 @@ where
 
 /// Apply a bitwise operation `op` to one input and return the result as a Buffer.
 /// The input is treated as a bitmap, meaning that offset and length are specified in number of bits.
-fn bitwise_unary_op_helper<F>(
+pub(crate) fn bitwise_unary_op_helper<F>(
     left: &Buffer,
     offset_in_bits: usize,
     len_in_bits: usize,
@@ -597,6 +622,23 @@ pub(super) fn buffer_bin_or(
     )
 }
 
+pub(super) fn buffer_bin_xor(
+    left: &Buffer,
+    left_offset_in_bits: usize,
+    right: &Buffer,
+    right_offset_in_bits: usize,
+    len_in_bits: usize,
+) -> Buffer {
+    bitwise_bin_op_helper(
+        left,
+        left_offset_in_bits,
+        right,
+        right_offset_in_bits,
+        len_in_bits,
+        |a, b| a ^ b,
+    )
+}
+
 pub(super) fn buffer_unary_not(
     left: &Buffer,
     offset_in_bits: usize,
@@ -889,6 +931,25 @@ mod tests {
     use super::*;
     use crate::datatypes::ToByteSlice;
 
+    #[test]
+    fn test_buffer_into_mutable() {
+        let buf = Buffer::from(&[0, 1, 2, 3]);
+        let ptr = buf.raw_data();
+        let mut buf = buf.into_mutable().unwrap();
+        assert_eq!(ptr, buf.raw_data());
+        buf.data_mut()[0] = 4;
+        assert_eq!(Buffer::from(&[4, 1, 2, 3]), buf.freeze());
+
+        // shared or sliced buffers are returned unchanged
+        let buf = Buffer::from(&[0, 1, 2, 3]);
+        let shared = buf.clone();
+        let buf = buf.into_mutable().unwrap_err();
+        assert_eq!(shared, buf);
+        drop(shared);
+        assert!(buf.slice(1).into_mutable().is_err());
+        assert!(buf.into_mutable().is_ok());
+    }
+
     #[test]
     fn test_buffer_data_equality() {
         let buf1 = Buffer::from(&[0, 1, 2, 3, 4]);
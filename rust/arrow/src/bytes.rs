@@ -111,6 +111,21 @@ impl Bytes {
         self.ptr as *mut u8
     }
 
+    /// Gives up the ownership of this region and returns its pointer, length and
+    /// capacity, when it was allocated by this crate. Otherwise, returns this region.
+    pub(crate) fn into_native_parts(
+        self,
+    ) -> std::result::Result<(*mut u8, usize, usize), Self> {
+        match self.deallocation {
+            Deallocation::Native(capacity) => {
+                let parts = (self.ptr as *mut u8, self.len, capacity);
+                std::mem::forget(self);
+                Ok(parts)
+            }
+            Deallocation::Foreign(_) => Err(self),
+        }
+    }
+
     pub fn capacity(&self) -> usize {
         match self.deallocation {
             Deallocation::Native(capacity) => capacity,
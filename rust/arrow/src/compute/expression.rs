@@ -0,0 +1,363 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines a small expression tree that can be evaluated against a `RecordBatch`.
+//!
+//! Expressions are made of column references, literals, arithmetic, comparison and
+//! boolean operators, and casts. They are evaluated by invoking the kernels of a
+//! `KernelRegistry`; no type coercion is performed, so the operands of an operator must
+//! have the same data type, which can be achieved with `Expr::Cast`.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::*;
+//! use arrow::compute::expression::{binary, col, lit, Operator};
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::record_batch::RecordBatch;
+//! use arrow::scalar::Scalar;
+//!
+//! let schema = Schema::new(vec![
+//!     Field::new("a", DataType::Int32, false),
+//!     Field::new("b", DataType::Int32, true),
+//! ]);
+//! let batch = RecordBatch::try_new(
+//!     Arc::new(schema),
+//!     vec![
+//!         Arc::new(Int32Array::from(vec![1, 2, 3])),
+//!         Arc::new(Int32Array::from(vec![Some(10), None, Some(30)])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! // a + b > 15
+//! let expr = binary(
+//!     binary(col("a"), Operator::Plus, col("b")),
+//!     Operator::Gt,
+//!     lit(Scalar::Int32(Some(15))),
+//! );
+//! let result = expr.evaluate(&batch).unwrap();
+//! let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+//! assert_eq!(&BooleanArray::from(vec![Some(false), None, Some(true)]), result);
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+
+use crate::array::ArrayRef;
+use crate::compute::kernels::{boolean, cast};
+use crate::compute::registry::KernelRegistry;
+use crate::datatypes::DataType;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+use crate::scalar::Scalar;
+
+lazy_static! {
+    static ref BUILTINS: KernelRegistry = KernelRegistry::with_builtins();
+}
+
+/// A binary operator of an expression
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    /// `left + right`
+    Plus,
+    /// `left - right`
+    Minus,
+    /// `left * right`
+    Multiply,
+    /// `left / right`
+    Divide,
+    /// `left % right`
+    Modulo,
+    /// `left = right`
+    Eq,
+    /// `left != right`
+    NotEq,
+    /// `left < right`
+    Lt,
+    /// `left <= right`
+    LtEq,
+    /// `left > right`
+    Gt,
+    /// `left >= right`
+    GtEq,
+    /// `left AND right`
+    And,
+    /// `left OR right`
+    Or,
+}
+
+impl Operator {
+    /// Returns the name of the kernel implementing this operator in a `KernelRegistry`
+    pub fn kernel_name(&self) -> &'static str {
+        match self {
+            Operator::Plus => "add",
+            Operator::Minus => "subtract",
+            Operator::Multiply => "multiply",
+            Operator::Divide => "divide",
+            Operator::Modulo => "modulus",
+            Operator::Eq => "eq",
+            Operator::NotEq => "neq",
+            Operator::Lt => "lt",
+            Operator::LtEq => "lt_eq",
+            Operator::Gt => "gt",
+            Operator::GtEq => "gt_eq",
+            Operator::And => "and",
+            Operator::Or => "or",
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Operator::Plus => "+",
+            Operator::Minus => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Modulo => "%",
+            Operator::Eq => "=",
+            Operator::NotEq => "!=",
+            Operator::Lt => "<",
+            Operator::LtEq => "<=",
+            Operator::Gt => ">",
+            Operator::GtEq => ">=",
+            Operator::And => "AND",
+            Operator::Or => "OR",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// An expression that evaluates to an array of the number of rows of a `RecordBatch`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    /// The column of the batch with the given name
+    Column(String),
+    /// A value repeated for every row of the batch
+    Literal(Scalar),
+    /// A binary operator applied to two expressions of the same data type
+    BinaryExpr {
+        left: Box<Expr>,
+        op: Operator,
+        right: Box<Expr>,
+    },
+    /// The negation of a boolean expression
+    Not(Box<Expr>),
+    /// Whether the values of an expression are null
+    IsNull(Box<Expr>),
+    /// Whether the values of an expression are not null
+    IsNotNull(Box<Expr>),
+    /// An expression cast to the given data type
+    Cast {
+        expr: Box<Expr>,
+        data_type: DataType,
+    },
+}
+
+/// Returns a reference to the column `name`
+pub fn col(name: &str) -> Expr {
+    Expr::Column(name.to_string())
+}
+
+/// Returns a literal expression
+pub fn lit(value: Scalar) -> Expr {
+    Expr::Literal(value)
+}
+
+/// Returns the expression `left op right`
+pub fn binary(left: Expr, op: Operator, right: Expr) -> Expr {
+    Expr::BinaryExpr {
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    }
+}
+
+impl Expr {
+    /// Evaluates this expression against `batch` with the built-in kernels.
+    ///
+    /// This function errors if a column does not exist, or if no kernel exists for an
+    /// operator and the data types of its operands.
+    pub fn evaluate(&self, batch: &RecordBatch) -> Result<ArrayRef> {
+        self.evaluate_with(batch, &BUILTINS)
+    }
+
+    /// Evaluates this expression against `batch`, dispatching operators to the kernels
+    /// of `registry`.
+    pub fn evaluate_with(
+        &self,
+        batch: &RecordBatch,
+        registry: &KernelRegistry,
+    ) -> Result<ArrayRef> {
+        match self {
+            Expr::Column(name) => {
+                let index = batch.schema().index_of(name)?;
+                Ok(batch.column(index).clone())
+            }
+            Expr::Literal(value) => Ok(value.to_array_of_size(batch.num_rows())),
+            Expr::BinaryExpr { left, op, right } => {
+                let left = left.evaluate_with(batch, registry)?;
+                let right = right.evaluate_with(batch, registry)?;
+                registry.invoke(op.kernel_name(), &[left, right])
+            }
+            Expr::Not(expr) => {
+                let array = expr.evaluate_with(batch, registry)?;
+                registry.invoke("not", &[array])
+            }
+            Expr::IsNull(expr) => {
+                let array = expr.evaluate_with(batch, registry)?;
+                Ok(Arc::new(boolean::is_null(array.as_ref())?))
+            }
+            Expr::IsNotNull(expr) => {
+                let array = expr.evaluate_with(batch, registry)?;
+                Ok(Arc::new(boolean::is_not_null(array.as_ref())?))
+            }
+            Expr::Cast { expr, data_type } => {
+                let array = expr.evaluate_with(batch, registry)?;
+                cast::cast(&array, data_type)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Column(name) => write!(f, "#{}", name),
+            Expr::Literal(value) => write!(f, "{}", value),
+            Expr::BinaryExpr { left, op, right } => {
+                write!(f, "({} {} {})", left, op, right)
+            }
+            Expr::Not(expr) => write!(f, "NOT {}", expr),
+            Expr::IsNull(expr) => write!(f, "{} IS NULL", expr),
+            Expr::IsNotNull(expr) => write!(f, "{} IS NOT NULL", expr),
+            Expr::Cast { expr, data_type } => {
+                write!(f, "CAST({} AS {:?})", expr, data_type)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::datatypes::{Field, Schema};
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("i", DataType::Int64, true),
+            Field::new("f", DataType::Float64, false),
+            Field::new("s", DataType::Utf8, true),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(Float64Array::from(vec![0.5, 2.0, 4.0])),
+                Arc::new(StringArray::from(vec![Some("a"), Some("b"), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let batch = batch();
+
+        // CAST(i AS Float64) * 2.0 >= f OR s = 'b'
+        let expr = binary(
+            binary(
+                binary(
+                    Expr::Cast {
+                        expr: Box::new(col("i")),
+                        data_type: DataType::Float64,
+                    },
+                    Operator::Multiply,
+                    lit(Scalar::Float64(Some(2.0))),
+                ),
+                Operator::GtEq,
+                col("f"),
+            ),
+            Operator::Or,
+            binary(
+                col("s"),
+                Operator::Eq,
+                lit(Scalar::Utf8(Some("b".to_string()))),
+            ),
+        );
+        assert_eq!(
+            "(((CAST(#i AS Float64) * 2) >= #f) OR (#s = b))",
+            expr.to_string()
+        );
+        let result = expr.evaluate(&batch).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![Some(true), None, None]), result);
+
+        let expr = Expr::Not(Box::new(Expr::IsNull(Box::new(col("s")))));
+        let result = expr.evaluate(&batch).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![true, true, false]), result);
+
+        let expr = binary(col("i"), Operator::Minus, lit(Scalar::Int64(None)));
+        let result = expr.evaluate(&batch).unwrap();
+        assert_eq!(3, result.null_count());
+    }
+
+    #[test]
+    fn test_evaluate_errors() {
+        let batch = batch();
+        assert!(col("x").evaluate(&batch).is_err());
+        // no implicit coercion
+        let expr = binary(col("i"), Operator::Plus, col("f"));
+        assert!(expr.evaluate(&batch).is_err());
+        assert!(Expr::Not(Box::new(col("i"))).evaluate(&batch).is_err());
+    }
+
+    #[test]
+    fn test_evaluate_with_registry() {
+        let batch = batch();
+        let mut registry = KernelRegistry::with_builtins();
+        registry.register(
+            "add",
+            vec![DataType::Utf8, DataType::Utf8],
+            Arc::new(|args: &[ArrayRef]| {
+                let left = args[0].as_any().downcast_ref::<StringArray>().unwrap();
+                let right = args[1].as_any().downcast_ref::<StringArray>().unwrap();
+                let result = left
+                    .iter()
+                    .zip(right.iter())
+                    .map(|(l, r)| Some(format!("{}{}", l?, r?)))
+                    .collect::<StringArray>();
+                Ok(Arc::new(result) as ArrayRef)
+            }),
+        );
+
+        let expr = binary(col("s"), Operator::Plus, col("s"));
+        assert!(expr.evaluate(&batch).is_err());
+        let result = expr.evaluate_with(&batch, &registry).unwrap();
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            &StringArray::from(vec![Some("aa"), Some("bb"), None]),
+            result
+        );
+    }
+}
@@ -0,0 +1,877 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines hash-based grouped aggregation over a stream of `RecordBatch`es, as in SQL's
+//! `SELECT keys, aggregates FROM batches GROUP BY keys`.
+//!
+//! The rows of each batch are assigned to groups by hashing the values of their key
+//! columns, and the aggregates of each group are updated column by column. Null key
+//! values form their own group, as do all NaNs and both zeros of floating point keys.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::*;
+//! use arrow::compute::group_by::{AggregateFunction, GroupedAggregator};
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::record_batch::RecordBatch;
+//!
+//! let schema = Arc::new(Schema::new(vec![
+//!     Field::new("key", DataType::Utf8, false),
+//!     Field::new("value", DataType::Int32, true),
+//! ]));
+//! let batch = RecordBatch::try_new(
+//!     schema.clone(),
+//!     vec![
+//!         Arc::new(StringArray::from(vec!["a", "b", "a"])),
+//!         Arc::new(Int32Array::from(vec![Some(1), Some(2), Some(3)])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! let mut aggregator =
+//!     GroupedAggregator::try_new(schema, vec![0], vec![(1, AggregateFunction::Sum)])
+//!         .unwrap();
+//! aggregator.push(&batch).unwrap();
+//! let result = aggregator.finish().unwrap();
+//!
+//! assert_eq!("sum(value)", result.schema().field(1).name());
+//! let keys = result.column(0).as_typed::<StringArray>().unwrap();
+//! let sums = result.column(1).as_typed::<Int64Array>().unwrap();
+//! assert_eq!(("a", 4), (keys.value(0), sums.value(0)));
+//! assert_eq!(("b", 2), (keys.value(1), sums.value(1)));
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Add;
+use std::sync::Arc;
+
+use num::ToPrimitive;
+
+use crate::array::*;
+use crate::compute::kernels::aggregate::for_each_value_bytes;
+use crate::compute::kernels::concat::concat;
+use crate::compute::kernels::take::take;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+/// An aggregate function computed for each group by a `GroupedAggregator`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AggregateFunction {
+    /// The number of non-null values, as `UInt64`
+    Count,
+    /// The sum of the values, as `Int64`, `UInt64` or `Float64` for signed integer,
+    /// unsigned integer and floating point values respectively
+    Sum,
+    /// The minimum value, of the data type of the values
+    Min,
+    /// The maximum value, of the data type of the values
+    Max,
+    /// The arithmetic mean of the values, as `Float64`
+    Mean,
+}
+
+impl fmt::Display for AggregateFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            AggregateFunction::Count => "count",
+            AggregateFunction::Sum => "sum",
+            AggregateFunction::Min => "min",
+            AggregateFunction::Max => "max",
+            AggregateFunction::Mean => "mean",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The state of an aggregate function for each group
+trait GroupsAccumulator {
+    /// Updates the states of the groups with `values`, where `groups[i]` is the group of
+    /// the i-th value, and `num_groups` the number of groups seen so far.
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<()>;
+
+    /// Returns the aggregated value of each group
+    fn finish(&mut self) -> ArrayRef;
+}
+
+#[derive(Default)]
+struct CountAccumulator {
+    counts: Vec<u64>,
+}
+
+impl GroupsAccumulator for CountAccumulator {
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<()> {
+        self.counts.resize(num_groups, 0);
+        for (i, group) in groups.iter().enumerate() {
+            if values.is_valid(i) {
+                self.counts[*group] += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(UInt64Array::from(std::mem::take(&mut self.counts)))
+    }
+}
+
+/// Sums values of type `T` into values of type `R`
+struct SumAccumulator<T: ArrowPrimitiveType, R: ArrowPrimitiveType> {
+    sums: Vec<Option<R::Native>>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: ArrowPrimitiveType, R: ArrowPrimitiveType> SumAccumulator<T, R> {
+    fn new() -> Self {
+        Self {
+            sums: vec![],
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, R> GroupsAccumulator for SumAccumulator<T, R>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: Into<R::Native>,
+    R::Native: Add<Output = R::Native>,
+{
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<()> {
+        let values = values.as_typed::<PrimitiveArray<T>>()?;
+        self.sums.resize(num_groups, None);
+        for (i, group) in groups.iter().enumerate() {
+            if values.is_valid(i) {
+                let sum = self.sums[*group].unwrap_or_else(R::default_value);
+                self.sums[*group] = Some(sum + values.value(i).into());
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        Arc::new(
+            std::mem::take(&mut self.sums)
+                .into_iter()
+                .collect::<PrimitiveArray<R>>(),
+        )
+    }
+}
+
+struct MinMaxAccumulator<T: ArrowPrimitiveType> {
+    values: Vec<Option<T::Native>>,
+    is_min: bool,
+    /// The data type of the values, which can have a timezone unlike `T::DATA_TYPE`
+    data_type: DataType,
+}
+
+impl<T: ArrowPrimitiveType> MinMaxAccumulator<T> {
+    fn new(is_min: bool, data_type: &DataType) -> Self {
+        Self {
+            values: vec![],
+            is_min,
+            data_type: data_type.clone(),
+        }
+    }
+}
+
+impl<T> GroupsAccumulator for MinMaxAccumulator<T>
+where
+    T: ArrowNumericType,
+{
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<()> {
+        let values = values.as_typed::<PrimitiveArray<T>>()?;
+        self.values.resize(num_groups, None);
+        for (i, group) in groups.iter().enumerate() {
+            if values.is_valid(i) {
+                let value = values.value(i);
+                let replace = match self.values[*group] {
+                    None => true,
+                    Some(current) if self.is_min => value < current,
+                    Some(current) => value > current,
+                };
+                if replace {
+                    self.values[*group] = Some(value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        let array = std::mem::take(&mut self.values)
+            .into_iter()
+            .collect::<PrimitiveArray<T>>();
+        let data = array.data();
+        make_array(Arc::new(ArrayData::new(
+            self.data_type.clone(),
+            data.len(),
+            Some(data.null_count()),
+            data.null_buffer().cloned(),
+            data.offset(),
+            data.buffers().to_vec(),
+            vec![],
+        )))
+    }
+}
+
+struct MeanAccumulator<T: ArrowPrimitiveType> {
+    sums: Vec<f64>,
+    counts: Vec<u64>,
+    phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: ArrowPrimitiveType> MeanAccumulator<T> {
+    fn new() -> Self {
+        Self {
+            sums: vec![],
+            counts: vec![],
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T> GroupsAccumulator for MeanAccumulator<T>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    fn update(
+        &mut self,
+        values: &ArrayRef,
+        groups: &[usize],
+        num_groups: usize,
+    ) -> Result<()> {
+        let values = values.as_typed::<PrimitiveArray<T>>()?;
+        self.sums.resize(num_groups, 0.0);
+        self.counts.resize(num_groups, 0);
+        for (i, group) in groups.iter().enumerate() {
+            if values.is_valid(i) {
+                self.sums[*group] += values.value(i).to_f64().unwrap_or(f64::NAN);
+                self.counts[*group] += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        let means = self
+            .sums
+            .iter()
+            .zip(self.counts.iter())
+            .map(|(sum, count)| {
+                if *count == 0 {
+                    None
+                } else {
+                    Some(sum / *count as f64)
+                }
+            })
+            .collect::<Float64Array>();
+        self.sums.clear();
+        self.counts.clear();
+        Arc::new(means)
+    }
+}
+
+/// Returns the data type of the aggregate `function` of values of `data_type`, and an
+/// accumulator to compute it, or an error if it is not supported for the data type.
+fn create_accumulator(
+    function: AggregateFunction,
+    data_type: &DataType,
+) -> Result<(DataType, Box<dyn GroupsAccumulator>)> {
+    macro_rules! sum {
+        ($t:ty, $r:ty) => {
+            (
+                <$r>::DATA_TYPE,
+                Box::new(SumAccumulator::<$t, $r>::new()) as Box<dyn GroupsAccumulator>,
+            )
+        };
+    }
+    macro_rules! min_max {
+        ($t:ty, $is_min:expr) => {
+            (
+                data_type.clone(),
+                Box::new(MinMaxAccumulator::<$t>::new($is_min, data_type))
+                    as Box<dyn GroupsAccumulator>,
+            )
+        };
+    }
+    macro_rules! mean {
+        ($t:ty) => {
+            (
+                DataType::Float64,
+                Box::new(MeanAccumulator::<$t>::new()) as Box<dyn GroupsAccumulator>,
+            )
+        };
+    }
+
+    use AggregateFunction::*;
+    Ok(match (function, data_type) {
+        (Count, _) => (
+            DataType::UInt64,
+            Box::new(CountAccumulator::default()) as Box<dyn GroupsAccumulator>,
+        ),
+        (Sum, DataType::Int8) => sum!(Int8Type, Int64Type),
+        (Sum, DataType::Int16) => sum!(Int16Type, Int64Type),
+        (Sum, DataType::Int32) => sum!(Int32Type, Int64Type),
+        (Sum, DataType::Int64) => sum!(Int64Type, Int64Type),
+        (Sum, DataType::UInt8) => sum!(UInt8Type, UInt64Type),
+        (Sum, DataType::UInt16) => sum!(UInt16Type, UInt64Type),
+        (Sum, DataType::UInt32) => sum!(UInt32Type, UInt64Type),
+        (Sum, DataType::UInt64) => sum!(UInt64Type, UInt64Type),
+        (Sum, DataType::Float32) => sum!(Float32Type, Float64Type),
+        (Sum, DataType::Float64) => sum!(Float64Type, Float64Type),
+        (Min, _) | (Max, _) => {
+            let is_min = function == Min;
+            match data_type {
+                DataType::Int8 => min_max!(Int8Type, is_min),
+                DataType::Int16 => min_max!(Int16Type, is_min),
+                DataType::Int32 => min_max!(Int32Type, is_min),
+                DataType::Int64 => min_max!(Int64Type, is_min),
+                DataType::UInt8 => min_max!(UInt8Type, is_min),
+                DataType::UInt16 => min_max!(UInt16Type, is_min),
+                DataType::UInt32 => min_max!(UInt32Type, is_min),
+                DataType::UInt64 => min_max!(UInt64Type, is_min),
+                DataType::Float32 => min_max!(Float32Type, is_min),
+                DataType::Float64 => min_max!(Float64Type, is_min),
+                DataType::Date32(_) => min_max!(Date32Type, is_min),
+                DataType::Date64(_) => min_max!(Date64Type, is_min),
+                DataType::Timestamp(TimeUnit::Second, _) => {
+                    min_max!(TimestampSecondType, is_min)
+                }
+                DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                    min_max!(TimestampMillisecondType, is_min)
+                }
+                DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                    min_max!(TimestampMicrosecondType, is_min)
+                }
+                DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                    min_max!(TimestampNanosecondType, is_min)
+                }
+                t => {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Aggregate {} not supported for data type {:?}",
+                        function, t
+                    )))
+                }
+            }
+        }
+        (Mean, DataType::Int8) => mean!(Int8Type),
+        (Mean, DataType::Int16) => mean!(Int16Type),
+        (Mean, DataType::Int32) => mean!(Int32Type),
+        (Mean, DataType::Int64) => mean!(Int64Type),
+        (Mean, DataType::UInt8) => mean!(UInt8Type),
+        (Mean, DataType::UInt16) => mean!(UInt16Type),
+        (Mean, DataType::UInt32) => mean!(UInt32Type),
+        (Mean, DataType::UInt64) => mean!(UInt64Type),
+        (Mean, DataType::Float32) => mean!(Float32Type),
+        (Mean, DataType::Float64) => mean!(Float64Type),
+        (_, t) => {
+            return Err(ArrowError::ComputeError(format!(
+                "Aggregate {} not supported for data type {:?}",
+                function, t
+            )))
+        }
+    })
+}
+
+/// Aggregates the rows of a stream of batches by the values of their key columns.
+///
+/// The batches are pushed one by one, and the result, with one row per group, is
+/// returned by `finish`. Its columns are the key columns, followed by one column per
+/// aggregate named after the function and the aggregated column, e.g. `sum(value)`. The
+/// groups are ordered by their first row in the stream.
+pub struct GroupedAggregator {
+    input_schema: SchemaRef,
+    schema: SchemaRef,
+    keys: Vec<usize>,
+    aggregates: Vec<(usize, Box<dyn GroupsAccumulator>)>,
+    /// The index of each group, by the encoded values of its keys
+    groups: HashMap<Vec<u8>, usize>,
+    /// The key values of the groups, in order, as chunks of arrays for each key column
+    key_chunks: Vec<Vec<ArrayRef>>,
+}
+
+impl fmt::Debug for GroupedAggregator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupedAggregator")
+            .field("schema", &self.schema)
+            .field("keys", &self.keys)
+            .field("num_groups", &self.groups.len())
+            .finish()
+    }
+}
+
+impl GroupedAggregator {
+    /// Creates an aggregator of batches of `input_schema`, grouping their rows by the
+    /// columns of the indices `keys`, and computing the given aggregate functions of the
+    /// columns of the given indices.
+    ///
+    /// This function errors if an index is out of bounds, if a key column's data type
+    /// cannot be hashed, or if an aggregate function does not support the data type of
+    /// its column.
+    pub fn try_new(
+        input_schema: SchemaRef,
+        keys: Vec<usize>,
+        aggregates: Vec<(usize, AggregateFunction)>,
+    ) -> Result<Self> {
+        let num_columns = input_schema.fields().len();
+        let field = |index: usize| {
+            if index < num_columns {
+                Ok(input_schema.field(index))
+            } else {
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "Column index {} out of bounds for schema of {} columns",
+                    index, num_columns
+                )))
+            }
+        };
+
+        let mut fields = vec![];
+        for key in &keys {
+            let field = field(*key)?;
+            // check that the key values can be hashed
+            for_each_value_bytes(&new_empty_array(field.data_type()), &mut |_, _| {})?;
+            fields.push(field.clone());
+        }
+
+        let mut accumulators = vec![];
+        for (index, function) in aggregates {
+            let field = field(index)?;
+            let (data_type, accumulator) =
+                create_accumulator(function, field.data_type())?;
+            fields.push(Field::new(
+                &format!("{}({})", function, field.name()),
+                data_type,
+                function != AggregateFunction::Count,
+            ));
+            accumulators.push((index, accumulator));
+        }
+
+        Ok(Self {
+            key_chunks: vec![vec![]; keys.len()],
+            input_schema,
+            schema: Arc::new(Schema::new(fields)),
+            keys,
+            aggregates: accumulators,
+            groups: HashMap::new(),
+        })
+    }
+
+    /// Returns the schema of the aggregated batch
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of groups seen so far
+    pub fn num_groups(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Assigns the rows of `batch` to their groups and updates the aggregates of these
+    /// groups.
+    ///
+    /// Returns an error if the schema of `batch` differs from the input schema of this
+    /// aggregator.
+    pub fn push(&mut self, batch: &RecordBatch) -> Result<()> {
+        if batch.schema() != self.input_schema {
+            return Err(ArrowError::InvalidArgumentError(
+                "GroupedAggregator requires batches of the same schema".to_string(),
+            ));
+        }
+
+        let num_rows = batch.num_rows();
+        let mut key_values = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            let mut values: Vec<Option<&[u8]>> = vec![None; num_rows];
+            for_each_value_bytes(batch.column(*key), &mut |i, bytes| {
+                values[i] = Some(bytes)
+            })?;
+            key_values.push(values);
+        }
+
+        // each key value is encoded as a validity byte, followed by the length and the
+        // bytes of valid values, so that the encoded keys are equal if and only if the
+        // key values are
+        let mut key = vec![];
+        let mut groups = Vec::with_capacity(num_rows);
+        let mut new_group_rows = vec![];
+        for row in 0..num_rows {
+            key.clear();
+            for values in &key_values {
+                match values[row] {
+                    None => key.push(0),
+                    Some(bytes) => {
+                        key.push(1);
+                        key.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+                        key.extend_from_slice(bytes);
+                    }
+                }
+            }
+            let group = match self.groups.get(&key) {
+                Some(group) => *group,
+                None => {
+                    let group = self.groups.len();
+                    self.groups.insert(key.clone(), group);
+                    new_group_rows.push(row as u32);
+                    group
+                }
+            };
+            groups.push(group);
+        }
+
+        if !new_group_rows.is_empty() {
+            let indices = UInt32Array::from(new_group_rows);
+            for (key, chunks) in self.keys.iter().zip(self.key_chunks.iter_mut()) {
+                chunks.push(take(batch.column(*key), &indices, None)?);
+            }
+        }
+
+        let num_groups = self.groups.len();
+        for (index, accumulator) in self.aggregates.iter_mut() {
+            accumulator.update(batch.column(*index), &groups, num_groups)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a batch of the keys and aggregates of each group, which has no rows if no
+    /// rows were pushed.
+    pub fn finish(mut self) -> Result<RecordBatch> {
+        let mut columns = vec![];
+        for (chunks, field) in self.key_chunks.iter().zip(self.schema.fields()) {
+            columns.push(if chunks.is_empty() {
+                new_empty_array(field.data_type())
+            } else {
+                let chunks: Vec<&Array> = chunks.iter().map(|c| c.as_ref()).collect();
+                concat(&chunks)?
+            });
+        }
+        let num_groups = self.groups.len();
+        for (_, accumulator) in self.aggregates.iter_mut() {
+            let column = accumulator.finish();
+            // accumulators only know of the groups seen when they were last updated
+            debug_assert_eq!(num_groups, column.len());
+            columns.push(column);
+        }
+        RecordBatch::try_new(self.schema, columns)
+    }
+}
+
+/// Aggregates all the batches of `reader` by the values of the key columns of the indices
+/// `keys`. See `GroupedAggregator` for the aggregates and the result.
+pub fn group_by<R: RecordBatchReader>(
+    reader: R,
+    keys: Vec<usize>,
+    aggregates: Vec<(usize, AggregateFunction)>,
+) -> Result<RecordBatch> {
+    let mut aggregator = GroupedAggregator::try_new(reader.schema(), keys, aggregates)?;
+    for batch in reader {
+        aggregator.push(&batch?)?;
+    }
+    aggregator.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use AggregateFunction::*;
+
+    /// A reader over batches held in memory
+    struct MemoryReader {
+        schema: SchemaRef,
+        batches: std::vec::IntoIter<RecordBatch>,
+    }
+
+    impl Iterator for MemoryReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.batches.next().map(Ok)
+        }
+    }
+
+    impl RecordBatchReader for MemoryReader {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Utf8, true),
+            Field::new("n", DataType::Int32, true),
+            Field::new("x", DataType::Float64, true),
+        ]))
+    }
+
+    fn batch(
+        k: Vec<Option<&str>>,
+        n: Vec<Option<i32>>,
+        x: Vec<Option<f64>>,
+    ) -> RecordBatch {
+        RecordBatch::try_new(
+            schema(),
+            vec![
+                Arc::new(StringArray::from(k)),
+                Arc::new(Int32Array::from(n)),
+                Arc::new(Float64Array::from(x)),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_group_by() {
+        let batches = vec![
+            batch(
+                vec![Some("a"), Some("b"), None, Some("a")],
+                vec![Some(1), None, Some(3), Some(4)],
+                vec![Some(0.5), Some(1.5), None, Some(2.5)],
+            ),
+            batch(vec![], vec![], vec![]),
+            batch(
+                vec![Some("c"), None, Some("a")],
+                vec![Some(i32::MAX), Some(6), Some(i32::MAX)],
+                vec![None, Some(-1.0), Some(3.5)],
+            ),
+        ];
+        let reader = MemoryReader {
+            schema: schema(),
+            batches: batches.into_iter(),
+        };
+        let aggregates = vec![(1, Count), (1, Sum), (1, Min), (2, Max), (2, Mean)];
+        let result = group_by(reader, vec![0], aggregates).unwrap();
+
+        let schema = result.schema();
+        let names: Vec<&str> =
+            schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(
+            vec!["k", "count(n)", "sum(n)", "min(n)", "max(x)", "mean(x)"],
+            names
+        );
+        assert_eq!(
+            &StringArray::from(vec![Some("a"), Some("b"), None, Some("c")]),
+            result.column(0).as_typed::<StringArray>().unwrap()
+        );
+        assert_eq!(
+            &UInt64Array::from(vec![3, 0, 2, 1]),
+            result.column(1).as_typed::<UInt64Array>().unwrap()
+        );
+        // sums do not overflow the type of the values
+        assert_eq!(
+            &Int64Array::from(vec![
+                Some(5 + i32::MAX as i64),
+                None,
+                Some(9),
+                Some(i32::MAX as i64)
+            ]),
+            result.column(2).as_typed::<Int64Array>().unwrap()
+        );
+        assert_eq!(
+            &Int32Array::from(vec![Some(1), None, Some(3), Some(i32::MAX)]),
+            result.column(3).as_typed::<Int32Array>().unwrap()
+        );
+        assert_eq!(
+            &Float64Array::from(vec![Some(3.5), Some(1.5), Some(-1.0), None]),
+            result.column(4).as_typed::<Float64Array>().unwrap()
+        );
+        assert_eq!(
+            &Float64Array::from(vec![Some(6.5 / 3.0), Some(1.5), Some(-1.0), None]),
+            result.column(5).as_typed::<Float64Array>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_group_by_multiple_keys() {
+        let b = batch(
+            vec![Some("a"), Some("a"), Some("a"), None, Some("a"), None],
+            vec![Some(1), Some(2), Some(1), Some(1), None, Some(1)],
+            vec![
+                Some(0.0),
+                Some(-0.0),
+                Some(-0.0),
+                Some(f64::NAN),
+                None,
+                Some(1.0),
+            ],
+        );
+        let mut aggregator =
+            GroupedAggregator::try_new(schema(), vec![0, 1], vec![(2, Count)]).unwrap();
+        aggregator.push(&b).unwrap();
+        assert_eq!(4, aggregator.num_groups());
+        let result = aggregator.finish().unwrap();
+        assert_eq!(
+            &StringArray::from(vec![Some("a"), Some("a"), None, Some("a")]),
+            result.column(0).as_typed::<StringArray>().unwrap()
+        );
+        assert_eq!(
+            &Int32Array::from(vec![Some(1), Some(2), Some(1), None]),
+            result.column(1).as_typed::<Int32Array>().unwrap()
+        );
+        assert_eq!(
+            &UInt64Array::from(vec![2, 1, 2, 0]),
+            result.column(2).as_typed::<UInt64Array>().unwrap()
+        );
+
+        // floating point keys, where both zeros are equal, and so are all NaNs
+        let mut aggregator =
+            GroupedAggregator::try_new(schema(), vec![2], vec![(1, Sum)]).unwrap();
+        aggregator.push(&b).unwrap();
+        aggregator
+            .push(&batch(vec![None], vec![Some(1)], vec![Some(-f64::NAN)]))
+            .unwrap();
+        let result = aggregator.finish().unwrap();
+        let keys = result.column(0).as_typed::<Float64Array>().unwrap();
+        assert_eq!(4, keys.len());
+        assert_eq!(0.0, keys.value(0));
+        assert!(keys.value(1).is_nan());
+        assert!(keys.is_null(2));
+        assert_eq!(
+            &Int64Array::from(vec![Some(4), Some(2), None, Some(1)]),
+            result.column(1).as_typed::<Int64Array>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_group_by_without_keys_or_rows() {
+        let b = batch(
+            vec![Some("a"), Some("b")],
+            vec![Some(1), Some(2)],
+            vec![None, None],
+        );
+        let mut aggregator = GroupedAggregator::try_new(
+            schema(),
+            vec![],
+            vec![(1, Sum), (2, Mean), (0, Count)],
+        )
+        .unwrap();
+        aggregator.push(&b).unwrap();
+        let result = aggregator.finish().unwrap();
+        assert_eq!(1, result.num_rows());
+        assert_eq!(
+            3,
+            result.column(0).as_typed::<Int64Array>().unwrap().value(0)
+        );
+        assert!(result.column(1).is_null(0));
+        assert_eq!(
+            2,
+            result.column(2).as_typed::<UInt64Array>().unwrap().value(0)
+        );
+
+        let aggregator =
+            GroupedAggregator::try_new(schema(), vec![0], vec![(2, Min)]).unwrap();
+        let result = aggregator.finish().unwrap();
+        assert_eq!(0, result.num_rows());
+        assert_eq!(&DataType::Float64, result.column(1).data_type());
+    }
+
+    #[test]
+    fn test_group_by_timestamp_min_max() {
+        let tz = Some("+02:00".to_string());
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Boolean, false),
+            Field::new(
+                "t",
+                DataType::Timestamp(TimeUnit::Millisecond, tz.clone()),
+                true,
+            ),
+        ]));
+        let b = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(BooleanArray::from(vec![true, false, true])),
+                Arc::new(TimestampMillisecondArray::from_vec(
+                    vec![5, 7, 3],
+                    tz.clone(),
+                )),
+            ],
+        )
+        .unwrap();
+        let mut aggregator =
+            GroupedAggregator::try_new(schema, vec![0], vec![(1, Min), (1, Max)])
+                .unwrap();
+        aggregator.push(&b).unwrap();
+        let result = aggregator.finish().unwrap();
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Millisecond, tz),
+            result.schema().field(1).data_type()
+        );
+        let min = result
+            .column(1)
+            .as_typed::<TimestampMillisecondArray>()
+            .unwrap();
+        let max = result
+            .column(2)
+            .as_typed::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!((3, 5), (min.value(0), max.value(0)));
+        assert_eq!((7, 7), (min.value(1), max.value(1)));
+    }
+
+    #[test]
+    fn test_group_by_errors() {
+        assert!(GroupedAggregator::try_new(schema(), vec![3], vec![]).is_err());
+        assert!(GroupedAggregator::try_new(schema(), vec![0], vec![(3, Count)]).is_err());
+        assert!(GroupedAggregator::try_new(schema(), vec![0], vec![(0, Sum)]).is_err());
+        assert!(GroupedAggregator::try_new(schema(), vec![0], vec![(0, Mean)]).is_err());
+
+        let list_schema = Arc::new(Schema::new(vec![Field::new(
+            "l",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        )]));
+        assert!(
+            GroupedAggregator::try_new(list_schema.clone(), vec![0], vec![]).is_err()
+        );
+        assert!(
+            GroupedAggregator::try_new(list_schema, vec![], vec![(0, Count)]).is_ok()
+        );
+
+        let mut aggregator =
+            GroupedAggregator::try_new(schema(), vec![0], vec![(1, Sum)]).unwrap();
+        let other = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("k", DataType::Utf8, true)])),
+            vec![Arc::new(StringArray::from(vec!["a"]))],
+        )
+        .unwrap();
+        assert!(aggregator.push(&other).is_err());
+    }
+}
@@ -17,14 +17,32 @@
 
 //! Defines aggregations over Arrow arrays.
 
-use std::ops::Add;
-
-use crate::array::{Array, GenericStringArray, PrimitiveArray, StringOffsetSizeTrait};
-use crate::datatypes::{ArrowNativeType, ArrowNumericType};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::convert::TryInto;
+use std::ops::{Add, BitAnd, BitOr, BitXor};
+use std::sync::Arc;
+
+use num::{CheckedAdd, CheckedMul, Float, One, ToPrimitive};
+
+use super::hll;
+use super::sort::SortOptions;
+use crate::array::{
+    build_compare, Array, ArrayRef, BinaryArray, BooleanArray, FixedSizeBinaryArray,
+    Float32Array, Float64Array, GenericStringArray, Int16Array, Int32Array, Int64Array,
+    Int8Array, LargeBinaryArray, PrimitiveArray, StringArray, StringOffsetSizeTrait,
+    UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+use crate::buffer::{buffer_bin_and, Buffer};
+use crate::compute::util::combine_option_buffers;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::scalar::Scalar;
 
 /// Generic test for NaN, the optimizer should be able to remove this for integer types.
 #[inline]
-fn is_nan<T: ArrowNativeType + PartialOrd + Copy>(a: T) -> bool {
+pub(super) fn is_nan<T: ArrowNativeType + PartialOrd + Copy>(a: T) -> bool {
     #[allow(clippy::eq_op)]
     !(a == a)
 }
@@ -72,7 +90,7 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeType,
 {
-    min_max_helper(array, |a, b| (is_nan(*a) & !is_nan(*b)) || a > b)
+    fold_lanes(array, |v| v, min_of, min_of)
 }
 
 /// Returns the maximum value in the array, according to the natural order.
@@ -83,113 +101,1494 @@ where
     T: ArrowNumericType,
     T::Native: ArrowNativeType,
 {
-    min_max_helper(array, |a, b| (!is_nan(*a) & is_nan(*b)) || a < b)
+    fold_lanes(array, |v| v, max_of, max_of)
 }
 
 /// Returns the maximum value in the string array, according to the natural order.
+///
+/// Returns `None` if the array is empty or only contains null values.
 pub fn max_string<T: StringOffsetSizeTrait>(
     array: &GenericStringArray<T>,
 ) -> Option<&str> {
     min_max_string(array, |a, b| a < b)
 }
 
-/// Returns the minimum value in the string array, according to the natural order.
-pub fn min_string<T: StringOffsetSizeTrait>(
-    array: &GenericStringArray<T>,
-) -> Option<&str> {
-    min_max_string(array, |a, b| a > b)
+/// Returns the minimum value in the string array, according to the natural order.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn min_string<T: StringOffsetSizeTrait>(
+    array: &GenericStringArray<T>,
+) -> Option<&str> {
+    min_max_string(array, |a, b| a > b)
+}
+
+/// Returns whether any non-null slot of `array` is `value`, checking 64 slots at a time
+fn contains_valid(array: &BooleanArray, value: bool) -> bool {
+    let len = array.len();
+    let offset = array.offset();
+    let data = array.data_ref();
+    let values = data.buffers()[0].bit_chunks(offset, len);
+    // flips the values so that the slots equal to `value` are set
+    let flip = if value { 0 } else { u64::MAX };
+    match data.null_buffer() {
+        Some(nulls) => {
+            let nulls = nulls.bit_chunks(offset, len);
+            values
+                .iter()
+                .zip(nulls.iter())
+                .any(|(values, valid)| (values ^ flip) & valid != 0)
+                || (values.remainder_bits() ^ flip) & nulls.remainder_bits() != 0
+        }
+        None => {
+            let remainder_mask = (1u64 << values.remainder_len()) - 1;
+            values.iter().any(|values| values ^ flip != 0)
+                || (values.remainder_bits() ^ flip) & remainder_mask != 0
+        }
+    }
+}
+
+/// Returns whether all the non-null values of the boolean array are `true`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bool_and(array: &BooleanArray) -> Option<bool> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+    Some(!contains_valid(array, false))
+}
+
+/// Returns whether any of the non-null values of the boolean array is `true`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bool_or(array: &BooleanArray) -> Option<bool> {
+    if array.null_count() == array.len() {
+        return None;
+    }
+    Some(contains_valid(array, true))
+}
+
+/// Returns the minimum value in the boolean array, where `false` is smaller than `true`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn min_boolean(array: &BooleanArray) -> Option<bool> {
+    bool_and(array)
+}
+
+/// Returns the maximum value in the boolean array, where `false` is smaller than `true`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
+    bool_or(array)
+}
+
+/// Helper function to compute both the minimum and the maximum of a sequence of values
+/// in a single pass, according to the strict ordering `lt`.
+fn min_max_values<T, I, F>(mut values: I, lt: F) -> Option<(T, T)>
+where
+    T: Copy,
+    I: Iterator<Item = T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let first = values.next()?;
+    Some(values.fold((first, first), |(min, max), item| {
+        (
+            if lt(&item, &min) { item } else { min },
+            if lt(&max, &item) { item } else { max },
+        )
+    }))
+}
+
+/// Returns both the minimum and the maximum value of a numeric array in a single pass.
+/// For floating point arrays any NaN values are considered to be greater than any other
+/// non-null value.
+fn min_max_primitive<T>(array: &PrimitiveArray<T>) -> Option<(T::Native, T::Native)>
+where
+    T: ArrowNumericType,
+{
+    fold_lanes(
+        array,
+        |v| (v, v),
+        |(min, max), v| (min_of(min, v), max_of(max, v)),
+        |(min, max), (other_min, other_max)| {
+            (min_of(min, other_min), max_of(max, other_max))
+        },
+    )
+}
+
+/// Returns both the minimum and the maximum value of a string array in a single pass.
+fn min_max_str<T: StringOffsetSizeTrait>(
+    array: &GenericStringArray<T>,
+) -> Option<(&str, &str)> {
+    min_max_values(
+        (0..array.len())
+            .filter(|i| array.is_valid(*i))
+            .map(|i| array.value(i)),
+        |a, b| a < b,
+    )
+}
+
+/// Returns both the minimum and the maximum value of the array, in a single pass.
+///
+/// The values are returned as [`Scalars`](crate::scalar::Scalar) of the array's data
+/// type, which are null if the array is empty or only contains null values. The ordering
+/// is the same as the one of the specialized kernels such as [`min`] or [`min_string`].
+///
+/// Returns an `ArrowError::ComputeError(String)` if the data type of the array is not
+/// supported.
+pub fn min_max(array: &ArrayRef) -> Result<(Scalar, Scalar)> {
+    macro_rules! min_max_typed {
+        ($array_type:ty, $scalar:ident, $min_max:expr) => {{
+            let array = array.as_typed::<$array_type>()?;
+            let (min, max) = match $min_max(array) {
+                Some((min, max)) => (Some(min.into()), Some(max.into())),
+                None => (None, None),
+            };
+            (Scalar::$scalar(min), Scalar::$scalar(max))
+        }};
+    }
+
+    Ok(match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_typed::<BooleanArray>()?;
+            (
+                Scalar::Boolean(min_boolean(array)),
+                Scalar::Boolean(max_boolean(array)),
+            )
+        }
+        DataType::Int8 => {
+            min_max_typed!(PrimitiveArray<Int8Type>, Int8, min_max_primitive)
+        }
+        DataType::Int16 => {
+            min_max_typed!(PrimitiveArray<Int16Type>, Int16, min_max_primitive)
+        }
+        DataType::Int32 => {
+            min_max_typed!(PrimitiveArray<Int32Type>, Int32, min_max_primitive)
+        }
+        DataType::Int64 => {
+            min_max_typed!(PrimitiveArray<Int64Type>, Int64, min_max_primitive)
+        }
+        DataType::UInt8 => {
+            min_max_typed!(PrimitiveArray<UInt8Type>, UInt8, min_max_primitive)
+        }
+        DataType::UInt16 => {
+            min_max_typed!(PrimitiveArray<UInt16Type>, UInt16, min_max_primitive)
+        }
+        DataType::UInt32 => {
+            min_max_typed!(PrimitiveArray<UInt32Type>, UInt32, min_max_primitive)
+        }
+        DataType::UInt64 => {
+            min_max_typed!(PrimitiveArray<UInt64Type>, UInt64, min_max_primitive)
+        }
+        DataType::Float32 => {
+            min_max_typed!(PrimitiveArray<Float32Type>, Float32, min_max_primitive)
+        }
+        DataType::Float64 => {
+            min_max_typed!(PrimitiveArray<Float64Type>, Float64, min_max_primitive)
+        }
+        DataType::Date32(_) => {
+            min_max_typed!(PrimitiveArray<Date32Type>, Date32, min_max_primitive)
+        }
+        DataType::Date64(_) => {
+            min_max_typed!(PrimitiveArray<Date64Type>, Date64, min_max_primitive)
+        }
+        DataType::Utf8 => min_max_typed!(GenericStringArray<i32>, Utf8, min_max_str),
+        DataType::LargeUtf8 => {
+            min_max_typed!(GenericStringArray<i64>, LargeUtf8, min_max_str)
+        }
+        t => {
+            return Err(ArrowError::ComputeError(format!(
+                "min_max not supported for data type {:?}",
+                t
+            )))
+        }
+    })
+}
+
+/// Returns the index of the row of `order_by` that sorts first according to `options`,
+/// or last if `last` is set, in a single scan. Among equal rows, the first one is
+/// returned, or the last one if `last` is set, as a stable sort would order them.
+fn extreme_row(
+    values: &ArrayRef,
+    order_by: &ArrayRef,
+    options: Option<SortOptions>,
+    last: bool,
+) -> Result<Option<usize>> {
+    if values.len() != order_by.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot order values by an array of different length".to_string(),
+        ));
+    }
+    if order_by.is_empty() {
+        return Ok(None);
+    }
+
+    let options = options.unwrap_or_default();
+    let comparator = build_compare(order_by.as_ref(), order_by.as_ref())?;
+    let cmp = |a: usize, b: usize| {
+        let order = match (order_by.is_valid(a), order_by.is_valid(b)) {
+            (true, true) if options.descending => comparator(a, b).reverse(),
+            (true, true) => comparator(a, b),
+            (false, false) => Ordering::Equal,
+            (false, true) if options.nulls_first => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (true, false) if options.nulls_first => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        };
+        if last {
+            order.reverse()
+        } else {
+            order
+        }
+    };
+
+    let mut extreme = 0;
+    for i in 1..order_by.len() {
+        let order = cmp(i, extreme);
+        if order == Ordering::Less || (last && order == Ordering::Equal) {
+            extreme = i;
+        }
+    }
+    Ok(Some(extreme))
+}
+
+/// Returns the value of `values` in the row that sorts first when ordering the rows by
+/// `order_by` with `options`, as in SQL's `FIRST_VALUE(values ORDER BY order_by)`,
+/// without sorting. The value is null if `values` is empty, or null in that row.
+///
+/// This function errors if the arrays have different lengths, if `order_by` cannot be
+/// sorted, or if the data type of `values` is not supported by `Scalar`.
+pub fn first(
+    values: &ArrayRef,
+    order_by: &ArrayRef,
+    options: Option<SortOptions>,
+) -> Result<Scalar> {
+    match extreme_row(values, order_by, options, false)? {
+        Some(row) => Scalar::try_from_array(values, row),
+        None => Scalar::new_null(values.data_type()),
+    }
+}
+
+/// Returns the value of `values` in the row that sorts last when ordering the rows by
+/// `order_by` with `options`, as in SQL's `LAST_VALUE(values ORDER BY order_by)`. See
+/// [`first`] for the null values and the errors.
+pub fn last(
+    values: &ArrayRef,
+    order_by: &ArrayRef,
+    options: Option<SortOptions>,
+) -> Result<Scalar> {
+    match extreme_row(values, order_by, options, true)? {
+        Some(row) => Scalar::try_from_array(values, row),
+        None => Scalar::new_null(values.data_type()),
+    }
+}
+
+/// The number of independent accumulators of [`fold_lanes`], whose updates the compiler
+/// can then vectorize
+const FOLD_LANES: usize = 16;
+
+/// Folds the non-null values of a numeric array into one accumulator per lane, which are
+/// then merged, or returns `None` if the array is empty or only contains null values.
+///
+/// `init` returns the accumulator of a single value, and all lanes start from the first
+/// non-null value, so `update` and `merge` must be idempotent, as min and max are. When
+/// the array has nulls, the validity bitmap masks the lanes 64 values at a time, so
+/// neither path branches per value.
+fn fold_lanes<T, A, I, U, M>(
+    array: &PrimitiveArray<T>,
+    init: I,
+    update: U,
+    merge: M,
+) -> Option<A>
+where
+    T: ArrowNumericType,
+    A: Copy,
+    I: Fn(T::Native) -> A,
+    U: Fn(A, T::Native) -> A,
+    M: Fn(A, A) -> A,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return None;
+    }
+
+    let values = array.value_slice(0, array.len());
+    let first = (0..array.len()).find(|i| array.is_valid(*i))?;
+    let mut lanes = [init(values[first]); FOLD_LANES];
+
+    let remainder = match array.data().null_buffer() {
+        None => {
+            let chunks = values.chunks_exact(FOLD_LANES);
+            let remainder = chunks.remainder();
+            chunks.for_each(|chunk| {
+                lanes
+                    .iter_mut()
+                    .zip(chunk)
+                    .for_each(|(lane, value)| *lane = update(*lane, *value));
+            });
+            remainder
+                .iter()
+                .fold(lanes[0], |acc, value| update(acc, *value))
+        }
+        Some(buffer) => {
+            // 64 values at a time, to match the chunks of the validity bitmap
+            let chunks = values.chunks_exact(64);
+            let remainder = chunks.remainder();
+            let bit_chunks = buffer.bit_chunks(array.offset(), array.len());
+            chunks.zip(bit_chunks.iter()).for_each(|(chunk, mask)| {
+                chunk
+                    .chunks_exact(FOLD_LANES)
+                    .enumerate()
+                    .for_each(|(j, chunk)| {
+                        let mask = mask >> (j * FOLD_LANES);
+                        lanes.iter_mut().zip(chunk).enumerate().for_each(
+                            |(i, (lane, value))| {
+                                let updated = update(*lane, *value);
+                                *lane =
+                                    if mask & (1 << i) != 0 { updated } else { *lane };
+                            },
+                        );
+                    });
+            });
+            let remainder_bits = bit_chunks.remainder_bits();
+            remainder
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| remainder_bits & (1 << i) != 0)
+                .fold(lanes[0], |acc, (_, value)| update(acc, *value))
+        }
+    };
+    Some(
+        lanes[1..]
+            .iter()
+            .fold(remainder, |acc, lane| merge(acc, *lane)),
+    )
+}
+
+/// Returns `b` if it is less than `a` for `min`, where NaN values are greater than any
+/// other value, and `a` otherwise.
+#[inline]
+pub(super) fn min_of<T: ArrowNativeType + PartialOrd>(a: T, b: T) -> T {
+    if (is_nan(a) & !is_nan(b)) | (a > b) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns `b` if it is greater than `a` for `max`, where NaN values are greater than
+/// any other value, and `a` otherwise.
+#[inline]
+pub(super) fn max_of<T: ArrowNativeType + PartialOrd>(a: T, b: T) -> T {
+    if (!is_nan(a) & is_nan(b)) | (a < b) {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns the sum of values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+#[cfg(not(simd_x86))]
+pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
+    let null_count = array.null_count();
+
+    if null_count == array.len() {
+        return None;
+    }
+
+    let data: &[T::Native] = array.value_slice(0, array.len());
+
+    match array.data().null_buffer() {
+        None => {
+            let sum = data.iter().fold(T::default_value(), |accumulator, value| {
+                accumulator + *value
+            });
+
+            Some(sum)
+        }
+        Some(buffer) => {
+            let mut sum = T::default_value();
+            let data_chunks = data.chunks_exact(64);
+            let remainder = data_chunks.remainder();
+
+            let bit_chunks = buffer.bit_chunks(array.offset(), array.len());
+            data_chunks
+                .zip(bit_chunks.iter())
+                .for_each(|(chunk, mask)| {
+                    // index_mask has value 1 << i in the loop
+                    let mut index_mask = 1;
+                    chunk.iter().for_each(|value| {
+                        if (mask & index_mask) != 0 {
+                            sum = sum + *value;
+                        }
+                        index_mask <<= 1;
+                    });
+                });
+
+            let remainder_bits = bit_chunks.remainder_bits();
+
+            remainder.iter().enumerate().for_each(|(i, value)| {
+                if remainder_bits & (1 << i) != 0 {
+                    sum = sum + *value;
+                }
+            });
+
+            Some(sum)
+        }
+    }
+}
+
+/// Folds the valid values of the array with `f`, starting from `init`.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values.
+fn try_fold_valid<T, A, F>(
+    array: &PrimitiveArray<T>,
+    init: A,
+    mut f: F,
+) -> Result<Option<A>>
+where
+    T: ArrowNumericType,
+    F: FnMut(A, T::Native) -> Result<A>,
+{
+    if array.null_count() == array.len() {
+        return Ok(None);
+    }
+
+    let data: &[T::Native] = array.value_slice(0, array.len());
+    let mut acc = init;
+    for (i, value) in data.iter().enumerate() {
+        if array.is_valid(i) {
+            acc = f(acc, *value)?;
+        }
+    }
+    Ok(Some(acc))
+}
+
+/// Reduces the valid values of the array with `op`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+fn reduce_valid<T, F>(array: &PrimitiveArray<T>, op: F) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    try_fold_valid(array, None, |acc, value| {
+        Ok(Some(acc.map_or(value, |acc| op(acc, value))))
+    })
+    .unwrap_or_default()
+    .flatten()
+}
+
+/// Returns the bitwise AND of the values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_and<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitAnd<Output = T::Native>,
+{
+    reduce_valid(array, |a, b| a & b)
+}
+
+/// Returns the bitwise OR of the values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_or<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitOr<Output = T::Native>,
+{
+    reduce_valid(array, |a, b| a | b)
+}
+
+/// Returns the bitwise XOR of the values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn bit_xor<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: BitXor<Output = T::Native>,
+{
+    reduce_valid(array, |a, b| a ^ b)
+}
+
+/// Returns the sum of values in the array, checking for overflow.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values, and
+/// `Err(ArrowError::ArithmeticOverflow)` if the sum overflows `T::Native`.
+pub fn sum_checked<T>(array: &PrimitiveArray<T>) -> Result<Option<T::Native>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedAdd,
+{
+    try_fold_valid(array, T::default_value(), |sum, value| {
+        sum.checked_add(&value).ok_or_else(|| {
+            ArrowError::ArithmeticOverflow(format!("{:?} + {:?}", sum, value))
+        })
+    })
+}
+
+/// Returns the sum of values in the array, accumulated in the wider type `R`, so that
+/// summing e.g. `Int32` values into `Int64` or `Float32` values into `Float64` does not
+/// overflow or lose precision as [`sum`] may.
+///
+/// Returns `None` if the array is empty or only contains null values.
+///
+/// ```
+/// use arrow::array::Int32Array;
+/// use arrow::compute::sum_widened;
+/// use arrow::datatypes::{Int32Type, Int64Type};
+///
+/// let array = Int32Array::from(vec![Some(i32::MAX), None, Some(i32::MAX)]);
+/// assert_eq!(
+///     Some(2 * i32::MAX as i64),
+///     sum_widened::<Int32Type, Int64Type>(&array)
+/// );
+/// ```
+pub fn sum_widened<T, R>(array: &PrimitiveArray<T>) -> Option<R::Native>
+where
+    T: ArrowNumericType,
+    R: ArrowNumericType,
+    T::Native: Into<R::Native>,
+    R::Native: Add<Output = R::Native>,
+{
+    try_fold_valid(array, R::default_value(), |sum, value| {
+        Ok(sum + value.into())
+    })
+    .unwrap_or_default()
+}
+
+/// Returns the bitmap of the rows of `array` selected by `mask`: the rows where the value
+/// of `array` is valid and that of `mask` is true. This function errors if the arrays
+/// have different lengths.
+fn mask_selection(array: &Array, mask: &BooleanArray) -> Result<Buffer> {
+    if array.len() != mask.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform a conditional aggregation with a mask of different length"
+                .to_string(),
+        ));
+    }
+    let len = array.len();
+    let mask_data = mask.data_ref();
+    let selection = mask_data.buffers()[0].bit_slice(mask.offset(), len);
+    let validity = combine_option_buffers(
+        mask_data.null_buffer(),
+        mask.offset(),
+        array.data_ref().null_buffer(),
+        array.offset(),
+        len,
+    );
+    Ok(match validity {
+        Some(validity) => buffer_bin_and(&selection, 0, &validity, 0, len),
+        None => selection,
+    })
+}
+
+/// Returns the sum of the values in the array of the rows where `mask` is true, as
+/// [`sum`] of the filtered array would, without filtering it. Null values are skipped,
+/// and so are the rows where `mask` is null.
+///
+/// Returns `Ok(None)` if no valid values are selected. This function errors if the arrays
+/// have different lengths.
+///
+/// ```
+/// use arrow::array::{BooleanArray, Int32Array};
+/// use arrow::compute::sum_if;
+///
+/// let array = Int32Array::from(vec![Some(1), Some(2), None, Some(4)]);
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), Some(true), Some(true)]);
+/// assert_eq!(Some(5), sum_if(&array, &mask).unwrap());
+/// ```
+pub fn sum_if<T>(
+    array: &PrimitiveArray<T>,
+    mask: &BooleanArray,
+) -> Result<Option<T::Native>>
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
+    let selection = mask_selection(array, mask)?;
+    let len = array.len();
+    if selection.count_set_bits_offset(0, len) == 0 {
+        return Ok(None);
+    }
+
+    let data: &[T::Native] = array.value_slice(0, len);
+    let data_chunks = data.chunks_exact(64);
+    let remainder = data_chunks.remainder();
+    let bit_chunks = selection.bit_chunks(0, len);
+
+    let mut sum = T::default_value();
+    data_chunks
+        .zip(bit_chunks.iter())
+        .for_each(|(chunk, bits)| {
+            chunk.iter().enumerate().for_each(|(i, value)| {
+                if bits & (1 << i) != 0 {
+                    sum = sum + *value;
+                }
+            });
+        });
+    let remainder_bits = bit_chunks.remainder_bits();
+    remainder.iter().enumerate().for_each(|(i, value)| {
+        if remainder_bits & (1 << i) != 0 {
+            sum = sum + *value;
+        }
+    });
+    Ok(Some(sum))
+}
+
+/// Returns the number of valid values in the array of the rows where `mask` is true, like
+/// SQL's `COUNT(x) FILTER (WHERE mask)`. The rows where `mask` is null are not counted.
+/// The number of true values of `mask` alone, like SQL's `COUNT_IF(mask)`, is
+/// [`BooleanArray::true_count`].
+///
+/// This function errors if the arrays have different lengths.
+pub fn count_if(array: &Array, mask: &BooleanArray) -> Result<usize> {
+    let selection = mask_selection(array, mask)?;
+    Ok(selection.count_set_bits_offset(0, array.len()))
+}
+
+/// Returns the product of the values of an integer array, checking for overflow.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values, and
+/// `Err(ArrowError::ArithmeticOverflow)` if the product overflows `T::Native`.
+pub fn product<T>(array: &PrimitiveArray<T>) -> Result<Option<T::Native>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedMul + One,
+{
+    try_fold_valid(array, T::Native::one(), |product, value| {
+        product.checked_mul(&value).ok_or_else(|| {
+            ArrowError::ArithmeticOverflow(format!("{:?} * {:?}", product, value))
+        })
+    })
+}
+
+/// Returns the product of the values of a floating point array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn product_float<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    reduce_valid(array, |a, b| a * b)
+}
+
+/// Options that define how sums of floating point values are computed
+#[derive(Clone, Debug, Default)]
+pub struct SumOptions {
+    /// Compensate the rounding errors of the additions with Neumaier's variant of Kahan
+    /// summation, so that the error does not grow with the number of values and the
+    /// result barely depends on their order, at the cost of a few more operations per
+    /// value
+    pub compensated: bool,
+}
+
+/// The running state of Neumaier's compensated summation: the sum of the values seen
+/// so far, and the sum of the rounding errors of the additions.
+#[derive(Clone, Copy)]
+struct CompensatedSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+impl<F: Float> CompensatedSum<F> {
+    fn new() -> Self {
+        Self {
+            sum: F::zero(),
+            compensation: F::zero(),
+        }
+    }
+
+    fn add(mut self, value: F) -> Self {
+        let sum = self.sum + value;
+        self.compensation = self.compensation
+            + if self.sum.abs() >= value.abs() {
+                (self.sum - sum) + value
+            } else {
+                (value - sum) + self.sum
+            };
+        self.sum = sum;
+        self
+    }
+
+    fn value(&self) -> F {
+        // the compensation of infinite sums is NaN
+        if self.sum.is_finite() {
+            self.sum + self.compensation
+        } else {
+            self.sum
+        }
+    }
+}
+
+/// Returns the sum of the values of a floating point array, computed as set in
+/// `options`.
+///
+/// Returns `None` if the array is empty or only contains null values.
+///
+/// ```
+/// use arrow::array::Float64Array;
+/// use arrow::compute::{sum, sum_float, SumOptions};
+///
+/// let array = Float64Array::from(vec![1.0, 1e100, 1.0, -1e100]);
+/// assert_eq!(Some(0.0), sum(&array));
+/// let compensated = Some(SumOptions { compensated: true });
+/// assert_eq!(Some(2.0), sum_float(&array, compensated));
+/// ```
+pub fn sum_float<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<SumOptions>,
+) -> Option<T::Native>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    if options.unwrap_or_default().compensated {
+        try_fold_valid(
+            array,
+            CompensatedSum::new(),
+            |sum, value| Ok(sum.add(value)),
+        )
+        .unwrap_or_default()
+        .map(|sum| sum.value())
+    } else {
+        sum(array)
+    }
+}
+
+/// The running state of Welford's algorithm: the count, mean and sum of squared
+/// differences from the mean of the values seen so far.
+#[derive(Clone, Copy, Debug, Default)]
+struct Moments {
+    count: u64,
+    mean: f64,
+    m2: f64,
+}
+
+impl Moments {
+    fn update(mut self, value: f64) -> Self {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        self
+    }
+
+    /// Combines the moments of two sets of values, as by Chan et al.
+    fn merge(&self, other: &Moments) -> Self {
+        let count = self.count + other.count;
+        if count == 0 {
+            return Moments::default();
+        }
+        let delta = other.mean - self.mean;
+        let other_weight = other.count as f64 / count as f64;
+        Moments {
+            count,
+            mean: self.mean + delta * other_weight,
+            m2: self.m2 + other.m2 + delta * delta * self.count as f64 * other_weight,
+        }
+    }
+
+    /// Returns the population or the sample variance of the values, if defined
+    fn variance(&self, sample: bool) -> Option<f64> {
+        let divisor = if sample {
+            self.count.checked_sub(1)?
+        } else {
+            self.count
+        };
+        if divisor == 0 {
+            None
+        } else {
+            Some(self.m2 / divisor as f64)
+        }
+    }
+}
+
+/// Computes the moments of the valid values of the array in a single, numerically stable
+/// pass, or `None` if the array is empty or only contains null values.
+fn moments<T>(array: &PrimitiveArray<T>) -> Option<Moments>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    try_fold_valid(array, Moments::default(), |moments, value| {
+        Ok(moments.update(value.to_f64().unwrap_or(f64::NAN)))
+    })
+    .unwrap_or_default()
+}
+
+/// Options that define how the variance and standard deviation aggregates are computed
+#[derive(Clone, Debug, Default)]
+pub struct VarianceOptions {
+    /// Compute the sample variance, which divides by the number of values minus one,
+    /// instead of the population variance, which divides by the number of values
+    pub sample: bool,
+}
+
+/// Returns the arithmetic mean of the values in the array.
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn mean<T>(array: &PrimitiveArray<T>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    moments(array).map(|moments| moments.mean)
+}
+
+/// Returns the arithmetic mean of the values in the array, whose sum is compensated as
+/// by [`sum_float`] if set in `options`. Otherwise, the mean is computed as by [`mean`].
+///
+/// Returns `None` if the array is empty or only contains null values.
+pub fn mean_with_options<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<SumOptions>,
+) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    if options.unwrap_or_default().compensated {
+        try_fold_valid(
+            array,
+            (CompensatedSum::new(), 0u64),
+            |(sum, count), value| {
+                Ok((sum.add(value.to_f64().unwrap_or(f64::NAN)), count + 1))
+            },
+        )
+        .unwrap_or_default()
+        .map(|(sum, count)| sum.value() / count as f64)
+    } else {
+        mean(array)
+    }
+}
+
+/// Returns the variance of the values in the array, computed with Welford's algorithm.
+/// The population variance is computed, unless `sample` is set in `options`.
+///
+/// Returns `None` if the array is empty or only contains null values, or if the sample
+/// variance is requested of a single value.
+pub fn var<T>(array: &PrimitiveArray<T>, options: Option<VarianceOptions>) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    moments(array)?.variance(options.unwrap_or_default().sample)
+}
+
+/// Returns the standard deviation of the values in the array, which is the square root
+/// of their variance. See [`var`] for the options and when `None` is returned.
+pub fn stddev<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<VarianceOptions>,
+) -> Option<f64>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+{
+    var(array, options).map(f64::sqrt)
+}
+
+/// The partial state of an aggregate, which is updated with the values of arrays and
+/// merged with the states of other values, e.g. of the other partitions of a dataset,
+/// so that the aggregate of all the values is computed without reading them again.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::kernels::aggregate::{AggregateState, SumState};
+/// use arrow::datatypes::Int32Type;
+/// # fn main() -> arrow::error::Result<()> {
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(2)]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(3)]));
+///
+/// let mut state = SumState::<Int32Type>::default();
+/// state.update(&a)?;
+/// let mut other = SumState::default();
+/// other.update(&b)?;
+/// state.merge(&other);
+/// assert_eq!(Some(6), state.evaluate());
+/// # Ok(())
+/// # }
+/// ```
+pub trait AggregateState {
+    /// The type of the value of the aggregate
+    type Output;
+
+    /// Updates the state with the values of `array`.
+    ///
+    /// This function errors if the array is not of a type supported by the aggregate.
+    fn update(&mut self, array: &ArrayRef) -> Result<()>;
+
+    /// Updates the state with the values of which `other` is the state
+    fn merge(&mut self, other: &Self);
+
+    /// Returns the value of the aggregate of the values seen so far
+    fn evaluate(&self) -> Self::Output;
+}
+
+/// The state of the number of non-null values, of arrays of any type
+#[derive(Clone, Debug, Default)]
+pub struct CountState {
+    count: u64,
+}
+
+impl AggregateState for CountState {
+    type Output = u64;
+
+    fn update(&mut self, array: &ArrayRef) -> Result<()> {
+        self.count += (array.len() - array.null_count()) as u64;
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.count += other.count;
+    }
+
+    fn evaluate(&self) -> u64 {
+        self.count
+    }
+}
+
+/// The state of the [`sum`] of the values of arrays of type `T`, which is `None` if
+/// they are all null
+#[derive(Debug)]
+pub struct SumState<T: ArrowNumericType> {
+    sum: Option<T::Native>,
+}
+
+impl<T: ArrowNumericType> Default for SumState<T> {
+    fn default() -> Self {
+        Self { sum: None }
+    }
+}
+
+impl<T> AggregateState for SumState<T>
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
+    type Output = Option<T::Native>;
+
+    fn update(&mut self, array: &ArrayRef) -> Result<()> {
+        let array_sum = sum(array.as_typed::<PrimitiveArray<T>>()?);
+        self.sum = combine_option(self.sum, array_sum, |a, b| a + b);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.sum = combine_option(self.sum, other.sum, |a, b| a + b);
+    }
+
+    fn evaluate(&self) -> Option<T::Native> {
+        self.sum
+    }
+}
+
+/// The state of the [`min`] of the values of arrays of type `T`, which is `None` if
+/// they are all null
+#[derive(Debug)]
+pub struct MinState<T: ArrowNumericType> {
+    min: Option<T::Native>,
+}
+
+impl<T: ArrowNumericType> Default for MinState<T> {
+    fn default() -> Self {
+        Self { min: None }
+    }
+}
+
+impl<T: ArrowNumericType> AggregateState for MinState<T> {
+    type Output = Option<T::Native>;
+
+    fn update(&mut self, array: &ArrayRef) -> Result<()> {
+        let array_min = min(array.as_typed::<PrimitiveArray<T>>()?);
+        self.min = combine_option(self.min, array_min, min_of);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.min = combine_option(self.min, other.min, min_of);
+    }
+
+    fn evaluate(&self) -> Option<T::Native> {
+        self.min
+    }
+}
+
+/// The state of the [`max`] of the values of arrays of type `T`, which is `None` if
+/// they are all null
+#[derive(Debug)]
+pub struct MaxState<T: ArrowNumericType> {
+    max: Option<T::Native>,
+}
+
+impl<T: ArrowNumericType> Default for MaxState<T> {
+    fn default() -> Self {
+        Self { max: None }
+    }
+}
+
+impl<T: ArrowNumericType> AggregateState for MaxState<T> {
+    type Output = Option<T::Native>;
+
+    fn update(&mut self, array: &ArrayRef) -> Result<()> {
+        let array_max = max(array.as_typed::<PrimitiveArray<T>>()?);
+        self.max = combine_option(self.max, array_max, max_of);
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.max = combine_option(self.max, other.max, max_of);
+    }
+
+    fn evaluate(&self) -> Option<T::Native> {
+        self.max
+    }
+}
+
+/// The state of the [`var`] of the values of numeric arrays, which can be of different
+/// types. States are merged with the parallel algorithm of Chan et al, so that the
+/// variance is as numerically stable as if it was computed in a single pass.
+#[derive(Clone, Debug, Default)]
+pub struct VarianceState {
+    options: VarianceOptions,
+    moments: Moments,
+}
+
+impl VarianceState {
+    /// Creates the state of the variance computed as set in `options`
+    pub fn new(options: Option<VarianceOptions>) -> Self {
+        Self {
+            options: options.unwrap_or_default(),
+            moments: Moments::default(),
+        }
+    }
+}
+
+impl AggregateState for VarianceState {
+    type Output = Option<f64>;
+
+    fn update(&mut self, array: &ArrayRef) -> Result<()> {
+        let array_moments = match array.data_type() {
+            DataType::Int8 => moments(array.as_typed::<Int8Array>()?),
+            DataType::Int16 => moments(array.as_typed::<Int16Array>()?),
+            DataType::Int32 => moments(array.as_typed::<Int32Array>()?),
+            DataType::Int64 => moments(array.as_typed::<Int64Array>()?),
+            DataType::UInt8 => moments(array.as_typed::<UInt8Array>()?),
+            DataType::UInt16 => moments(array.as_typed::<UInt16Array>()?),
+            DataType::UInt32 => moments(array.as_typed::<UInt32Array>()?),
+            DataType::UInt64 => moments(array.as_typed::<UInt64Array>()?),
+            DataType::Float32 => moments(array.as_typed::<Float32Array>()?),
+            DataType::Float64 => moments(array.as_typed::<Float64Array>()?),
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The variance of values of type {:?} is not supported",
+                    t
+                )))
+            }
+        };
+        if let Some(array_moments) = array_moments {
+            self.moments = self.moments.merge(&array_moments);
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, other: &Self) {
+        self.moments = self.moments.merge(&other.moments);
+    }
+
+    fn evaluate(&self) -> Option<f64> {
+        self.moments.variance(self.options.sample)
+    }
+}
+
+/// Combines two optional partial aggregates with `op`, where `None` is the aggregate of
+/// no values
+#[inline]
+fn combine_option<T, F: Fn(T, T) -> T>(a: Option<T>, b: Option<T>, op: F) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(op(a, b)),
+        (a, None) => a,
+        (None, b) => b,
+    }
+}
+
+/// The running state of Welford's algorithm over pairs of values: the moments of each
+/// side, and the sum of the products of the differences from the means.
+#[derive(Default)]
+struct CoMoments {
+    x: Moments,
+    y: Moments,
+    c: f64,
+}
+
+/// Computes the co-moments of the pairs of `x` and `y` values where neither is null, or
+/// `None` if there are no such pairs. This function errors if the arrays have different
+/// lengths.
+fn co_moments<T, U>(
+    x: &PrimitiveArray<T>,
+    y: &PrimitiveArray<U>,
+) -> Result<Option<CoMoments>>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+    U: ArrowNumericType,
+    U::Native: ToPrimitive,
+{
+    if x.len() != y.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform a two-column aggregation on arrays of different length"
+                .to_string(),
+        ));
+    }
+
+    let mut m = CoMoments::default();
+    for i in (0..x.len()).filter(|i| x.is_valid(*i) && y.is_valid(*i)) {
+        let value_x = x.value(i).to_f64().unwrap_or(f64::NAN);
+        let value_y = y.value(i).to_f64().unwrap_or(f64::NAN);
+        let delta_x = value_x - m.x.mean;
+        m.x = m.x.update(value_x);
+        m.y = m.y.update(value_y);
+        m.c += delta_x * (value_y - m.y.mean);
+    }
+    Ok(if m.x.count == 0 { None } else { Some(m) })
+}
+
+/// Returns the covariance of the values of `x` and `y`, skipping the pairs of values
+/// where either is null. The population covariance is computed, unless `sample` is set
+/// in `options`.
+///
+/// Returns `Ok(None)` if there are no pairs of valid values, or if the sample covariance
+/// is requested of a single pair. This function errors if the arrays have different
+/// lengths.
+pub fn covariance<T, U>(
+    x: &PrimitiveArray<T>,
+    y: &PrimitiveArray<U>,
+    options: Option<VarianceOptions>,
+) -> Result<Option<f64>>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+    U: ArrowNumericType,
+    U::Native: ToPrimitive,
+{
+    let options = options.unwrap_or_default();
+    Ok(co_moments(x, y)?.and_then(|m| {
+        let divisor = if options.sample {
+            m.x.count - 1
+        } else {
+            m.x.count
+        };
+        if divisor == 0 {
+            None
+        } else {
+            Some(m.c / divisor as f64)
+        }
+    }))
+}
+
+/// Returns the Pearson correlation coefficient of the values of `x` and `y`, skipping the
+/// pairs of values where either is null.
+///
+/// Returns `Ok(None)` if there are no pairs of valid values, or if the values of either
+/// array are all equal, as the coefficient is then undefined. This function errors if the
+/// arrays have different lengths.
+pub fn corr<T, U>(x: &PrimitiveArray<T>, y: &PrimitiveArray<U>) -> Result<Option<f64>>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive,
+    U: ArrowNumericType,
+    U::Native: ToPrimitive,
+{
+    Ok(co_moments(x, y)?.and_then(|m| {
+        if m.x.m2 == 0.0 || m.y.m2 == 0.0 {
+            None
+        } else {
+            Some(m.c / (m.x.m2 * m.y.m2).sqrt())
+        }
+    }))
+}
+
+/// How [`quantile`] computes a quantile that lies between two values `i <= j`, at the
+/// fraction `f` of the distance between them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum QuantileInterpolation {
+    /// `i + (j - i) * f`
+    Linear,
+    /// `i`
+    Lower,
+    /// `j`
+    Higher,
+    /// `i` or `j`, whichever is nearest, or the one of even rank if `f` is 0.5
+    Nearest,
+    /// `(i + j) / 2`
+    Midpoint,
 }
 
-/// Helper function to perform min/max lambda function on values from a numeric array.
-fn min_max_helper<T, F>(array: &PrimitiveArray<T>, cmp: F) -> Option<T::Native>
+/// Returns the `q`-th quantile of the values in the array, where `q` is between 0 and 1,
+/// e.g. 0.5 for the median. Null values are skipped, and NaN values are greater than
+/// any other value.
+///
+/// The values that surround the quantile are found with a selection algorithm, in
+/// linear time on average, rather than by sorting the array.
+///
+/// Returns `Ok(None)` if the array is empty or only contains null values, and an error
+/// if `q` is not between 0 and 1.
+pub fn quantile<T>(
+    array: &PrimitiveArray<T>,
+    q: f64,
+    interpolation: QuantileInterpolation,
+) -> Result<Option<f64>>
 where
     T: ArrowNumericType,
-    F: Fn(&T::Native, &T::Native) -> bool,
+    T::Native: ToPrimitive,
 {
-    let null_count = array.null_count();
-
-    // Includes case array.len() == 0
-    if null_count == array.len() {
-        return None;
+    if !(0.0..=1.0).contains(&q) {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Quantile must be between 0 and 1, got {}",
+            q
+        )));
     }
 
-    let data = array.data();
-    let m = array.value_slice(0, data.len());
-    let mut n;
+    let mut values: Vec<T::Native> = (0..array.len())
+        .filter(|i| array.is_valid(*i))
+        .map(|i| array.value(i))
+        .collect();
+    if values.is_empty() {
+        return Ok(None);
+    }
 
-    if null_count == 0 {
-        // optimized path for arrays without null values
-        n = m[1..]
-            .iter()
-            .fold(m[0], |max, item| if cmp(&max, item) { *item } else { max });
-    } else {
-        n = T::default_value();
-        let mut has_value = false;
-        for (i, item) in m.iter().enumerate() {
-            if data.is_valid(i) && (!has_value || cmp(&n, item)) {
-                has_value = true;
-                n = *item
+    let cmp = |a: &T::Native, b: &T::Native| {
+        a.partial_cmp(b)
+            .unwrap_or_else(|| is_nan(*a).cmp(&is_nan(*b)))
+    };
+    let rank = q * (values.len() - 1) as f64;
+    let lower_rank = rank.floor() as usize;
+    let fraction = rank - lower_rank as f64;
+
+    let (_, lower, higher) = values.select_nth_unstable_by(lower_rank, cmp);
+    let lower = lower.to_f64().unwrap_or(f64::NAN);
+    // the next value is the smallest of the values after the lower one
+    let higher = match higher.iter().min_by(|a, b| cmp(a, b)) {
+        Some(higher) if fraction > 0.0 => higher.to_f64().unwrap_or(f64::NAN),
+        _ => lower,
+    };
+
+    let value = match interpolation {
+        QuantileInterpolation::Linear => lower + (higher - lower) * fraction,
+        QuantileInterpolation::Lower => lower,
+        QuantileInterpolation::Higher => higher,
+        QuantileInterpolation::Nearest => {
+            if fraction < 0.5 || (fraction == 0.5 && lower_rank & 1 == 0) {
+                lower
+            } else {
+                higher
             }
         }
-    }
-    Some(n)
+        QuantileInterpolation::Midpoint => (lower + higher) / 2.0,
+    };
+    Ok(Some(value))
 }
 
-/// Returns the sum of values in the array.
+/// Returns the median of the values in the array, which is the mean of the two middle
+/// values if their number is even. See [`quantile`] for how nulls and NaNs are handled.
 ///
 /// Returns `None` if the array is empty or only contains null values.
-#[cfg(not(simd_x86))]
-pub fn sum<T>(array: &PrimitiveArray<T>) -> Option<T::Native>
+pub fn median<T>(array: &PrimitiveArray<T>) -> Option<f64>
 where
     T: ArrowNumericType,
-    T::Native: Add<Output = T::Native>,
+    T::Native: ToPrimitive,
 {
-    let null_count = array.null_count();
-
-    if null_count == array.len() {
-        return None;
-    }
+    quantile(array, 0.5, QuantileInterpolation::Linear).unwrap_or_default()
+}
 
-    let data: &[T::Native] = array.value_slice(0, array.len());
+/// The bytes that all zeros and all NaNs of a floating point type are counted as, so that
+/// `-0.0` equals `0.0` and NaNs with different payloads are equal
+static CANONICAL_ZERO: [u8; 8] = [0; 8];
+static CANONICAL_NAN: [u8; 8] = [0xff; 8];
 
-    match array.data().null_buffer() {
-        None => {
-            let sum = data.iter().fold(T::default_value(), |accumulator, value| {
-                accumulator + *value
+/// Calls `f` with the index and the bytes of each valid value of `array`, in a form where
+/// values are equal if and only if their bytes are, e.g. to hash them.
+pub(crate) fn for_each_value_bytes<'a, F>(array: &'a ArrayRef, f: &mut F) -> Result<()>
+where
+    F: FnMut(usize, &'a [u8]),
+{
+    // the values of the fixed-width types are compared by their `width` bytes
+    let fixed_width = |width: usize, is_float: bool, f: &mut F| {
+        let data = array.data_ref();
+        let values = &data.buffers()[0].data()[data.offset() * width..];
+        (0..array.len())
+            .filter(|i| array.is_valid(*i))
+            .for_each(|i| {
+                let bytes = &values[i * width..(i + 1) * width];
+                let (is_zero, is_nan) = match (is_float, width) {
+                    (true, 4) => {
+                        let value = f32::from_ne_bytes(bytes.try_into().unwrap());
+                        (value == 0.0, value.is_nan())
+                    }
+                    (true, _) => {
+                        let value = f64::from_ne_bytes(bytes.try_into().unwrap());
+                        (value == 0.0, value.is_nan())
+                    }
+                    _ => (false, false),
+                };
+                if is_zero {
+                    f(i, &CANONICAL_ZERO[..width])
+                } else if is_nan {
+                    f(i, &CANONICAL_NAN[..width])
+                } else {
+                    f(i, bytes)
+                }
             });
-
-            Some(sum)
+    };
+
+    match array.data_type() {
+        DataType::Null => {}
+        DataType::Boolean => {
+            let array = array.as_typed::<BooleanArray>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, if array.value(i) { &[1] } else { &[0] }));
         }
-        Some(buffer) => {
-            let mut sum = T::default_value();
-            let data_chunks = data.chunks_exact(64);
-            let remainder = data_chunks.remainder();
-
-            let bit_chunks = buffer.bit_chunks(array.offset(), array.len());
-            data_chunks
-                .zip(bit_chunks.iter())
-                .for_each(|(chunk, mask)| {
-                    // index_mask has value 1 << i in the loop
-                    let mut index_mask = 1;
-                    chunk.iter().for_each(|value| {
-                        if (mask & index_mask) != 0 {
-                            sum = sum + *value;
-                        }
-                        index_mask <<= 1;
-                    });
-                });
+        DataType::Int8 | DataType::UInt8 => fixed_width(1, false, f),
+        DataType::Int16 | DataType::UInt16 => fixed_width(2, false, f),
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => fixed_width(4, false, f),
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => fixed_width(8, false, f),
+        DataType::Float32 => fixed_width(4, true, f),
+        DataType::Float64 => fixed_width(8, true, f),
+        DataType::Utf8 => {
+            let array = array.as_typed::<GenericStringArray<i32>>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, array.value(i).as_bytes()));
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_typed::<GenericStringArray<i64>>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, array.value(i).as_bytes()));
+        }
+        DataType::Binary => {
+            let array = array.as_typed::<BinaryArray>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, array.value(i)));
+        }
+        DataType::LargeBinary => {
+            let array = array.as_typed::<LargeBinaryArray>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, array.value(i)));
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array.as_typed::<FixedSizeBinaryArray>()?;
+            (0..array.len())
+                .filter(|i| array.is_valid(*i))
+                .for_each(|i| f(i, array.value(i)));
+        }
+        t => {
+            return Err(ArrowError::ComputeError(format!(
+                "Counting distinct values not supported for data type {:?}",
+                t
+            )))
+        }
+    }
+    Ok(())
+}
 
-            let remainder_bits = bit_chunks.remainder_bits();
+/// Returns the number of distinct non-null values in the array. Floating point zeros of
+/// either sign are the same value, as are all NaNs.
+///
+/// This function errors if the array is not of a primitive, boolean, binary or string
+/// type.
+pub fn count_distinct(array: &ArrayRef) -> Result<usize> {
+    let mut values = HashSet::new();
+    for_each_value_bytes(array, &mut |_, bytes| {
+        values.insert(bytes);
+    })?;
+    Ok(values.len())
+}
 
-            remainder.iter().enumerate().for_each(|(i, value)| {
-                if remainder_bits & (1 << i) != 0 {
-                    sum = sum + *value;
-                }
-            });
+/// Returns an estimate of the number of distinct non-null values in the array, computed
+/// with the HyperLogLog algorithm in constant memory, which makes it suited to arrays too
+/// large for [`count_distinct`]. The estimate has a standard error of about 0.8%, and is
+/// exact for few distinct values.
+///
+/// This function errors if the array is not of a type supported by [`count_distinct`].
+pub fn approx_count_distinct(array: &ArrayRef) -> Result<usize> {
+    let mut registers = vec![0u8; hll::HLL_NUM_REGISTERS];
+    hll::update_sketch(&mut registers, array)?;
+    Ok(hll::estimate(&registers))
+}
 
-            Some(sum)
-        }
-    }
+/// Returns a batch summarizing the counts of each column of `batch`, with one row per
+/// column and the columns:
+/// * `column_name`: the name of the column
+/// * `count`: the number of non-null values
+/// * `null_count`: the number of null values
+/// * `approx_distinct`: the estimated number of distinct non-null values, computed by
+///   [`approx_count_distinct`], or null if the column's data type is not supported
+///
+/// The value and null counts are read from the columns' metadata, so only the distinct
+/// counts scan the data.
+pub fn count_summary(batch: &RecordBatch) -> Result<RecordBatch> {
+    let schema = Schema::new(vec![
+        Field::new("column_name", DataType::Utf8, false),
+        Field::new("count", DataType::UInt64, false),
+        Field::new("null_count", DataType::UInt64, false),
+        Field::new("approx_distinct", DataType::UInt64, true),
+    ]);
+
+    let columns = batch.columns();
+    let names: StringArray = batch
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| Some(field.name().as_str()))
+        .collect();
+    let counts: UInt64Array = columns
+        .iter()
+        .map(|c| Some((c.len() - c.null_count()) as u64))
+        .collect();
+    let null_counts: UInt64Array = columns
+        .iter()
+        .map(|c| Some(c.null_count() as u64))
+        .collect();
+    let approx_distinct: UInt64Array = columns
+        .iter()
+        .map(|c| approx_count_distinct(c).ok().map(|n| n as u64))
+        .collect();
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(names),
+            Arc::new(counts),
+            Arc::new(null_counts),
+            Arc::new(approx_distinct),
+        ],
+    )
 }
 
 #[cfg(simd_x86)]
@@ -576,6 +1975,7 @@ mod tests {
     use super::*;
     use crate::array::*;
     use crate::compute::add;
+    use std::sync::Arc;
 
     #[test]
     fn test_primitive_array_sum() {
@@ -654,6 +2054,559 @@ mod tests {
         assert_eq!(Some((1..=100).filter(|i| i % 33 == 0).sum()), sum(&c));
     }
 
+    #[test]
+    fn test_primitive_array_sum_checked() {
+        let a = Int32Array::from(vec![Some(1), None, Some(i32::MAX - 1), Some(-5)]);
+        assert_eq!(Some(i32::MAX - 5), sum_checked(&a).unwrap());
+        let sliced = a.slice(1, 2);
+        let sliced = sliced.as_typed::<Int32Array>().unwrap();
+        assert_eq!(Some(i32::MAX - 1), sum_checked(sliced).unwrap());
+
+        let a = Int32Array::from(vec![Some(i32::MAX), None, Some(1)]);
+        assert!(matches!(
+            sum_checked(&a),
+            Err(ArrowError::ArithmeticOverflow(_))
+        ));
+        // the overflowing value is null
+        let a = Int8Array::from(vec![Some(i8::MAX), None]);
+        let b = Int8Array::from(vec![Some(0), Some(1)]);
+        let c = add(&a, &b).unwrap();
+        assert_eq!(Some(i8::MAX), sum_checked(&c).unwrap());
+
+        assert_eq!(
+            None,
+            sum_checked(&Int32Array::from(vec![None, None])).unwrap()
+        );
+        assert_eq!(
+            None,
+            sum_checked(&UInt64Array::from(Vec::<u64>::new())).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_sum_if() {
+        // more than 64 values, so that both the chunks and the remainder are summed
+        let a: Int64Array = (0..100)
+            .map(|i| if i % 10 == 0 { None } else { Some(i) })
+            .collect();
+        let mask: BooleanArray = (0..100)
+            .map(|i| if i % 7 == 0 { None } else { Some(i % 2 == 0) })
+            .collect();
+        let expected = (0..100)
+            .filter(|i| i % 10 != 0 && i % 7 != 0 && i % 2 == 0)
+            .sum::<i64>();
+        assert_eq!(Some(expected), sum_if(&a, &mask).unwrap());
+
+        let mask = BooleanArray::from(vec![false; 100]);
+        assert_eq!(None, sum_if(&a, &mask).unwrap());
+        let mask = BooleanArray::from(vec![true; 99]);
+        assert!(sum_if(&a, &mask).is_err());
+    }
+
+    #[test]
+    fn test_sum_count_if_sliced() {
+        let a = Float64Array::from(vec![Some(1.0), Some(2.0), None, Some(4.0)]);
+        let a = a.slice(1, 3);
+        let a = as_primitive_array::<Float64Type>(&a);
+        let mask = BooleanArray::from(vec![Some(true), Some(true), Some(true), None]);
+        let mask = mask.slice(1, 3);
+        let mask = as_boolean_array(&mask);
+        assert_eq!(Some(2.0), sum_if(a, mask).unwrap());
+        assert_eq!(1, count_if(a, mask).unwrap());
+
+        let strings = StringArray::from(vec![Some("a"), None, Some("c")]);
+        assert_eq!(1, count_if(&strings, mask).unwrap());
+    }
+
+    #[test]
+    fn test_primitive_array_sum_widened() {
+        let a = Int32Array::from(vec![Some(i32::MAX), None, Some(i32::MAX), Some(2)]);
+        assert_eq!(
+            Some(2 * i32::MAX as i64 + 2),
+            sum_widened::<Int32Type, Int64Type>(&a)
+        );
+        let a = UInt32Array::from(vec![u32::MAX, u32::MAX]);
+        assert_eq!(
+            Some(2 * u32::MAX as u64),
+            sum_widened::<UInt32Type, UInt64Type>(&a)
+        );
+
+        // 16_777_216 + 1 is not representable as an f32
+        let a = Float32Array::from(vec![16_777_216.0, 1.0, 1.0]);
+        assert_eq!(Some(16_777_216.0), sum(&a));
+        assert_eq!(
+            Some(16_777_218.0),
+            sum_widened::<Float32Type, Float64Type>(&a)
+        );
+
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(None, sum_widened::<Int32Type, Int64Type>(&a));
+    }
+
+    #[test]
+    fn test_product() {
+        let a = Int64Array::from(vec![Some(2), None, Some(-3), Some(7)]);
+        assert_eq!(Some(-42), product(&a).unwrap());
+        let sliced = a.slice(1, 2);
+        let sliced = sliced.as_typed::<Int64Array>().unwrap();
+        assert_eq!(Some(-3), product(sliced).unwrap());
+
+        let a = Int8Array::from(vec![16, 8]);
+        assert!(matches!(
+            product(&a),
+            Err(ArrowError::ArithmeticOverflow(_))
+        ));
+
+        let a = Float32Array::from(vec![0.5, 8.0, 0.25]);
+        assert_eq!(Some(1.0), product_float(&a));
+        let a = Float64Array::from(vec![f64::MAX, 2.0]);
+        assert_eq!(Some(f64::INFINITY), product_float(&a));
+
+        let a = UInt8Array::from(vec![None, None]);
+        assert_eq!(None, product(&a).unwrap());
+        assert_eq!(None, product(&UInt8Array::from(Vec::<u8>::new())).unwrap());
+    }
+
+    #[test]
+    fn test_aggregate_states_merge_partitions() {
+        let values: Vec<Option<f64>> = (0..1000)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some(1e6 + i as f64 / 3.0)
+                }
+            })
+            .collect();
+        let array = Float64Array::from(values);
+        let partitions: Vec<ArrayRef> = vec![
+            array.slice(0, 10),
+            array.slice(10, 0),
+            array.slice(10, 600),
+            array.slice(610, 390),
+        ];
+
+        let mut count = CountState::default();
+        let mut total = SumState::<Float64Type>::default();
+        let mut minimum = MinState::<Float64Type>::default();
+        let mut maximum = MaxState::<Float64Type>::default();
+        let mut variance = VarianceState::new(Some(VarianceOptions { sample: true }));
+        for partition in &partitions {
+            let mut partition_count = CountState::default();
+            partition_count.update(partition).unwrap();
+            count.merge(&partition_count);
+
+            let mut partition_sum = SumState::default();
+            partition_sum.update(partition).unwrap();
+            total.merge(&partition_sum);
+
+            let mut partition_min = MinState::default();
+            partition_min.update(partition).unwrap();
+            minimum.merge(&partition_min);
+
+            let mut partition_max = MaxState::default();
+            partition_max.update(partition).unwrap();
+            maximum.merge(&partition_max);
+
+            let mut partition_variance =
+                VarianceState::new(Some(VarianceOptions { sample: true }));
+            partition_variance.update(partition).unwrap();
+            variance.merge(&partition_variance);
+        }
+
+        assert_eq!(857, count.evaluate());
+        let expected_sum = sum(&array).unwrap();
+        assert!((total.evaluate().unwrap() - expected_sum).abs() < 1e-6 * expected_sum);
+        assert_eq!(min(&array), minimum.evaluate());
+        assert_eq!(max(&array), maximum.evaluate());
+        let expected_var = var(&array, Some(VarianceOptions { sample: true })).unwrap();
+        assert!(
+            (variance.evaluate().unwrap() - expected_var).abs() < 1e-9 * expected_var
+        );
+    }
+
+    #[test]
+    fn test_aggregate_states_empty_and_invalid() {
+        let nulls: ArrayRef = Arc::new(Int32Array::from(vec![None, None]));
+        let mut total = SumState::<Int32Type>::default();
+        total.update(&nulls).unwrap();
+        total.merge(&SumState::default());
+        assert_eq!(None, total.evaluate());
+
+        let mut variance = VarianceState::new(None);
+        variance.update(&nulls).unwrap();
+        assert_eq!(None, variance.evaluate());
+        variance
+            .update(&(Arc::new(UInt8Array::from(vec![1, 3])) as ArrayRef))
+            .unwrap();
+        variance.update(&nulls).unwrap();
+        variance.merge(&VarianceState::new(None));
+        assert_eq!(Some(1.0), variance.evaluate());
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        assert!(total.update(&strings).is_err());
+        assert!(MinState::<Int64Type>::default().update(&nulls).is_err());
+        assert!(variance.update(&strings).is_err());
+        let mut count = CountState::default();
+        count.update(&strings).unwrap();
+        assert_eq!(1, count.evaluate());
+    }
+
+    #[test]
+    fn test_compensated_sum_and_mean() {
+        let compensated = Some(SumOptions { compensated: true });
+
+        let a = Float64Array::from(vec![
+            Some(1.0),
+            Some(1e100),
+            None,
+            Some(1.0),
+            Some(-1e100),
+        ]);
+        assert_eq!(Some(2.0), sum_float(&a, compensated.clone()));
+        assert_eq!(Some(0.5), mean_with_options(&a, compensated.clone()));
+        // the naive sum of the SIMD lanes depends on their number
+        #[cfg(not(feature = "simd"))]
+        {
+            assert_eq!(Some(0.0), sum_float(&a, None));
+            assert_eq!(Some(0.0), mean_with_options(&a, None));
+        }
+
+        // the error of the compensated sum does not grow with the number of values
+        let a = Float32Array::from(vec![0.1f32; 100_000]);
+        let kahan = sum_float(&a, compensated.clone()).unwrap();
+        assert!((kahan - 10_000.0).abs() < 0.01, "{}", kahan);
+
+        // integers are summed as f64 for the mean
+        let a = Int64Array::from(vec![i64::MAX, 1, -i64::MAX]);
+        assert_eq!(Some(1.0 / 3.0), mean_with_options(&a, compensated.clone()));
+
+        // infinite and NaN values are kept
+        let a = Float64Array::from(vec![1.0, f64::INFINITY, 2.0]);
+        assert_eq!(Some(f64::INFINITY), sum_float(&a, compensated.clone()));
+        let a = Float64Array::from(vec![1.0, f64::NAN]);
+        assert!(sum_float(&a, compensated.clone()).unwrap().is_nan());
+
+        let a = Float64Array::from(vec![None, None]);
+        assert_eq!(None, sum_float(&a, compensated.clone()));
+        assert_eq!(None, mean_with_options(&a, compensated));
+    }
+
+    #[test]
+    fn test_mean_var_stddev() {
+        let sample = Some(VarianceOptions { sample: true });
+        let a = Int32Array::from(vec![
+            Some(2),
+            None,
+            Some(4),
+            Some(4),
+            Some(4),
+            Some(5),
+            Some(5),
+            None,
+            Some(7),
+            Some(9),
+        ]);
+        assert_eq!(Some(5.0), mean(&a));
+        assert_eq!(Some(4.0), var(&a, None));
+        assert_eq!(Some(2.0), stddev(&a, None));
+        assert_eq!(Some(32.0 / 7.0), var(&a, sample.clone()));
+        assert_eq!(Some((32.0f64 / 7.0).sqrt()), stddev(&a, sample.clone()));
+
+        // sliced
+        let sliced = a.slice(2, 3);
+        let sliced = sliced.as_typed::<Int32Array>().unwrap();
+        assert_eq!(Some(4.0), mean(sliced));
+        assert_eq!(Some(0.0), var(sliced, sample.clone()));
+
+        // a large offset does not lose precision, as it would with the sum of squares
+        let a = Float64Array::from(vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]);
+        assert_eq!(Some(1e9 + 10.0), mean(&a));
+        assert_eq!(Some(30.0), var(&a, sample.clone()));
+
+        let a = UInt8Array::from(vec![Some(7), None]);
+        assert_eq!(Some(7.0), mean(&a));
+        assert_eq!(Some(0.0), var(&a, None));
+        assert_eq!(None, var(&a, sample.clone()));
+        assert_eq!(None, stddev(&a, sample));
+
+        let a = Float32Array::from(vec![None, None]);
+        assert_eq!(None, mean(&a));
+        assert_eq!(None, var(&a, None));
+        assert_eq!(None, stddev(&Int64Array::from(Vec::<i64>::new()), None));
+    }
+
+    #[test]
+    fn test_covariance_corr() {
+        let sample = Some(VarianceOptions { sample: true });
+        let x =
+            Int32Array::from(vec![Some(1), Some(2), None, Some(3), Some(4), Some(100)]);
+        let y = Float64Array::from(vec![
+            Some(2.0),
+            Some(4.0),
+            Some(0.0),
+            Some(6.0),
+            Some(8.0),
+            None,
+        ]);
+        assert_eq!(Some(2.5), covariance(&x, &y, None).unwrap());
+        assert_eq!(
+            Some(10.0 / 3.0),
+            covariance(&x, &y, sample.clone()).unwrap()
+        );
+        assert_eq!(Some(1.0), corr(&x, &y).unwrap());
+
+        let y = Int64Array::from(vec![Some(4), Some(3), Some(9), Some(2), Some(1), None]);
+        assert_eq!(Some(-1.25), covariance(&x, &y, None).unwrap());
+        assert_eq!(Some(-1.0), corr(&x, &y).unwrap());
+
+        let y = UInt8Array::from(vec![Some(1), Some(0), None, Some(0), Some(1), None]);
+        assert_eq!(Some(0.0), corr(&x, &y).unwrap());
+
+        // constant values
+        let y = Int32Array::from(vec![5, 5, 5, 5, 5, 5]);
+        assert_eq!(Some(0.0), covariance(&x, &y, None).unwrap());
+        assert_eq!(None, corr(&x, &y).unwrap());
+
+        // no pairs, or a single one
+        let y = Int32Array::from(vec![None, None, Some(1), None, None, None]);
+        assert_eq!(None, covariance(&x, &y, None).unwrap());
+        assert_eq!(None, corr(&x, &y).unwrap());
+        let y = Int32Array::from(vec![None, None, None, None, Some(1), None]);
+        assert_eq!(Some(0.0), covariance(&x, &y, None).unwrap());
+        assert_eq!(None, covariance(&x, &y, sample).unwrap());
+
+        let y = Int32Array::from(vec![1]);
+        assert!(covariance(&x, &y, None).is_err());
+        assert!(corr(&x, &y).is_err());
+    }
+
+    #[test]
+    fn test_median_quantile() {
+        let a = Int32Array::from(vec![Some(7), None, Some(1), Some(3), Some(9)]);
+        assert_eq!(Some(5.0), median(&a));
+        let a = Int32Array::from(vec![Some(7), Some(1), None, Some(3)]);
+        assert_eq!(Some(3.0), median(&a));
+
+        // ranks 0..=9 of the values 10, 20, ..., 100
+        let a = Float64Array::from(vec![
+            Some(70.0),
+            None,
+            Some(10.0),
+            Some(100.0),
+            Some(40.0),
+            Some(20.0),
+            None,
+            Some(90.0),
+            Some(30.0),
+            Some(60.0),
+            Some(50.0),
+            Some(80.0),
+        ]);
+        let quantile_of = |q, interpolation| quantile(&a, q, interpolation).unwrap();
+        assert_eq!(Some(10.0), quantile_of(0.0, QuantileInterpolation::Linear));
+        assert_eq!(Some(100.0), quantile_of(1.0, QuantileInterpolation::Lower));
+        // rank 2.25
+        assert_eq!(Some(32.5), quantile_of(0.25, QuantileInterpolation::Linear));
+        assert_eq!(Some(30.0), quantile_of(0.25, QuantileInterpolation::Lower));
+        assert_eq!(Some(40.0), quantile_of(0.25, QuantileInterpolation::Higher));
+        assert_eq!(
+            Some(30.0),
+            quantile_of(0.25, QuantileInterpolation::Nearest)
+        );
+        assert_eq!(
+            Some(35.0),
+            quantile_of(0.25, QuantileInterpolation::Midpoint)
+        );
+        // rank 4.5
+        assert_eq!(Some(50.0), quantile_of(0.5, QuantileInterpolation::Nearest));
+        // rank 7.2
+        assert_eq!(Some(80.0), quantile_of(0.8, QuantileInterpolation::Nearest));
+
+        // NaN is the largest value
+        let a = Float32Array::from(vec![f32::NAN, 2.0, 1.0]);
+        assert_eq!(Some(2.0), median(&a));
+        assert!(quantile(&a, 1.0, QuantileInterpolation::Linear)
+            .unwrap()
+            .unwrap()
+            .is_nan());
+
+        assert_eq!(None, median(&Int8Array::from(vec![None, None])));
+        assert_eq!(
+            None,
+            quantile(
+                &UInt64Array::from(Vec::<u64>::new()),
+                0.1,
+                QuantileInterpolation::Lower
+            )
+            .unwrap()
+        );
+        assert!(quantile(&a, 1.5, QuantileInterpolation::Linear).is_err());
+        assert!(quantile(&a, f64::NAN, QuantileInterpolation::Linear).is_err());
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let cases: Vec<(ArrayRef, usize)> = vec![
+            (
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(2),
+                    Some(1),
+                    None,
+                ])),
+                2,
+            ),
+            (
+                Arc::new(Float64Array::from(vec![
+                    0.0,
+                    -0.0,
+                    f64::NAN,
+                    -f64::NAN,
+                    1.5,
+                    1.5,
+                ])),
+                3,
+            ),
+            (Arc::new(Float32Array::from(vec![-0.0, 0.0, 2.0])), 2),
+            (
+                Arc::new(BooleanArray::from(vec![Some(true), None, Some(true)])),
+                1,
+            ),
+            (
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    None,
+                    Some("a"),
+                ])),
+                2,
+            ),
+            (
+                Arc::new(LargeBinaryArray::from(vec![&b"x"[..], &b""[..], &b""[..]])),
+                2,
+            ),
+            (
+                Arc::new(TimestampSecondArray::from_vec(vec![1, 1, 3], None)),
+                2,
+            ),
+            (Arc::new(NullArray::new(3)), 0),
+            (Arc::new(UInt8Array::from(Vec::<u8>::new())), 0),
+        ];
+        for (array, expected) in cases {
+            assert_eq!(expected, count_distinct(&array).unwrap(), "{:?}", array);
+            assert_eq!(
+                expected,
+                approx_count_distinct(&array).unwrap(),
+                "{:?}",
+                array
+            );
+        }
+
+        // sliced
+        let a: ArrayRef = Arc::new(Int16Array::from(vec![1, 2, 3, 3, 4]));
+        let a = a.slice(2, 3);
+        assert_eq!(2, count_distinct(&a).unwrap());
+        assert_eq!(2, approx_count_distinct(&a).unwrap());
+
+        let structs: ArrayRef = Arc::new(StructArray::from(vec![(
+            Field::new("a", DataType::Int32, false),
+            Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+        )]));
+        assert!(count_distinct(&structs).is_err());
+    }
+
+    #[test]
+    fn test_approx_count_distinct() {
+        let a: ArrayRef = Arc::new(Int64Array::from(
+            (0..200_000).map(|i| i % 100_000).collect::<Vec<i64>>(),
+        ));
+        assert_eq!(100_000, count_distinct(&a).unwrap());
+        let estimate = approx_count_distinct(&a).unwrap() as f64;
+        assert!((estimate - 100_000.0).abs() < 3_000.0, "{}", estimate);
+
+        let a: ArrayRef = Arc::new(
+            (0..5_000)
+                .map(|i| Some(format!("value {}", i)))
+                .collect::<StringArray>(),
+        );
+        let estimate = approx_count_distinct(&a).unwrap() as f64;
+        assert!((estimate - 5_000.0).abs() < 150.0, "{}", estimate);
+    }
+
+    #[test]
+    fn test_bit_and_or_xor() {
+        let a = Int32Array::from(vec![Some(0b1101), None, Some(0b0111), Some(0b0101)]);
+        assert_eq!(Some(0b0101), bit_and(&a));
+        assert_eq!(Some(0b1111), bit_or(&a));
+        assert_eq!(Some(0b1111), bit_xor(&a));
+
+        let a = Int8Array::from(vec![-1, -2]);
+        assert_eq!(Some(-2), bit_and(&a));
+        assert_eq!(Some(-1), bit_or(&a));
+        assert_eq!(Some(1), bit_xor(&a));
+
+        let a = UInt64Array::from(vec![Some(u64::MAX), Some(1 << 40), None]);
+        let a = a.slice(1, 2);
+        let a = a.as_typed::<UInt64Array>().unwrap();
+        assert_eq!(Some(1 << 40), bit_and(a));
+        assert_eq!(Some(1 << 40), bit_xor(a));
+
+        let a = UInt16Array::from(vec![None, None]);
+        assert_eq!(None, bit_and(&a));
+        assert_eq!(None, bit_or(&a));
+        assert_eq!(None, bit_xor(&UInt16Array::from(Vec::<u16>::new())));
+    }
+
+    #[test]
+    fn test_count_summary() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+        ]);
+        let mut lists = ListBuilder::new(Int32Builder::new(2));
+        lists.values().append_value(1).unwrap();
+        lists.append(true).unwrap();
+        lists.append(false).unwrap();
+        lists.append(true).unwrap();
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(1)])),
+                Arc::new(StringArray::from(vec![Some("x"), Some("y"), Some("z")])),
+                Arc::new(lists.finish()),
+            ],
+        )
+        .unwrap();
+
+        let summary = count_summary(&batch).unwrap();
+        assert_eq!(3, summary.num_rows());
+        assert_eq!(
+            &StringArray::from(vec!["a", "b", "c"]),
+            summary.column(0).as_typed::<StringArray>().unwrap()
+        );
+        assert_eq!(
+            &UInt64Array::from(vec![2, 3, 2]),
+            summary.column(1).as_typed::<UInt64Array>().unwrap()
+        );
+        assert_eq!(
+            &UInt64Array::from(vec![1, 0, 1]),
+            summary.column(2).as_typed::<UInt64Array>().unwrap()
+        );
+        assert_eq!(
+            &UInt64Array::from(vec![Some(1), Some(3), None]),
+            summary.column(3).as_typed::<UInt64Array>().unwrap()
+        );
+        assert!(!summary.schema().field(1).is_nullable());
+        assert!(summary.schema().field(3).is_nullable());
+    }
+
     #[test]
     fn test_primitive_array_min_max() {
         let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
@@ -865,4 +2818,399 @@ mod tests {
         assert_eq!(Some("a"), min_string(&a));
         assert_eq!(Some("b"), max_string(&a));
     }
+
+    #[test]
+    fn test_string_min_max_large_and_sliced() {
+        let a = LargeStringArray::from(vec![Some("b"), None, Some("d"), Some("a")]);
+        assert_eq!(Some("a"), min_string(&a));
+        assert_eq!(Some("d"), max_string(&a));
+
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), Some("b"), Some("z")]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(Some("b"), min_string(a));
+        assert_eq!(Some("c"), max_string(a));
+
+        let a = StringArray::from(Vec::<&str>::new());
+        assert_eq!(None, min_string(&a));
+        assert_eq!(None, max_string(&a));
+    }
+
+    #[test]
+    fn test_boolean_min_max() {
+        let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(Some(false), min_boolean(&a));
+        assert_eq!(Some(true), max_boolean(&a));
+
+        let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        assert_eq!(Some(true), min_boolean(&a));
+        assert_eq!(Some(true), max_boolean(&a));
+
+        let a = BooleanArray::from(vec![None, None]);
+        assert_eq!(None, min_boolean(&a));
+        assert_eq!(None, max_boolean(&a));
+    }
+
+    #[test]
+    fn test_bool_and_or() {
+        // more than a word of values, with a single `false` in the remainder
+        let values: Vec<Option<bool>> = (0..100)
+            .map(|i| match i {
+                90 => Some(false),
+                i if i % 3 == 0 => None,
+                _ => Some(true),
+            })
+            .collect();
+        let a = BooleanArray::from(values);
+        assert_eq!(Some(false), bool_and(&a));
+        assert_eq!(Some(true), bool_or(&a));
+
+        // slices that skip the `false` value
+        let sliced = a.slice(3, 80);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(Some(true), bool_and(sliced));
+        let sliced = a.slice(91, 9);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(Some(true), bool_and(sliced));
+
+        // nulls are skipped
+        let values: Vec<Option<bool>> = (0..70)
+            .map(|i| if i % 2 == 0 { None } else { Some(false) })
+            .collect();
+        let a = BooleanArray::from(values);
+        assert_eq!(Some(false), bool_and(&a));
+        assert_eq!(Some(false), bool_or(&a));
+
+        // without a null buffer
+        let a = BooleanArray::from(vec![false; 130]);
+        assert_eq!(Some(false), bool_and(&a));
+        assert_eq!(Some(false), bool_or(&a));
+        let a = BooleanArray::from(vec![true; 130]);
+        assert_eq!(Some(true), bool_and(&a));
+        assert_eq!(Some(true), bool_or(&a));
+        let sliced = a.slice(5, 3);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(Some(true), bool_and(sliced));
+
+        let a = BooleanArray::from(Vec::<bool>::new());
+        assert_eq!(None, bool_and(&a));
+        assert_eq!(None, bool_or(&a));
+        let a = BooleanArray::from(vec![None; 65]);
+        assert_eq!(None, bool_and(&a));
+        assert_eq!(None, bool_or(&a));
+    }
+
+    #[test]
+    fn test_first_last() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+            Some("e"),
+        ]));
+        let order_by: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(3),
+            Some(1),
+            Some(5),
+            None,
+            Some(1),
+        ]));
+
+        // nulls first by default, ties are resolved by position
+        assert_eq!(
+            Scalar::Utf8(Some("d".to_string())),
+            first(&values, &order_by, None).unwrap()
+        );
+        assert_eq!(Scalar::Utf8(None), last(&values, &order_by, None).unwrap());
+
+        let options = SortOptions {
+            descending: false,
+            nulls_first: false,
+        };
+        assert_eq!(
+            Scalar::Utf8(Some("b".to_string())),
+            first(&values, &order_by, Some(options)).unwrap()
+        );
+        assert_eq!(
+            Scalar::Utf8(Some("d".to_string())),
+            last(&values, &order_by, Some(options)).unwrap()
+        );
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        assert_eq!(
+            Scalar::Utf8(None),
+            first(&values, &order_by, Some(options)).unwrap()
+        );
+        assert_eq!(
+            Scalar::Utf8(Some("d".to_string())),
+            last(&values, &order_by, Some(options)).unwrap()
+        );
+        let values = values.slice(0, 3);
+        let order_by = order_by.slice(0, 3);
+        assert_eq!(
+            Scalar::Utf8(Some("b".to_string())),
+            last(&values, &order_by, Some(options)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_first_last_edge_cases() {
+        let values: ArrayRef = Arc::new(Float64Array::from(Vec::<f64>::new()));
+        let order_by: ArrayRef = Arc::new(StringArray::from(Vec::<&str>::new()));
+        assert_eq!(
+            Scalar::Float64(None),
+            first(&values, &order_by, None).unwrap()
+        );
+        assert_eq!(
+            Scalar::Float64(None),
+            last(&values, &order_by, None).unwrap()
+        );
+
+        let values: ArrayRef = Arc::new(Int64Array::from(vec![1, 2, 3]));
+        let order_by: ArrayRef = Arc::new(StringArray::from(vec!["b", "c", "a"]));
+        assert_eq!(
+            Scalar::Int64(Some(3)),
+            first(&values, &order_by, None).unwrap()
+        );
+        assert_eq!(
+            Scalar::Int64(Some(2)),
+            last(&values, &order_by, None).unwrap()
+        );
+
+        let order_by: ArrayRef = Arc::new(StringArray::from(vec!["b"]));
+        assert!(first(&values, &order_by, None).is_err());
+    }
+
+    #[test]
+    fn test_min_max_one_pass() {
+        let a: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(5), None, Some(-3), Some(9)]));
+        assert_eq!(
+            (Scalar::Int32(Some(-3)), Scalar::Int32(Some(9))),
+            min_max(&a).unwrap()
+        );
+
+        let a: ArrayRef =
+            Arc::new(Float64Array::from(vec![Some(f64::NAN), Some(1.0), None]));
+        let (min, max) = min_max(&a).unwrap();
+        assert_eq!(Scalar::Float64(Some(1.0)), min);
+        assert!(matches!(max, Scalar::Float64(Some(v)) if v.is_nan()));
+
+        let a: ArrayRef = Arc::new(StringArray::from(vec![Some("b"), None, Some("a")]));
+        assert_eq!(
+            (
+                Scalar::Utf8(Some("a".to_string())),
+                Scalar::Utf8(Some("b".to_string()))
+            ),
+            min_max(&a).unwrap()
+        );
+
+        let a: ArrayRef = Arc::new(BooleanArray::from(vec![Some(true), Some(false)]));
+        assert_eq!(
+            (Scalar::Boolean(Some(false)), Scalar::Boolean(Some(true))),
+            min_max(&a).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_min_max_one_pass_all_nulls_and_sliced() {
+        let a: ArrayRef = Arc::new(Int64Array::from(vec![None, None]));
+        assert_eq!(
+            (Scalar::Int64(None), Scalar::Int64(None)),
+            min_max(&a).unwrap()
+        );
+
+        let a: ArrayRef =
+            Arc::new(UInt8Array::from(vec![Some(1), Some(7), None, Some(3)]));
+        let a = a.slice(1, 3);
+        assert_eq!(
+            (Scalar::UInt8(Some(3)), Scalar::UInt8(Some(7))),
+            min_max(&a).unwrap()
+        );
+
+        let a: ArrayRef = Arc::new(BinaryArray::from(vec![b"a".as_ref()]));
+        assert!(min_max(&a).is_err());
+    }
+
+    #[test]
+    fn test_aggregates_of_slices() {
+        // offsets and lengths across and within the 64-value chunks of the bitmaps
+        let slices = [
+            (0, 130),
+            (1, 129),
+            (3, 64),
+            (63, 2),
+            (64, 66),
+            (70, 0),
+            (129, 1),
+        ];
+
+        let values: Vec<Option<i64>> = (0..130)
+            .map(|i| {
+                if i % 5 == 2 {
+                    None
+                } else {
+                    Some((i * 31) % 97 - 40)
+                }
+            })
+            .collect();
+        let array = Int64Array::from(values.clone());
+        for (offset, len) in slices.iter() {
+            let sliced = array.slice(*offset, *len);
+            let sliced = sliced.as_typed::<Int64Array>().unwrap();
+            let expected = Int64Array::from(values[*offset..offset + len].to_vec());
+            assert_eq!(expected.null_count(), sliced.null_count());
+            assert_eq!(sum(&expected), sum(sliced), "{} {}", offset, len);
+            assert_eq!(min(&expected), min(sliced), "{} {}", offset, len);
+            assert_eq!(max(&expected), max(sliced), "{} {}", offset, len);
+
+            // and without a null bitmap
+            let dense = Int64Array::from(
+                values[*offset..offset + len]
+                    .iter()
+                    .map(|v| v.unwrap_or(0))
+                    .collect::<Vec<_>>(),
+            );
+            let all = Int64Array::from(
+                values.iter().map(|v| v.unwrap_or(0)).collect::<Vec<_>>(),
+            );
+            let sliced = all.slice(*offset, *len);
+            let sliced = sliced.as_typed::<Int64Array>().unwrap();
+            assert_eq!(sum(&dense), sum(sliced));
+            assert_eq!(min(&dense), min(sliced));
+            assert_eq!(max(&dense), max(sliced));
+        }
+
+        let values: Vec<Option<bool>> = (0..130)
+            .map(|i| match i % 7 {
+                0 => None,
+                1 | 4 => Some(false),
+                _ => Some(true),
+            })
+            .collect();
+        let array = BooleanArray::from(values.clone());
+        for (offset, len) in slices.iter() {
+            let sliced = array.slice(*offset, *len);
+            let sliced = sliced.as_typed::<BooleanArray>().unwrap();
+            let expected = BooleanArray::from(values[*offset..offset + len].to_vec());
+            assert_eq!(expected.null_count(), sliced.null_count());
+            assert_eq!(
+                min_boolean(&expected),
+                min_boolean(sliced),
+                "{} {}",
+                offset,
+                len
+            );
+            assert_eq!(
+                max_boolean(&expected),
+                max_boolean(sliced),
+                "{} {}",
+                offset,
+                len
+            );
+        }
+        // the only false value is outside of the slice
+        let array = BooleanArray::from(vec![false, true, true]);
+        let sliced = array.slice(1, 2);
+        assert_eq!(
+            Some(true),
+            min_boolean(sliced.as_typed::<BooleanArray>().unwrap())
+        );
+
+        let values: Vec<Option<String>> = (0..130)
+            .map(|i| {
+                if i % 3 == 0 {
+                    None
+                } else {
+                    Some(format!("s{}", (i * 17) % 53))
+                }
+            })
+            .collect();
+        let array: StringArray = values.iter().cloned().collect();
+        let array: ArrayRef = Arc::new(array);
+        for (offset, len) in slices.iter() {
+            let sliced = array.slice(*offset, *len);
+            let expected: StringArray =
+                values[*offset..offset + len].iter().cloned().collect();
+            let expected: ArrayRef = Arc::new(expected);
+            assert_eq!(expected.null_count(), sliced.null_count());
+            let (typed, expected_typed) = (
+                sliced.as_typed::<StringArray>().unwrap(),
+                expected.as_typed::<StringArray>().unwrap(),
+            );
+            assert_eq!(min_string(expected_typed), min_string(typed));
+            assert_eq!(max_string(expected_typed), max_string(typed));
+            assert_eq!(min_max(&expected).unwrap(), min_max(&sliced).unwrap());
+            assert_eq!(
+                count_distinct(&expected).unwrap(),
+                count_distinct(&sliced).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_min_max_lanes_match_naive() {
+        // values and validity that are neither aligned to the lanes nor to the 64 values
+        // of the validity chunks, with NaNs and nulls in the first and last lanes
+        let values: Vec<Option<f64>> = (0..203)
+            .map(|i| match i {
+                _ if i % 7 == 3 || i == 0 => None,
+                _ if i % 50 == 49 => Some(f64::NAN),
+                _ => Some(((i * 37) % 101) as f64 - 50.0),
+            })
+            .collect();
+        for offset in [0, 1, 13, 64, 150, 202].iter() {
+            for len in [0, 1, 17, 40].iter() {
+                let len = (*len).min(values.len() - offset);
+                let valid = values[*offset..offset + len]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                // NaN values are greater than any other value, while `f64::min` and
+                // `f64::max` skip them
+                let (expected_min, expected_max) = if valid.is_empty() {
+                    (None, None)
+                } else if valid.iter().any(|v| v.is_nan()) {
+                    let min = valid.iter().fold(f64::NAN, |acc, v| acc.min(*v));
+                    (Some(min), Some(f64::NAN))
+                } else {
+                    let min = valid.iter().fold(f64::INFINITY, |acc, v| acc.min(*v));
+                    let max = valid.iter().fold(f64::NEG_INFINITY, |acc, v| acc.max(*v));
+                    (Some(min), Some(max))
+                };
+
+                let array = Float64Array::from(values.clone());
+                let array = array.slice(*offset, len);
+                let array = array.as_typed::<Float64Array>().unwrap();
+                let actual = (min(array), max(array));
+                assert_eq!(
+                    format!("{:?}", (expected_min, expected_max)),
+                    format!("{:?}", actual),
+                    "offset {} len {}",
+                    offset,
+                    len
+                );
+
+                // without nulls, through the branch-free path
+                let non_null = Float64Array::from(valid);
+                assert_eq!(0, non_null.null_count());
+                assert_eq!(
+                    format!("{:?}", (expected_min, expected_max)),
+                    format!("{:?}", (min(&non_null), max(&non_null)))
+                );
+                let array: ArrayRef = Arc::new(non_null);
+                let expected =
+                    (Scalar::Float64(expected_min), Scalar::Float64(expected_max));
+                assert_eq!(
+                    format!("{:?}", expected),
+                    format!("{:?}", min_max(&array).unwrap())
+                );
+            }
+        }
+    }
 }
@@ -24,22 +24,31 @@
 
 #[cfg(feature = "simd")]
 use std::mem;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 #[cfg(feature = "simd")]
 use std::slice::from_raw_parts_mut;
 use std::sync::Arc;
 
-use num::{One, Zero};
+use num::traits::{
+    CheckedNeg, CheckedRem, MulAdd, SaturatingAdd, SaturatingMul, SaturatingSub,
+};
+use num::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, Num, One, Signed, Zero,
+};
 
 #[cfg(feature = "simd")]
 use crate::bitmap::Bitmap;
-use crate::buffer::Buffer;
-#[cfg(feature = "simd")]
 use crate::buffer::MutableBuffer;
-use crate::compute::util::combine_option_bitmap;
+use crate::buffer::{buffer_bin_or, Buffer};
+use crate::compute::kernels::aggregate::{max_of, min_of};
+use crate::compute::kernels::cast::cast;
 #[cfg(simd_x86)]
 use crate::compute::util::simd_load_set_invalid;
+use crate::compute::util::{
+    combine_option_bitmap, combine_option_buffers, into_mutable_values,
+};
 use crate::datatypes;
+use crate::datatypes::DataType;
 use crate::datatypes::ToByteSlice;
 use crate::error::{ArrowError, Result};
 use crate::{array::*, util::bit_util};
@@ -151,20 +160,308 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
-/// Helper function to divide two arrays.
+/// Helper function to perform math lambda function on values from a single array. If
+/// value is null then the output value is also null.
+pub fn unary_math_op<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native) -> T::Native,
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let values = array
+        .value_slice(0, array.len())
+        .iter()
+        .map(|v| op(*v))
+        .collect::<Vec<T::Native>>();
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Like `unary_math_op`, but takes ownership of `array` to apply `op` to its values in
+/// place, without allocating, when `array` is the only reference to its values and is
+/// not sliced. Otherwise, a new array is allocated.
+pub fn unary_mut<T, F>(array: PrimitiveArray<T>, op: F) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Num,
+    F: Fn(T::Native) -> T::Native,
+{
+    match into_mutable_values(array) {
+        Ok(mut values) => {
+            let len = values.len;
+            values.values.typed_data_mut::<T::Native>()[..len]
+                .iter_mut()
+                .for_each(|v| *v = op(*v));
+            Ok(PrimitiveArray::<T>::from(values.finish()))
+        }
+        Err(data) => unary_math_op(&PrimitiveArray::<T>::from(data), op),
+    }
+}
+
+/// Like `math_op`, but takes ownership of `left` to write the results into its values in
+/// place, without allocating, when `left` is the only reference to its values and is not
+/// sliced. Otherwise, a new array is allocated.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths
+pub fn binary_mut<T, F>(
+    left: PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Num,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let null_bit_buffer =
+        combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
+    match into_mutable_values(left) {
+        Ok(mut values) => {
+            let len = values.len;
+            values.values.typed_data_mut::<T::Native>()[..len]
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, v)| *v = op(*v, right.value(i)));
+            values.null_bit_buffer = null_bit_buffer;
+            Ok(PrimitiveArray::<T>::from(values.finish()))
+        }
+        Err(data) => math_op(&PrimitiveArray::<T>::from(data), right, op),
+    }
+}
+
+/// Helper function to perform math lambda function on values from an array and a scalar
+/// value. If the array value is null then the output value is also null, so `null + 1`
+/// is `null`.
+pub fn math_op_scalar<T, F>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    unary_math_op(array, |v| op(v, scalar))
+}
+
+/// Options that define how checked arithmetic kernels should behave
+#[derive(Clone, Debug, Default)]
+pub struct ArithmeticOptions {
+    /// Emit a null slot instead of returning an error when an operation overflows
+    pub null_on_overflow: bool,
+}
+
+/// Helper function to perform a checked math lambda function on values from two arrays.
+/// `op` returns `None` when the operation overflows, in which case this function either
+/// errors or sets the output slot to null, according to `options`. If either left or
+/// right value is null then the output value is also null.
+///
+/// # Errors
+///
+/// This function errors if:
+/// * the arrays have different lengths
+/// * an overflow occurs and `options.null_on_overflow` is not set
+fn math_checked_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op_name: &str,
+    op: F,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> Option<T::Native>,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let null_bit_buffer = combine_option_bitmap(left.data_ref(), right.data_ref(), len)?;
+
+    math_checked_op_by_index(
+        len,
+        null_bit_buffer,
+        |i| op(left.value(i), right.value(i)),
+        |i| {
+            ArrowError::ArithmeticOverflow(format!(
+                "{:?} {} {:?}",
+                left.value(i),
+                op_name,
+                right.value(i)
+            ))
+        },
+        options,
+    )
+}
+
+/// Helper function to perform a checked math lambda function on values from a single
+/// array, such as `-x`. See `math_checked_op` for the handling of overflows.
+fn math_checked_unary_op<T, F>(
+    array: &PrimitiveArray<T>,
+    op_name: &str,
+    op: F,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native) -> Option<T::Native>,
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    math_checked_op_by_index(
+        array.len(),
+        null_bit_buffer,
+        |i| op(array.value(i)),
+        |i| ArrowError::ArithmeticOverflow(format!("{}({:?})", op_name, array.value(i))),
+        options,
+    )
+}
+
+/// Helper function to perform a checked math lambda function on values from an array
+/// and a scalar value, such as `x / 2`. The values of null slots are not computed. See
+/// `math_checked_op` for the handling of overflows.
+fn math_checked_op_scalar<T, F>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    op_name: &str,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> Option<T::Native>,
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    math_checked_op_by_index(
+        array.len(),
+        null_bit_buffer,
+        |i| op(array.value(i), scalar),
+        |i| {
+            ArrowError::ArithmeticOverflow(format!(
+                "{:?} {} {:?}",
+                array.value(i),
+                op_name,
+                scalar
+            ))
+        },
+        None,
+    )
+}
+
+/// Computes the `len` values of a checked operation, where `op` computes the value of
+/// the i-th slot, or `None` when it fails, and `error` returns the error of the i-th
+/// slot when it fails.
+fn math_checked_op_by_index<T, F, E>(
+    len: usize,
+    null_bit_buffer: Option<Buffer>,
+    op: F,
+    error: E,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(usize) -> Option<T::Native>,
+    E: Fn(usize) -> ArrowError,
+{
+    let options = options.unwrap_or_default();
+
+    // slots that overflowed are unset in this bitmap, which is only allocated on the
+    // first overflow
+    let mut overflow_buf: Option<MutableBuffer> = None;
+
+    let mut values = Vec::with_capacity(len);
+    for i in 0..len {
+        let is_valid = null_bit_buffer
+            .as_ref()
+            .map(|b| bit_util::get_bit(b.data(), i))
+            .unwrap_or(true);
+        if !is_valid {
+            values.push(T::default_value());
+            continue;
+        }
+
+        match op(i) {
+            Some(value) => values.push(value),
+            None if options.null_on_overflow => {
+                let num_bytes = bit_util::ceil(len, 8);
+                let buf = overflow_buf.get_or_insert_with(|| {
+                    MutableBuffer::new(num_bytes).with_bitset(num_bytes, true)
+                });
+                bit_util::unset_bit(buf.data_mut(), i);
+                values.push(T::default_value());
+            }
+            None => return Err(error(i)),
+        }
+    }
+
+    let overflow_buf = overflow_buf.map(|b| b.freeze());
+    let null_bit_buffer = combine_option_buffers(
+        null_bit_buffer.as_ref(),
+        0,
+        overflow_buf.as_ref(),
+        0,
+        len,
+    );
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Helper function to perform a division-like math lambda function (such as `/` or `%`)
+/// on values from two arrays, checking the right hand values for zero.
 ///
 /// # Errors
 ///
 /// This function errors if:
 /// * the arrays have different lengths
 /// * a division by zero is found
-fn math_divide<T>(
+fn math_checked_divide_op<T, F>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
+    op: F,
 ) -> Result<PrimitiveArray<T>>
 where
     T: datatypes::ArrowNumericType,
-    T::Native: Div<Output = T::Native> + Zero,
+    T::Native: Zero,
+    F: Fn(T::Native, T::Native) -> T::Native,
 {
     if left.len() != right.len() {
         return Err(ArrowError::ComputeError(
@@ -185,7 +482,7 @@ where
                     if right_value.is_zero() {
                         return Err(ArrowError::DivideByZero);
                     } else {
-                        left.value(i) / right_value
+                        op(left.value(i), right_value)
                     }
                 } else {
                     T::default_value()
@@ -199,7 +496,7 @@ where
             values.push(if right_value.is_zero() {
                 return Err(ArrowError::DivideByZero);
             } else {
-                left.value(i) / right_value
+                op(left.value(i), right_value)
             });
         }
     };
@@ -216,6 +513,50 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
+/// Helper function to perform a division-like math lambda function on values from two
+/// arrays, where a zero right hand value makes the output slot null instead of erroring.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths.
+fn math_divide_opt_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Zero,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let null_bit_buffer = combine_option_bitmap(left.data_ref(), right.data_ref(), len)?;
+
+    // zero divisors are handled like overflows that emit null slots
+    math_checked_op_by_index(
+        len,
+        null_bit_buffer,
+        |i| {
+            let right_value = right.value(i);
+            if right_value.is_zero() {
+                None
+            } else {
+                Some(op(left.value(i), right_value))
+            }
+        },
+        |_| ArrowError::DivideByZero,
+        Some(ArithmeticOptions {
+            null_on_overflow: true,
+        }),
+    )
+}
+
 /// SIMD vectorized version of `math_op` above.
 #[cfg(simd_x86)]
 fn simd_math_op<T, F>(
@@ -266,17 +607,21 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
-/// SIMD vectorized version of `divide`, the divide kernel needs it's own implementation as there
-/// is a need to handle situations where a divide by `0` occurs.  This is complicated by `NULL`
-/// slots and padding.
+/// SIMD vectorized version of `math_checked_divide_op`, used by the `divide` and
+/// `modulus` kernels, which need their own implementation as there is a need to handle
+/// situations where a divide by `0` occurs.  This is complicated by `NULL` slots and
+/// padding: the right hand values of null slots are replaced by `1` before checking for
+/// zeros, so that only valid slots can cause an `Err(ArrowError::DivideByZero)`.
 #[cfg(simd_x86)]
-fn simd_divide<T>(
+fn simd_checked_divide_op<T, F>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
+    op: F,
 ) -> Result<PrimitiveArray<T>>
 where
     T: datatypes::ArrowNumericType,
     T::Native: One + Zero,
+    F: Fn(T::Simd, T::Simd) -> T::Simd,
 {
     if left.len() != right.len() {
         return Err(ArrowError::ComputeError(
@@ -301,7 +646,7 @@ where
             return Err(ArrowError::DivideByZero);
         }
         let simd_left = T::load(left.value_slice(i, lanes));
-        let simd_result = T::bin_op(simd_left, right_no_invalid_zeros, |a, b| a / b);
+        let simd_result = T::bin_op(simd_left, right_no_invalid_zeros, &op);
 
         let result_slice: &mut [T::Native] = unsafe {
             from_raw_parts_mut(
@@ -365,6 +710,9 @@ where
 }
 
 /// Perform `-` operation on an array. If value is null then the result is also null.
+///
+/// Day-time intervals pack two values into each slot, use `negate_interval_day_time`
+/// to negate them.
 pub fn negate<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
 where
     T: datatypes::ArrowSignedNumericType,
@@ -376,6 +724,80 @@ where
     return signed_unary_math_op(array, |x| -x);
 }
 
+/// Perform `-` operation on an array, checking for overflow. If value is null then the
+/// result is also null. If a negation overflows, which happens for the minimum value of
+/// signed integer types, the result of this operation will be
+/// `Err(ArrowError::ArithmeticOverflow)`, unless `null_on_overflow` is set in `options`,
+/// in which case the overflowing slot is null.
+pub fn negate_checked<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowSignedNumericType,
+    T::Native: Neg<Output = T::Native> + CheckedNeg,
+{
+    math_checked_unary_op(array, "-", |x| x.checked_neg(), options)
+}
+
+/// Perform `-` operation on an array of day-time intervals, negating both the days and
+/// the milliseconds of each interval. If value is null then the result is also null.
+/// If either negation overflows, which happens for `i32::MIN` days or milliseconds, the
+/// result of this operation will be `Err(ArrowError::ArithmeticOverflow)`.
+pub fn negate_interval_day_time(
+    array: &IntervalDayTimeArray,
+) -> Result<IntervalDayTimeArray> {
+    math_checked_unary_op(
+        array,
+        "-",
+        |v| {
+            // days are stored in the upper and milliseconds in the lower 32 bits
+            let days = ((v >> 32) as i32).checked_neg()?;
+            let millis = (v as i32).checked_neg()?;
+            Some(((days as i64) << 32) | (millis as u32 as i64))
+        },
+        None,
+    )
+}
+
+/// Computes the absolute value of each value of an array. If value is null then the
+/// result is also null.
+pub fn abs<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowSignedNumericType,
+    T::Native: Neg<Output = T::Native> + Signed,
+{
+    // only the valid slots are computed, as the values of null slots are unspecified
+    math_checked_unary_op(array, "abs", |x| Some(x.abs()), None)
+}
+
+/// Computes the absolute value of each value of an array, checking for overflow. If
+/// value is null then the result is also null. If the absolute value overflows, which
+/// happens for the minimum value of signed integer types, the result of this operation
+/// will be `Err(ArrowError::ArithmeticOverflow)`, unless `null_on_overflow` is set in
+/// `options`, in which case the overflowing slot is null.
+pub fn abs_checked<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowSignedNumericType,
+    T::Native: Neg<Output = T::Native> + CheckedNeg + Signed,
+{
+    math_checked_unary_op(
+        array,
+        "abs",
+        |x| {
+            if x.is_negative() {
+                x.checked_neg()
+            } else {
+                Some(x)
+            }
+        },
+        options,
+    )
+}
+
 /// Perform `left * right` operation on two arrays. If either left or right value is null
 /// then the result is also null.
 pub fn multiply<T>(
@@ -396,9 +818,54 @@ where
     return math_op(left, right, |a, b| a * b);
 }
 
+/// Perform `a * b + c` operation on three arrays in a single pass. If any of the values
+/// is null then the result is also null. Floating point values are computed with a
+/// single rounding error, using `mul_add`.
+pub fn multiply_add<T>(
+    a: &PrimitiveArray<T>,
+    b: &PrimitiveArray<T>,
+    c: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: MulAdd<Output = T::Native>,
+{
+    if a.len() != b.len() || a.len() != c.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = a.len();
+    let null_bit_buffer = combine_option_bitmap(a.data_ref(), b.data_ref(), len)?;
+    let null_bit_buffer = combine_option_buffers(
+        null_bit_buffer.as_ref(),
+        0,
+        c.data_ref().null_buffer(),
+        c.offset(),
+        len,
+    );
+
+    let values = (0..len)
+        .map(|i| a.value(i).mul_add(b.value(i), c.value(i)))
+        .collect::<Vec<T::Native>>();
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
 /// Perform `left / right` operation on two arrays. If either left or right value is null
 /// then the result is also null. If any right hand value is zero then the result of this
-/// operation will be `Err(ArrowError::DivideByZero)`.
+/// operation will be `Err(ArrowError::DivideByZero)`; use `divide_opt` to get null slots
+/// for zero divisors instead.
 pub fn divide<T>(
     left: &PrimitiveArray<T>,
     right: &PrimitiveArray<T>,
@@ -413,41 +880,742 @@ where
         + One,
 {
     #[cfg(simd_x86)]
-    return simd_divide(&left, &right);
+    return simd_checked_divide_op(&left, &right, |a, b| a / b);
     #[cfg(not(simd_x86))]
-    return math_divide(&left, &right);
+    return math_checked_divide_op(&left, &right, |a, b| a / b);
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::array::Int32Array;
-
-    #[test]
-    fn test_primitive_array_add() {
-        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
-        let b = Int32Array::from(vec![6, 7, 8, 9, 8]);
-        let c = add(&a, &b).unwrap();
-        assert_eq!(11, c.value(0));
-        assert_eq!(13, c.value(1));
-        assert_eq!(15, c.value(2));
-        assert_eq!(17, c.value(3));
-        assert_eq!(17, c.value(4));
-    }
-
-    #[test]
-    fn test_primitive_array_add_sliced() {
-        let a = Int32Array::from(vec![0, 0, 0, 5, 6, 7, 8, 9, 0]);
-        let b = Int32Array::from(vec![0, 0, 0, 6, 7, 8, 9, 8, 0]);
-        let a = a.slice(3, 5);
-        let b = b.slice(3, 5);
-        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
-        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+/// Perform `left % right` operation on two arrays. If either left or right value is null
+/// then the result is also null. The result has the same sign as the left hand value.
+/// If any right hand value is zero then the result of this operation will be
+/// `Err(ArrowError::DivideByZero)`.
+pub fn modulus<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Rem<Output = T::Native> + Zero + One,
+{
+    #[cfg(simd_x86)]
+    return simd_checked_divide_op(&left, &right, |a, b| a % b);
+    #[cfg(not(simd_x86))]
+    return math_checked_divide_op(left, right, |a, b| a % b);
+}
 
-        assert_eq!(5, a.value(0));
-        assert_eq!(6, b.value(0));
+/// Perform `left / right` operation on two arrays. If either left or right value is null
+/// then the result is also null. Unlike `divide`, a zero right hand value does not make
+/// this operation fail: the result is null for that slot instead.
+pub fn divide_opt<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Div<Output = T::Native> + Zero,
+{
+    math_divide_opt_op(left, right, |a, b| a / b)
+}
 
+/// Perform `left % right` operation on two arrays. If either left or right value is null
+/// then the result is also null. Unlike `modulus`, a zero right hand value does not make
+/// this operation fail: the result is null for that slot instead.
+pub fn modulus_opt<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Rem<Output = T::Native> + Zero,
+{
+    math_divide_opt_op(left, right, |a, b| a % b)
+}
+
+/// Perform `left + right` operation on two integer arrays, checking for overflow. If
+/// either left or right value is null then the result is also null. If an addition
+/// overflows then the result of this operation will be
+/// `Err(ArrowError::ArithmeticOverflow)`, unless `null_on_overflow` is set in `options`,
+/// in which case the overflowing slot is null.
+pub fn add_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedAdd,
+{
+    math_checked_op(left, right, "+", |a, b| a.checked_add(&b), options)
+}
+
+/// Perform `left - right` operation on two integer arrays, checking for overflow. If
+/// either left or right value is null then the result is also null. If a subtraction
+/// overflows then the result of this operation will be
+/// `Err(ArrowError::ArithmeticOverflow)`, unless `null_on_overflow` is set in `options`,
+/// in which case the overflowing slot is null.
+pub fn subtract_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedSub,
+{
+    math_checked_op(left, right, "-", |a, b| a.checked_sub(&b), options)
+}
+
+/// Perform `left * right` operation on two integer arrays, checking for overflow. If
+/// either left or right value is null then the result is also null. If a multiplication
+/// overflows then the result of this operation will be
+/// `Err(ArrowError::ArithmeticOverflow)`, unless `null_on_overflow` is set in `options`,
+/// in which case the overflowing slot is null.
+pub fn multiply_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    options: Option<ArithmeticOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedMul,
+{
+    math_checked_op(left, right, "*", |a, b| a.checked_mul(&b), options)
+}
+
+/// Perform `left + right` operation on two integer arrays, clamping the results that
+/// overflow to the minimum or maximum value of the type. If either left or right value
+/// is null then the result is also null.
+pub fn add_saturating<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: SaturatingAdd,
+{
+    math_op(left, right, |a, b| a.saturating_add(&b))
+}
+
+/// Perform `left - right` operation on two integer arrays, clamping the results that
+/// overflow to the minimum or maximum value of the type. If either left or right value
+/// is null then the result is also null.
+pub fn subtract_saturating<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: SaturatingSub,
+{
+    math_op(left, right, |a, b| a.saturating_sub(&b))
+}
+
+/// Perform `left * right` operation on two integer arrays, clamping the results that
+/// overflow to the minimum or maximum value of the type. If either left or right value
+/// is null then the result is also null.
+pub fn multiply_saturating<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: SaturatingMul,
+{
+    math_op(left, right, |a, b| a.saturating_mul(&b))
+}
+
+/// Perform `left + right` operation on an array and a scalar value. If the array value is
+/// null then the result is also null.
+pub fn add_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
+    math_op_scalar(array, scalar, |a, b| a + b)
+}
+
+/// Perform `left - right` operation on an array and a scalar value. If the array value is
+/// null then the result is also null.
+pub fn subtract_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Sub<Output = T::Native>,
+{
+    math_op_scalar(array, scalar, |a, b| a - b)
+}
+
+/// Perform `left * right` operation on an array and a scalar value. If the array value is
+/// null then the result is also null.
+pub fn multiply_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Mul<Output = T::Native>,
+{
+    math_op_scalar(array, scalar, |a, b| a * b)
+}
+
+/// Perform `left / right` operation on an array of integers and a scalar value. If the
+/// array value is null then the result is also null. If the scalar is zero then the
+/// result of this operation will be `Err(ArrowError::DivideByZero)`, and if the division
+/// overflows, which happens for the minimum value of signed types divided by `-1`, it
+/// will be `Err(ArrowError::ArithmeticOverflow)`.
+pub fn divide_scalar<T>(
+    array: &PrimitiveArray<T>,
+    divisor: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedDiv + Zero,
+{
+    if divisor.is_zero() {
+        return Err(ArrowError::DivideByZero);
+    }
+    math_checked_op_scalar(array, divisor, "/", |a, b| a.checked_div(&b))
+}
+
+/// Perform `left % right` operation on an array of integers and a scalar value. If the
+/// array value is null then the result is also null. If the scalar is zero then the
+/// result of this operation will be `Err(ArrowError::DivideByZero)`, and if the
+/// remainder overflows, which happens for the minimum value of signed types divided by
+/// `-1`, it will be `Err(ArrowError::ArithmeticOverflow)`.
+pub fn modulo_scalar<T>(
+    array: &PrimitiveArray<T>,
+    divisor: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: CheckedRem + Zero,
+{
+    if divisor.is_zero() {
+        return Err(ArrowError::DivideByZero);
+    }
+    math_checked_op_scalar(array, divisor, "%", |a, b| a.checked_rem(&b))
+}
+
+/// Promotes a numeric array to a floating point array, as expected by the floating point
+/// kernels such as `sqrt` or `power`.
+///
+/// `Float32` and `Float64` arrays are returned as is, while integer arrays are cast to
+/// `Float64`. Any other data type results in an `ArrowError::ComputeError`.
+pub fn promote_to_float(array: &ArrayRef) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Float32 | DataType::Float64 => Ok(array.clone()),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => cast(array, &DataType::Float64),
+        t => Err(ArrowError::ComputeError(format!(
+            "Cannot promote {:?} to a floating point type",
+            t
+        ))),
+    }
+}
+
+/// Raise each value of a floating point array to the power of `exponent`. If the value is
+/// null then the result is also null.
+pub fn powf_scalar<T>(
+    array: &PrimitiveArray<T>,
+    exponent: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.powf(exponent))
+}
+
+/// Raise each value of `base` to the power of the corresponding value of `exponent`. If
+/// either value is null then the result is also null.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths
+pub fn power<T>(
+    base: &PrimitiveArray<T>,
+    exponent: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    math_op(base, exponent, |a, b| a.powf(b))
+}
+
+/// Compute the square root of each value of a floating point array. If the value is null
+/// then the result is also null, while negative values produce `NaN`.
+pub fn sqrt<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.sqrt())
+}
+
+/// Compute `e^x` for each value of a floating point array. If the value is null then the
+/// result is also null.
+pub fn exp<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.exp())
+}
+
+/// Compute the natural logarithm of each value of a floating point array. If the value is
+/// null then the result is also null, while negative values produce `NaN`.
+pub fn ln<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.ln())
+}
+
+/// Compute the base 10 logarithm of each value of a floating point array. If the value is
+/// null then the result is also null, while negative values produce `NaN`.
+pub fn log10<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.log10())
+}
+
+/// Compute the base 2 logarithm of each value of a floating point array. If the value is
+/// null then the result is also null, while negative values produce `NaN`.
+pub fn log2<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.log2())
+}
+
+/// Compute the sine of each value (in radians) of a floating point array. If the value
+/// is null then the result is also null.
+pub fn sin<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.sin())
+}
+
+/// Compute the cosine of each value (in radians) of a floating point array. If the value
+/// is null then the result is also null.
+pub fn cos<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.cos())
+}
+
+/// Compute the tangent of each value (in radians) of a floating point array. If the value
+/// is null then the result is also null.
+pub fn tan<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.tan())
+}
+
+/// Compute the arcsine of each value of a floating point array. If the value is null
+/// then the result is also null. Values outside of `[-1, 1]` produce `NaN`.
+pub fn asin<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.asin())
+}
+
+/// Compute the arccosine of each value of a floating point array. If the value is null
+/// then the result is also null. Values outside of `[-1, 1]` produce `NaN`.
+pub fn acos<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.acos())
+}
+
+/// Compute the arctangent of each value of a floating point array. If the value is null
+/// then the result is also null.
+pub fn atan<T>(array: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    unary_math_op(array, |x| x.atan())
+}
+
+/// Compute the four quadrant arctangent of `y` and `x`, in radians, for each pair of
+/// values of two floating point arrays. If either value is null then the result is also
+/// null.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths
+pub fn atan2<T>(y: &PrimitiveArray<T>, x: &PrimitiveArray<T>) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    T::Native: Float,
+{
+    math_op(y, x, |a, b| a.atan2(b))
+}
+
+/// How the element-wise kernels [`min_elementwise`], [`max_elementwise`], [`least`] and
+/// [`greatest`] handle null values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NullPolicy {
+    /// Null values are skipped: the result is only null when all the values are null,
+    /// like `LEAST` and `GREATEST` of PostgreSQL
+    Skip,
+    /// The result is null when any value is null
+    Propagate,
+}
+
+fn elementwise_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    nulls: NullPolicy,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform element-wise operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    let (left_data, right_data) = (left.data_ref(), right.data_ref());
+
+    let null_bit_buffer = match nulls {
+        NullPolicy::Propagate => {
+            combine_option_bitmap(left_data, right_data, left.len())?
+        }
+        NullPolicy::Skip => match (left_data.null_buffer(), right_data.null_buffer()) {
+            (Some(l), Some(r)) => Some(buffer_bin_or(
+                l,
+                left.offset(),
+                r,
+                right.offset(),
+                left.len(),
+            )),
+            _ => None,
+        },
+    };
+    let values = (0..left.len())
+        .map(|i| {
+            let (l, r) = (left.value(i), right.value(i));
+            match (nulls, left.is_valid(i), right.is_valid(i)) {
+                (NullPolicy::Skip, false, _) => r,
+                (NullPolicy::Skip, _, false) => l,
+                _ => op(l, r),
+            }
+        })
+        .collect::<Vec<T::Native>>();
+
+    // the data type of `left` is kept, e.g. with the timezone of timestamps
+    let data = ArrayData::new(
+        left.data_type().clone(),
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Returns the least of the values of the same index of two numeric or temporal arrays,
+/// like the SQL function `LEAST` with two arguments. NaN values are greater than any
+/// other value, and null values are handled according to `nulls`.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths
+pub fn min_elementwise<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    nulls: NullPolicy,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+{
+    elementwise_op(left, right, nulls, min_of)
+}
+
+/// Returns the greatest of the values of the same index of two numeric or temporal
+/// arrays, like the SQL function `GREATEST` with two arguments. NaN values are greater
+/// than any other value, and null values are handled according to `nulls`.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths
+pub fn max_elementwise<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    nulls: NullPolicy,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+{
+    elementwise_op(left, right, nulls, max_of)
+}
+
+fn fold_elementwise<T, F>(
+    arrays: &[&PrimitiveArray<T>],
+    nulls: NullPolicy,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native + Copy,
+{
+    let (first, rest) = arrays.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "Element-wise operations require at least one array".to_string(),
+        )
+    })?;
+    rest.iter()
+        .try_fold(PrimitiveArray::<T>::from(first.data()), |acc, array| {
+            elementwise_op(&acc, array, nulls, op)
+        })
+}
+
+/// Returns the least of the values of the same index of numeric or temporal `arrays`,
+/// like the SQL function `LEAST`, as [`min_elementwise`] does for two arrays.
+///
+/// # Errors
+///
+/// This function errors if `arrays` is empty, or if the arrays have different lengths
+pub fn least<T>(
+    arrays: &[&PrimitiveArray<T>],
+    nulls: NullPolicy,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+{
+    fold_elementwise(arrays, nulls, min_of)
+}
+
+/// Returns the greatest of the values of the same index of numeric or temporal `arrays`,
+/// like the SQL function `GREATEST`, as [`max_elementwise`] does for two arrays.
+///
+/// # Errors
+///
+/// This function errors if `arrays` is empty, or if the arrays have different lengths
+pub fn greatest<T>(
+    arrays: &[&PrimitiveArray<T>],
+    nulls: NullPolicy,
+) -> Result<PrimitiveArray<T>>
+where
+    T: datatypes::ArrowNumericType,
+{
+    fold_elementwise(arrays, nulls, max_of)
+}
+
+/// Returns the common numeric type that the `*_dyn` arithmetic kernels cast `left` and
+/// `right` to. Integer types are widened so that both represent all their values, while
+/// integers combined with floating point types result in `Float32` if it can represent
+/// the integers exactly, and `Float64` otherwise.
+///
+/// Returns `None` if there is no such type, for example if either type is not numeric or
+/// if `UInt64` is combined with a signed integer type.
+pub fn arithmetic_supertype(left: &DataType, right: &DataType) -> Option<DataType> {
+    use DataType::*;
+
+    // (is float, is signed, bit width) of the numeric types
+    fn numeric_kind(data_type: &DataType) -> Option<(bool, bool, u8)> {
+        match data_type {
+            Int8 => Some((false, true, 8)),
+            Int16 => Some((false, true, 16)),
+            Int32 => Some((false, true, 32)),
+            Int64 => Some((false, true, 64)),
+            UInt8 => Some((false, false, 8)),
+            UInt16 => Some((false, false, 16)),
+            UInt32 => Some((false, false, 32)),
+            UInt64 => Some((false, false, 64)),
+            Float32 => Some((true, true, 32)),
+            Float64 => Some((true, true, 64)),
+            _ => None,
+        }
+    }
+
+    if left == right {
+        return numeric_kind(left).map(|_| left.clone());
+    }
+
+    let (l_float, l_signed, l_width) = numeric_kind(left)?;
+    let (r_float, r_signed, r_width) = numeric_kind(right)?;
+
+    let data_type = if l_float || r_float {
+        // a Float32 represents integers of up to 16 bits exactly
+        let width =
+            |is_float: bool, width: u8| if is_float { (width, 0) } else { (0, width) };
+        let (l_float_width, l_int_width) = width(l_float, l_width);
+        let (r_float_width, r_int_width) = width(r_float, r_width);
+        if l_float_width.max(r_float_width) == 32 && l_int_width.max(r_int_width) <= 16 {
+            Float32
+        } else {
+            Float64
+        }
+    } else if l_signed == r_signed {
+        let width = l_width.max(r_width);
+        match (l_signed, width) {
+            (true, 8) => Int8,
+            (true, 16) => Int16,
+            (true, 32) => Int32,
+            (true, _) => Int64,
+            (false, 8) => UInt8,
+            (false, 16) => UInt16,
+            (false, 32) => UInt32,
+            (false, _) => UInt64,
+        }
+    } else {
+        // the signed type must be wider than the unsigned one
+        let (signed_width, unsigned_width) = if l_signed {
+            (l_width, r_width)
+        } else {
+            (r_width, l_width)
+        };
+        match signed_width.max(unsigned_width * 2) {
+            16 => Int16,
+            32 => Int32,
+            64 => Int64,
+            _ => return None,
+        }
+    };
+    Some(data_type)
+}
+
+/// Casts both arrays to their `arithmetic_supertype`, and applies the typed kernel `$op`.
+macro_rules! math_dyn_op {
+    ($left:expr, $right:expr, $op_name:expr, $op:ident) => {{
+        let (left, right) = ($left, $right);
+        let data_type = arithmetic_supertype(left.data_type(), right.data_type())
+            .ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Cannot perform {} between {:?} and {:?}",
+                    $op_name,
+                    left.data_type(),
+                    right.data_type()
+                ))
+            })?;
+        let left = cast(left, &data_type)?;
+        let right = cast(right, &data_type)?;
+
+        macro_rules! typed_op {
+            ($array_type:ident) => {{
+                let left = left.as_typed::<$array_type>()?;
+                let right = right.as_typed::<$array_type>()?;
+                Ok(Arc::new($op(left, right)?) as ArrayRef)
+            }};
+        }
+
+        match data_type {
+            DataType::Int8 => typed_op!(Int8Array),
+            DataType::Int16 => typed_op!(Int16Array),
+            DataType::Int32 => typed_op!(Int32Array),
+            DataType::Int64 => typed_op!(Int64Array),
+            DataType::UInt8 => typed_op!(UInt8Array),
+            DataType::UInt16 => typed_op!(UInt16Array),
+            DataType::UInt32 => typed_op!(UInt32Array),
+            DataType::UInt64 => typed_op!(UInt64Array),
+            DataType::Float32 => typed_op!(Float32Array),
+            DataType::Float64 => typed_op!(Float64Array),
+            _ => unreachable!("arithmetic supertypes are numeric"),
+        }
+    }};
+}
+
+/// Perform `left + right` operation on two arrays of possibly different numeric types,
+/// which are first cast to their `arithmetic_supertype`. If either left or right value
+/// is null then the result is also null.
+pub fn add_dyn(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    math_dyn_op!(left, right, "addition", add)
+}
+
+/// Perform `left - right` operation on two arrays of possibly different numeric types,
+/// which are first cast to their `arithmetic_supertype`. If either left or right value
+/// is null then the result is also null.
+pub fn subtract_dyn(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    math_dyn_op!(left, right, "subtraction", subtract)
+}
+
+/// Perform `left * right` operation on two arrays of possibly different numeric types,
+/// which are first cast to their `arithmetic_supertype`. If either left or right value
+/// is null then the result is also null.
+pub fn multiply_dyn(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    math_dyn_op!(left, right, "multiplication", multiply)
+}
+
+/// Perform `left / right` operation on two arrays of possibly different numeric types,
+/// which are first cast to their `arithmetic_supertype`. If either left or right value
+/// is null then the result is also null. If any right hand value is zero then the result
+/// of this operation will be `Err(ArrowError::DivideByZero)`.
+pub fn divide_dyn(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    math_dyn_op!(left, right, "division", divide)
+}
+
+/// Perform `left % right` operation on two arrays of possibly different numeric types,
+/// which are first cast to their `arithmetic_supertype`. If either left or right value
+/// is null then the result is also null. If any right hand value is zero then the result
+/// of this operation will be `Err(ArrowError::DivideByZero)`.
+pub fn modulus_dyn(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    math_dyn_op!(left, right, "modulus", modulus)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::Int32Array;
+
+    #[test]
+    fn test_primitive_array_add() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        let b = Int32Array::from(vec![6, 7, 8, 9, 8]);
         let c = add(&a, &b).unwrap();
+        assert_eq!(11, c.value(0));
+        assert_eq!(13, c.value(1));
+        assert_eq!(15, c.value(2));
+        assert_eq!(17, c.value(3));
+        assert_eq!(17, c.value(4));
+    }
+
+    #[test]
+    fn test_primitive_array_add_sliced() {
+        let a = Int32Array::from(vec![0, 0, 0, 5, 6, 7, 8, 9, 0]);
+        let b = Int32Array::from(vec![0, 0, 0, 6, 7, 8, 9, 8, 0]);
+        let a = a.slice(3, 5);
+        let b = b.slice(3, 5);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        assert_eq!(5, a.value(0));
+        assert_eq!(6, b.value(0));
+
+        let c = add(a, b).unwrap();
         assert_eq!(5, c.len());
         assert_eq!(11, c.value(0));
         assert_eq!(13, c.value(1));
@@ -514,7 +1682,7 @@ mod tests {
         let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
         let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
 
-        let c = divide(&a, &b).unwrap();
+        let c = divide(a, b).unwrap();
         assert_eq!(5, c.len());
         assert_eq!(3, c.value(0));
         assert_eq!(2, c.value(1));
@@ -579,7 +1747,7 @@ mod tests {
         let b = b.slice(8, 6);
         let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
 
-        let c = divide(&a, &b).unwrap();
+        let c = divide(a, b).unwrap();
         assert_eq!(6, c.len());
         assert_eq!(3, c.value(0));
         assert_eq!(true, c.is_null(1));
@@ -618,4 +1786,621 @@ mod tests {
         assert_eq!(true, c.is_null(3));
         assert_eq!(13, c.value(2));
     }
+
+    #[test]
+    fn test_primitive_array_add_scalar() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(8), None]);
+        let c = add_scalar(&a, 3).unwrap();
+        assert_eq!(5, c.len());
+        assert_eq!(8, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(10, c.value(2));
+        assert_eq!(11, c.value(3));
+        assert!(c.is_null(4));
+    }
+
+    #[test]
+    fn test_primitive_array_add_scalar_sliced() {
+        let a = Int32Array::from(vec![Some(1), None, Some(5), None, Some(9)]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = add_scalar(a, 1).unwrap();
+        assert_eq!(4, c.len());
+        assert!(c.is_null(0));
+        assert_eq!(6, c.value(1));
+        assert!(c.is_null(2));
+        assert_eq!(10, c.value(3));
+    }
+
+    #[test]
+    fn test_primitive_array_subtract_multiply_scalar() {
+        let a = Int32Array::from(vec![5, 6, 7]);
+        let c = subtract_scalar(&a, 2).unwrap();
+        assert_eq!(Int32Array::from(vec![3, 4, 5]), c);
+        let c = multiply_scalar(&a, 3).unwrap();
+        assert_eq!(Int32Array::from(vec![15, 18, 21]), c);
+    }
+
+    #[test]
+    fn test_primitive_array_divide_modulo_scalar() {
+        let a = Int32Array::from(vec![Some(15), None, Some(8), Some(-9)]);
+        let c = divide_scalar(&a, 4).unwrap();
+        assert_eq!(3, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(2, c.value(2));
+        assert_eq!(-2, c.value(3));
+        let c = modulo_scalar(&a, 4).unwrap();
+        assert_eq!(3, c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!(0, c.value(2));
+        assert_eq!(-1, c.value(3));
+    }
+
+    #[test]
+    fn test_primitive_array_divide_scalar_by_zero() {
+        let a = Int32Array::from(vec![15]);
+        assert!(matches!(
+            divide_scalar(&a, 0),
+            Err(ArrowError::DivideByZero)
+        ));
+        assert!(matches!(
+            modulo_scalar(&a, 0),
+            Err(ArrowError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn test_primitive_array_divide_scalar_overflow() {
+        let a = Int32Array::from(vec![Some(i32::MIN), None, Some(6)]);
+        let e = divide_scalar(&a, -1).unwrap_err();
+        assert_eq!("Arithmetic overflow: -2147483648 / -1", e.to_string());
+        let e = modulo_scalar(&a, -1).unwrap_err();
+        assert_eq!("Arithmetic overflow: -2147483648 % -1", e.to_string());
+
+        // the values of null slots are not computed
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![i32::MIN, 6]));
+        let a = a.with_null_buffer(Some(Buffer::from([0b10]))).unwrap();
+        let a = a.as_typed::<Int32Array>().unwrap();
+        let c = divide_scalar(a, -1).unwrap();
+        assert_eq!(Int32Array::from(vec![None, Some(-6)]), c);
+        let c = modulo_scalar(a, -1).unwrap();
+        assert_eq!(Int32Array::from(vec![None, Some(0)]), c);
+    }
+
+    #[test]
+    fn test_primitive_array_add_checked() {
+        let a = Int8Array::from(vec![Some(100), None, Some(27), Some(-128)]);
+        let b = Int8Array::from(vec![Some(27), Some(1), None, Some(0)]);
+        let c = add_checked(&a, &b, None).unwrap();
+        assert_eq!(c, Int8Array::from(vec![Some(127), None, None, Some(-128)]));
+    }
+
+    #[test]
+    fn test_primitive_array_add_checked_overflow() {
+        let a = Int8Array::from(vec![Some(100), Some(1)]);
+        let b = Int8Array::from(vec![Some(28), Some(1)]);
+        let e =
+            add_checked(&a, &b, None).expect_err("should have failed due to overflow");
+        assert_eq!("Arithmetic overflow: 100 + 28", format!("{}", e));
+    }
+
+    #[test]
+    fn test_primitive_array_checked_null_on_overflow() {
+        let options = Some(ArithmeticOptions {
+            null_on_overflow: true,
+        });
+        let a = UInt8Array::from(vec![Some(200), None, Some(3), Some(0)]);
+        let b = UInt8Array::from(vec![Some(100), Some(1), Some(4), Some(1)]);
+        let c = add_checked(&a, &b, options.clone()).unwrap();
+        assert_eq!(c, UInt8Array::from(vec![None, None, Some(7), Some(1)]));
+        let c = subtract_checked(&a, &b, options.clone()).unwrap();
+        assert_eq!(c, UInt8Array::from(vec![Some(100), None, None, None]));
+        let c = multiply_checked(&a, &b, options).unwrap();
+        assert_eq!(c, UInt8Array::from(vec![None, None, Some(12), Some(0)]));
+    }
+
+    #[test]
+    fn test_primitive_array_checked_sliced() {
+        let a = Int32Array::from(vec![Some(1), Some(i32::MAX), None, Some(4)]);
+        let b = Int32Array::from(vec![Some(2), Some(3), Some(2), Some(2)]);
+        let a = a.slice(2, 2);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b = b.slice(2, 2);
+        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = multiply_checked(a, b, None).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(8)]));
+    }
+
+    #[test]
+    fn test_primitive_array_saturating() {
+        let a = Int8Array::from(vec![Some(100), None, Some(-100), Some(5)]);
+        let b = Int8Array::from(vec![Some(100), Some(1), Some(100), Some(-3)]);
+        let c = add_saturating(&a, &b).unwrap();
+        assert_eq!(Int8Array::from(vec![Some(127), None, Some(0), Some(2)]), c);
+        let c = subtract_saturating(&a, &b).unwrap();
+        assert_eq!(Int8Array::from(vec![Some(0), None, Some(-128), Some(8)]), c);
+        let c = multiply_saturating(&a, &b).unwrap();
+        assert_eq!(
+            Int8Array::from(vec![Some(127), None, Some(-128), Some(-15)]),
+            c
+        );
+
+        let a = UInt32Array::from(vec![1, u32::MAX]);
+        let b = UInt32Array::from(vec![2, 2]);
+        let c = subtract_saturating(&a, &b).unwrap();
+        assert_eq!(UInt32Array::from(vec![0, u32::MAX - 2]), c);
+        let c = add_saturating(&a, &b).unwrap();
+        assert_eq!(UInt32Array::from(vec![3, u32::MAX]), c);
+    }
+
+    #[test]
+    fn test_primitive_array_modulus() {
+        let a = Int32Array::from(vec![15, 15, 8, 1, -9]);
+        let b = Int32Array::from(vec![5, 6, 8, 9, 4]);
+        let c = modulus(&a, &b).unwrap();
+        assert_eq!(c, Int32Array::from(vec![0, 3, 0, 1, -1]));
+    }
+
+    #[test]
+    fn test_primitive_array_modulus_with_nulls_sliced() {
+        let a = Int32Array::from(vec![Some(0), Some(15), None, Some(8), Some(9)]);
+        let b = Int32Array::from(vec![Some(0), Some(4), Some(0), Some(3), None]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b = b.slice(1, 4);
+        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = modulus(a, b).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(3), None, Some(2), None]));
+    }
+
+    #[test]
+    fn test_primitive_array_divide_modulus_null_zero_divisors() {
+        // spans several SIMD chunks, the values of the null slots of `b` are zeros
+        let a = Int64Array::from((0..100).map(|i| Some(i * 3)).collect::<Vec<_>>());
+        let b = Int64Array::from(
+            (0..100)
+                .map(|i| if i % 7 == 0 { None } else { Some(i % 5 + 1) })
+                .collect::<Vec<_>>(),
+        );
+        let quotient = divide(&a, &b).unwrap();
+        let remainder = modulus(&a, &b).unwrap();
+        for i in 0..100 {
+            if i % 7 == 0 {
+                assert!(quotient.is_null(i as usize));
+                assert!(remainder.is_null(i as usize));
+            } else {
+                assert_eq!(i * 3 / (i % 5 + 1), quotient.value(i as usize));
+                assert_eq!(i * 3 % (i % 5 + 1), remainder.value(i as usize));
+            }
+        }
+
+        let mut values = vec![Some(1i64); 100];
+        values[97] = Some(0);
+        assert!(modulus(&a, &Int64Array::from(values)).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_unary_binary_mut() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let ptr = a.raw_values();
+        let a = unary_mut(a, |v| v * 2).unwrap();
+        assert_eq!(ptr, a.raw_values());
+        assert_eq!(Int32Array::from(vec![Some(2), None, Some(6)]), a);
+
+        let b = Int32Array::from(vec![Some(5), Some(5), None]);
+        let c = binary_mut(a, &b, |l, r| l + r).unwrap();
+        assert_eq!(ptr, c.raw_values());
+        assert_eq!(Int32Array::from(vec![Some(7), None, None]), c);
+
+        // shared and sliced arrays are not mutated
+        let shared = c.data();
+        let d = binary_mut(c, &b, |l, r| l - r).unwrap();
+        assert_ne!(ptr, d.raw_values());
+        assert_eq!(Int32Array::from(vec![Some(2), None, None]), d);
+        let c = Int32Array::from(shared);
+        assert_eq!(Int32Array::from(vec![Some(7), None, None]), c);
+
+        let sliced = Int32Array::from(c.slice(1, 2).data());
+        drop(c);
+        let e = unary_mut(sliced, |v| v + 1).unwrap();
+        assert_eq!(Int32Array::from(vec![None, None]), e);
+
+        assert!(binary_mut(e, &b, |l, r| l + r).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_multiply_add() {
+        let a = Int32Array::from(vec![Some(2), Some(3), None, Some(-4)]);
+        let b = Int32Array::from(vec![Some(5), Some(6), Some(1), Some(2)]);
+        let c = Int32Array::from(vec![Some(1), None, Some(1), Some(10)]);
+        let result = multiply_add(&a, &b, &c).unwrap();
+        assert_eq!(
+            Int32Array::from(vec![Some(11), None, None, Some(2)]),
+            result
+        );
+
+        let c = c.slice(1, 3);
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert!(multiply_add(&a, &b, c).is_err());
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b = b.slice(0, 3);
+        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        let result = multiply_add(a, b, c).unwrap();
+        assert_eq!(Int32Array::from(vec![None, None, Some(6)]), result);
+    }
+
+    #[test]
+    fn test_primitive_array_multiply_add_f64() {
+        // the product is not rounded before the addition
+        let x = 1.0 + f64::EPSILON;
+        let a = Float64Array::from(vec![x, 2.0]);
+        let b = Float64Array::from(vec![x, 3.0]);
+        let c = Float64Array::from(vec![-(1.0 + 2.0 * f64::EPSILON), 0.5]);
+        let result = multiply_add(&a, &b, &c).unwrap();
+        assert_eq!(f64::EPSILON * f64::EPSILON, result.value(0));
+        assert_eq!(6.5, result.value(1));
+    }
+
+    #[test]
+    fn test_primitive_array_divide_modulus_opt() {
+        let a = Int32Array::from(vec![Some(15), Some(15), None, Some(8), Some(-9)]);
+        let b = Int32Array::from(vec![Some(0), Some(4), Some(0), Some(3), Some(0)]);
+        let c = divide_opt(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![None, Some(3), None, Some(2), None])
+        );
+        let c = modulus_opt(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![None, Some(3), None, Some(2), None])
+        );
+
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let b = b.slice(2, 3);
+        let b = b.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = divide_opt(a, b).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, None, None]));
+
+        let a = Float64Array::from(vec![1.0, 3.0]);
+        let b = Float64Array::from(vec![0.0, 2.0]);
+        let c = divide_opt(&a, &b).unwrap();
+        assert_eq!(c, Float64Array::from(vec![None, Some(1.5)]));
+    }
+
+    #[test]
+    fn test_primitive_array_modulus_f64() {
+        let a = Float64Array::from(vec![15.5, -7.5]);
+        let b = Float64Array::from(vec![5.0, 2.0]);
+        let c = modulus(&a, &b).unwrap();
+        assert!((0.5 - c.value(0)).abs() < f64::EPSILON);
+        assert!((-1.5 - c.value(1)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    #[should_panic(expected = "DivideByZero")]
+    fn test_primitive_array_modulus_by_zero() {
+        let a = Int32Array::from(vec![15]);
+        let b = Int32Array::from(vec![0]);
+        modulus(&a, &b).unwrap();
+    }
+
+    #[test]
+    fn test_primitive_array_sqrt_exp_ln() {
+        let a = Float64Array::from(vec![Some(4.0), None, Some(1.0), Some(-1.0)]);
+        let c = sqrt(&a).unwrap();
+        assert!((2.0 - c.value(0)).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!((1.0 - c.value(2)).abs() < f64::EPSILON);
+        assert!(c.value(3).is_nan());
+
+        let c = exp(&a).unwrap();
+        assert!((std::f64::consts::E - c.value(2)).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+
+        let c = ln(&a).unwrap();
+        assert!(c.value(2).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!(c.value(3).is_nan());
+    }
+
+    #[test]
+    fn test_primitive_array_log10_log2() {
+        let a = Float32Array::from(vec![Some(100.0), None, Some(8.0)]);
+        let c = log10(&a).unwrap();
+        assert!((2.0 - c.value(0)).abs() < f32::EPSILON);
+        assert!(c.is_null(1));
+        let c = log2(&a).unwrap();
+        assert!((3.0 - c.value(2)).abs() < f32::EPSILON);
+        assert!(c.is_null(1));
+    }
+
+    #[test]
+    fn test_primitive_array_power() {
+        let a = Float64Array::from(vec![Some(2.0), None, Some(9.0), Some(4.0)]);
+        let b = Float64Array::from(vec![Some(3.0), Some(2.0), Some(0.5), None]);
+        let c = power(&a, &b).unwrap();
+        assert!((8.0 - c.value(0)).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!((3.0 - c.value(2)).abs() < f64::EPSILON);
+        assert!(c.is_null(3));
+
+        let c = powf_scalar(&a, 2.0).unwrap();
+        assert!((4.0 - c.value(0)).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!((81.0 - c.value(2)).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_promote_to_float() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(4), None, Some(16)]));
+        let a = promote_to_float(&a).unwrap();
+        assert_eq!(&DataType::Float64, a.data_type());
+        let c = sqrt(as_primitive_array::<datatypes::Float64Type>(&a)).unwrap();
+        assert_eq!(c, Float64Array::from(vec![Some(2.0), None, Some(4.0)]));
+
+        let a: ArrayRef = Arc::new(Float32Array::from(vec![1.0]));
+        assert_eq!(
+            &DataType::Float32,
+            promote_to_float(&a).unwrap().data_type()
+        );
+
+        let a: ArrayRef = Arc::new(StringArray::from(vec!["1"]));
+        assert!(promote_to_float(&a).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_trigonometry() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let a = Float64Array::from(vec![Some(0.0), None, Some(FRAC_PI_2), Some(PI)]);
+        let c = sin(&a).unwrap();
+        assert!(c.value(0).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!((1.0 - c.value(2)).abs() < f64::EPSILON);
+
+        let c = cos(&a).unwrap();
+        assert!((1.0 - c.value(0)).abs() < f64::EPSILON);
+        assert!(c.is_null(1));
+        assert!((-1.0 - c.value(3)).abs() < f64::EPSILON);
+
+        let a = Float64Array::from(vec![Some(FRAC_PI_4), None]);
+        let c = tan(&a).unwrap();
+        assert!((1.0 - c.value(0)).abs() < 1e-10);
+        assert!(c.is_null(1));
+    }
+
+    #[test]
+    fn test_primitive_array_inverse_trigonometry() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4};
+
+        let a = Float32Array::from(vec![Some(1.0), None, Some(2.0)]);
+        let c = asin(&a).unwrap();
+        assert!((FRAC_PI_2 - c.value(0)).abs() < f32::EPSILON);
+        assert!(c.is_null(1));
+        assert!(c.value(2).is_nan());
+
+        let c = acos(&a).unwrap();
+        assert!(c.value(0).abs() < f32::EPSILON);
+        assert!(c.is_null(1));
+        assert!(c.value(2).is_nan());
+
+        let c = atan(&a).unwrap();
+        assert!((FRAC_PI_4 - c.value(0)).abs() < f32::EPSILON);
+        assert!(c.is_null(1));
+    }
+
+    #[test]
+    fn test_primitive_array_atan2() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+
+        let y = Float64Array::from(vec![Some(1.0), Some(0.0), None, Some(1.0)]);
+        let x = Float64Array::from(vec![Some(0.0), Some(-1.0), Some(1.0), None]);
+        let c = atan2(&y, &x).unwrap();
+        assert!((FRAC_PI_2 - c.value(0)).abs() < f64::EPSILON);
+        assert!((PI - c.value(1)).abs() < f64::EPSILON);
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+    }
+
+    #[test]
+    fn test_primitive_array_abs() {
+        let a = Int32Array::from(vec![Some(-5), None, Some(3), Some(0)]);
+        let c = abs(&a).unwrap();
+        assert_eq!(Int32Array::from(vec![Some(5), None, Some(3), Some(0)]), c);
+
+        let a = Float64Array::from(vec![-1.5, 2.0]);
+        let c = abs(&a).unwrap();
+        assert_eq!(Float64Array::from(vec![1.5, 2.0]), c);
+
+        let a = Int32Array::from(vec![Some(-1), None, Some(-3), Some(4), None]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = abs(a).unwrap();
+        assert_eq!(Int32Array::from(vec![None, Some(3), Some(4), None]), c);
+
+        // the values of null slots are not computed
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![i32::MIN, -2]));
+        let a = a.with_null_buffer(Some(Buffer::from([0b10]))).unwrap();
+        let c = abs(a.as_typed::<Int32Array>().unwrap()).unwrap();
+        assert_eq!(Int32Array::from(vec![None, Some(2)]), c);
+    }
+
+    #[test]
+    fn test_negate_durations_and_intervals() {
+        let a = DurationSecondArray::from(vec![Some(-5), None, Some(3)]);
+        let c = negate(&a).unwrap();
+        assert_eq!(DurationSecondArray::from(vec![Some(5), None, Some(-3)]), c);
+
+        let a = IntervalYearMonthArray::from(vec![14, -2]);
+        let c = negate(&a).unwrap();
+        assert_eq!(IntervalYearMonthArray::from(vec![-14, 2]), c);
+
+        // 2 days and 3 milliseconds, -1 days and 500 milliseconds
+        let a = IntervalDayTimeArray::from(vec![
+            Some((2 << 32) | 3),
+            None,
+            Some((-1i64 << 32) | 500),
+        ]);
+        let c = negate_interval_day_time(&a).unwrap();
+        assert_eq!((-2i64 << 32) | (-3i32 as u32 as i64), c.value(0));
+        assert!(c.is_null(1));
+        assert_eq!((1 << 32) | (-500i32 as u32 as i64), c.value(2));
+
+        let a = IntervalDayTimeArray::from(vec![(i32::MIN as i64) << 32]);
+        assert!(negate_interval_day_time(&a).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_negate_checked() {
+        let a = Int8Array::from(vec![Some(-5), None, Some(i8::MAX)]);
+        let c = negate_checked(&a, None).unwrap();
+        assert_eq!(Int8Array::from(vec![Some(5), None, Some(-i8::MAX)]), c);
+
+        let a = Int8Array::from(vec![Some(1), Some(i8::MIN)]);
+        let e = negate_checked(&a, None).unwrap_err();
+        assert_eq!("Arithmetic overflow: -(-128)", e.to_string());
+
+        let options = ArithmeticOptions {
+            null_on_overflow: true,
+        };
+        let c = negate_checked(&a, Some(options)).unwrap();
+        assert_eq!(Int8Array::from(vec![Some(-1), None]), c);
+    }
+
+    #[test]
+    fn test_primitive_array_abs_checked() {
+        let a = Int64Array::from(vec![Some(-5), None, Some(i64::MAX), Some(i64::MIN)]);
+        let e = abs_checked(&a, None).unwrap_err();
+        assert_eq!(
+            "Arithmetic overflow: abs(-9223372036854775808)",
+            e.to_string()
+        );
+
+        let a = a.slice(0, 3);
+        let a = a.as_any().downcast_ref::<Int64Array>().unwrap();
+        let c = abs_checked(a, None).unwrap();
+        assert_eq!(Int64Array::from(vec![Some(5), None, Some(i64::MAX)]), c);
+    }
+
+    #[test]
+    fn test_arithmetic_supertype() {
+        use DataType::*;
+        let cases = vec![
+            (Int32, Int32, Some(Int32)),
+            (Int8, Int64, Some(Int64)),
+            (UInt8, UInt32, Some(UInt32)),
+            (UInt8, Int8, Some(Int16)),
+            (Int64, UInt32, Some(Int64)),
+            (UInt32, Int16, Some(Int64)),
+            (UInt64, Int8, None),
+            (Float32, Int16, Some(Float32)),
+            (UInt32, Float32, Some(Float64)),
+            (Float32, Float64, Some(Float64)),
+            (Utf8, Int32, None),
+            (Utf8, Utf8, None),
+        ];
+        for (left, right, expected) in cases {
+            assert_eq!(expected, arithmetic_supertype(&left, &right));
+            assert_eq!(expected, arithmetic_supertype(&right, &left));
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_dyn_mixed_types() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(5), None, Some(-3)]));
+        let b: ArrayRef =
+            Arc::new(Int64Array::from(vec![Some(i64::MAX - 5), Some(1), Some(2)]));
+        let c = add_dyn(&a, &b).unwrap();
+        assert_eq!(
+            &Int64Array::from(vec![Some(i64::MAX), None, Some(-1)]),
+            c.as_any().downcast_ref::<Int64Array>().unwrap()
+        );
+
+        let b: ArrayRef = Arc::new(UInt8Array::from(vec![1, 2, 3]));
+        let c = multiply_dyn(&b, &a).unwrap();
+        assert_eq!(
+            &Int32Array::from(vec![Some(5), None, Some(-9)]),
+            c.as_any().downcast_ref::<Int32Array>().unwrap()
+        );
+
+        let b: ArrayRef = Arc::new(Float32Array::from(vec![2.0, 2.0, 2.0]));
+        let c = divide_dyn(&a, &b).unwrap();
+        assert_eq!(
+            &Float64Array::from(vec![Some(2.5), None, Some(-1.5)]),
+            c.as_any().downcast_ref::<Float64Array>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_dyn_errors() {
+        let a: ArrayRef = Arc::new(UInt64Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(Int8Array::from(vec![1]));
+        assert!(subtract_dyn(&a, &b).is_err());
+
+        let b: ArrayRef = Arc::new(UInt8Array::from(vec![0]));
+        assert!(matches!(modulus_dyn(&a, &b), Err(ArrowError::DivideByZero)));
+    }
+
+    #[test]
+    fn test_min_max_elementwise() {
+        let a = Int32Array::from(vec![Some(1), Some(5), None, None]);
+        let b = Int32Array::from(vec![Some(3), Some(2), Some(4), None]);
+
+        let c = min_elementwise(&a, &b, NullPolicy::Skip).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(1), Some(2), Some(4), None]));
+        let c = max_elementwise(&a, &b, NullPolicy::Skip).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(3), Some(5), Some(4), None]));
+        let c = max_elementwise(&a, &b, NullPolicy::Propagate).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(3), Some(5), None, None]));
+
+        let b = Int32Array::from(vec![1]);
+        assert!(min_elementwise(&a, &b, NullPolicy::Skip).is_err());
+    }
+
+    #[test]
+    fn test_min_elementwise_sliced_floats() {
+        let a = Float64Array::from(vec![Some(0.0), Some(f64::NAN), Some(1.0), None]);
+        let a = a.slice(1, 3);
+        let a = as_primitive_array::<datatypes::Float64Type>(&a);
+        let b = Float64Array::from(vec![Some(2.0), None, Some(f64::NAN)]);
+
+        let c = min_elementwise(a, &b, NullPolicy::Skip).unwrap();
+        assert_eq!(c.value(0), 2.0);
+        assert_eq!(c.value(1), 1.0);
+        assert!(c.value(2).is_nan());
+        assert_eq!(c.null_count(), 0);
+        let c = max_elementwise(a, &b, NullPolicy::Propagate).unwrap();
+        assert!(c.value(0).is_nan());
+        assert!(c.is_null(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_least_greatest_timestamps() {
+        let a = TimestampSecondArray::from_opt_vec(
+            vec![Some(1), None, Some(7)],
+            Some("UTC".to_string()),
+        );
+        let b = TimestampSecondArray::from_opt_vec(
+            vec![Some(2), Some(3), None],
+            Some("UTC".to_string()),
+        );
+        let c = TimestampSecondArray::from_opt_vec(
+            vec![Some(0), None, Some(9)],
+            Some("UTC".to_string()),
+        );
+
+        let result = least(&[&a, &b, &c], NullPolicy::Skip).unwrap();
+        assert_eq!(result.data_type(), a.data_type());
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            vec![Some(0), Some(3), Some(7)]
+        );
+        let result = greatest(&[&a, &b, &c], NullPolicy::Propagate).unwrap();
+        assert_eq!(result.iter().collect::<Vec<_>>(), vec![Some(2), None, None]);
+        assert!(greatest::<datatypes::Int32Type>(&[], NullPolicy::Skip).is_err());
+    }
 }
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines boolean kernels on Arrow `BooleanArray`'s, e.g. `AND`, `OR` and `NOT`.
+//! Defines boolean kernels on Arrow `BooleanArray`'s, e.g. `AND`, `OR`, `XOR` and `NOT`.
 //!
 //! These kernels can leverage SIMD if available on your system.  Currently no runtime
 //! detection is provided, you should enable the specific SIMD intrinsics using
@@ -27,9 +27,12 @@ use std::sync::Arc;
 
 use crate::array::{Array, ArrayData, BooleanArray, PrimitiveArray};
 use crate::buffer::{
-    buffer_bin_and, buffer_bin_or, buffer_unary_not, Buffer, MutableBuffer,
+    buffer_bin_and, buffer_bin_or, buffer_bin_xor, buffer_unary_not, Buffer,
+    MutableBuffer,
+};
+use crate::compute::util::{
+    combine_option_bitmap, combine_option_buffers, into_mutable_values,
 };
-use crate::compute::util::combine_option_bitmap;
 use crate::datatypes::{ArrowNumericType, DataType};
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util::ceil;
@@ -53,14 +56,14 @@ where
 
     let left_data = left.data_ref();
     let right_data = right.data_ref();
-    let null_bit_buffer = combine_option_bitmap(&left_data, &right_data, len)?;
+    let null_bit_buffer = combine_option_bitmap(left_data, right_data, len)?;
 
     let left_buffer = &left_data.buffers()[0];
     let right_buffer = &right_data.buffers()[0];
     let left_offset = left.offset();
     let right_offset = right.offset();
 
-    let values = op(&left_buffer, left_offset, &right_buffer, right_offset, len);
+    let values = op(left_buffer, left_offset, right_buffer, right_offset, len);
 
     let data = ArrayData::new(
         DataType::Boolean,
@@ -92,7 +95,7 @@ where
 /// # }
 /// ```
 pub fn and(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
-    binary_boolean_kernel(&left, &right, buffer_bin_and)
+    binary_boolean_kernel(left, right, buffer_bin_and)
 }
 
 /// Performs `OR` operation on two arrays. If either left or right value is null then the
@@ -113,7 +116,154 @@ pub fn and(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
 /// # }
 /// ```
 pub fn or(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
-    binary_boolean_kernel(&left, &right, buffer_bin_or)
+    binary_boolean_kernel(left, right, buffer_bin_or)
+}
+
+/// Performs `XOR` operation on two arrays. If either left or right value is null then the
+/// result is also null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow::array::BooleanArray;
+/// use arrow::error::Result;
+/// use arrow::compute::kernels::boolean::xor;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(false), Some(true), None]);
+/// let b = BooleanArray::from(vec![Some(true), Some(true), Some(false)]);
+/// let xor_ab = xor(&a, &b)?;
+/// assert_eq!(xor_ab, BooleanArray::from(vec![Some(true), Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn xor(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel(left, right, buffer_bin_xor)
+}
+
+/// Helper function to implement binary kernels with Kleene logic, where `op` computes
+/// the values and validity of 64 slots at a time from the left values, left validity,
+/// right values and right validity of these slots
+fn binary_kleene_kernel<F>(
+    left: &BooleanArray,
+    right: &BooleanArray,
+    op: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(u64, u64, u64, u64) -> (u64, u64),
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let num_bytes = ceil(len, 8);
+    let validity = |array: &BooleanArray| match array.data_ref().null_buffer() {
+        Some(buffer) => (buffer.clone(), array.offset()),
+        None => (
+            MutableBuffer::new(num_bytes)
+                .with_bitset(num_bytes, true)
+                .freeze(),
+            0,
+        ),
+    };
+    let (left_valid, left_valid_offset) = validity(left);
+    let (right_valid, right_valid_offset) = validity(right);
+
+    let left_chunks = left.data_ref().buffers()[0].bit_chunks(left.offset(), len);
+    let left_valid_chunks = left_valid.bit_chunks(left_valid_offset, len);
+    let right_chunks = right.data_ref().buffers()[0].bit_chunks(right.offset(), len);
+    let right_valid_chunks = right_valid.bit_chunks(right_valid_offset, len);
+
+    let mut values = MutableBuffer::new(num_bytes);
+    let mut valid = MutableBuffer::new(num_bytes);
+    left_chunks
+        .iter()
+        .zip(left_valid_chunks.iter())
+        .zip(right_chunks.iter().zip(right_valid_chunks.iter()))
+        .for_each(|((l, l_valid), (r, r_valid))| {
+            let (v, v_valid) = op(l, l_valid, r, r_valid);
+            values.extend_from_slice(&v.to_le_bytes());
+            valid.extend_from_slice(&v_valid.to_le_bytes());
+        });
+
+    let remainder_bytes = ceil(left_chunks.remainder_len(), 8);
+    let (v, v_valid) = op(
+        left_chunks.remainder_bits(),
+        left_valid_chunks.remainder_bits(),
+        right_chunks.remainder_bits(),
+        right_valid_chunks.remainder_bits(),
+    );
+    values.extend_from_slice(&v.to_le_bytes()[0..remainder_bytes]);
+    valid.extend_from_slice(&v_valid.to_le_bytes()[0..remainder_bytes]);
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        Some(valid.freeze()),
+        0,
+        vec![values.freeze()],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Performs `AND` operation on two arrays with the three-valued logic of SQL, where null
+/// means unknown: the result is false if either value is false, even if the other is
+/// null, and otherwise null if either value is null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow::array::BooleanArray;
+/// use arrow::error::Result;
+/// use arrow::compute::kernels::boolean::and_kleene;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(false), Some(true), None]);
+/// let b = BooleanArray::from(vec![None, None, Some(false)]);
+/// let and_ab = and_kleene(&a, &b)?;
+/// assert_eq!(and_ab, BooleanArray::from(vec![Some(false), None, Some(false)]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn and_kleene(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return and(left, right);
+    }
+    binary_kleene_kernel(left, right, |l, l_valid, r, r_valid| {
+        let known_false = (l_valid & !l) | (r_valid & !r);
+        (l & r, (l_valid & r_valid) | known_false)
+    })
+}
+
+/// Performs `OR` operation on two arrays with the three-valued logic of SQL, where null
+/// means unknown: the result is true if either value is true, even if the other is
+/// null, and otherwise null if either value is null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow::array::BooleanArray;
+/// use arrow::error::Result;
+/// use arrow::compute::kernels::boolean::or_kleene;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(false), Some(true), None]);
+/// let b = BooleanArray::from(vec![None, None, Some(true)]);
+/// let or_ab = or_kleene(&a, &b)?;
+/// assert_eq!(or_ab, BooleanArray::from(vec![None, Some(true), Some(true)]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn or_kleene(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return or(left, right);
+    }
+    binary_kleene_kernel(left, right, |l, l_valid, r, r_valid| {
+        let known_true = (l_valid & l) | (r_valid & r);
+        (known_true, (l_valid & r_valid) | known_true)
+    })
 }
 
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
@@ -137,10 +287,7 @@ pub fn not(left: &BooleanArray) -> Result<BooleanArray> {
     let len = left.len();
 
     let data = left.data_ref();
-    let null_bit_buffer = data
-        .null_bitmap()
-        .as_ref()
-        .map(|b| b.bits.slice(left_offset));
+    let null_bit_buffer = data.null_buffer().map(|b| b.bit_slice(left_offset, len));
 
     let values = buffer_unary_not(&data.buffers()[0], left_offset, len);
 
@@ -156,6 +303,87 @@ pub fn not(left: &BooleanArray) -> Result<BooleanArray> {
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Helper function to implement binary kernels that write the results into the values
+/// of `left` in place, applying `op` to 8 slots at a time, when `left` is the only
+/// reference to its values and is not sliced. Otherwise, `fallback` is called.
+fn binary_boolean_kernel_mut<F, G>(
+    left: BooleanArray,
+    right: &BooleanArray,
+    op: F,
+    fallback: G,
+) -> Result<BooleanArray>
+where
+    F: Fn(u8, u8) -> u8,
+    G: Fn(&BooleanArray, &BooleanArray) -> Result<BooleanArray>,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let null_bit_buffer = combine_option_bitmap(left.data_ref(), right.data_ref(), len)?;
+    match into_mutable_values(left) {
+        Ok(mut values) => {
+            let right_values =
+                right.data_ref().buffers()[0].bit_slice(right.offset(), len);
+            values.values.data_mut()[..ceil(len, 8)]
+                .iter_mut()
+                .zip(right_values.data())
+                .for_each(|(l, r)| *l = op(*l, *r));
+            values.null_bit_buffer = null_bit_buffer;
+            Ok(BooleanArray::from(values.finish()))
+        }
+        Err(data) => fallback(&BooleanArray::from(data), right),
+    }
+}
+
+/// Like [`and`], but takes ownership of `left` to write the results into its values in
+/// place, without allocating, when `left` is the only reference to its values and is not
+/// sliced. Otherwise, a new array is allocated.
+/// # Error
+/// This function errors when the arrays have different lengths.
+pub fn and_mut(left: BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel_mut(left, right, |l, r| l & r, and)
+}
+
+/// Like [`or`], but takes ownership of `left` to write the results into its values in
+/// place, without allocating, when `left` is the only reference to its values and is not
+/// sliced. Otherwise, a new array is allocated.
+/// # Error
+/// This function errors when the arrays have different lengths.
+pub fn or_mut(left: BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel_mut(left, right, |l, r| l | r, or)
+}
+
+/// Like [`xor`], but takes ownership of `left` to write the results into its values in
+/// place, without allocating, when `left` is the only reference to its values and is not
+/// sliced. Otherwise, a new array is allocated.
+/// # Error
+/// This function errors when the arrays have different lengths.
+pub fn xor_mut(left: BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    binary_boolean_kernel_mut(left, right, |l, r| l ^ r, xor)
+}
+
+/// Like [`not`], but takes ownership of `left` to negate its values in place, without
+/// allocating, when `left` is the only reference to its values and is not sliced.
+/// Otherwise, a new array is allocated.
+/// # Error
+/// This function never errors.
+pub fn not_mut(left: BooleanArray) -> Result<BooleanArray> {
+    match into_mutable_values(left) {
+        Ok(mut values) => {
+            let len = ceil(values.len, 8);
+            values.values.data_mut()[..len]
+                .iter_mut()
+                .for_each(|v| *v = !*v);
+            Ok(BooleanArray::from(values.finish()))
+        }
+        Err(data) => not(&BooleanArray::from(data)),
+    }
+}
+
 /// Returns a non-null [BooleanArray] with whether each value of the array is null.
 /// # Error
 /// This function never errors.
@@ -269,24 +497,13 @@ where
 
     // AND of original left null bitmap with right expression
     // Here we take care of the possible offsets of the left and right arrays all at once.
-    let modified_null_buffer = match left_data.null_bitmap() {
-        Some(left_null_bitmap) => match right_combo_buffer {
-            Some(rcb) => Some(buffer_bin_and(
-                &left_null_bitmap.bits,
-                left_data.offset(),
-                &rcb,
-                right_data.offset(),
-                left_data.len(),
-            )),
-            None => Some(
-                left_null_bitmap
-                    .bits
-                    .bit_slice(left_data.offset(), left.len()),
-            ),
-        },
-        None => right_combo_buffer
-            .map(|rcb| rcb.bit_slice(right_data.offset(), right_data.len())),
-    };
+    let modified_null_buffer = combine_option_buffers(
+        left_data.null_buffer(),
+        left_data.offset(),
+        right_combo_buffer.as_ref(),
+        right_data.offset(),
+        left_data.len(),
+    );
 
     // Align/shift left data on offset as needed, since new bitmaps are shifted and aligned to 0 already
     // NOTE: this probably only works for primitive arrays.
@@ -383,6 +600,92 @@ mod tests {
         assert_eq!(c, expected);
     }
 
+    #[test]
+    fn test_bool_array_kleene_truth_tables() {
+        let values = [Some(true), Some(false), None];
+        let (a, b): (Vec<_>, Vec<_>) = values
+            .iter()
+            .flat_map(|a| values.iter().map(move |b| (*a, *b)))
+            .unzip();
+        let a = BooleanArray::from(a);
+        let b = BooleanArray::from(b);
+
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            Some(false),
+            None,
+        ]);
+        assert_eq!(expected, and_kleene(&a, &b).unwrap());
+        assert_eq!(expected, and_kleene(&b, &a).unwrap());
+
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            None,
+            None,
+        ]);
+        assert_eq!(expected, or_kleene(&a, &b).unwrap());
+        assert_eq!(expected, or_kleene(&b, &a).unwrap());
+    }
+
+    #[test]
+    fn test_bool_array_kleene_sliced_and_without_nulls() {
+        // longer than 64 values, sliced at different offsets, with nulls on one side only
+        let a: Vec<Option<bool>> = (0..200)
+            .map(|i| if i % 5 == 0 { None } else { Some(i % 3 == 0) })
+            .collect();
+        let b: Vec<bool> = (0..200).map(|i| i % 2 == 0).collect();
+        let a_array = BooleanArray::from(a.clone());
+        let b_array = BooleanArray::from(b.clone());
+        let a_sliced = a_array.slice(3, 130);
+        let a_sliced = a_sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b_sliced = b_array.slice(67, 130);
+        let b_sliced = b_sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let and_expected: BooleanArray = (0..130)
+            .map(|i| match (a[i + 3], b[i + 67]) {
+                (Some(false), _) | (_, false) => Some(false),
+                (Some(true), true) => Some(true),
+                (None, true) => None,
+            })
+            .collect::<Vec<_>>()
+            .into();
+        let or_expected: BooleanArray = (0..130)
+            .map(|i| match (a[i + 3], b[i + 67]) {
+                (Some(true), _) | (_, true) => Some(true),
+                (Some(false), false) => Some(false),
+                (None, false) => None,
+            })
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(and_expected, and_kleene(a_sliced, b_sliced).unwrap());
+        assert_eq!(or_expected, or_kleene(b_sliced, a_sliced).unwrap());
+
+        // without nulls, the results are the ones of `and` and `or`
+        assert_eq!(
+            and(&b_array, &b_array).unwrap(),
+            and_kleene(&b_array, &b_array).unwrap()
+        );
+        assert_eq!(
+            or(&b_array, &b_array).unwrap(),
+            or_kleene(&b_array, &b_array).unwrap()
+        );
+
+        assert!(and_kleene(a_sliced, &b_array).is_err());
+        assert!(or_kleene(a_sliced, &b_array).is_err());
+    }
+
     #[test]
     fn test_bool_array_not() {
         let a = BooleanArray::from(vec![false, true]);
@@ -393,6 +696,110 @@ mod tests {
         assert_eq!(c, expected);
     }
 
+    #[test]
+    fn test_bool_array_not_sliced_with_nulls() {
+        let a: BooleanArray = (0..100)
+            .map(|i| if i % 3 == 0 { None } else { Some(i % 2 == 0) })
+            .collect::<Vec<_>>()
+            .into();
+        let a = a.slice(5, 90);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let c = not(a).unwrap();
+
+        let expected: BooleanArray = (5..95)
+            .map(|i| if i % 3 == 0 { None } else { Some(i % 2 != 0) })
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(expected, c);
+    }
+
+    #[test]
+    fn test_bool_array_mut() {
+        let a = BooleanArray::from(vec![Some(false), Some(true), None, Some(true)]);
+        let ptr = a.raw_values();
+        let a = not_mut(a).unwrap();
+        assert_eq!(ptr, a.raw_values());
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]),
+            a
+        );
+
+        let b = BooleanArray::from(vec![true, true, false, false, true]);
+        let b = b.slice(1, 4);
+        let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let c = and_mut(a, b).unwrap();
+        assert_eq!(ptr, c.raw_values());
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]),
+            c
+        );
+        let c = or_mut(c, b).unwrap();
+        assert_eq!(ptr, c.raw_values());
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]),
+            c
+        );
+
+        // shared arrays are not mutated
+        let shared = c.data();
+        let d = xor_mut(c, b).unwrap();
+        assert_ne!(ptr, d.raw_values());
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), Some(false), None, Some(false)]),
+            d
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]),
+            BooleanArray::from(shared)
+        );
+
+        assert!(and_mut(d, &BooleanArray::from(vec![true])).is_err());
+    }
+
+    #[test]
+    fn test_bool_array_xor() {
+        let a = BooleanArray::from(vec![false, false, true, true]);
+        let b = BooleanArray::from(vec![false, true, false, true]);
+        let c = xor(&a, &b).unwrap();
+
+        let expected = BooleanArray::from(vec![false, true, true, false]);
+
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn test_bool_array_sliced_odd_offsets() {
+        let a: BooleanArray = (0..150)
+            .map(|i| if i % 7 == 0 { None } else { Some(i % 2 == 0) })
+            .collect::<Vec<_>>()
+            .into();
+        let b: BooleanArray = (0..150)
+            .map(|i| if i % 11 == 0 { None } else { Some(i % 3 == 0) })
+            .collect::<Vec<_>>()
+            .into();
+        let a = a.slice(3, 130);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b = b.slice(13, 130);
+        let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let expected = |op: fn(bool, bool) -> bool| -> BooleanArray {
+            (0..130)
+                .map(|i| {
+                    let (l, r) = (i + 3, i + 13);
+                    if l % 7 == 0 || r % 11 == 0 {
+                        None
+                    } else {
+                        Some(op(l % 2 == 0, r % 3 == 0))
+                    }
+                })
+                .collect::<Vec<_>>()
+                .into()
+        };
+        assert_eq!(and(a, b).unwrap(), expected(|l, r| l & r));
+        assert_eq!(or(a, b).unwrap(), expected(|l, r| l | r));
+        assert_eq!(xor(a, b).unwrap(), expected(|l, r| l ^ r));
+    }
+
     #[test]
     fn test_bool_array_and_nulls() {
         let a = BooleanArray::from(vec![
@@ -450,7 +857,7 @@ mod tests {
         let b = b.slice(8, 4);
         let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
 
-        let c = and(&a, &b).unwrap();
+        let c = and(a, b).unwrap();
 
         let expected = BooleanArray::from(vec![false, false, false, true]);
 
@@ -473,7 +880,7 @@ mod tests {
         let b = b.slice(8, 4);
         let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
 
-        let c = and(&a, &b).unwrap();
+        let c = and(a, b).unwrap();
 
         let expected = BooleanArray::from(vec![false, false, false, true]);
 
@@ -491,7 +898,7 @@ mod tests {
         let a = a.slice(8, 4);
         let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
 
-        let c = and(&a, &b).unwrap();
+        let c = and(a, &b).unwrap();
 
         let expected = BooleanArray::from(vec![false, false, false, true]);
 
@@ -509,7 +916,7 @@ mod tests {
         let b = b.slice(8, 4);
         let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
 
-        let c = and(&a, &b).unwrap();
+        let c = and(&a, b).unwrap();
 
         let expected = BooleanArray::from(vec![false, false, false, true]);
 
@@ -534,7 +941,7 @@ mod tests {
         let b = b.slice(2, 4);
         let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
 
-        let c = and(&a, &b).unwrap();
+        let c = and(a, b).unwrap();
 
         let expected =
             BooleanArray::from(vec![Some(false), Some(false), None, Some(true)]);
@@ -714,7 +1121,7 @@ mod tests {
         ]);
         let comp = comp.slice(2, 3); // Some(false), None, Some(true)
         let comp = comp.as_any().downcast_ref::<BooleanArray>().unwrap();
-        let res = nullif(&a, &comp).unwrap();
+        let res = nullif(a, comp).unwrap();
 
         let expected = Int32Array::from(vec![
             Some(15), // False => keep it
@@ -38,6 +38,8 @@
 use std::str;
 use std::sync::Arc;
 
+use chrono::Timelike;
+
 use crate::buffer::Buffer;
 use crate::compute::kernels::arithmetic::{divide, multiply};
 use crate::datatypes::*;
@@ -73,7 +75,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 
         (Utf8, Date32(DateUnit::Day)) => true,
         (Utf8, Date64(DateUnit::Millisecond)) => true,
+        (Utf8, Decimal(_, _)) => true,
+        (Utf8, Time32(TimeUnit::Second))
+        | (Utf8, Time32(TimeUnit::Millisecond))
+        | (Utf8, Time64(TimeUnit::Microsecond))
+        | (Utf8, Time64(TimeUnit::Nanosecond)) => true,
         (Utf8, _) => DataType::is_numeric(to_type),
+        (Time32(_), Utf8) | (Time64(_), Utf8) => true,
         (_, Utf8) => DataType::is_numeric(from_type) || from_type == &Binary,
 
         // start numeric casts
@@ -202,6 +210,13 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Timestamp(_, _)) => true,
         (Timestamp(_, _), Date32(_)) => true,
         (Timestamp(_, _), Date64(_)) => true,
+        (Timestamp(_, _), Time32(to_unit)) => {
+            matches!(to_unit, TimeUnit::Second | TimeUnit::Millisecond)
+        }
+        (Timestamp(_, _), Time64(to_unit)) => {
+            matches!(to_unit, TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        }
+        (Time32(_), Timestamp(_, _)) | (Time64(_), Timestamp(_, _)) => true,
         // date64 to timestamp might not make sense,
         (Int64, Duration(_)) => true,
         (Null, Int32) => true,
@@ -216,12 +231,21 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Boolean to Utf8: `true` => '1', `false` => `0`
 /// * Utf8 to numeric: strings that can't be parsed to numbers return null, float strings
 ///   in integer casts return null
+/// * Utf8 to decimal: strings that can't be parsed to decimals return null, extra
+///   fractional digits are rounded half up, and values that exceed the precision make
+///   the cast error. See `cast_utf8_to_decimal` to configure this behavior
 /// * Numeric to boolean: 0 returns `false`, any other value returns `true`
 /// * List to List: the underlying data type is cast
 /// * Primitive to List: a list array with 1 value per slot is created
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Utf8 to Time32/Time64: strings that can't be parsed as a time of day (e.g.
+///   `12:30:45.123`) return null
+/// * Time32/Time64 to Utf8: times are formatted as `%H:%M:%S%.f`, values that are
+///   not a valid time of day return null
+/// * Timestamp to Time32/Time64: the time of day of the timestamp, ignoring its timezone
+/// * Time32/Time64 to Timestamp: the time of day on 1970-01-01
 /// * Temporal to/from backing primitive: zero-copy with data type change
 ///
 /// Unsupported Casts
@@ -351,7 +375,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Float32 => cast_bool_to_numeric::<Float32Type>(array),
             Float64 => cast_bool_to_numeric::<Float64Type>(array),
             Utf8 => {
-                let from = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                let from = array.as_typed::<BooleanArray>()?;
                 let mut b = StringBuilder::new(array.len());
                 for i in 0..array.len() {
                     if array.is_null(i) {
@@ -379,9 +403,12 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Int64 => cast_string_to_numeric::<Int64Type>(array),
             Float32 => cast_string_to_numeric::<Float32Type>(array),
             Float64 => cast_string_to_numeric::<Float64Type>(array),
+            Decimal(precision, scale) => {
+                cast_utf8_to_decimal(array, *precision, *scale, None)
+            }
             Date32(DateUnit::Day) => {
                 let zero_time = chrono::NaiveTime::from_hms(0, 0, 0);
-                let string_array = array.as_any().downcast_ref::<StringArray>().unwrap();
+                let string_array = array.as_typed::<StringArray>()?;
                 let mut builder = PrimitiveBuilder::<Date32Type>::new(string_array.len());
                 for i in 0..string_array.len() {
                     if string_array.is_null(i) {
@@ -399,7 +426,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 Ok(Arc::new(builder.finish()) as ArrayRef)
             }
             Date64(DateUnit::Millisecond) => {
-                let string_array = array.as_any().downcast_ref::<StringArray>().unwrap();
+                let string_array = array.as_typed::<StringArray>()?;
                 let mut builder = PrimitiveBuilder::<Date64Type>::new(string_array.len());
                 for i in 0..string_array.len() {
                     if string_array.is_null(i) {
@@ -415,6 +442,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 }
                 Ok(Arc::new(builder.finish()) as ArrayRef)
             }
+            Time32(unit) | Time64(unit) => cast_string_to_time(array, unit, to_type),
             _ => Err(ArrowError::ComputeError(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -432,7 +460,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Float32 => cast_numeric_to_string::<Float32Type>(array),
             Float64 => cast_numeric_to_string::<Float64Type>(array),
             Binary => {
-                let from = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                let from = array.as_typed::<BinaryArray>()?;
                 let mut b = StringBuilder::new(array.len());
                 for i in 0..array.len() {
                     if array.is_null(i) {
@@ -447,6 +475,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
 
                 Ok(Arc::new(b.finish()) as ArrayRef)
             }
+            Time32(unit) | Time64(unit) => cast_time_to_string(array, unit),
             _ => Err(ArrowError::ComputeError(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -578,7 +607,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
         (Date64(_), Int64) => cast_array_data::<Int64Type>(array, to_type.clone()),
         (Time64(_), Int64) => cast_array_data::<Int64Type>(array, to_type.clone()),
         (Date32(DateUnit::Day), Date64(DateUnit::Millisecond)) => {
-            let date_array = array.as_any().downcast_ref::<Date32Array>().unwrap();
+            let date_array = array.as_typed::<Date32Array>()?;
             let mut b = Date64Builder::new(array.len());
             for i in 0..array.len() {
                 if array.is_null(i) {
@@ -591,7 +620,7 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
             Ok(Arc::new(b.finish()) as ArrayRef)
         }
         (Date64(DateUnit::Millisecond), Date32(DateUnit::Day)) => {
-            let date_array = array.as_any().downcast_ref::<Date64Array>().unwrap();
+            let date_array = array.as_typed::<Date64Array>()?;
             let mut b = Date32Builder::new(array.len());
             for i in 0..array.len() {
                 if array.is_null(i) {
@@ -788,6 +817,25 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
                 }
             }
         }
+        (Timestamp(from_unit, _), Time32(to_unit))
+        | (Timestamp(from_unit, _), Time64(to_unit)) => {
+            let day = SECONDS_IN_DAY * time_unit_multiple(from_unit);
+            let values = temporal_values(array)
+                .into_iter()
+                .map(|v| {
+                    v.map(|v| convert_time_unit(v.rem_euclid(day), from_unit, to_unit))
+                })
+                .collect();
+            temporal_array_from_values(values, to_type)
+        }
+        (Time32(from_unit), Timestamp(to_unit, _))
+        | (Time64(from_unit), Timestamp(to_unit, _)) => {
+            let values = temporal_values(array)
+                .into_iter()
+                .map(|v| v.map(|v| convert_time_unit(v, from_unit, to_unit)))
+                .collect();
+            temporal_array_from_values(values, to_type)
+        }
         // date64 to timestamp might not make sense,
         (Int64, Duration(to_unit)) => {
             use TimeUnit::*;
@@ -825,6 +873,88 @@ fn time_unit_multiple(unit: &TimeUnit) -> i64 {
     }
 }
 
+/// Converts `value` from `from_unit` to `to_unit`, truncating extra precision
+fn convert_time_unit(value: i64, from_unit: &TimeUnit, to_unit: &TimeUnit) -> i64 {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    if from_size >= to_size {
+        value / (from_size / to_size)
+    } else {
+        value * (to_size / from_size)
+    }
+}
+
+/// Returns the values of a Time32, Time64 or Timestamp array as `i64`s
+fn temporal_values(array: &ArrayRef) -> Vec<Option<i64>> {
+    match array.data_type() {
+        DataType::Time32(_) => Int32Array::from(array.data())
+            .iter()
+            .map(|v| v.map(i64::from))
+            .collect(),
+        _ => Int64Array::from(array.data()).iter().collect(),
+    }
+}
+
+/// Builds a Time32, Time64 or Timestamp array of type `to_type` from `values`
+fn temporal_array_from_values(
+    values: Vec<Option<i64>>,
+    to_type: &DataType,
+) -> Result<ArrayRef> {
+    let array: ArrayRef = match to_type {
+        DataType::Time32(_) => Arc::new(
+            values
+                .into_iter()
+                .map(|v| v.map(|v| v as i32))
+                .collect::<Int32Array>(),
+        ),
+        _ => Arc::new(Int64Array::from(values)),
+    };
+    cast(&array, to_type)
+}
+
+/// Casts a Utf8 array to a Time32 or Time64 array of `unit`, strings that can't be
+/// parsed as a time of day return null
+fn cast_string_to_time(
+    array: &ArrayRef,
+    unit: &TimeUnit,
+    to_type: &DataType,
+) -> Result<ArrayRef> {
+    let string_array = array.as_typed::<StringArray>()?;
+    let values = string_array
+        .iter()
+        .map(|v| {
+            let time = v?.parse::<chrono::NaiveTime>().ok()?;
+            let nanos = time.num_seconds_from_midnight() as i64 * NANOSECONDS
+                + time.nanosecond() as i64;
+            Some(convert_time_unit(nanos, &TimeUnit::Nanosecond, unit))
+        })
+        .collect();
+    temporal_array_from_values(values, to_type)
+}
+
+/// Casts a Time32 or Time64 array of `unit` to a Utf8 array, values that are not
+/// a valid time of day return null
+fn cast_time_to_string(array: &ArrayRef, unit: &TimeUnit) -> Result<ArrayRef> {
+    let per_second = time_unit_multiple(unit);
+    let mut b = StringBuilder::new(array.len());
+    for value in temporal_values(array) {
+        let time = value
+            .filter(|v| (0..SECONDS_IN_DAY * per_second).contains(v))
+            .and_then(|v| {
+                chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+                    (v / per_second) as u32,
+                    ((v % per_second) * (NANOSECONDS / per_second)) as u32,
+                )
+            });
+        match time {
+            Some(time) => b.append_value(&time.to_string())?,
+            None => b.append_null()?,
+        }
+    }
+
+    Ok(Arc::new(b.finish()) as ArrayRef)
+}
+
 /// Number of seconds in a day
 const SECONDS_IN_DAY: i64 = 86_400;
 /// Number of milliseconds in a second
@@ -866,9 +996,7 @@ where
     TO::Native: num::NumCast,
 {
     Ok(Arc::new(numeric_cast::<FROM, TO>(
-        from.as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
+        from.as_typed::<PrimitiveArray<FROM>>()?,
     )))
 }
 
@@ -894,13 +1022,8 @@ where
     FROM: ArrowNumericType,
     FROM::Native: std::string::ToString,
 {
-    numeric_to_string_cast::<FROM>(
-        array
-            .as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    )
-    .map(|to| Arc::new(to) as ArrayRef)
+    numeric_to_string_cast::<FROM>(array.as_typed::<PrimitiveArray<FROM>>()?)
+        .map(|to| Arc::new(to) as ArrayRef)
 }
 
 fn numeric_to_string_cast<T>(from: &PrimitiveArray<T>) -> Result<StringArray>
@@ -921,13 +1044,151 @@ where
     Ok(b.finish())
 }
 
+/// How decimal values with more fractional digits than the scale are rounded
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DecimalRoundingMode {
+    /// Round to the nearest value, and ties away from zero: `2.5` becomes `3` and `-2.5`
+    /// becomes `-3`
+    #[default]
+    HalfUp,
+    /// Round to the nearest value, and ties to the nearest even value: `2.5` becomes `2`
+    /// and `3.5` becomes `4`
+    HalfEven,
+    /// Discard the extra fractional digits: `2.9` becomes `2` and `-2.9` becomes `-2`
+    Truncate,
+}
+
+/// Options that define how strings are cast to decimals
+#[derive(Clone, Debug, Default)]
+pub struct DecimalCastOptions {
+    /// How values with more fractional digits than the scale are rounded
+    pub rounding: DecimalRoundingMode,
+    /// Emit a null slot instead of returning an error when a value exceeds the precision
+    pub null_on_overflow: bool,
+}
+
+/// The result of parsing a string as a decimal
+enum ParsedDecimal {
+    Value(i128),
+    Invalid,
+    Overflow,
+}
+
+/// Parses `value`, such as `-12.345`, as a decimal with the given precision and scale
+fn parse_decimal(
+    value: &str,
+    precision: usize,
+    scale: usize,
+    rounding: DecimalRoundingMode,
+) -> ParsedDecimal {
+    let (negative, digits) = match value.as_bytes().first() {
+        Some(b'-') => (true, &value[1..]),
+        Some(b'+') => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let mut parts = digits.splitn(2, '.');
+    let integer = parts.next().unwrap_or_default().as_bytes();
+    let fraction = parts.next().unwrap_or_default().as_bytes();
+    if (integer.is_empty() && fraction.is_empty())
+        || !integer.iter().chain(fraction).all(|c| c.is_ascii_digit())
+    {
+        return ParsedDecimal::Invalid;
+    }
+
+    // the digits of the unscaled value, padding the fraction with zeros up to the scale
+    let kept_fraction = &fraction[..fraction.len().min(scale)];
+    let padding = std::iter::repeat(&b'0').take(scale - kept_fraction.len());
+    let mut unscaled: i128 = 0;
+    for digit in integer.iter().chain(kept_fraction).chain(padding) {
+        unscaled = match unscaled
+            .checked_mul(10)
+            .and_then(|v| v.checked_add((digit - b'0') as i128))
+        {
+            Some(v) => v,
+            None => return ParsedDecimal::Overflow,
+        };
+    }
+
+    let dropped = &fraction[kept_fraction.len()..];
+    let round_up = match (rounding, dropped.split_first()) {
+        (DecimalRoundingMode::Truncate, _) | (_, None) => false,
+        (DecimalRoundingMode::HalfUp, Some((first, _))) => *first >= b'5',
+        (DecimalRoundingMode::HalfEven, Some((first, rest))) => {
+            *first > b'5'
+                || (*first == b'5'
+                    && (rest.iter().any(|c| *c != b'0') || unscaled % 2 == 1))
+        }
+    };
+    if round_up {
+        unscaled += 1;
+    }
+
+    if unscaled >= 10i128.pow(precision as u32) {
+        return ParsedDecimal::Overflow;
+    }
+    ParsedDecimal::Value(if negative { -unscaled } else { unscaled })
+}
+
+/// Casts a Utf8 array to a decimal array with the given precision and scale.
+///
+/// Strings that can't be parsed to decimals, such as `1e3` or `abc`, return null.
+/// Fractional digits beyond the scale are rounded according to `options.rounding`.
+/// Values that exceed the precision make this function error, unless
+/// `options.null_on_overflow` is set, in which case they return null.
+pub fn cast_utf8_to_decimal(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+    options: Option<DecimalCastOptions>,
+) -> Result<ArrayRef> {
+    let options = options.unwrap_or_default();
+    if precision > 38 || scale > precision {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Invalid decimal precision {} and scale {}",
+            precision, scale
+        )));
+    }
+    let string_array = array
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Casting from {:?} to decimal not supported",
+                array.data_type()
+            ))
+        })?;
+
+    let mut builder = DecimalBuilder::new(string_array.len() * 16, precision, scale);
+    for i in 0..string_array.len() {
+        if string_array.is_null(i) {
+            builder.append_null()?;
+            continue;
+        }
+        let value = string_array.value(i);
+        match parse_decimal(value, precision, scale, options.rounding) {
+            ParsedDecimal::Value(v) => builder.append_value(v)?,
+            ParsedDecimal::Invalid => builder.append_null()?,
+            ParsedDecimal::Overflow if options.null_on_overflow => {
+                builder.append_null()?
+            }
+            ParsedDecimal::Overflow => {
+                return Err(ArrowError::ComputeError(format!(
+                    "'{}' does not fit in a decimal of precision {} and scale {}",
+                    value, precision, scale
+                )))
+            }
+        }
+    }
+    Ok(Arc::new(builder.finish()) as ArrayRef)
+}
+
 /// Cast numeric types to Utf8
 fn cast_string_to_numeric<TO>(from: &ArrayRef) -> Result<ArrayRef>
 where
     TO: ArrowNumericType,
 {
     Ok(Arc::new(string_to_numeric_cast::<TO>(
-        from.as_any().downcast_ref::<StringArray>().unwrap(),
+        from.as_typed::<StringArray>()?,
     )))
 }
 
@@ -956,12 +1217,8 @@ fn cast_numeric_to_bool<FROM>(from: &ArrayRef) -> Result<ArrayRef>
 where
     FROM: ArrowNumericType,
 {
-    numeric_to_bool_cast::<FROM>(
-        from.as_any()
-            .downcast_ref::<PrimitiveArray<FROM>>()
-            .unwrap(),
-    )
-    .map(|to| Arc::new(to) as ArrayRef)
+    numeric_to_bool_cast::<FROM>(from.as_typed::<PrimitiveArray<FROM>>()?)
+        .map(|to| Arc::new(to) as ArrayRef)
 }
 
 fn numeric_to_bool_cast<T>(from: &PrimitiveArray<T>) -> Result<BooleanArray>
@@ -992,7 +1249,7 @@ where
     TO::Native: num::cast::NumCast,
 {
     Ok(Arc::new(bool_to_numeric_cast::<TO>(
-        from.as_any().downcast_ref::<BooleanArray>().unwrap(),
+        from.as_typed::<BooleanArray>()?,
     )))
 }
 
@@ -1164,10 +1421,7 @@ where
 {
     // attempt to cast the source array values to the target value type (the dictionary values type)
     let cast_values = cast(array, &dict_value_type)?;
-    let values = cast_values
-        .as_any()
-        .downcast_ref::<PrimitiveArray<V>>()
-        .unwrap();
+    let values = cast_values.as_typed::<PrimitiveArray<V>>()?;
 
     let keys_builder = PrimitiveBuilder::<K>::new(values.len());
     let values_builder = PrimitiveBuilder::<V>::new(values.len());
@@ -1191,7 +1445,7 @@ where
     K: ArrowDictionaryKeyType,
 {
     let cast_values = cast(array, &DataType::Utf8)?;
-    let values = cast_values.as_any().downcast_ref::<StringArray>().unwrap();
+    let values = cast_values.as_typed::<StringArray>()?;
 
     let keys_builder = PrimitiveBuilder::<K>::new(values.len());
     let values_builder = StringBuilder::new(values.len());
@@ -1375,6 +1629,100 @@ mod tests {
         assert_eq!(false, c.is_valid(2));
     }
 
+    fn decimal_values(array: &ArrayRef) -> Vec<Option<i128>> {
+        let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+        (0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    None
+                } else {
+                    Some(array.value(i))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cast_utf8_to_decimal() {
+        let a = StringArray::from(vec![
+            Some("1.5"),
+            Some("-2.345"),
+            None,
+            Some("abc"),
+            Some(".25"),
+            Some("+7"),
+            Some("1e3"),
+            Some("-"),
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        assert_eq!(&DataType::Decimal(5, 2), b.data_type());
+        assert_eq!(
+            vec![
+                Some(150),
+                Some(-235),
+                None,
+                None,
+                Some(25),
+                Some(700),
+                None,
+                None
+            ],
+            decimal_values(&b)
+        );
+    }
+
+    #[test]
+    fn test_cast_utf8_to_decimal_rounding() {
+        let a = StringArray::from(vec!["2.5", "3.5", "-2.5", "2.51", "-2.9", "0.49"]);
+        let array = Arc::new(a) as ArrayRef;
+        let cast_with = |rounding| {
+            let options = DecimalCastOptions {
+                rounding,
+                null_on_overflow: false,
+            };
+            let b = cast_utf8_to_decimal(&array, 3, 0, Some(options)).unwrap();
+            decimal_values(&b)
+                .into_iter()
+                .map(Option::unwrap)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![3, 4, -3, 3, -3, 0],
+            cast_with(DecimalRoundingMode::HalfUp)
+        );
+        assert_eq!(
+            vec![2, 4, -2, 3, -3, 0],
+            cast_with(DecimalRoundingMode::HalfEven)
+        );
+        assert_eq!(
+            vec![2, 3, -2, 2, -2, 0],
+            cast_with(DecimalRoundingMode::Truncate)
+        );
+    }
+
+    #[test]
+    fn test_cast_utf8_to_decimal_overflow() {
+        let a = StringArray::from(vec!["999.99", "999.995", "-1000"]);
+        let array = Arc::new(a) as ArrayRef;
+        assert!(cast(&array, &DataType::Decimal(5, 2)).is_err());
+
+        let options = DecimalCastOptions {
+            rounding: DecimalRoundingMode::HalfUp,
+            null_on_overflow: true,
+        };
+        let b = cast_utf8_to_decimal(&array, 5, 2, Some(options.clone())).unwrap();
+        assert_eq!(vec![Some(99999), None, None], decimal_values(&b));
+
+        let a = StringArray::from(vec!["1".repeat(40).as_str()]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast_utf8_to_decimal(&array, 38, 0, Some(options)).unwrap();
+        assert_eq!(vec![None], decimal_values(&b));
+
+        assert!(cast_utf8_to_decimal(&array, 39, 0, None).is_err());
+        assert!(cast_utf8_to_decimal(&array, 2, 3, None).is_err());
+    }
+
     #[test]
     fn test_cast_bool_to_i32() {
         let a = BooleanArray::from(vec![Some(true), Some(false), None]);
@@ -1515,6 +1863,88 @@ mod tests {
         assert!(c.is_null(2));
     }
 
+    #[test]
+    fn test_cast_utf8_to_time() {
+        let a = StringArray::from(vec![
+            Some("12:30:45.123456"),
+            Some("00:00:00"),
+            Some("25:00:00"),
+            None,
+        ]);
+        let array = Arc::new(a) as ArrayRef;
+
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(45_045_123, c.value(0));
+        assert_eq!(0, c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(45_045_123_456_000, c.value(0));
+
+        // round trip
+        let b = cast(&b, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("12:30:45.123456", c.value(0));
+        assert_eq!("00:00:00", c.value(1));
+        assert!(c.is_null(2));
+        assert!(c.is_null(3));
+    }
+
+    #[test]
+    fn test_cast_invalid_time_to_utf8() {
+        let a = Time32SecondArray::from(vec![-1, 86_399, 86_400]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert!(c.is_null(0));
+        assert_eq!("23:59:59", c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_timestamp_to_time() {
+        // 2018-12-25T12:30:45.123, 1969-12-31T23:59:59.999
+        let a = TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1_545_741_045_123), Some(-1), None],
+            Some("UTC".to_string()),
+        );
+        let array = Arc::new(a) as ArrayRef;
+
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(45_045, c.value(0));
+        assert_eq!(86_399, c.value(1));
+        assert!(c.is_null(2));
+
+        let b = cast(&array, &DataType::Time64(TimeUnit::Microsecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64MicrosecondArray>().unwrap();
+        assert_eq!(45_045_123_000, c.value(0));
+        assert_eq!(86_399_999_000, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_time_to_timestamp() {
+        let a = Time32MillisecondArray::from(vec![Some(45_045_123), None]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Nanosecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(45_045_123_000_000, c.value(0));
+        assert!(c.is_null(1));
+
+        let a = Time64NanosecondArray::from(vec![45_045_123_456_789]);
+        let array = Arc::new(a) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(45_045, c.value(0));
+    }
+
     #[test]
     fn test_cast_date32_to_int32() {
         let a = Date32Array::from(vec![10000, 17890]);
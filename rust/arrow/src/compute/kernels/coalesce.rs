@@ -0,0 +1,232 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the coalesce kernel, which returns the first non-null value of several arrays,
+//! as SQL's `COALESCE` does.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::util::unify_dictionaries;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns the validity of the slots of `array` as 64-bit words, where the bits past its
+/// length are unset
+fn validity_words(array: &ArrayRef) -> Vec<u64> {
+    let len = array.len();
+    match array.data_ref().null_buffer() {
+        Some(buffer) => {
+            let chunks = buffer.bit_chunks(array.offset(), len);
+            let mut words = chunks.iter().collect::<Vec<_>>();
+            if chunks.remainder_len() > 0 {
+                words.push(chunks.remainder_bits());
+            }
+            words
+        }
+        None => set_words(len),
+    }
+}
+
+/// Returns `len` set bits as 64-bit words, where the bits past `len` are unset
+fn set_words(len: usize) -> Vec<u64> {
+    let mut words = vec![u64::MAX; len / 64];
+    if len % 64 > 0 {
+        words.push((1 << (len % 64)) - 1);
+    }
+    words
+}
+
+/// Returns an array whose values are the first non-null value of `arrays` at each slot,
+/// or null if they are all null.
+///
+/// The array each value is taken from is found 64 slots at a time from the validity of
+/// the arrays, and runs of values of the same array are copied at once. Dictionary
+/// arrays of different dictionaries are coalesced into an array of the union of their
+/// dictionaries.
+///
+/// # Errors
+/// This function errors if `arrays` is empty, or if the arrays are of different lengths
+/// or data types, or of a nested type other than lists and structs.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::kernels::coalesce::coalesce;
+/// use arrow::error::Result;
+/// # fn main() -> Result<()> {
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, None]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![Some(4), Some(5), None]));
+/// let coalesced = coalesce(&[a, b])?;
+/// assert_eq!(
+///     coalesced.as_typed::<Int32Array>()?,
+///     &Int32Array::from(vec![Some(1), Some(5), None])
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn coalesce(arrays: &[ArrayRef]) -> Result<ArrayRef> {
+    let first = match arrays.first() {
+        Some(array) => array,
+        None => {
+            return Err(ArrowError::InvalidArgumentError(
+                "coalesce requires input of at least one array".to_string(),
+            ))
+        }
+    };
+    if arrays
+        .iter()
+        .any(|array| array.data_type() != first.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to coalesce arrays of different data types.".to_string(),
+        ));
+    }
+    if arrays.iter().any(|array| array.len() != first.len()) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to coalesce arrays of different lengths.".to_string(),
+        ));
+    }
+    match first.data_type() {
+        DataType::Float16 | DataType::FixedSizeList(_, _) | DataType::Union(_) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Coalesce is not supported for arrays of type {:?}",
+                first.data_type()
+            )))
+        }
+        _ if first.null_count() == 0 || arrays.len() == 1 => return Ok(first.clone()),
+        DataType::Null => return Ok(first.clone()),
+        _ => {}
+    }
+
+    // the index of the array of the value of each slot, found from the slots that
+    // are still null before each array
+    let len = first.len();
+    let mut sources: Vec<Option<usize>> = vec![None; len];
+    let mut remaining = set_words(len);
+    for (i, array) in arrays.iter().enumerate() {
+        if remaining.iter().all(|word| *word == 0) {
+            break;
+        }
+        let valid = validity_words(array);
+        for (w, (remaining, valid)) in remaining.iter_mut().zip(valid).enumerate() {
+            let mut selected = *remaining & valid;
+            while selected != 0 {
+                sources[w * 64 + selected.trailing_zeros() as usize] = Some(i);
+                selected &= selected - 1;
+            }
+            *remaining &= !valid;
+        }
+    }
+
+    let arrays = unify_dictionaries(arrays)?;
+    let data = arrays
+        .iter()
+        .map(|array| array.data_ref().as_ref())
+        .collect::<Vec<_>>();
+    let mut mutable = MutableArrayData::new(data, true, len);
+    let mut start = 0;
+    for end in 1..=len {
+        if end == len || sources[end] != sources[start] {
+            match sources[start] {
+                Some(i) => mutable.extend(i, start, end),
+                None => mutable.extend_nulls(end - start),
+            }
+            start = end;
+        }
+    }
+    Ok(make_array(Arc::new(mutable.freeze())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::datatypes::Int8Type;
+
+    #[test]
+    fn test_coalesce_long_and_sliced() {
+        let a: Int64Array = (0..200)
+            .map(|i| if i % 2 == 0 { Some(i) } else { None })
+            .collect::<Vec<_>>()
+            .into();
+        let a = a.slice(3, 150);
+        let b: Int64Array = (0..150)
+            .map(|i| if i % 3 == 0 { Some(-i) } else { None })
+            .collect::<Vec<_>>()
+            .into();
+        let c = Int64Array::from(vec![7; 150]);
+        let b: ArrayRef = Arc::new(b);
+        let c: ArrayRef = Arc::new(c);
+
+        let coalesced = coalesce(&[a.clone(), b.clone()]).unwrap();
+        let expected: Int64Array = (0..150)
+            .map(|i| match (i + 3) % 2 == 0 {
+                true => Some(i + 3),
+                false if i % 3 == 0 => Some(-i),
+                false => None,
+            })
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(coalesced.as_typed::<Int64Array>().unwrap(), &expected);
+
+        let coalesced = coalesce(&[a, b, c]).unwrap();
+        let expected: Int64Array = expected
+            .iter()
+            .map(|v| v.unwrap_or(7))
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(coalesced.as_typed::<Int64Array>().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_coalesce_strings_and_dictionaries() {
+        let a: ArrayRef = Arc::new(StringArray::from(vec![None, Some("b"), None]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), Some("y"), None]));
+        let coalesced = coalesce(&[a, b]).unwrap();
+        assert_eq!(
+            coalesced.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![Some("x"), Some("b"), None])
+        );
+
+        let a: DictionaryArray<Int8Type> =
+            vec![None, Some("b"), None].into_iter().collect();
+        let b: DictionaryArray<Int8Type> =
+            vec![Some("x"), Some("y"), Some("z")].into_iter().collect();
+        let coalesced = coalesce(&[Arc::new(a) as ArrayRef, Arc::new(b)]).unwrap();
+        let coalesced = coalesced.as_typed::<DictionaryArray<Int8Type>>().unwrap();
+        let values = coalesced.values();
+        let values = values.as_typed::<StringArray>().unwrap();
+        let strings = coalesced
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as usize)))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some("x"), Some("b"), Some("z")], strings);
+    }
+
+    #[test]
+    fn test_coalesce_errors() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None]));
+        let c: ArrayRef = Arc::new(Int32Array::from(vec![Some(1)]));
+        assert!(coalesce(&[]).is_err());
+        assert!(coalesce(&[a.clone(), b]).is_err());
+        assert!(coalesce(&[a, c]).is_err());
+    }
+}
@@ -22,14 +22,26 @@
 //! `RUSTFLAGS="-C target-feature=+avx2"` for example.  See the documentation
 //! [here](https://doc.rust-lang.org/stable/core/arch/) for more information.
 
+use memchr::memmem::Finder;
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 use std::sync::Arc;
 
 use crate::array::*;
-use crate::buffer::{Buffer, MutableBuffer};
-use crate::compute::util::combine_option_bitmap;
-use crate::datatypes::{ArrowNumericType, DataType};
+use crate::buffer::{
+    bitwise_bin_op_helper, bitwise_unary_op_helper, buffer_bin_and, buffer_bin_or,
+    buffer_unary_not, Buffer, MutableBuffer,
+};
+use crate::compute::kernels::boolean::not;
+use crate::compute::kernels::take::take;
+use crate::compute::kernels::temporal::reinterpret;
+use crate::compute::util::{combine_option_bitmap, combine_option_buffers};
+use crate::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, ArrowNumericType, ArrowPrimitiveType,
+    DataType, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    IntervalUnit, TimeUnit, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
 
@@ -78,7 +90,10 @@ macro_rules! compare_op {
 
 macro_rules! compare_op_scalar {
     ($left: expr, $right:expr, $op:expr) => {{
-        let null_bit_buffer = $left.data().null_buffer().cloned();
+        let null_bit_buffer = $left
+            .data()
+            .null_buffer()
+            .map(|b| b.bit_slice($left.offset(), $left.len()));
 
         let byte_capacity = bit_util::ceil($left.len(), 8);
         let actual_capacity = bit_util::round_upto_multiple_of_64(byte_capacity);
@@ -133,7 +148,37 @@ where
     compare_op_scalar!(left, right, op)
 }
 
-pub fn like_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Translates a SQL LIKE pattern into an anchored regular expression: `%` matches any
+/// sequence of characters, `_` matches any single character and all the other
+/// characters match themselves.
+fn like_to_regex(pattern: &str, case_insensitive: bool) -> Result<Regex> {
+    let mut re_pattern = String::with_capacity(pattern.len() + 8);
+    re_pattern.push_str(if case_insensitive { "(?is)^" } else { "(?s)^" });
+    let mut buffer = [0; 4];
+    for c in pattern.chars() {
+        match c {
+            '%' => re_pattern.push_str(".*"),
+            '_' => re_pattern.push('.'),
+            c => re_pattern.push_str(&regex::escape(c.encode_utf8(&mut buffer))),
+        }
+    }
+    re_pattern.push('$');
+    Regex::new(&re_pattern).map_err(|e| {
+        ArrowError::ComputeError(format!(
+            "Unable to build regex from LIKE pattern: {}",
+            e
+        ))
+    })
+}
+
+/// Matches each value of `left` against the LIKE pattern of the same slot of `right`,
+/// negating the result when `negate` is set.
+fn like_op<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+    case_insensitive: bool,
+    negate: bool,
+) -> Result<BooleanArray> {
     let mut map = HashMap::new();
     if left.len() != right.len() {
         return Err(ArrowError::ComputeError(
@@ -152,18 +197,11 @@ pub fn like_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray
         let re = if let Some(ref regex) = map.get(pat) {
             regex
         } else {
-            let re_pattern = pat.replace("%", ".*").replace("_", ".");
-            let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                ArrowError::ComputeError(format!(
-                    "Unable to build regex from LIKE pattern: {}",
-                    e
-                ))
-            })?;
-            map.insert(pat, re);
+            map.insert(pat, like_to_regex(pat, case_insensitive)?);
             map.get(pat).unwrap()
         };
 
-        result.append(re.is_match(haystack))?;
+        result.append(re.is_match(haystack) != negate)?;
     }
 
     let data = ArrayData::new(
@@ -182,40 +220,59 @@ fn is_like_pattern(c: char) -> bool {
     c == '%' || c == '_'
 }
 
-pub fn like_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
-    let null_bit_buffer = left.data().null_buffer().cloned();
+/// Matches each value of `left` against the LIKE pattern `right`, negating the result
+/// when `negate` is set.
+fn like_scalar_op<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+    case_insensitive: bool,
+    negate: bool,
+) -> Result<BooleanArray> {
+    let null_bit_buffer = left
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), left.len()));
     let mut result = BooleanBufferBuilder::new(left.len());
 
-    if !right.contains(is_like_pattern) {
+    if case_insensitive {
+        let re = like_to_regex(right, true)?;
+        for i in 0..left.len() {
+            result.append(re.is_match(left.value(i)) != negate)?;
+        }
+    } else if !right.contains(is_like_pattern) {
         // fast path, can use equals
         for i in 0..left.len() {
-            result.append(left.value(i) == right)?;
+            result.append((left.value(i) == right) != negate)?;
         }
     } else if right.ends_with('%') && !right[..right.len() - 1].contains(is_like_pattern)
     {
         // fast path, can use starts_with
+        let prefix = &right[..right.len() - 1];
         for i in 0..left.len() {
-            result.append(left.value(i).starts_with(&right[..right.len() - 1]))?;
+            result.append(left.value(i).starts_with(prefix) != negate)?;
         }
     } else if right.starts_with('%') && !right[1..].contains(is_like_pattern) {
         // fast path, can use ends_with
+        let suffix = &right[1..];
         for i in 0..left.len() {
-            result.append(left.value(i).ends_with(&right[1..]))?;
+            result.append(left.value(i).ends_with(suffix) != negate)?;
+        }
+    } else if right.len() >= 2
+        && right.starts_with('%')
+        && right.ends_with('%')
+        && !right[1..right.len() - 1].contains(is_like_pattern)
+    {
+        // fast path, can use contains
+        let finder = Finder::new(&right[1..right.len() - 1]);
+        for i in 0..left.len() {
+            result.append(finder.find(left.value(i).as_bytes()).is_some() != negate)?;
         }
     } else {
-        let re_pattern = right.replace("%", ".*").replace("_", ".");
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::ComputeError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-
+        let re = like_to_regex(right, false)?;
         for i in 0..left.len() {
-            let haystack = left.value(i);
-            result.append(re.is_match(haystack))?;
+            result.append(re.is_match(left.value(i)) != negate)?;
         }
-    };
+    }
 
     let data = ArrayData::new(
         DataType::Boolean,
@@ -229,7 +286,133 @@ pub fn like_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray>
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
-pub fn nlike_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform SQL `left LIKE right` operation on two \[Large\]StringArrays, where `%` in
+/// a pattern matches any sequence of characters and `_` matches any single character.
+pub fn like_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    like_op(left, right, false, false)
+}
+
+/// Perform SQL `left LIKE right` operation on a \[Large\]StringArray and a pattern.
+///
+/// Patterns made of a literal with at most a leading and a trailing `%` are matched
+/// without building a regular expression.
+pub fn like_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    like_scalar_op(left, right, false, false)
+}
+
+/// Perform SQL `left NOT LIKE right` operation on two \[Large\]StringArrays.
+pub fn nlike_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    like_op(left, right, false, true)
+}
+
+/// Perform SQL `left NOT LIKE right` operation on a \[Large\]StringArray and a pattern.
+pub fn nlike_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    like_scalar_op(left, right, false, true)
+}
+
+/// Perform SQL `left ILIKE right` operation on two \[Large\]StringArrays: `left LIKE
+/// right`, ignoring case.
+pub fn ilike_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    like_op(left, right, true, false)
+}
+
+/// Perform SQL `left ILIKE right` operation on a \[Large\]StringArray and a pattern.
+pub fn ilike_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    like_scalar_op(left, right, true, false)
+}
+
+/// Evaluates `op` on the bytes of each value of `left`, keeping the nulls of `left`
+fn utf8_scalar_predicate<OffsetSize, F>(
+    left: &GenericStringArray<OffsetSize>,
+    op: F,
+) -> Result<BooleanArray>
+where
+    OffsetSize: StringOffsetSizeTrait,
+    F: Fn(&[u8]) -> bool,
+{
+    let null_bit_buffer = left
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), left.len()));
+    let mut result = BooleanBufferBuilder::new(left.len());
+    for i in 0..left.len() {
+        result.append(op(left.value(i).as_bytes()))?;
+    }
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![result.finish()],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Perform `left.starts_with(right)` operation on a \[Large\]StringArray and a prefix.
+pub fn starts_with_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    let prefix = right.as_bytes();
+    utf8_scalar_predicate(left, |value| value.starts_with(prefix))
+}
+
+/// Perform `left.ends_with(right)` operation on a \[Large\]StringArray and a suffix.
+pub fn ends_with_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    let suffix = right.as_bytes();
+    utf8_scalar_predicate(left, |value| value.ends_with(suffix))
+}
+
+/// Perform `left.contains(right)` operation on a \[Large\]StringArray and a substring.
+///
+/// The substring is searched with a `memchr` finder built once for all the values.
+pub fn contains_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    let finder = Finder::new(right);
+    utf8_scalar_predicate(left, |value| finder.find(value).is_some())
+}
+
+fn build_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {}", e))
+    })
+}
+
+/// Perform `regexp_is_match(left, right)` operation on two \[Large\]StringArrays: whether
+/// each value of `left` contains a match of the regular expression of the same slot of
+/// `right`. Each distinct pattern is compiled once.
+///
+/// This function errors if a pattern is not a valid regular expression.
+pub fn regexp_is_match_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
     let mut map = HashMap::new();
     if left.len() != right.len() {
         return Err(ArrowError::ComputeError(
@@ -243,23 +426,19 @@ pub fn nlike_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArra
 
     let mut result = BooleanBufferBuilder::new(left.len());
     for i in 0..left.len() {
-        let haystack = left.value(i);
+        if left.is_null(i) || right.is_null(i) {
+            result.append(false)?;
+            continue;
+        }
         let pat = right.value(i);
         let re = if let Some(ref regex) = map.get(pat) {
             regex
         } else {
-            let re_pattern = pat.replace("%", ".*").replace("_", ".");
-            let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-                ArrowError::ComputeError(format!(
-                    "Unable to build regex from LIKE pattern: {}",
-                    e
-                ))
-            })?;
-            map.insert(pat, re);
+            map.insert(pat, build_regex(pat)?);
             map.get(pat).unwrap()
         };
 
-        result.append(!re.is_match(haystack))?;
+        result.append(re.is_match(left.value(i)))?;
     }
 
     let data = ArrayData::new(
@@ -274,38 +453,23 @@ pub fn nlike_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArra
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
-pub fn nlike_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
-    let null_bit_buffer = left.data().null_buffer().cloned();
-    let mut result = BooleanBufferBuilder::new(left.len());
+/// Perform `regexp_is_match(left, right)` operation on a \[Large\]StringArray and a
+/// regular expression, which is compiled once.
+///
+/// This function errors if `right` is not a valid regular expression.
+pub fn regexp_is_match_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    let null_bit_buffer = left
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), left.len()));
+    let re = build_regex(right)?;
 
-    if !right.contains(is_like_pattern) {
-        // fast path, can use equals
-        for i in 0..left.len() {
-            result.append(left.value(i) != right)?;
-        }
-    } else if right.ends_with('%') && !right[..right.len() - 1].contains(is_like_pattern)
-    {
-        // fast path, can use ends_with
-        for i in 0..left.len() {
-            result.append(!left.value(i).starts_with(&right[..right.len() - 1]))?;
-        }
-    } else if right.starts_with('%') && !right[1..].contains(is_like_pattern) {
-        // fast path, can use starts_with
-        for i in 0..left.len() {
-            result.append(!left.value(i).ends_with(&right[1..]))?;
-        }
-    } else {
-        let re_pattern = right.replace("%", ".*").replace("_", ".");
-        let re = Regex::new(&format!("^{}$", re_pattern)).map_err(|e| {
-            ArrowError::ComputeError(format!(
-                "Unable to build regex from LIKE pattern: {}",
-                e
-            ))
-        })?;
-        for i in 0..left.len() {
-            let haystack = left.value(i);
-            result.append(!re.is_match(haystack))?;
-        }
+    let mut result = BooleanBufferBuilder::new(left.len());
+    for i in 0..left.len() {
+        result.append(left.is_valid(i) && re.is_match(left.value(i)))?;
     }
 
     let data = ArrayData::new(
@@ -320,54 +484,627 @@ pub fn nlike_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
-pub fn eq_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left == right` operation on two \[Large\]StringArrays.
+pub fn eq_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a == b)
+}
+
+/// Perform `left == right` operation on a \[Large\]StringArray and a scalar.
+pub fn eq_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a == b)
+}
+
+/// Perform `left != right` operation on two \[Large\]StringArrays.
+pub fn neq_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a != b)
+}
+
+/// Perform `left != right` operation on a \[Large\]StringArray and a scalar.
+pub fn neq_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a != b)
+}
+
+/// Perform `left < right` operation on two \[Large\]StringArrays.
+pub fn lt_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a < b)
+}
+
+/// Perform `left < right` operation on a \[Large\]StringArray and a scalar.
+pub fn lt_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a < b)
+}
+
+/// Perform `left <= right` operation on two \[Large\]StringArrays.
+pub fn lt_eq_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left <= right` operation on a \[Large\]StringArray and a scalar.
+pub fn lt_eq_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left > right` operation on two \[Large\]StringArrays.
+pub fn gt_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a > b)
+}
+
+/// Perform `left > right` operation on a \[Large\]StringArray and a scalar.
+pub fn gt_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a > b)
+}
+
+/// Perform `left >= right` operation on two \[Large\]StringArrays.
+pub fn gt_eq_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left >= right` operation on a \[Large\]StringArray and a scalar.
+pub fn gt_eq_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &str,
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left == right` operation on two \[Large\]BinaryArrays.
+pub fn eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a == b)
 }
 
-pub fn eq_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left == right` operation on a \[Large\]BinaryArray and a scalar.
+pub fn eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a == b)
 }
 
-pub fn neq_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left != right` operation on two \[Large\]BinaryArrays.
+pub fn neq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a != b)
 }
 
-pub fn neq_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left != right` operation on a \[Large\]BinaryArray and a scalar.
+pub fn neq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a != b)
 }
 
-pub fn lt_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left < right` operation on two \[Large\]BinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn lt_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a < b)
 }
 
-pub fn lt_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left < right` operation on a \[Large\]BinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn lt_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a < b)
 }
 
-pub fn lt_eq_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left <= right` operation on two \[Large\]BinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn lt_eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a <= b)
 }
 
-pub fn lt_eq_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left <= right` operation on a \[Large\]BinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn lt_eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a <= b)
 }
 
-pub fn gt_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left > right` operation on two \[Large\]BinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn gt_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a > b)
 }
 
-pub fn gt_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left > right` operation on a \[Large\]BinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn gt_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a > b)
 }
 
-pub fn gt_eq_utf8(left: &StringArray, right: &StringArray) -> Result<BooleanArray> {
+/// Perform `left >= right` operation on two \[Large\]BinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn gt_eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
     compare_op!(left, right, |a, b| a >= b)
 }
 
-pub fn gt_eq_utf8_scalar(left: &StringArray, right: &str) -> Result<BooleanArray> {
+/// Perform `left >= right` operation on a \[Large\]BinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn gt_eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
     compare_op_scalar!(left, right, |a, b| a >= b)
 }
 
+/// Perform `left == right` operation on two FixedSizeBinaryArrays.
+pub fn eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a == b)
+}
+
+/// Perform `left == right` operation on a FixedSizeBinaryArray and a scalar.
+pub fn eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a == b)
+}
+
+/// Perform `left != right` operation on two FixedSizeBinaryArrays.
+pub fn neq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a != b)
+}
+
+/// Perform `left != right` operation on a FixedSizeBinaryArray and a scalar.
+pub fn neq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a != b)
+}
+
+/// Perform `left < right` operation on two FixedSizeBinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn lt_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a < b)
+}
+
+/// Perform `left < right` operation on a FixedSizeBinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn lt_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a < b)
+}
+
+/// Perform `left <= right` operation on two FixedSizeBinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn lt_eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left <= right` operation on a FixedSizeBinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn lt_eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left > right` operation on two FixedSizeBinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn gt_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a > b)
+}
+
+/// Perform `left > right` operation on a FixedSizeBinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn gt_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a > b)
+}
+
+/// Perform `left >= right` operation on two FixedSizeBinaryArrays, comparing their values
+/// lexicographically as bytes.
+pub fn gt_eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left >= right` operation on a FixedSizeBinaryArray and a scalar, comparing
+/// values lexicographically as bytes.
+pub fn gt_eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a >= b)
+}
+
+/// Helper function to compare two boolean arrays with the bitwise operation `op`, applied
+/// to 64 values at a time.
+fn compare_op_boolean<F>(
+    left: &BooleanArray,
+    right: &BooleanArray,
+    op: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(u64, u64) -> u64,
+{
+    let len = left.len();
+    if len != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+
+    let null_bit_buffer = combine_option_bitmap(left.data_ref(), right.data_ref(), len)?;
+    let values = bitwise_bin_op_helper(
+        &left.data_ref().buffers()[0],
+        left.offset(),
+        &right.data_ref().buffers()[0],
+        right.offset(),
+        len,
+        op,
+    );
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![values],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Helper function to compare a boolean array with a scalar, with the bitwise operation
+/// `op` taking 64 values of the array and the scalar repeated 64 times.
+fn compare_op_boolean_scalar<F>(
+    left: &BooleanArray,
+    right: bool,
+    op: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(u64, u64) -> u64,
+{
+    let len = left.len();
+    let right = if right { u64::MAX } else { 0 };
+    let null_bit_buffer = left
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), len));
+    let values =
+        bitwise_unary_op_helper(&left.data_ref().buffers()[0], left.offset(), len, |a| {
+            op(a, right)
+        });
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![values],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Perform `left == right` operation on two BooleanArrays.
+pub fn eq_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| !(a ^ b))
+}
+
+/// Perform `left == right` operation on a BooleanArray and a scalar.
+pub fn eq_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| !(a ^ b))
+}
+
+/// Perform `left != right` operation on two BooleanArrays.
+pub fn neq_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| a ^ b)
+}
+
+/// Perform `left != right` operation on a BooleanArray and a scalar.
+pub fn neq_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| a ^ b)
+}
+
+/// Perform `left < right` operation on two BooleanArrays, where `false < true`.
+pub fn lt_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| !a & b)
+}
+
+/// Perform `left < right` operation on a BooleanArray and a scalar, where `false < true`.
+pub fn lt_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| !a & b)
+}
+
+/// Perform `left <= right` operation on two BooleanArrays, where `false < true`.
+pub fn lt_eq_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| !a | b)
+}
+
+/// Perform `left <= right` operation on a BooleanArray and a scalar, where
+/// `false < true`.
+pub fn lt_eq_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| !a | b)
+}
+
+/// Perform `left > right` operation on two BooleanArrays, where `false < true`.
+pub fn gt_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| a & !b)
+}
+
+/// Perform `left > right` operation on a BooleanArray and a scalar, where `false < true`.
+pub fn gt_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| a & !b)
+}
+
+/// Perform `left >= right` operation on two BooleanArrays, where `false < true`.
+pub fn gt_eq_bool(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    compare_op_boolean(left, right, |a, b| a | !b)
+}
+
+/// Perform `left >= right` operation on a BooleanArray and a scalar, where
+/// `false < true`.
+pub fn gt_eq_bool_scalar(left: &BooleanArray, right: bool) -> Result<BooleanArray> {
+    compare_op_boolean_scalar(left, right, |a, b| a | !b)
+}
+
+/// Evaluates `op` on the values of the dictionary array `left` once, and maps the result
+/// to each of its keys. Null keys and keys of null results are null.
+fn dict_compare_op_scalar<K, F>(left: &DictionaryArray<K>, op: F) -> Result<BooleanArray>
+where
+    K: ArrowPrimitiveType,
+    F: FnOnce(&Array) -> Result<BooleanArray>,
+{
+    let values = op(left.values().as_ref())?;
+    Ok(left
+        .keys()
+        .iter()
+        .map(|key| {
+            let key = key?.to_usize()?;
+            if values.is_valid(key) {
+                Some(values.value(key))
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+macro_rules! dict_utf8_compare_op_scalar {
+    ($left: expr, $right: expr, $op: ident) => {
+        dict_compare_op_scalar($left, |values| match values.data_type() {
+            DataType::Utf8 => $op(values.as_typed::<StringArray>()?, $right),
+            DataType::LargeUtf8 => $op(values.as_typed::<LargeStringArray>()?, $right),
+            t => Err(ArrowError::ComputeError(format!(
+                "Expected a dictionary of Utf8 or LargeUtf8 values, got {:?}",
+                t
+            ))),
+        })
+    };
+}
+
+macro_rules! dict_primitive_compare_op_scalar {
+    ($left: expr, $right: expr, $op: ident) => {
+        dict_compare_op_scalar($left, |values| {
+            match values.as_any().downcast_ref::<PrimitiveArray<T>>() {
+                Some(values) => $op(values, $right),
+                None => Err(ArrowError::ComputeError(format!(
+                    "Expected a dictionary of {:?} values, got {:?}",
+                    T::DATA_TYPE,
+                    values.data_type()
+                ))),
+            }
+        })
+    };
+}
+
+/// Perform `left == right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn eq_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, eq_utf8_scalar)
+}
+
+/// Perform `left != right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn neq_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, neq_utf8_scalar)
+}
+
+/// Perform `left < right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn lt_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, lt_utf8_scalar)
+}
+
+/// Perform `left <= right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn lt_eq_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, lt_eq_utf8_scalar)
+}
+
+/// Perform `left > right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn gt_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, gt_utf8_scalar)
+}
+
+/// Perform `left >= right` operation on a dictionary array of \[Large\]Utf8 values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn gt_eq_dict_utf8_scalar<K: ArrowDictionaryKeyType>(
+    left: &DictionaryArray<K>,
+    right: &str,
+) -> Result<BooleanArray> {
+    dict_utf8_compare_op_scalar!(left, right, gt_eq_utf8_scalar)
+}
+
+/// Perform `left == right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn eq_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, eq_scalar)
+}
+
+/// Perform `left != right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn neq_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, neq_scalar)
+}
+
+/// Perform `left < right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn lt_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, lt_scalar)
+}
+
+/// Perform `left <= right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn lt_eq_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, lt_eq_scalar)
+}
+
+/// Perform `left > right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn gt_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, gt_scalar)
+}
+
+/// Perform `left >= right` operation on a dictionary array of primitive values and a
+/// scalar, comparing the scalar to each distinct value only once.
+pub fn gt_eq_dict_scalar<K, T>(
+    left: &DictionaryArray<K>,
+    right: T::Native,
+) -> Result<BooleanArray>
+where
+    K: ArrowDictionaryKeyType,
+    T: ArrowNumericType,
+{
+    dict_primitive_compare_op_scalar!(left, right, gt_eq_scalar)
+}
+
 /// Helper function to perform boolean lambda function on values from two arrays using
 /// SIMD.
 #[cfg(simd_x86)]
@@ -443,7 +1180,10 @@ where
     use std::mem;
 
     let len = left.len();
-    let null_bit_buffer = left.data().null_buffer().cloned();
+    let null_bit_buffer = left
+        .data()
+        .null_buffer()
+        .map(|b| b.bit_slice(left.offset(), len));
     let lanes = T::lanes();
     let mut result = MutableBuffer::new(left.len() * mem::size_of::<bool>());
     let simd_right = T::init(right);
@@ -625,6 +1365,119 @@ where
     return compare_op_scalar!(left, right, |a, b| a >= b);
 }
 
+/// Evaluates `op` on each slot of an array of length `len` into a BooleanArray
+fn between_op<F>(len: usize, null_bit_buffer: Option<Buffer>, op: F) -> BooleanArray
+where
+    F: Fn(usize) -> bool,
+{
+    let num_bytes = bit_util::ceil(len, 8);
+    let mut bool_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let bool_slice = bool_buf.data_mut();
+    for i in 0..len {
+        if op(i) {
+            bit_util::set_bit(bool_slice, i);
+        }
+    }
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![bool_buf.freeze()],
+        vec![],
+    );
+    BooleanArray::from(Arc::new(data))
+}
+
+/// Combines the null bitmaps of three arrays of the same length
+fn combine_three_bitmaps(a: &Array, b: &Array, c: &Array) -> Result<Option<Buffer>> {
+    if a.len() != b.len() || a.len() != c.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    let ab = combine_option_bitmap(a.data_ref(), b.data_ref(), a.len())?;
+    Ok(combine_option_buffers(
+        ab.as_ref(),
+        0,
+        c.data_ref().null_buffer(),
+        c.offset(),
+        a.len(),
+    ))
+}
+
+/// Perform SQL `array BETWEEN low AND high` operation on three arrays, i.e.
+/// `low <= array AND array <= high`, in a single pass. A slot is null when the slot of
+/// any input is null.
+pub fn between<T>(
+    array: &PrimitiveArray<T>,
+    low: &PrimitiveArray<T>,
+    high: &PrimitiveArray<T>,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+{
+    let null_bit_buffer = combine_three_bitmaps(array, low, high)?;
+    Ok(between_op(array.len(), null_bit_buffer, |i| {
+        let value = array.value(i);
+        low.value(i) <= value && value <= high.value(i)
+    }))
+}
+
+/// Perform SQL `array BETWEEN low AND high` operation on an array and two scalar bounds
+/// in a single pass.
+pub fn between_scalar<T>(
+    array: &PrimitiveArray<T>,
+    low: T::Native,
+    high: T::Native,
+) -> Result<BooleanArray>
+where
+    T: ArrowNumericType,
+{
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+    Ok(between_op(array.len(), null_bit_buffer, |i| {
+        let value = array.value(i);
+        low <= value && value <= high
+    }))
+}
+
+/// Perform SQL `array BETWEEN low AND high` operation on three \[Large\]StringArrays in a
+/// single pass.
+pub fn between_utf8<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    low: &GenericStringArray<OffsetSize>,
+    high: &GenericStringArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    let null_bit_buffer = combine_three_bitmaps(array, low, high)?;
+    Ok(between_op(array.len(), null_bit_buffer, |i| {
+        let value = array.value(i);
+        low.value(i) <= value && value <= high.value(i)
+    }))
+}
+
+/// Perform SQL `array BETWEEN low AND high` operation on a \[Large\]StringArray and two
+/// scalar bounds in a single pass.
+pub fn between_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    low: &str,
+    high: &str,
+) -> Result<BooleanArray> {
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+    Ok(between_op(array.len(), null_bit_buffer, |i| {
+        let value = array.value(i);
+        low <= value && value <= high
+    }))
+}
+
 /// Checks if a `GenericListArray` contains a value in the `PrimitiveArray`
 pub fn contains<T, OffsetSize>(
     left: &PrimitiveArray<T>,
@@ -658,7 +1511,7 @@ where
     for i in 0..left_len {
         if bit_util::get_bit(not_both_null_bitmap, i) {
             let list = right.value(i);
-            let list = list.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+            let list = list.as_typed::<PrimitiveArray<T>>()?;
 
             for j in 0..list.len() {
                 if list.is_valid(j) && (left.value(i) == list.value(j)) {
@@ -713,10 +1566,7 @@ where
         // contains(null, null) = false
         if bit_util::get_bit(not_both_null_bitmap, i) {
             let list = right.value(i);
-            let list = list
-                .as_any()
-                .downcast_ref::<GenericStringArray<OffsetSize>>()
-                .unwrap();
+            let list = list.as_typed::<GenericStringArray<OffsetSize>>()?;
 
             for j in 0..list.len() {
                 if list.is_valid(j) && (left.value(i) == list.value(j)) {
@@ -739,6 +1589,507 @@ where
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Checks whether each slot of `array` is in the set of the values of `set`, where
+/// `key` returns the key of a slot of either array.
+fn in_list_op<'a, A, K, F>(array: &'a A, set: &'a A, key: F) -> BooleanArray
+where
+    A: Array,
+    K: Hash + Eq,
+    F: Fn(&'a A, usize) -> K,
+{
+    let keys = (0..set.len())
+        .filter(|i| set.is_valid(*i))
+        .map(|i| key(set, i))
+        .collect::<HashSet<_>>();
+    let set_has_nulls = set.null_count() > 0;
+
+    let len = array.len();
+    let num_bytes = bit_util::ceil(len, 8);
+    let mut bool_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let bool_slice = bool_buf.data_mut();
+    let null_slice = null_buf.data_mut();
+    let mut null_count = 0;
+
+    for i in 0..len {
+        if array.is_valid(i) && keys.contains(&key(array, i)) {
+            bit_util::set_bit(bool_slice, i);
+            bit_util::set_bit(null_slice, i);
+        } else if array.is_valid(i) && !set_has_nulls {
+            bit_util::set_bit(null_slice, i);
+        } else {
+            null_count += 1;
+        }
+    }
+
+    let null_bit_buffer = if null_count > 0 {
+        Some(null_buf.freeze())
+    } else {
+        None
+    };
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        Some(null_count),
+        null_bit_buffer,
+        0,
+        vec![bool_buf.freeze()],
+        vec![],
+    );
+    BooleanArray::from(Arc::new(data))
+}
+
+/// Perform SQL `array IN (set)` operation: checks whether each value of `array` is one
+/// of the values of `set`, which is typically an array of literals.
+///
+/// Following SQL, the result is null where `array` is null, and where the value is not
+/// found and `set` contains a null. Floating point values are compared by their bit
+/// representation, so `NaN` is found in a set containing `NaN`.
+///
+/// This function errors if `array` and `set` have different data types, or if their
+/// data type is not supported.
+///
+/// Example:
+///
+/// ```
+/// use arrow::array::{BooleanArray, Int32Array};
+/// use arrow::compute::in_list;
+///
+/// let array = Int32Array::from(vec![Some(1), Some(2), None]);
+/// let set = Int32Array::from(vec![Some(1), Some(3)]);
+/// let result = in_list(&array, &set).unwrap();
+/// assert_eq!(BooleanArray::from(vec![Some(true), Some(false), None]), result);
+///
+/// let set = Int32Array::from(vec![Some(1), None]);
+/// let result = in_list(&array, &set).unwrap();
+/// assert_eq!(BooleanArray::from(vec![Some(true), None, None]), result);
+/// ```
+pub fn in_list(array: &Array, set: &Array) -> Result<BooleanArray> {
+    if array.data_type() != set.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "in_list requires arrays of the same type, got {:?} and {:?}",
+            array.data_type(),
+            set.data_type()
+        )));
+    }
+
+    macro_rules! in_list_typed {
+        ($array_type:ty) => {{
+            let array = array.as_typed::<$array_type>()?;
+            let set = set.as_typed::<$array_type>()?;
+            Ok(in_list_op(array, set, |a, i| a.value(i)))
+        }};
+    }
+    // primitive values are compared by their bits, as unsigned integers of the same width
+    macro_rules! in_list_primitive {
+        ($unsigned_type:ty) => {{
+            let array = reinterpret::<$unsigned_type>(array, <$unsigned_type>::DATA_TYPE);
+            let set = reinterpret::<$unsigned_type>(set, <$unsigned_type>::DATA_TYPE);
+            Ok(in_list_op(&array, &set, |a, i| a.value(i)))
+        }};
+    }
+
+    match array.data_type() {
+        DataType::Boolean => in_list_typed!(BooleanArray),
+        DataType::Utf8 => in_list_typed!(StringArray),
+        DataType::LargeUtf8 => in_list_typed!(LargeStringArray),
+        DataType::Binary => in_list_typed!(BinaryArray),
+        DataType::LargeBinary => in_list_typed!(LargeBinaryArray),
+        DataType::FixedSizeBinary(_) => in_list_typed!(FixedSizeBinaryArray),
+        DataType::Int8 | DataType::UInt8 => in_list_primitive!(UInt8Type),
+        DataType::Int16 | DataType::UInt16 => in_list_primitive!(UInt16Type),
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            in_list_primitive!(UInt32Type)
+        }
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => {
+            in_list_primitive!(UInt64Type)
+        }
+        t => Err(ArrowError::ComputeError(format!(
+            "in_list does not support type {:?}",
+            t
+        ))),
+    }
+}
+
+/// Returns the number of nanoseconds in one `unit`
+fn nanoseconds_per_unit(unit: &TimeUnit) -> i128 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Helper function to compare two timestamp arrays of any units and timezones. Arrays of
+/// the same unit are compared with `same_unit_op`, otherwise their values are converted
+/// to nanoseconds, without overflow, and compared with `op`.
+fn compare_timestamps<F, G>(
+    left: &Array,
+    right: &Array,
+    same_unit_op: F,
+    op: G,
+) -> Result<BooleanArray>
+where
+    F: Fn(&Int64Array, &Int64Array) -> Result<BooleanArray>,
+    G: Fn(i128, i128) -> bool,
+{
+    let (left_unit, right_unit) = match (left.data_type(), right.data_type()) {
+        (DataType::Timestamp(left_unit, _), DataType::Timestamp(right_unit, _)) => {
+            (left_unit, right_unit)
+        }
+        (left_type, right_type) => {
+            return Err(ArrowError::ComputeError(format!(
+                "Expected two timestamp arrays, got {:?} and {:?}",
+                left_type, right_type
+            )))
+        }
+    };
+    let left_values = reinterpret::<Int64Type>(left, Int64Type::DATA_TYPE);
+    let right_values = reinterpret::<Int64Type>(right, Int64Type::DATA_TYPE);
+    if left_unit == right_unit {
+        return same_unit_op(&left_values, &right_values);
+    }
+
+    let left_scale = nanoseconds_per_unit(left_unit);
+    let right_scale = nanoseconds_per_unit(right_unit);
+    compare_op!(left_values, right_values, |a: i64, b: i64| {
+        op(a as i128 * left_scale, b as i128 * right_scale)
+    })
+}
+
+/// Perform `left == right` operation on two timestamp arrays, which may have different
+/// units and timezones.
+///
+/// Timestamps are compared as instants: their values are relative to the UNIX epoch in
+/// UTC whatever their timezone, so the timezones are ignored, and values of different
+/// units are compared exactly.
+///
+/// ```
+/// use arrow::array::{BooleanArray, TimestampMillisecondArray, TimestampNanosecondArray};
+/// use arrow::compute::eq_timestamp;
+///
+/// let left = TimestampMillisecondArray::from_opt_vec(
+///     vec![Some(1_000), Some(2_000), None],
+///     Some("+02:00".to_string()),
+/// );
+/// let right = TimestampNanosecondArray::from_opt_vec(
+///     vec![Some(1_000_000_000), Some(2_000_000_001), Some(0)],
+///     None,
+/// );
+/// let result = eq_timestamp(&left, &right).unwrap();
+/// assert_eq!(BooleanArray::from(vec![Some(true), Some(false), None]), result);
+/// ```
+pub fn eq_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, eq::<Int64Type>, |a, b| a == b)
+}
+
+/// Perform `left != right` operation on two timestamp arrays, which may have different
+/// units and timezones. See [`eq_timestamp`] for details.
+pub fn neq_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, neq::<Int64Type>, |a, b| a != b)
+}
+
+/// Perform `left < right` operation on two timestamp arrays, which may have different
+/// units and timezones. See [`eq_timestamp`] for details.
+pub fn lt_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, lt::<Int64Type>, |a, b| a < b)
+}
+
+/// Perform `left <= right` operation on two timestamp arrays, which may have different
+/// units and timezones. See [`eq_timestamp`] for details.
+pub fn lt_eq_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, lt_eq::<Int64Type>, |a, b| a <= b)
+}
+
+/// Perform `left > right` operation on two timestamp arrays, which may have different
+/// units and timezones. See [`eq_timestamp`] for details.
+pub fn gt_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, gt::<Int64Type>, |a, b| a > b)
+}
+
+/// Perform `left >= right` operation on two timestamp arrays, which may have different
+/// units and timezones. See [`eq_timestamp`] for details.
+pub fn gt_eq_timestamp(left: &Array, right: &Array) -> Result<BooleanArray> {
+    compare_timestamps(left, right, gt_eq::<Int64Type>, |a, b| a >= b)
+}
+
+/// Checks that `left` and `right` can be compared row-wise
+fn check_nested_arrays(left: &Array, right: &Array) -> Result<()> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    if left.data_type() != right.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot compare arrays of different types, got {:?} and {:?}",
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns a `BooleanArray` of `values`, null where either `left` or `right` is null
+fn nested_eq_result(left: &Array, right: &Array, values: Buffer) -> Result<BooleanArray> {
+    let len = left.len();
+    let null_bit_buffer = combine_option_bitmap(left.data_ref(), right.data_ref(), len)?;
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![values],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Perform `left == right` operation on two `StructArray`s of the same type.
+///
+/// Two rows are equal if all their fields are equal, where nulls within the rows are
+/// equal to each other, as in [`not_distinct`]. The result is null where either row is
+/// null.
+pub fn eq_struct(left: &StructArray, right: &StructArray) -> Result<BooleanArray> {
+    check_nested_arrays(left, right)?;
+    let len = left.len();
+    let mut values = new_all_set_buffer(bit_util::ceil(len, 8));
+    for (left, right) in left.columns().iter().zip(right.columns()) {
+        let field_eq = not_distinct(left.as_ref(), right.as_ref())?;
+        values = buffer_bin_and(&values, 0, &field_eq.data_ref().buffers()[0], 0, len);
+    }
+    nested_eq_result(left, right, values)
+}
+
+/// Perform `left != right` operation on two `StructArray`s of the same type, the
+/// negation of [`eq_struct`].
+pub fn neq_struct(left: &StructArray, right: &StructArray) -> Result<BooleanArray> {
+    not(&eq_struct(left, right)?)
+}
+
+/// Perform `left == right` operation on two \[Large\]ListArrays of the same type.
+///
+/// Two rows are equal if they have the same length and their elements are equal, where
+/// null elements are equal to each other, as in [`not_distinct`]. The elements of all the
+/// rows are compared at once. The result is null where either row is null.
+pub fn eq_list<OffsetSize: OffsetSizeTrait>(
+    left: &GenericListArray<OffsetSize>,
+    right: &GenericListArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    check_nested_arrays(left, right)?;
+    let len = left.len();
+    let (left_values, right_values) = (left.values(), right.values());
+    if left_values.len() > u32::MAX as usize || right_values.len() > u32::MAX as usize {
+        return Err(ArrowError::ComputeError(
+            "Cannot compare lists of more than u32::MAX elements".to_string(),
+        ));
+    }
+    // the rows of the same length are the only ones whose elements need comparing
+    let comparable = |i: usize| {
+        left.is_valid(i)
+            && right.is_valid(i)
+            && left.value_length(i) == right.value_length(i)
+    };
+
+    let mut left_indices = vec![];
+    let mut right_indices = vec![];
+    for i in 0..len {
+        if comparable(i) {
+            let left_start = left.value_offset(i).to_usize().unwrap();
+            let right_start = right.value_offset(i).to_usize().unwrap();
+            let length = left.value_length(i).to_usize().unwrap();
+            left_indices.extend((left_start..left_start + length).map(|j| j as u32));
+            right_indices.extend((right_start..right_start + length).map(|j| j as u32));
+        }
+    }
+    let left_values = take(&left_values, &UInt32Array::from(left_indices), None)?;
+    let right_values = take(&right_values, &UInt32Array::from(right_indices), None)?;
+    let elements_eq = not_distinct(left_values.as_ref(), right_values.as_ref())?;
+
+    let mut values = BooleanBufferBuilder::new(len);
+    let mut element = 0;
+    for i in 0..len {
+        if comparable(i) {
+            let length = left.value_length(i).to_usize().unwrap();
+            values.append((element..element + length).all(|j| elements_eq.value(j)))?;
+            element += length;
+        } else {
+            values.append(false)?;
+        }
+    }
+    nested_eq_result(left, right, values.finish())
+}
+
+/// Perform `left != right` operation on two \[Large\]ListArrays of the same type, the
+/// negation of [`eq_list`].
+pub fn neq_list<OffsetSize: OffsetSizeTrait>(
+    left: &GenericListArray<OffsetSize>,
+    right: &GenericListArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    not(&eq_list(left, right)?)
+}
+
+/// Perform `left == right` operation on two arrays of the same type, or timestamp arrays
+/// of any units and timezones, dispatching to the typed kernels. The values of the result
+/// are unspecified where either side is null.
+fn eq_dyn(left: &Array, right: &Array) -> Result<BooleanArray> {
+    if let (DataType::Timestamp(_, _), DataType::Timestamp(_, _)) =
+        (left.data_type(), right.data_type())
+    {
+        return eq_timestamp(left, right);
+    }
+    if left.data_type() != right.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot compare arrays of different types, got {:?} and {:?}",
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+
+    macro_rules! eq_typed {
+        ($array_type:ty, $op:expr) => {{
+            let left = left.as_typed::<$array_type>()?;
+            let right = right.as_typed::<$array_type>()?;
+            $op(left, right)
+        }};
+    }
+    macro_rules! eq_primitive {
+        ($data_type:ty) => {
+            eq_typed!(PrimitiveArray<$data_type>, eq::<$data_type>)
+        };
+    }
+    // temporal values are compared as the integers backing them
+    macro_rules! eq_reinterpreted {
+        ($data_type:ty) => {{
+            let left = reinterpret::<$data_type>(left, <$data_type>::DATA_TYPE);
+            let right = reinterpret::<$data_type>(right, <$data_type>::DATA_TYPE);
+            eq(&left, &right)
+        }};
+    }
+
+    match left.data_type() {
+        DataType::Boolean => eq_typed!(BooleanArray, eq_bool),
+        DataType::Int8 => eq_primitive!(Int8Type),
+        DataType::Int16 => eq_primitive!(Int16Type),
+        DataType::Int32 => eq_primitive!(Int32Type),
+        DataType::Int64 => eq_primitive!(Int64Type),
+        DataType::UInt8 => eq_primitive!(UInt8Type),
+        DataType::UInt16 => eq_primitive!(UInt16Type),
+        DataType::UInt32 => eq_primitive!(UInt32Type),
+        DataType::UInt64 => eq_primitive!(UInt64Type),
+        DataType::Float32 => eq_primitive!(Float32Type),
+        DataType::Float64 => eq_primitive!(Float64Type),
+        DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => eq_reinterpreted!(Int32Type),
+        DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => eq_reinterpreted!(Int64Type),
+        DataType::Utf8 => eq_typed!(StringArray, eq_utf8),
+        DataType::LargeUtf8 => eq_typed!(LargeStringArray, eq_utf8),
+        DataType::Binary => eq_typed!(BinaryArray, eq_binary),
+        DataType::LargeBinary => eq_typed!(LargeBinaryArray, eq_binary),
+        DataType::FixedSizeBinary(_) => {
+            eq_typed!(FixedSizeBinaryArray, eq_fixed_size_binary)
+        }
+        DataType::Struct(_) => eq_typed!(StructArray, eq_struct),
+        DataType::List(_) => eq_typed!(ListArray, eq_list),
+        DataType::LargeList(_) => eq_typed!(LargeListArray, eq_list),
+        t => Err(ArrowError::ComputeError(format!(
+            "Null-safe comparison not supported for type {:?}",
+            t
+        ))),
+    }
+}
+
+/// Returns the bits of `left IS NOT DISTINCT FROM right`, from the result `eq` of
+/// `left == right`: both sides are null, or neither side is null and `eq` is true.
+fn not_distinct_buffer(left: &Array, right: &Array, eq: &BooleanArray) -> Buffer {
+    let len = left.len();
+    let all_set = new_all_set_buffer(bit_util::ceil(len, 8));
+    let validity = |array: &Array| match array.data_ref().null_buffer() {
+        Some(buffer) => (buffer.clone(), array.offset()),
+        None => (all_set.clone(), 0),
+    };
+    let (left_valid, left_offset) = validity(left);
+    let (right_valid, right_offset) = validity(right);
+
+    let both_valid =
+        buffer_bin_and(&left_valid, left_offset, &right_valid, right_offset, len);
+    let equal = buffer_bin_and(
+        &both_valid,
+        0,
+        &eq.data_ref().buffers()[0],
+        eq.offset(),
+        len,
+    );
+    let either_valid =
+        buffer_bin_or(&left_valid, left_offset, &right_valid, right_offset, len);
+    let both_null = buffer_unary_not(&either_valid, 0, len);
+    buffer_bin_or(&equal, 0, &both_null, 0, len)
+}
+
+/// Perform `left IS NOT DISTINCT FROM right` operation on two arrays of the same type,
+/// SQL's null-safe equality: two nulls are not distinct, a null and a value are
+/// distinct, and two values are not distinct if they are equal. The result has no
+/// nulls.
+///
+/// ```
+/// use arrow::array::{BooleanArray, Int32Array};
+/// use arrow::compute::not_distinct;
+///
+/// let left = Int32Array::from(vec![Some(1), Some(2), None, None]);
+/// let right = Int32Array::from(vec![Some(1), None, Some(3), None]);
+/// let result = not_distinct(&left, &right).unwrap();
+/// assert_eq!(BooleanArray::from(vec![true, false, false, true]), result);
+/// ```
+pub fn not_distinct(left: &Array, right: &Array) -> Result<BooleanArray> {
+    let len = left.len();
+    if len != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform comparison operation on arrays of different length"
+                .to_string(),
+        ));
+    }
+    let values = match (left.data_type(), right.data_type()) {
+        (DataType::Null, DataType::Null) => new_all_set_buffer(bit_util::ceil(len, 8)),
+        _ => {
+            let eq = eq_dyn(left, right)?;
+            not_distinct_buffer(left, right, &eq)
+        }
+    };
+    let data =
+        ArrayData::new(DataType::Boolean, len, None, None, 0, vec![values], vec![]);
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
+/// Perform `left IS DISTINCT FROM right` operation on two arrays of the same type, the
+/// negation of [`not_distinct`]. The result has no nulls.
+pub fn distinct(left: &Array, right: &Array) -> Result<BooleanArray> {
+    let not_distinct = not_distinct(left, right)?;
+    let len = not_distinct.len();
+    let values = buffer_unary_not(&not_distinct.data_ref().buffers()[0], 0, len);
+    let data =
+        ArrayData::new(DataType::Boolean, len, None, None, 0, vec![values], vec![]);
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
 // create a buffer and fill it with valid bits
 #[inline]
 fn new_all_set_buffer(len: usize) -> Buffer {
@@ -751,7 +2102,8 @@ fn new_all_set_buffer(len: usize) -> Buffer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::datatypes::{Int8Type, ToByteSlice};
+    use crate::compute::util::tests::dictionary;
+    use crate::datatypes::ToByteSlice;
     use crate::{array::Int32Array, datatypes::Field};
 
     #[test]
@@ -856,6 +2208,23 @@ mod tests {
         assert_eq!(false, c.value(2));
     }
 
+    #[test]
+    fn test_primitive_array_scalar_sliced() {
+        let a = Int32Array::from(vec![Some(1), None, Some(3), Some(2), None]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let c = gt_eq_scalar(a, 2).unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![None, Some(true), Some(true), None]),
+            c
+        );
+        let c = neq_scalar(a, 3).unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![None, Some(false), Some(true), None]),
+            c
+        );
+    }
+
     #[test]
     fn test_primitive_array_lt_eq() {
         let a = Int32Array::from(vec![8, 8, 8, 8, 8]);
@@ -1123,6 +2492,23 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_utf8_scalar_sliced_and_large() {
+        let a =
+            StringArray::from(vec![Some("arrow"), None, Some("parquet"), Some("avro")]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        let c = lt_utf8_scalar(a, "b").unwrap();
+        assert_eq!(BooleanArray::from(vec![None, Some(false), Some(true)]), c);
+
+        let a = LargeStringArray::from(vec![Some("arrow"), None, Some("parquet")]);
+        let c = eq_utf8_scalar(&a, "parquet").unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(false), None, Some(true)]), c);
+        let b = LargeStringArray::from(vec![Some("b"), Some("b"), Some("b")]);
+        let c = gt_utf8(&a, &b).unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(false), None, Some(true)]), c);
+    }
+
     macro_rules! test_utf8_scalar {
         ($test_name:ident, $left:expr, $right:expr, $op:expr, $expected:expr) => {
             #[test]
@@ -1193,6 +2579,57 @@ mod tests {
         vec![false, true, false, false]
     );
 
+    test_utf8_scalar!(
+        test_utf8_array_starts_with_scalar,
+        vec!["arrow", "parrow", "arrows", "arr", ""],
+        "arrow",
+        starts_with_utf8_scalar,
+        vec![true, false, true, false, false]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_ends_with_scalar,
+        vec!["arrow", "parrow", "arrows", "arr", ""],
+        "arrow",
+        ends_with_utf8_scalar,
+        vec![true, true, false, false, false]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_contains_scalar,
+        vec!["arrow", "parrows", "arr", "", "ow ar"],
+        "rro",
+        contains_utf8_scalar,
+        vec![true, true, false, false, false]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_contains_scalar_empty,
+        vec!["arrow", ""],
+        "",
+        contains_utf8_scalar,
+        vec![true, true]
+    );
+
+    #[test]
+    fn test_utf8_scalar_predicates_sliced_with_nulls() {
+        let a =
+            StringArray::from(vec![Some("arrow"), None, Some("rows"), Some("parrow")]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+
+        let expected = BooleanArray::from(vec![None, Some(false), Some(true)]);
+        assert_eq!(expected, starts_with_utf8_scalar(a, "par").unwrap());
+        let expected = BooleanArray::from(vec![None, Some(true), Some(false)]);
+        assert_eq!(expected, ends_with_utf8_scalar(a, "ws").unwrap());
+        let expected = BooleanArray::from(vec![None, Some(true), Some(true)]);
+        assert_eq!(expected, contains_utf8_scalar(a, "row").unwrap());
+
+        let a = LargeStringArray::from(vec![Some("arrow"), None, Some("flight")]);
+        let expected = BooleanArray::from(vec![Some(false), None, Some(true)]);
+        assert_eq!(expected, contains_utf8_scalar(&a, "igh").unwrap());
+    }
+
     test_utf8!(
         test_utf8_array_nlike,
         vec!["arrow", "arrow", "arrow", "arrow", "arrow", "arrows", "arrow"],
@@ -1255,6 +2692,88 @@ mod tests {
         vec![true, false, true, true]
     );
 
+    test_utf8_scalar!(
+        test_utf8_array_like_scalar_contains,
+        vec!["arrow", "parrow", "arrows", "arr"],
+        "%rrow%",
+        like_utf8_scalar,
+        vec![true, true, true, false]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_like_scalar_escaped,
+        vec!["a.c", "abc", "a+c", "a.cd"],
+        "a.c%",
+        like_utf8_scalar,
+        vec![true, false, false, true]
+    );
+
+    test_utf8!(
+        test_utf8_array_ilike,
+        vec!["arrow", "ARROW", "Arrow", "parquet", "a(row"],
+        vec!["arrow", "ar%", "%RO%", "PAR_UET", "A(R%"],
+        ilike_utf8,
+        vec![true, true, true, true, true]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_ilike_scalar,
+        vec!["arrow", "ARROW", "parrow", "arr"],
+        "%ArRoW",
+        ilike_utf8_scalar,
+        vec![true, true, true, false]
+    );
+
+    test_utf8!(
+        test_utf8_array_regexp_is_match,
+        vec!["arrow", "arrow", "arrow", "parquet", "flight"],
+        vec!["^ar", "^AR", "(?i)^AR", "qu.t$", "[0-9]"],
+        regexp_is_match_utf8,
+        vec![true, false, true, true, false]
+    );
+
+    test_utf8_scalar!(
+        test_utf8_array_regexp_is_match_scalar,
+        vec!["arrow", "parquet", "datafusion", "flight"],
+        "^(ar|fl)",
+        regexp_is_match_utf8_scalar,
+        vec![true, false, false, true]
+    );
+
+    #[test]
+    fn test_utf8_regexp_is_match_nulls_and_invalid() {
+        let a = LargeStringArray::from(vec![Some("arrow"), None, Some("avro")]);
+        let b = LargeStringArray::from(vec![None, Some("a"), Some("v")]);
+        let c = regexp_is_match_utf8(&a, &b).unwrap();
+        assert_eq!(BooleanArray::from(vec![None, None, Some(true)]), c);
+
+        let a = StringArray::from(vec![Some("x"), None, Some("arrow"), Some("ab")]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        let c = regexp_is_match_utf8_scalar(a, "ro").unwrap();
+        assert_eq!(BooleanArray::from(vec![None, Some(true), Some(false)]), c);
+
+        assert!(regexp_is_match_utf8_scalar(a, "(").is_err());
+    }
+
+    #[test]
+    fn test_utf8_like_sliced_and_large() {
+        let a = StringArray::from(vec![Some("arrow"), None, Some("Parquet"), Some("x")]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        let c = like_utf8_scalar(a, "%a%").unwrap();
+        assert_eq!(BooleanArray::from(vec![None, Some(true), Some(false)]), c);
+        let c = ilike_utf8_scalar(a, "p%").unwrap();
+        assert_eq!(BooleanArray::from(vec![None, Some(true), Some(false)]), c);
+
+        let a = LargeStringArray::from(vec![Some("arrow"), None, Some("line\nbreak")]);
+        let b = LargeStringArray::from(vec![Some("_rr_w"), Some("%"), Some("line%")]);
+        let c = like_utf8(&a, &b).unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(true), None, Some(true)]), c);
+        let c = nlike_utf8_scalar(&a, "a%").unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(false), None, Some(true)]), c);
+    }
+
     test_utf8!(
         test_utf8_array_neq,
         vec!["arrow", "arrow", "arrow", "arrow"],
@@ -1329,4 +2848,714 @@ mod tests {
         gt_eq_utf8_scalar,
         vec![false, false, true, true]
     );
+
+    #[test]
+    fn test_in_list_primitive() {
+        let array = Int64Array::from(vec![Some(1), Some(2), None, Some(4)]);
+        let set = Int64Array::from(vec![4, 1, 1]);
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]),
+            c
+        );
+
+        // a null in the set makes values that are not found null
+        let set = Int64Array::from(vec![Some(4), None]);
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![None, None, None, Some(true)]), c);
+
+        let array = Float32Array::from(vec![f32::NAN, 1.5, 2.0]);
+        let array = array.slice(1, 2);
+        let set = Float32Array::from(vec![f32::NAN, 2.0]);
+        let c = in_list(array.as_ref(), &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![false, true]), c);
+
+        let array = Date32Array::from(vec![1, 2]);
+        let set = Date32Array::from(vec![2]);
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![false, true]), c);
+    }
+
+    #[test]
+    fn test_in_list_utf8_and_boolean() {
+        let array = StringArray::from(vec![Some("arrow"), None, Some("parquet")]);
+        let set = StringArray::from(vec!["parquet", "flight"]);
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(false), None, Some(true)]), c);
+
+        let array = BooleanArray::from(vec![true, false]);
+        let set = BooleanArray::from(vec![Some(true), None]);
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(true), None]), c);
+
+        // the set may be empty
+        let set = BooleanArray::from(Vec::<bool>::new());
+        let c = in_list(&array, &set).unwrap();
+        assert_eq!(BooleanArray::from(vec![false, false]), c);
+    }
+
+    #[test]
+    fn test_in_list_invalid_types() {
+        let array = Int32Array::from(vec![1]);
+        assert!(in_list(&array, &Int64Array::from(vec![1])).is_err());
+        let array = NullArray::new(1);
+        assert!(in_list(&array, &array).is_err());
+    }
+
+    #[test]
+    fn test_between() {
+        let a = Int32Array::from(vec![Some(1), Some(5), None, Some(10), Some(11)]);
+        let c = between_scalar(&a, 5, 10).unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(true),
+                Some(false)
+            ]),
+            c
+        );
+
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        let low = Int32Array::from(vec![Some(5), Some(0), Some(0), None]);
+        let high = Int32Array::from(vec![6, 0, 9, 20]);
+        let c = between(a, &low, &high).unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(false), None]),
+            c
+        );
+
+        assert!(between(&low, &high, &Int32Array::from(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_between_utf8() {
+        let a = StringArray::from(vec![Some("arrow"), Some("flight"), None]);
+        let c = between_utf8_scalar(&a, "b", "g").unwrap();
+        assert_eq!(BooleanArray::from(vec![Some(false), Some(true), None]), c);
+
+        let a = LargeStringArray::from(vec!["arrow", "flight"]);
+        let low = LargeStringArray::from(vec!["a", "g"]);
+        let high = LargeStringArray::from(vec!["arrow", "z"]);
+        let c = between_utf8(&a, &low, &high).unwrap();
+        assert_eq!(BooleanArray::from(vec![true, false]), c);
+    }
+
+    #[test]
+    fn test_binary_comparisons() {
+        let a = BinaryArray::from(vec![
+            Some(b"arrow".as_ref()),
+            Some(b"\x00\xff".as_ref()),
+            None,
+            Some(b"".as_ref()),
+        ]);
+        let b = BinaryArray::from(vec![
+            Some(b"arrows".as_ref()),
+            Some(b"\x00\xfe".as_ref()),
+            Some(b"a".as_ref()),
+            Some(b"".as_ref()),
+        ]);
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]),
+            lt_binary(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(true)]),
+            gt_eq_binary(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), Some(false), None, Some(true)]),
+            eq_binary(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]),
+            gt_binary_scalar(&a, b"a").unwrap()
+        );
+
+        let a = LargeBinaryArray::from(vec![b"ab".as_ref(), b"b".as_ref()]);
+        let a = a.slice(1, 1);
+        let a = a.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![true]),
+            neq_binary_scalar(a, b"ab").unwrap()
+        );
+        assert!(
+            lt_eq_binary(a, &LargeBinaryArray::from(vec![b"a".as_ref(); 2])).is_err()
+        );
+    }
+
+    #[test]
+    fn test_fixed_size_binary_comparisons() {
+        let a = FixedSizeBinaryArray::from(vec![
+            Some(vec![0, 1]),
+            None,
+            Some(vec![2, 0]),
+            Some(vec![1, 1]),
+        ]);
+        let b = FixedSizeBinaryArray::from(vec![
+            Some(vec![0, 2]),
+            Some(vec![0, 0]),
+            Some(vec![1, 255]),
+            Some(vec![1, 1]),
+        ]);
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]),
+            lt_eq_fixed_size_binary(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), None, Some(true), Some(false)]),
+            gt_fixed_size_binary(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(true)]),
+            eq_fixed_size_binary_scalar(&a, &[1, 1]).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(false)]),
+            lt_fixed_size_binary_scalar(&a, &[1, 0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bool() {
+        let a = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+        ]);
+        let b = BooleanArray::from(vec![
+            Some(false),
+            Some(true),
+            Some(false),
+            Some(true),
+            Some(true),
+        ]);
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(true),
+                None
+            ]),
+            eq_bool(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                None
+            ]),
+            neq_bool(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(false),
+                None
+            ]),
+            lt_bool(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(true),
+                None
+            ]),
+            lt_eq_bool(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(false),
+                None
+            ]),
+            gt_bool(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                None
+            ]),
+            gt_eq_bool(&a, &b).unwrap()
+        );
+
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(false),
+                Some(true),
+                Some(true),
+                None
+            ]),
+            eq_bool_scalar(&a, true).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                None
+            ]),
+            lt_bool_scalar(&a, true).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(false),
+                None
+            ]),
+            gt_bool_scalar(&a, true).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(true),
+                None
+            ]),
+            gt_eq_bool_scalar(&a, false).unwrap()
+        );
+
+        assert!(eq_bool(&a, &BooleanArray::from(vec![true])).is_err());
+    }
+
+    #[test]
+    fn test_bool_sliced() {
+        // more than 64 values with unaligned offsets, to compare whole words and
+        // remainders
+        let a: BooleanArray = (0..200)
+            .map(|i| if i % 7 == 0 { None } else { Some(i % 3 == 0) })
+            .collect();
+        let b: BooleanArray = (0..200).map(|i| Some(i % 2 == 0)).collect();
+        let a = a.slice(3, 150);
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b = b.slice(5, 150);
+        let b = b.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let expected: BooleanArray = (0..150)
+            .map(|i| {
+                if a.is_null(i) {
+                    None
+                } else {
+                    Some(a.value(i) <= b.value(i))
+                }
+            })
+            .collect();
+        assert_eq!(expected, lt_eq_bool(a, b).unwrap());
+
+        let expected: BooleanArray = (0..150)
+            .map(|i| {
+                if a.is_null(i) {
+                    None
+                } else {
+                    Some(!a.value(i))
+                }
+            })
+            .collect();
+        assert_eq!(expected, neq_bool_scalar(a, true).unwrap());
+    }
+
+    #[test]
+    fn test_timestamp_across_units() {
+        let a = TimestampSecondArray::from_opt_vec(
+            vec![Some(1), Some(2), None, Some(-1), Some(i64::MAX)],
+            Some("UTC".to_string()),
+        );
+        let b = TimestampNanosecondArray::from_opt_vec(
+            vec![
+                Some(1_000_000_000),
+                Some(2_000_000_001),
+                Some(0),
+                Some(-1_000_000_001),
+                Some(i64::MAX),
+            ],
+            None,
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(false),
+                Some(false)
+            ]),
+            eq_timestamp(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(false)
+            ]),
+            lt_timestamp(&a, &b).unwrap()
+        );
+        // does not overflow when converting to nanoseconds
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(true)
+            ]),
+            gt_eq_timestamp(&a, &b).unwrap()
+        );
+
+        // same unit, different timezones
+        let c = TimestampNanosecondArray::from_opt_vec(
+            vec![Some(1_000_000_000), None, Some(0), Some(5), Some(6)],
+            Some("+01:00".to_string()),
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                None,
+                Some(false),
+                Some(true),
+                Some(true)
+            ]),
+            neq_timestamp(&b, &c).unwrap()
+        );
+
+        // sliced
+        let a = a.slice(1, 2);
+        let b = b.slice(1, 2);
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None]),
+            lt_eq_timestamp(a.as_ref(), b.as_ref()).unwrap()
+        );
+
+        assert!(eq_timestamp(&c, &Int64Array::from(vec![1, 2, 3, 4, 5])).is_err());
+        assert!(eq_timestamp(a.as_ref(), &c).is_err());
+
+        // null-safe comparisons also normalize units
+        let a = TimestampMillisecondArray::from_opt_vec(vec![Some(1), None], None);
+        let b = TimestampMicrosecondArray::from_opt_vec(vec![Some(1_000), None], None);
+        assert_eq!(
+            BooleanArray::from(vec![true, true]),
+            not_distinct(&a, &b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_distinct() {
+        let a = Int32Array::from(vec![Some(1), Some(2), None, None, Some(5)]);
+        let b = Int32Array::from(vec![Some(1), None, Some(3), None, Some(4)]);
+        assert_eq!(
+            BooleanArray::from(vec![true, false, false, true, false]),
+            not_distinct(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![false, true, true, false, true]),
+            distinct(&a, &b).unwrap()
+        );
+
+        // sliced, and without null buffer on one side
+        let a = a.slice(1, 3);
+        let b = Int32Array::from(vec![0, 2, 3, 7]);
+        let b = b.slice(1, 3);
+        assert_eq!(
+            BooleanArray::from(vec![true, false, false]),
+            not_distinct(a.as_ref(), b.as_ref()).unwrap()
+        );
+
+        let a = StringArray::from(vec![Some("a"), None, Some("c"), None]);
+        let b = StringArray::from(vec![Some("a"), Some("b"), Some("d"), None]);
+        assert_eq!(
+            BooleanArray::from(vec![false, true, true, false]),
+            distinct(&a, &b).unwrap()
+        );
+
+        let a = BooleanArray::from(vec![Some(true), Some(false), None, Some(false)]);
+        let b = BooleanArray::from(vec![Some(true), Some(true), None, Some(false)]);
+        assert_eq!(
+            BooleanArray::from(vec![true, false, true, true]),
+            not_distinct(&a, &b).unwrap()
+        );
+
+        let a = Date32Array::from(vec![Some(1), None]);
+        let b = Date32Array::from(vec![Some(1), Some(2)]);
+        assert_eq!(
+            BooleanArray::from(vec![true, false]),
+            not_distinct(&a, &b).unwrap()
+        );
+
+        let a = NullArray::new(2);
+        assert_eq!(
+            BooleanArray::from(vec![false, false]),
+            distinct(&a, &a).unwrap()
+        );
+
+        assert!(not_distinct(&a, &b).is_err());
+        assert!(not_distinct(&b, &Date32Array::from(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_eq_struct() {
+        let int_field = Field::new("i", DataType::Int32, true);
+        let str_field = Field::new("s", DataType::Utf8, true);
+        let a = StructArray::from((
+            vec![
+                (
+                    int_field.clone(),
+                    Arc::new(Int32Array::from(vec![
+                        Some(1),
+                        Some(2),
+                        None,
+                        Some(4),
+                        Some(5),
+                    ])) as ArrayRef,
+                ),
+                (
+                    str_field.clone(),
+                    Arc::new(StringArray::from(vec![
+                        Some("a"),
+                        Some("b"),
+                        Some("c"),
+                        None,
+                        Some("e"),
+                    ])),
+                ),
+            ],
+            Buffer::from([0b01111]),
+            1,
+        ));
+        let b = StructArray::from(vec![
+            (
+                int_field,
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(3),
+                    None,
+                    Some(4),
+                    Some(5),
+                ])) as ArrayRef,
+            ),
+            (
+                str_field,
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    Some("c"),
+                    None,
+                    Some("x"),
+                ])),
+            ),
+        ]);
+
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                Some(true),
+                Some(true),
+                None
+            ]),
+            eq_struct(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                Some(false),
+                Some(false),
+                None
+            ]),
+            neq_struct(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![true, false, true, true, false]),
+            not_distinct(&a, &b).unwrap()
+        );
+
+        let a_sliced = a.slice(1, 3);
+        let a_sliced = a_sliced.as_any().downcast_ref::<StructArray>().unwrap();
+        let b_sliced = b.slice(1, 3);
+        let b_sliced = b_sliced.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![false, true, true]),
+            eq_struct(a_sliced, b_sliced).unwrap()
+        );
+
+        let c = StructArray::from(vec![(
+            Field::new("i", DataType::Int32, true),
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5])) as ArrayRef,
+        )]);
+        assert!(eq_struct(&a, &c).is_err());
+        assert!(eq_struct(a_sliced, &b).is_err());
+    }
+
+    #[test]
+    fn test_eq_list() {
+        fn build(rows: Vec<Option<Vec<Option<i32>>>>) -> ListArray {
+            let mut builder = ListBuilder::new(Int32Builder::new(0));
+            for row in rows {
+                if let Some(values) = &row {
+                    for value in values {
+                        builder.values().append_option(*value).unwrap();
+                    }
+                }
+                builder.append(row.is_some()).unwrap();
+            }
+            builder.finish()
+        }
+
+        let a = build(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            None,
+            Some(vec![None, Some(4)]),
+            Some(vec![]),
+            Some(vec![Some(5), Some(6)]),
+        ]);
+        let b = build(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3), Some(4)]),
+            Some(vec![Some(7)]),
+            Some(vec![None, Some(4)]),
+            Some(vec![]),
+            Some(vec![Some(5), Some(7)]),
+        ]);
+
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                Some(true),
+                Some(true),
+                Some(false)
+            ]),
+            eq_list(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                Some(false),
+                Some(true)
+            ]),
+            neq_list(&a, &b).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![true, false, false, true, true, false]),
+            not_distinct(&a, &b).unwrap()
+        );
+
+        // sliced at different offsets
+        let a_sliced = a.slice(1, 2);
+        let a_sliced = a_sliced.as_any().downcast_ref::<ListArray>().unwrap();
+        let b_sliced = b.slice(0, 2);
+        let b_sliced = b_sliced.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), None]),
+            eq_list(a_sliced, b_sliced).unwrap()
+        );
+        let b_sliced = b.slice(3, 2);
+        let b_sliced = b_sliced.as_any().downcast_ref::<ListArray>().unwrap();
+        let a_sliced = a.slice(3, 2);
+        let a_sliced = a_sliced.as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(
+            BooleanArray::from(vec![true, true]),
+            eq_list(a_sliced, b_sliced).unwrap()
+        );
+
+        let mut builder = LargeListBuilder::new(StringBuilder::new(0));
+        builder.values().append_value("a").unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_value("b").unwrap();
+        builder.append(true).unwrap();
+        let a = builder.finish();
+        let mut builder = LargeListBuilder::new(StringBuilder::new(0));
+        builder.values().append_value("a").unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_value("c").unwrap();
+        builder.append(true).unwrap();
+        let b = builder.finish();
+        assert_eq!(
+            BooleanArray::from(vec![true, false]),
+            eq_list(&a, &b).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dict_utf8_scalar() {
+        let values: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("abc"), None, Some("def")]));
+        let keys = Int8Array::from(vec![Some(0), None, Some(2), Some(1), Some(0)]);
+        let a = dictionary(keys, values);
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(false), None, Some(true)]),
+            eq_dict_utf8_scalar(&a, "abc").unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), None, Some(true), None, Some(false)]),
+            gt_dict_utf8_scalar(&a, "abc").unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(true), None, Some(true)]),
+            lt_eq_dict_utf8_scalar(&a, "def").unwrap()
+        );
+
+        let values: ArrayRef = Arc::new(LargeStringArray::from(vec!["abc", "def"]));
+        let keys = UInt16Array::from(vec![Some(1), None, Some(0)]);
+        let a = dictionary(keys, values);
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), None, Some(false)]),
+            neq_dict_utf8_scalar(&a, "abc").unwrap()
+        );
+        let a = DictionaryArray::<UInt16Type>::from(a.slice(1, 2).data());
+        assert_eq!(
+            BooleanArray::from(vec![None, Some(true)]),
+            lt_dict_utf8_scalar(&a, "def").unwrap()
+        );
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let keys = Int8Array::from(vec![Some(1), None]);
+        assert!(eq_dict_utf8_scalar(&dictionary(keys, values), "1").is_err());
+    }
+
+    #[test]
+    fn test_dict_scalar() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(10), None, Some(3)]));
+        let keys = Int32Array::from(vec![Some(2), Some(0), None, Some(1), Some(2)]);
+        let a = dictionary(keys, values);
+        assert_eq!(
+            BooleanArray::from(vec![Some(false), Some(true), None, None, Some(false)]),
+            eq_dict_scalar::<_, Int32Type>(&a, 10).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(false), None, None, Some(true)]),
+            lt_dict_scalar::<_, Int32Type>(&a, 10).unwrap()
+        );
+        assert_eq!(
+            BooleanArray::from(vec![Some(true), Some(true), None, None, Some(true)]),
+            gt_eq_dict_scalar::<_, Int32Type>(&a, 3).unwrap()
+        );
+        assert!(eq_dict_scalar::<_, Int64Type>(&a, 10).is_err());
+    }
 }
@@ -30,10 +30,13 @@
 //! assert_eq!(arr.len(), 3);
 //! ```
 
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 use crate::array::*;
+use crate::datatypes::SchemaRef;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 
 /// Concatenate multiple [Array] of the same type into a single [ArrayRef].
 pub fn concat(arrays: &[&Array]) -> Result<ArrayRef> {
@@ -70,6 +73,191 @@ pub fn concat(arrays: &[&Array]) -> Result<ArrayRef> {
     Ok(make_array(Arc::new(mutable.freeze())))
 }
 
+/// Callback receiving the rows buffered by a [BatchCoalescer] when its memory limit is
+/// exceeded
+pub type SpillFn = Box<dyn FnMut(RecordBatch) -> Result<()> + Send>;
+
+/// Accumulates incoming [RecordBatch]es of a schema and emits batches of a target number
+/// of rows, e.g. to coalesce the small batches of a filter or to re-chunk a stream.
+///
+/// Example:
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::BatchCoalescer;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::record_batch::RecordBatch;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let batch = |values: Vec<i32>| {
+///     let column: ArrayRef = Arc::new(Int32Array::from(values));
+///     RecordBatch::try_new(schema.clone(), vec![column]).unwrap()
+/// };
+///
+/// let mut coalescer = BatchCoalescer::new(schema.clone(), 4).unwrap();
+/// assert!(coalescer.push(batch(vec![1, 2, 3])).unwrap().is_empty());
+/// let emitted = coalescer.push(batch(vec![4, 5, 6])).unwrap();
+/// assert_eq!(emitted.len(), 1);
+/// assert_eq!(emitted[0].num_rows(), 4);
+/// assert_eq!(coalescer.finish().unwrap().unwrap().num_rows(), 2);
+/// ```
+pub struct BatchCoalescer {
+    schema: SchemaRef,
+    target_rows: usize,
+    /// The memory limit of the buffered batches, in bytes, and the callback to spill them
+    /// to
+    spill: Option<(usize, SpillFn)>,
+    buffered: VecDeque<RecordBatch>,
+    buffered_rows: usize,
+}
+
+impl BatchCoalescer {
+    /// Creates a new `BatchCoalescer` emitting batches of `target_rows` rows of `schema`.
+    ///
+    /// Returns an error if `target_rows` is 0.
+    pub fn new(schema: SchemaRef, target_rows: usize) -> Result<Self> {
+        if target_rows == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "BatchCoalescer requires a positive target_rows".to_string(),
+            ));
+        }
+        Ok(Self {
+            schema,
+            target_rows,
+            spill: None,
+            buffered: VecDeque::new(),
+            buffered_rows: 0,
+        })
+    }
+
+    /// Sets a limit of `memory_limit` bytes to the memory held by the buffered batches.
+    ///
+    /// When a push leaves more than `memory_limit` bytes buffered, the buffered rows are
+    /// concatenated into a single batch and passed to `spill` instead of being emitted,
+    /// and the coalescer continues with an empty buffer. The memory of the buffered
+    /// batches includes the buffers they share with the rows already emitted.
+    pub fn with_spill(mut self, memory_limit: usize, spill: SpillFn) -> Self {
+        self.spill = Some((memory_limit, spill));
+        self
+    }
+
+    /// Returns the schema of the batches of this coalescer
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of rows buffered, which is always less than the target
+    pub fn buffered_rows(&self) -> usize {
+        self.buffered_rows
+    }
+
+    /// Returns the number of bytes of memory held by the buffered batches
+    pub fn buffered_memory_size(&self) -> usize {
+        self.buffered
+            .iter()
+            .flat_map(|batch| batch.columns())
+            .map(|column| column.get_array_memory_size())
+            .sum()
+    }
+
+    /// Buffers `batch` and returns the batches of the target number of rows that are
+    /// complete.
+    ///
+    /// Returns an error if the schema of `batch` differs from the schema of this
+    /// coalescer, or if the spill callback fails.
+    pub fn push(&mut self, batch: RecordBatch) -> Result<Vec<RecordBatch>> {
+        if batch.schema() != self.schema {
+            return Err(ArrowError::InvalidArgumentError(
+                "BatchCoalescer requires batches of the same schema".to_string(),
+            ));
+        }
+        if batch.num_rows() == 0 {
+            return Ok(vec![]);
+        }
+        self.buffered_rows += batch.num_rows();
+        self.buffered.push_back(batch);
+
+        let mut emitted = vec![];
+        while self.buffered_rows >= self.target_rows {
+            emitted.push(self.take_rows(self.target_rows)?);
+        }
+
+        if let Some((memory_limit, _)) = &self.spill {
+            if self.buffered_rows > 0 && self.buffered_memory_size() > *memory_limit {
+                let batch = self.take_rows(self.buffered_rows)?;
+                let (_, spill) = self.spill.as_mut().unwrap();
+                spill(batch)?;
+            }
+        }
+        Ok(emitted)
+    }
+
+    /// Returns a batch of the rows still buffered, if any
+    pub fn finish(mut self) -> Result<Option<RecordBatch>> {
+        if self.buffered_rows == 0 {
+            return Ok(None);
+        }
+        self.take_rows(self.buffered_rows).map(Some)
+    }
+
+    /// Removes the first `num_rows` buffered rows and concatenates them into a batch
+    fn take_rows(&mut self, num_rows: usize) -> Result<RecordBatch> {
+        let mut pieces = vec![];
+        let mut remaining = num_rows;
+        while remaining > 0 {
+            let batch = self.buffered.pop_front().unwrap();
+            if batch.num_rows() > remaining {
+                self.buffered.push_front(slice_batch(
+                    &batch,
+                    remaining,
+                    batch.num_rows() - remaining,
+                )?);
+                pieces.push(slice_batch(&batch, 0, remaining)?);
+                remaining = 0;
+            } else {
+                remaining -= batch.num_rows();
+                pieces.push(batch);
+            }
+        }
+        self.buffered_rows -= num_rows;
+
+        if pieces.len() == 1 {
+            return Ok(pieces.pop().unwrap());
+        }
+        let columns = (0..self.schema.fields().len())
+            .map(|i| {
+                let arrays = pieces
+                    .iter()
+                    .map(|batch| batch.column(i).as_ref())
+                    .collect::<Vec<_>>();
+                concat(&arrays)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+}
+
+impl std::fmt::Debug for BatchCoalescer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BatchCoalescer")
+            .field("schema", &self.schema)
+            .field("target_rows", &self.target_rows)
+            .field("memory_limit", &self.spill.as_ref().map(|(limit, _)| limit))
+            .field("buffered_rows", &self.buffered_rows)
+            .finish()
+    }
+}
+
+fn slice_batch(batch: &RecordBatch, offset: usize, len: usize) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| column.slice(offset, len))
+        .collect();
+    RecordBatch::try_new(batch.schema(), columns)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +306,18 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_concat_edge_case_arrays() -> Result<()> {
+        for array in crate::util::edge_cases::edge_case_arrays() {
+            let arr = concat(&[array.as_ref(), array.as_ref()])?;
+            assert_eq!(2 * array.len(), arr.len());
+            assert_eq!(2 * array.null_count(), arr.null_count());
+            assert_eq!(&array.data(), &arr.slice(0, array.len()).data());
+            assert_eq!(&array.data(), &arr.slice(array.len(), array.len()).data());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_concat_primitive_arrays() -> Result<()> {
         let arr = concat(&[
@@ -254,4 +454,96 @@ mod tests {
 
         Ok(())
     }
+
+    fn int32_batch(schema: &SchemaRef, values: std::ops::Range<i32>) -> RecordBatch {
+        let column: ArrayRef = Arc::new(Int32Array::from(values.collect::<Vec<_>>()));
+        RecordBatch::try_new(schema.clone(), vec![column]).unwrap()
+    }
+
+    fn int32_values(batch: &RecordBatch) -> Vec<i32> {
+        let column = batch.column(0);
+        let column = column.as_any().downcast_ref::<Int32Array>().unwrap();
+        (0..column.len()).map(|i| column.value(i)).collect()
+    }
+
+    #[test]
+    fn test_batch_coalescer() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let mut coalescer = BatchCoalescer::new(schema.clone(), 4)?;
+
+        assert!(coalescer.push(int32_batch(&schema, 0..3))?.is_empty());
+        let emitted = coalescer.push(int32_batch(&schema, 3..7))?;
+        assert_eq!(1, emitted.len());
+        assert_eq!(vec![0, 1, 2, 3], int32_values(&emitted[0]));
+        assert_eq!(3, coalescer.buffered_rows());
+
+        assert!(coalescer.push(int32_batch(&schema, 7..7))?.is_empty());
+        let emitted = coalescer.push(int32_batch(&schema, 7..17))?;
+        let emitted = emitted.iter().map(int32_values).collect::<Vec<_>>();
+        assert_eq!(
+            vec![vec![4, 5, 6, 7], vec![8, 9, 10, 11], vec![12, 13, 14, 15]],
+            emitted
+        );
+
+        let remaining = coalescer.finish()?.unwrap();
+        assert_eq!(vec![16], int32_values(&remaining));
+
+        let coalescer = BatchCoalescer::new(schema.clone(), 4)?;
+        assert!(coalescer.finish()?.is_none());
+
+        assert!(BatchCoalescer::new(schema, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_coalescer_schema_mismatch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let other = Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        let mut coalescer = BatchCoalescer::new(schema, 4).unwrap();
+        assert!(coalescer.push(int32_batch(&other, 0..3)).is_err());
+    }
+
+    #[test]
+    fn test_batch_coalescer_spill() -> Result<()> {
+        use std::sync::Mutex;
+
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let spilled = Arc::new(Mutex::new(vec![]));
+        let sink = spilled.clone();
+        let spill: SpillFn = Box::new(move |batch| {
+            sink.lock().unwrap().push(int32_values(&batch));
+            Ok(())
+        });
+        let small = int32_batch(&schema, 0..2);
+        let memory_limit = small.column(0).get_array_memory_size();
+        let mut coalescer =
+            BatchCoalescer::new(schema.clone(), 10)?.with_spill(memory_limit, spill);
+
+        // a single batch stays within the limit
+        assert!(coalescer.push(small)?.is_empty());
+        assert!(spilled.lock().unwrap().is_empty());
+        assert_eq!(2, coalescer.buffered_rows());
+
+        // the buffered rows are spilled once the limit is exceeded
+        assert!(coalescer.push(int32_batch(&schema, 2..5))?.is_empty());
+        assert_eq!(vec![vec![0, 1, 2, 3, 4]], *spilled.lock().unwrap());
+        assert_eq!(0, coalescer.buffered_rows());
+
+        // full batches are emitted before spilling, and the remaining rows hold the
+        // buffer of the whole batch
+        let emitted = coalescer.push(int32_batch(&schema, 5..40))?;
+        let emitted = emitted.iter().map(int32_values).collect::<Vec<_>>();
+        let expected = vec![
+            (5..15).collect::<Vec<_>>(),
+            (15..25).collect(),
+            (25..35).collect(),
+        ];
+        assert_eq!(expected, emitted);
+        assert_eq!(
+            vec![vec![0, 1, 2, 3, 4], vec![35, 36, 37, 38, 39]],
+            *spilled.lock().unwrap()
+        );
+        assert!(coalescer.finish()?.is_none());
+        Ok(())
+    }
 }
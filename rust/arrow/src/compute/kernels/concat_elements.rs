@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to concatenate the values of the same index of \[Large\]String
+//! arrays, as opposed to [`concat`](super::concat::concat), which concatenates arrays.
+//!
+//! The lengths of the concatenated values are summed before they are copied, so that
+//! the values of the returned array are written to a buffer of the right size.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::compute::util::combine_option_bitmap;
+use crate::datatypes::ToByteSlice;
+use crate::error::{ArrowError, Result};
+
+/// Returns `offset` as an offset of a string or list array, or an error if the values
+/// of the array are too large for its offsets
+pub(super) fn offset_from_usize<OffsetSize: OffsetSizeTrait>(
+    offset: usize,
+) -> Result<OffsetSize> {
+    OffsetSize::from_usize(offset).ok_or_else(|| {
+        ArrowError::ComputeError(
+            "The values are too large for the offsets of the array".to_string(),
+        )
+    })
+}
+
+/// Concatenates the values of `left` and `right` of the same index, e.g. `["a", "b"]`
+/// and `["c", "d"]` to `["ac", "bd"]`, like the SQL operator `||`. The result is null
+/// when either value is null.
+///
+/// This function errors when the arrays have different lengths, or when the
+/// concatenated values do not fit in the offsets of the returned array.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::concat_elements::concat_elements_utf8;
+/// let left = StringArray::from(vec![Some("arrow"), None, Some("x")]);
+/// let right = StringArray::from(vec![Some("-rs"), Some("y"), Some("")]);
+/// let result = concat_elements_utf8(&left, &right).unwrap();
+/// assert_eq!(
+///     result,
+///     StringArray::from(vec![Some("arrow-rs"), None, Some("x")])
+/// );
+/// ```
+pub fn concat_elements_utf8<OffsetSize: StringOffsetSizeTrait>(
+    left: &GenericStringArray<OffsetSize>,
+    right: &GenericStringArray<OffsetSize>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot concatenate the values of arrays of different length".to_string(),
+        ));
+    }
+    let null_bit_buffer =
+        combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
+    let is_valid = |i: usize| left.is_valid(i) && right.is_valid(i);
+
+    let capacity = (0..left.len())
+        .filter(|i| is_valid(*i))
+        .map(|i| left.value(i).len() + right.value(i).len())
+        .sum();
+    let mut values = String::with_capacity(capacity);
+    let mut offsets: Vec<OffsetSize> = Vec::with_capacity(left.len() + 1);
+    offsets.push(OffsetSize::zero());
+    for i in 0..left.len() {
+        if is_valid(i) {
+            values.push_str(left.value(i));
+            values.push_str(right.value(i));
+        }
+        offsets.push(offset_from_usize(values.len())?);
+    }
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![
+            Buffer::from(offsets.to_byte_slice()),
+            Buffer::from(values.as_bytes()),
+        ],
+        vec![],
+    );
+    Ok(GenericStringArray::<OffsetSize>::from(Arc::new(data)))
+}
+
+/// Concatenates the values of `arrays` of the same index, separated by `separator`,
+/// like the SQL function `CONCAT_WS`, or `CONCAT` without separator. Null values are
+/// skipped, along with their separator, so that the result is never null: it is empty
+/// when all the values are null.
+///
+/// This function errors when `arrays` is empty, when the arrays have different lengths,
+/// or when the concatenated values do not fit in the offsets of the returned array.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::concat_elements::concat_ws_utf8;
+/// let first = StringArray::from(vec![Some("2021"), None]);
+/// let second = StringArray::from(vec![Some("01"), None]);
+/// let third = StringArray::from(vec![Some("31"), Some("12")]);
+/// let result = concat_ws_utf8(&[&first, &second, &third], Some("-")).unwrap();
+/// assert_eq!(result, StringArray::from(vec!["2021-01-31", "12"]));
+/// ```
+pub fn concat_ws_utf8<OffsetSize: StringOffsetSizeTrait>(
+    arrays: &[&GenericStringArray<OffsetSize>],
+    separator: Option<&str>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let len = match arrays.first() {
+        Some(array) => array.len(),
+        None => {
+            return Err(ArrowError::ComputeError(
+                "concat_ws requires at least one array".to_string(),
+            ))
+        }
+    };
+    if arrays.iter().any(|array| array.len() != len) {
+        return Err(ArrowError::ComputeError(
+            "Cannot concatenate the values of arrays of different length".to_string(),
+        ));
+    }
+    let separator = separator.unwrap_or("");
+
+    let mut capacity = 0;
+    for i in 0..len {
+        let valid = arrays.iter().filter(|array| array.is_valid(i));
+        let (count, value_len) = valid
+            .fold((0usize, 0usize), |(count, value_len), array| {
+                (count + 1, value_len + array.value(i).len())
+            });
+        capacity += value_len + separator.len() * count.saturating_sub(1);
+    }
+    let mut values = String::with_capacity(capacity);
+    let mut offsets: Vec<OffsetSize> = Vec::with_capacity(len + 1);
+    offsets.push(OffsetSize::zero());
+    for i in 0..len {
+        let valid = arrays.iter().filter(|array| array.is_valid(i));
+        for (j, array) in valid.enumerate() {
+            if j > 0 {
+                values.push_str(separator);
+            }
+            values.push_str(array.value(i));
+        }
+        offsets.push(offset_from_usize(values.len())?);
+    }
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        len,
+        Some(0),
+        None,
+        0,
+        vec![
+            Buffer::from(offsets.to_byte_slice()),
+            Buffer::from(values.as_bytes()),
+        ],
+        vec![],
+    );
+    Ok(GenericStringArray::<OffsetSize>::from(Arc::new(data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat_elements_utf8_sliced() {
+        let left = LargeStringArray::from(vec![Some("x"), Some("a"), None, Some("c")]);
+        let left = left.slice(1, 3);
+        let left = left.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let right = LargeStringArray::from(vec![Some("b"), Some("b"), None]);
+        let result = concat_elements_utf8(left, &right).unwrap();
+        assert_eq!(result, LargeStringArray::from(vec![Some("ab"), None, None]));
+        assert_eq!(result.null_count(), 2);
+    }
+
+    #[test]
+    fn test_concat_elements_utf8_different_lengths() {
+        let left = StringArray::from(vec!["a"]);
+        let right = StringArray::from(vec!["a", "b"]);
+        assert!(concat_elements_utf8(&left, &right).is_err());
+    }
+
+    #[test]
+    fn test_concat_ws_utf8() {
+        let first = StringArray::from(vec![None, Some("a"), None, Some("")]);
+        let second = StringArray::from(vec![None, None, Some("b"), Some("")]);
+        let third = StringArray::from(vec![None, Some("c"), Some("c"), None]);
+        let arrays = [&first, &second, &third];
+
+        let result = concat_ws_utf8(&arrays, Some(", ")).unwrap();
+        assert_eq!(result, StringArray::from(vec!["", "a, c", "b, c", ", "]));
+        assert_eq!(result.null_count(), 0);
+
+        let result = concat_ws_utf8(&arrays, None).unwrap();
+        assert_eq!(result, StringArray::from(vec!["", "ac", "bc", ""]));
+
+        assert!(concat_ws_utf8::<i32>(&[], None).is_err());
+    }
+}
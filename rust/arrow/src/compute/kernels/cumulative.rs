@@ -0,0 +1,175 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines cumulative kernels, such as running totals, on numeric arrays.
+
+use std::ops::{Add, Mul};
+use std::sync::Arc;
+
+use num::{One, Zero};
+
+use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::{ArrowNumericType, ToByteSlice};
+use crate::error::Result;
+use crate::util::bit_util;
+
+/// Options that define how cumulative kernels handle nulls
+#[derive(Clone, Debug, Default)]
+pub struct CumulativeOptions {
+    /// By default, null slots are skipped: they are null in the output and do not
+    /// contribute to the accumulated value. When this is set, the first null slot makes
+    /// all the following output slots null.
+    pub propagate_nulls: bool,
+}
+
+/// Computes the running accumulation of the values of `array` with `op`, starting from
+/// `init`.
+fn cumulative_op<T, F>(
+    array: &PrimitiveArray<T>,
+    init: T::Native,
+    op: F,
+    options: Option<CumulativeOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let options = options.unwrap_or_default();
+    let len = array.len();
+
+    let mut acc = init;
+    let mut values = Vec::with_capacity(len);
+    let mut first_null = None;
+    for i in 0..len {
+        if array.is_null(i) {
+            first_null.get_or_insert(i);
+            values.push(T::default_value());
+        } else if options.propagate_nulls && first_null.is_some() {
+            values.push(T::default_value());
+        } else {
+            acc = op(acc, array.value(i));
+            values.push(acc);
+        }
+    }
+
+    let null_bit_buffer = match first_null {
+        None => None,
+        Some(first_null) if options.propagate_nulls => {
+            let num_bytes = bit_util::ceil(len, 8);
+            let mut buffer = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+            let data = buffer.data_mut();
+            (0..first_null).for_each(|i| bit_util::set_bit(data, i));
+            Some(buffer.freeze())
+        }
+        Some(_) => array
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(array.offset(), len)),
+    };
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        len,
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(values.to_byte_slice())],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Returns the cumulative sum of the values of `array`: the i-th output slot is the sum
+/// of the values of the slots `0..=i`. See `CumulativeOptions` for the handling of
+/// nulls.
+pub fn cumsum<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<CumulativeOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native> + Zero,
+{
+    cumulative_op(array, T::Native::zero(), |a, b| a + b, options)
+}
+
+/// Returns the cumulative product of the values of `array`: the i-th output slot is the
+/// product of the values of the slots `0..=i`. See `CumulativeOptions` for the handling
+/// of nulls.
+pub fn cumprod<T>(
+    array: &PrimitiveArray<T>,
+    options: Option<CumulativeOptions>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Mul<Output = T::Native> + One,
+{
+    cumulative_op(array, T::Native::one(), |a, b| a * b, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cumsum() {
+        let a = Int32Array::from(vec![1, 2, 3, -4]);
+        assert_eq!(
+            Int32Array::from(vec![1, 3, 6, 2]),
+            cumsum(&a, None).unwrap()
+        );
+
+        let a = Float64Array::from(vec![0.5, 1.5]);
+        assert_eq!(
+            Float64Array::from(vec![0.5, 2.0]),
+            cumsum(&a, None).unwrap()
+        );
+
+        let a = Int32Array::from(Vec::<i32>::new());
+        assert_eq!(0, cumsum(&a, None).unwrap().len());
+    }
+
+    #[test]
+    fn test_cumsum_nulls() {
+        let a = Int64Array::from(vec![Some(1), None, Some(2), Some(3), None]);
+        let expected = Int64Array::from(vec![Some(1), None, Some(3), Some(6), None]);
+        assert_eq!(expected, cumsum(&a, None).unwrap());
+
+        let options = CumulativeOptions {
+            propagate_nulls: true,
+        };
+        let expected = Int64Array::from(vec![Some(1), None, None, None, None]);
+        assert_eq!(expected, cumsum(&a, Some(options)).unwrap());
+    }
+
+    #[test]
+    fn test_cumprod_sliced() {
+        let a = UInt8Array::from(vec![Some(7), Some(2), None, Some(3), Some(4)]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<UInt8Array>().unwrap();
+
+        let expected = UInt8Array::from(vec![Some(2), None, Some(6), Some(24)]);
+        assert_eq!(expected, cumprod(a, None).unwrap());
+
+        let options = CumulativeOptions {
+            propagate_nulls: true,
+        };
+        let expected = UInt8Array::from(vec![Some(2), None, None, None]);
+        assert_eq!(expected, cumprod(a, Some(options)).unwrap());
+    }
+}
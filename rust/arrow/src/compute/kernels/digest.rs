@@ -0,0 +1,213 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to compute checksums and digests of the values of \[Large\]String
+//! and \[Large\]Binary arrays.
+//!
+//! Each algorithm is only available when the feature of the crate implementing it is
+//! enabled: `md5`, `sha2` or `crc32fast`.
+
+use super::encoding::Encoding;
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// The length of the longest digest of [`DigestAlgorithm`]
+const MAX_DIGEST_LEN: usize = 32;
+
+/// The digest algorithms supported by [`digest`] and [`digest_hex`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DigestAlgorithm {
+    /// The 16-byte MD5 digest
+    #[cfg(feature = "md5")]
+    Md5,
+    /// The 32-byte SHA-256 digest
+    #[cfg(feature = "sha2")]
+    Sha256,
+    /// The CRC-32 (IEEE) checksum, as 4 big-endian bytes
+    #[cfg(feature = "crc32fast")]
+    Crc32,
+}
+
+impl DigestAlgorithm {
+    /// The number of bytes of a digest
+    fn digest_len(&self) -> usize {
+        match self {
+            #[cfg(feature = "md5")]
+            DigestAlgorithm::Md5 => 16,
+            #[cfg(feature = "sha2")]
+            DigestAlgorithm::Sha256 => 32,
+            #[cfg(feature = "crc32fast")]
+            DigestAlgorithm::Crc32 => 4,
+        }
+    }
+
+    /// Computes the digest of `input` into `output`, whose length must be
+    /// `self.digest_len()`
+    fn digest_into(&self, input: &[u8], output: &mut [u8]) {
+        match self {
+            #[cfg(feature = "md5")]
+            DigestAlgorithm::Md5 => output.copy_from_slice(&md5::compute(input).0),
+            #[cfg(feature = "sha2")]
+            DigestAlgorithm::Sha256 => {
+                use sha2::Digest;
+                output.copy_from_slice(&sha2::Sha256::digest(input))
+            }
+            #[cfg(feature = "crc32fast")]
+            DigestAlgorithm::Crc32 => {
+                output.copy_from_slice(&crc32fast::hash(input).to_be_bytes())
+            }
+        }
+    }
+}
+
+fn digest_values<'a>(
+    values: impl Iterator<Item = Option<&'a [u8]>>,
+    len: usize,
+    algorithm: DigestAlgorithm,
+) -> Result<FixedSizeBinaryArray> {
+    let digest_len = algorithm.digest_len();
+    let mut builder = FixedSizeBinaryBuilder::new(len * digest_len, digest_len as i32);
+    let mut digest = [0u8; MAX_DIGEST_LEN];
+    for value in values {
+        match value {
+            Some(value) => {
+                algorithm.digest_into(value, &mut digest[..digest_len]);
+                builder.append_value(&digest[..digest_len])?;
+            }
+            None => builder.append_null()?,
+        }
+    }
+    Ok(builder.finish())
+}
+
+/// Computes the digest of each value of a \[Large\]String or \[Large\]Binary array with
+/// `algorithm`, returning a FixedSizeBinary array of the length of its digests. Nulls
+/// are preserved. The digests of strings are those of their UTF-8 bytes.
+///
+/// This function errors when `array` is not a \[Large\]String or \[Large\]Binary array.
+pub fn digest(array: &Array, algorithm: DigestAlgorithm) -> Result<FixedSizeBinaryArray> {
+    let len = array.len();
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected");
+            digest_values(array.iter().map(|v| v.map(str::as_bytes)), len, algorithm)
+        }
+        DataType::LargeUtf8 => {
+            let array = array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected");
+            digest_values(array.iter().map(|v| v.map(str::as_bytes)), len, algorithm)
+        }
+        DataType::Binary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<BinaryArray>()
+                .expect("A binary is expected");
+            digest_values(array.iter(), len, algorithm)
+        }
+        DataType::LargeBinary => {
+            let array = array
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .expect("A large binary is expected");
+            digest_values(array.iter(), len, algorithm)
+        }
+        _ => Err(ArrowError::ComputeError(format!(
+            "digest does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Computes the digest of each value of a \[Large\]String or \[Large\]Binary array with
+/// `algorithm`, like [`digest`] does, returning a String array of the digests as
+/// lowercase hexadecimal digits. Nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String or \[Large\]Binary array.
+pub fn digest_hex(array: &Array, algorithm: DigestAlgorithm) -> Result<StringArray> {
+    let digests = digest(array, algorithm)?;
+    let hex_len = algorithm.digest_len() * 2;
+    let mut builder =
+        StringBuilder::with_capacity(digests.len(), digests.len() * hex_len);
+    let mut hex = [0u8; MAX_DIGEST_LEN * 2];
+    for i in 0..digests.len() {
+        if digests.is_valid(i) {
+            Encoding::Hex.encode_into(digests.value(i), &mut hex[..hex_len]);
+            // hexadecimal digits are ASCII
+            builder.append_value(std::str::from_utf8(&hex[..hex_len]).unwrap())?;
+        } else {
+            builder.append_null()?;
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "md5")]
+    fn test_md5() {
+        let array = StringArray::from(vec![Some("abc"), None, Some("")]);
+        let digests = digest_hex(&array, DigestAlgorithm::Md5).unwrap();
+        assert_eq!(
+            digests,
+            StringArray::from(vec![
+                Some("900150983cd24fb0d6963f7d28e17f72"),
+                None,
+                Some("d41d8cd98f00b204e9800998ecf8427e"),
+            ])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_sha256() {
+        let array = BinaryArray::from(vec![Some(b"abc".as_ref()), None]);
+        let digests = digest(&array, DigestAlgorithm::Sha256).unwrap();
+        assert_eq!(digests.value_length(), 32);
+        assert!(digests.is_null(1));
+        let digests = digest_hex(&array, DigestAlgorithm::Sha256).unwrap();
+        assert_eq!(
+            digests.value(0),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "crc32fast")]
+    fn test_crc32() {
+        let array = LargeStringArray::from(vec!["x", "abc", "y"]);
+        let array = array.slice(1, 2);
+        let digests = digest(array.as_ref(), DigestAlgorithm::Crc32).unwrap();
+        assert_eq!(digests.len(), 2);
+        assert_eq!(digests.value(0), &[0x35, 0x24, 0x41, 0xc2]);
+    }
+
+    #[test]
+    #[cfg(feature = "crc32fast")]
+    fn test_digest_unsupported_type() {
+        let array = Int32Array::from(vec![1, 2]);
+        assert!(digest(&array, DigestAlgorithm::Crc32).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to encode \[Large\]BinaryArrays to \[Large\]StringArrays as base64 or
+//! hex, and to decode them back
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::{DataType, ToByteSlice};
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The text encodings of binary values supported by [`encode`] and [`decode`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Encoding {
+    /// The standard base64 alphabet of RFC 4648, with padding
+    Base64,
+    /// Hexadecimal digits. Lowercase digits are emitted when encoding, and both cases are
+    /// accepted when decoding.
+    Hex,
+}
+
+/// Options that define how [`decode`] should behave
+#[derive(Clone, Debug, Default)]
+pub struct DecodeOptions {
+    /// Emit a null slot instead of returning an error when a value is not validly encoded
+    pub null_on_error: bool,
+}
+
+impl Encoding {
+    fn name(&self) -> &'static str {
+        match self {
+            Encoding::Base64 => "base64",
+            Encoding::Hex => "hex",
+        }
+    }
+
+    /// The length of the encoding of `len` bytes
+    fn encoded_len(&self, len: usize) -> usize {
+        match self {
+            Encoding::Base64 => bit_util::ceil(len, 3) * 4,
+            Encoding::Hex => len * 2,
+        }
+    }
+
+    /// An upper bound of the number of bytes that `len` encoded bytes decode to
+    fn decoded_len(&self, len: usize) -> usize {
+        match self {
+            Encoding::Base64 => len / 4 * 3,
+            Encoding::Hex => len / 2,
+        }
+    }
+
+    /// Encodes `input` into `output`, whose length must be
+    /// `self.encoded_len(input.len())`
+    pub(super) fn encode_into(&self, input: &[u8], output: &mut [u8]) {
+        match self {
+            Encoding::Base64 => {
+                for (chunk, out) in input.chunks(3).zip(output.chunks_mut(4)) {
+                    let b0 = chunk[0] as usize;
+                    let b1 = chunk.get(1).copied().unwrap_or(0) as usize;
+                    let b2 = chunk.get(2).copied().unwrap_or(0) as usize;
+                    out[0] = BASE64_ALPHABET[b0 >> 2];
+                    out[1] = BASE64_ALPHABET[((b0 & 0x03) << 4) | (b1 >> 4)];
+                    out[2] = if chunk.len() > 1 {
+                        BASE64_ALPHABET[((b1 & 0x0f) << 2) | (b2 >> 6)]
+                    } else {
+                        b'='
+                    };
+                    out[3] = if chunk.len() > 2 {
+                        BASE64_ALPHABET[b2 & 0x3f]
+                    } else {
+                        b'='
+                    };
+                }
+            }
+            Encoding::Hex => hex::encode_to_slice(input, output)
+                .expect("The output has the length of the encoded input"),
+        }
+    }
+
+    /// Decodes `input` into `output`, whose length must be at least
+    /// `self.decoded_len(input.len())`. Returns the number of decoded bytes, or `None`
+    /// if `input` is not validly encoded.
+    pub(super) fn decode_into(&self, input: &[u8], output: &mut [u8]) -> Option<usize> {
+        match self {
+            Encoding::Base64 => {
+                if input.len() % 4 != 0 {
+                    return None;
+                }
+                let padding = input.iter().rev().take_while(|c| **c == b'=').count();
+                if padding > 2 {
+                    return None;
+                }
+                let len = input.len() / 4 * 3 - padding;
+                for (i, (chunk, out)) in
+                    input.chunks(4).zip(output.chunks_mut(3)).enumerate()
+                {
+                    let last = (i + 1) * 4 == input.len();
+                    let mut sextets = [0u8; 4];
+                    for (j, c) in chunk.iter().enumerate() {
+                        sextets[j] = match c {
+                            b'A'..=b'Z' => c - b'A',
+                            b'a'..=b'z' => c - b'a' + 26,
+                            b'0'..=b'9' => c - b'0' + 52,
+                            b'+' => 62,
+                            b'/' => 63,
+                            b'=' if last && j >= 4 - padding => 0,
+                            _ => return None,
+                        };
+                    }
+                    let bytes = [
+                        (sextets[0] << 2) | (sextets[1] >> 4),
+                        (sextets[1] << 4) | (sextets[2] >> 2),
+                        (sextets[2] << 6) | sextets[3],
+                    ];
+                    let n = if last { 3 - padding } else { 3 };
+                    out[..n].copy_from_slice(&bytes[..n]);
+                }
+                Some(len)
+            }
+            Encoding::Hex => {
+                if input.len() % 2 != 0 {
+                    return None;
+                }
+                let len = input.len() / 2;
+                hex::decode_to_slice(input, &mut output[..len]).ok()?;
+                Some(len)
+            }
+        }
+    }
+}
+
+fn offset_from_usize<OffsetSize: OffsetSizeTrait>(offset: usize) -> Result<OffsetSize> {
+    OffsetSize::from_usize(offset).ok_or_else(|| {
+        ArrowError::ComputeError(
+            "The encoded values are too large for the offsets of the array".to_string(),
+        )
+    })
+}
+
+fn generic_encode<OffsetSize>(
+    array: &GenericBinaryArray<OffsetSize>,
+    encoding: Encoding,
+) -> Result<ArrayRef>
+where
+    OffsetSize: BinaryOffsetSizeTrait + StringOffsetSizeTrait,
+{
+    // the length of the encoded values is known upfront: compute the offsets first, and
+    // then encode each value directly into its slot of the values buffer
+    let mut offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    let mut length_so_far = 0;
+    offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            length_so_far += encoding.encoded_len(array.value(i).len());
+        }
+        offsets.push(offset_from_usize(length_so_far)?);
+    }
+
+    let mut values = MutableBuffer::new(length_so_far);
+    values.resize(length_so_far);
+    let data = values.data_mut();
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let start = offsets[i].to_usize().unwrap();
+            let end = offsets[i + 1].to_usize().unwrap();
+            encoding.encode_into(array.value(i), &mut data[start..end]);
+        }
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(offsets.to_byte_slice()), values.freeze()],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+fn generic_decode<OffsetSize>(
+    array: &GenericStringArray<OffsetSize>,
+    encoding: Encoding,
+    options: DecodeOptions,
+) -> Result<ArrayRef>
+where
+    OffsetSize: BinaryOffsetSizeTrait + StringOffsetSizeTrait,
+{
+    // the values are decoded directly into a buffer large enough for all of them, which
+    // is truncated to the actual length of the decoded values at the end
+    let capacity = (0..array.len())
+        .filter(|i| array.is_valid(*i))
+        .map(|i| encoding.decoded_len(array.value(i).len()))
+        .sum();
+    let mut values = MutableBuffer::new(capacity);
+    values.resize(capacity);
+    let data = values.data_mut();
+
+    let num_bytes = bit_util::ceil(array.len(), 8);
+    let mut null_buffer = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let null_slice = null_buffer.data_mut();
+    let mut null_count = 0;
+
+    let mut offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    let mut length_so_far = 0;
+    offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let value = array.value(i);
+            match encoding.decode_into(value.as_bytes(), &mut data[length_so_far..]) {
+                Some(len) => {
+                    length_so_far += len;
+                    bit_util::set_bit(null_slice, i);
+                }
+                None if options.null_on_error => null_count += 1,
+                None => {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Cannot decode '{}' as {}",
+                        value,
+                        encoding.name()
+                    )))
+                }
+            }
+        } else {
+            null_count += 1;
+        }
+        offsets.push(offset_from_usize(length_so_far)?);
+    }
+    values.resize(length_so_far);
+
+    let data = ArrayData::new(
+        <OffsetSize as BinaryOffsetSizeTrait>::DATA_TYPE,
+        array.len(),
+        Some(null_count),
+        Some(null_buffer.freeze()),
+        0,
+        vec![Buffer::from(offsets.to_byte_slice()), values.freeze()],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Encodes the values of a \[Large\]Binary array with `encoding`, returning a
+/// \[Large\]String array. Nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]Binary array, or when the encoded
+/// values do not fit in the offsets of the returned array.
+pub fn encode(array: &Array, encoding: Encoding) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Binary => generic_encode(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray>()
+                .expect("A binary is expected"),
+            encoding,
+        ),
+        DataType::LargeBinary => generic_encode(
+            array
+                .as_any()
+                .downcast_ref::<LargeBinaryArray>()
+                .expect("A large binary is expected"),
+            encoding,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "encode does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Decodes the values of a \[Large\]String array encoded with `encoding`, returning a
+/// \[Large\]Binary array. Nulls are preserved.
+///
+/// Values that are not validly encoded either make this function error, or are decoded
+/// as nulls when `options.null_on_error` is set.
+///
+/// This function also errors when `array` is not a \[Large\]String array.
+pub fn decode(
+    array: &Array,
+    encoding: Encoding,
+    options: Option<DecodeOptions>,
+) -> Result<ArrayRef> {
+    let options = options.unwrap_or_default();
+    match array.data_type() {
+        DataType::Utf8 => generic_decode(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+            encoding,
+            options,
+        ),
+        DataType::LargeUtf8 => generic_decode(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+            encoding,
+            options,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "decode does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binary_values() -> Vec<Option<&'static [u8]>> {
+        vec![
+            Some(b""),
+            Some(b"f"),
+            None,
+            Some(b"fo"),
+            Some(b"foo"),
+            Some(b"foob"),
+            Some(b"\x00\xff\x10"),
+        ]
+    }
+
+    #[test]
+    fn test_encode_base64() {
+        let array = BinaryArray::from(binary_values());
+        let result = encode(&array, Encoding::Base64).unwrap();
+        let expected = StringArray::from(vec![
+            Some(""),
+            Some("Zg=="),
+            None,
+            Some("Zm8="),
+            Some("Zm9v"),
+            Some("Zm9vYg=="),
+            Some("AP8Q"),
+        ]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<StringArray>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_hex_sliced() {
+        let array: ArrayRef = Arc::new(LargeBinaryArray::from(binary_values()));
+        let array = array.slice(1, 6);
+        let result = encode(array.as_ref(), Encoding::Hex).unwrap();
+        let expected = LargeStringArray::from(vec![
+            Some("66"),
+            None,
+            Some("666f"),
+            Some("666f6f"),
+            Some("666f6f62"),
+            Some("00ff10"),
+        ]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<LargeStringArray>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let array = BinaryArray::from(binary_values());
+        for encoding in &[Encoding::Base64, Encoding::Hex] {
+            let encoded = encode(&array, *encoding).unwrap();
+            let decoded = decode(encoded.as_ref(), *encoding, None).unwrap();
+            assert_eq!(
+                &array,
+                decoded.as_any().downcast_ref::<BinaryArray>().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let array = StringArray::from(vec![
+            Some("Zm9v"),
+            Some("Zm9"),
+            None,
+            Some("Zm=v"),
+            Some("Zg=="),
+            Some("Z==="),
+        ]);
+        assert!(decode(&array, Encoding::Base64, None).is_err());
+
+        let options = DecodeOptions {
+            null_on_error: true,
+        };
+        let result = decode(&array, Encoding::Base64, Some(options.clone())).unwrap();
+        let expected: Vec<Option<&[u8]>> =
+            vec![Some(b"foo"), None, None, None, Some(b"f"), None];
+        assert_eq!(
+            &BinaryArray::from(expected),
+            result.as_any().downcast_ref::<BinaryArray>().unwrap()
+        );
+        assert_eq!(4, result.null_count());
+
+        let array = LargeStringArray::from(vec!["0aFf", "0", "zz"]);
+        assert!(decode(&array, Encoding::Hex, None).is_err());
+        let result = decode(&array, Encoding::Hex, Some(options)).unwrap();
+        let expected: Vec<Option<&[u8]>> = vec![Some(b"\x0a\xff"), None, None];
+        assert_eq!(
+            &LargeBinaryArray::from(expected),
+            result.as_any().downcast_ref::<LargeBinaryArray>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_unsupported_types() {
+        let array = Int32Array::from(vec![1]);
+        assert!(encode(&array, Encoding::Hex).is_err());
+        assert!(decode(&array, Encoding::Hex, None).is_err());
+    }
+}
@@ -18,12 +18,13 @@
 //! Defines miscellaneous array kernels.
 
 use crate::array::*;
+use crate::compute::kernels::take::take;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 use crate::{
     bitmap::Bitmap,
-    buffer::{Buffer, MutableBuffer},
+    buffer::{buffer_bin_and, Buffer, MutableBuffer},
     util::bit_util,
 };
 use std::{mem, sync::Arc};
@@ -216,7 +217,7 @@ pub struct FilterContext {
 
 macro_rules! filter_primitive_array {
     ($context:expr, $array:expr, $array_type:ident) => {{
-        let input_array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        let input_array = $array.as_typed::<$array_type>()?;
         let output_array = $context.filter_primitive_array(input_array)?;
         Ok(Arc::new(output_array))
     }};
@@ -224,7 +225,7 @@ macro_rules! filter_primitive_array {
 
 macro_rules! filter_dictionary_array {
     ($context:expr, $array:expr, $array_type:ident) => {{
-        let input_array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        let input_array = $array.as_typed::<$array_type>()?;
         let output_array = $context.filter_dictionary_array(input_array)?;
         Ok(Arc::new(output_array))
     }};
@@ -232,7 +233,7 @@ macro_rules! filter_dictionary_array {
 
 macro_rules! filter_boolean_item_list_array {
     ($context:expr, $array:expr, $list_type:ident, $list_builder_type:ident) => {{
-        let input_array = $array.as_any().downcast_ref::<$list_type>().unwrap();
+        let input_array = $array.as_typed::<$list_type>()?;
         let values_builder = BooleanBuilder::new($context.filtered_count);
         let mut builder = $list_builder_type::new(values_builder);
         for i in 0..$context.filter_u64.len() {
@@ -250,10 +251,7 @@ macro_rules! filter_boolean_item_list_array {
                         builder.append(false)?;
                     } else {
                         let this_inner_list = input_array.value(data_index);
-                        let inner_list = this_inner_list
-                            .as_any()
-                            .downcast_ref::<BooleanArray>()
-                            .unwrap();
+                        let inner_list = this_inner_list.as_typed::<BooleanArray>()?;
                         for k in 0..inner_list.len() {
                             if inner_list.is_null(k) {
                                 builder.values().append_null()?;
@@ -272,7 +270,7 @@ macro_rules! filter_boolean_item_list_array {
 
 macro_rules! filter_primitive_item_list_array {
     ($context:expr, $array:expr, $item_type:ident, $list_type:ident, $list_builder_type:ident) => {{
-        let input_array = $array.as_any().downcast_ref::<$list_type>().unwrap();
+        let input_array = $array.as_typed::<$list_type>()?;
         let values_builder = PrimitiveBuilder::<$item_type>::new($context.filtered_count);
         let mut builder = $list_builder_type::new(values_builder);
         for i in 0..$context.filter_u64.len() {
@@ -290,10 +288,8 @@ macro_rules! filter_primitive_item_list_array {
                         builder.append(false)?;
                     } else {
                         let this_inner_list = input_array.value(data_index);
-                        let inner_list = this_inner_list
-                            .as_any()
-                            .downcast_ref::<PrimitiveArray<$item_type>>()
-                            .unwrap();
+                        let inner_list =
+                            this_inner_list.as_typed::<PrimitiveArray<$item_type>>()?;
                         for k in 0..inner_list.len() {
                             if inner_list.is_null(k) {
                                 builder.values().append_null()?;
@@ -312,7 +308,7 @@ macro_rules! filter_primitive_item_list_array {
 
 macro_rules! filter_non_primitive_item_list_array {
     ($context:expr, $array:expr, $item_array_type:ident, $item_builder:ident, $list_type:ident, $list_builder_type:ident) => {{
-        let input_array = $array.as_any().downcast_ref::<$list_type>().unwrap();
+        let input_array = $array.as_typed::<$list_type>()?;
         let values_builder = $item_builder::new($context.filtered_count);
         let mut builder = $list_builder_type::new(values_builder);
         for i in 0..$context.filter_u64.len() {
@@ -330,10 +326,8 @@ macro_rules! filter_non_primitive_item_list_array {
                         builder.append(false)?;
                     } else {
                         let this_inner_list = input_array.value(data_index);
-                        let inner_list = this_inner_list
-                            .as_any()
-                            .downcast_ref::<$item_array_type>()
-                            .unwrap();
+                        let inner_list =
+                            this_inner_list.as_typed::<$item_array_type>()?;
                         for k in 0..inner_list.len() {
                             if inner_list.is_null(k) {
                                 builder.values().append_null()?;
@@ -403,7 +397,7 @@ impl FilterContext {
             DataType::Float32 => filter_primitive_array!(self, array, Float32Array),
             DataType::Float64 => filter_primitive_array!(self, array, Float64Array),
             DataType::Boolean => {
-                let input_array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+                let input_array = array.as_typed::<BooleanArray>()?;
                 let mut builder = BooleanArray::builder(self.filtered_count);
                 for i in 0..self.filter_u64.len() {
                     // foreach u64 batch
@@ -465,7 +459,7 @@ impl FilterContext {
                 filter_primitive_array!(self, array, TimestampNanosecondArray)
             }
             DataType::Binary => {
-                let input_array = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+                let input_array = array.as_typed::<BinaryArray>()?;
                 let mut values: Vec<Option<&[u8]>> = Vec::with_capacity(self.filtered_count);
                 for i in 0..self.filter_u64.len() {
                     // foreach u64 batch
@@ -489,7 +483,7 @@ impl FilterContext {
                 Ok(Arc::new(BinaryArray::from(values)))
             }
             DataType::Utf8 => {
-                let input_array = array.as_any().downcast_ref::<StringArray>().unwrap();
+                let input_array = array.as_typed::<StringArray>()?;
                 let mut values: Vec<Option<&str>> = Vec::with_capacity(self.filtered_count);
                 for i in 0..self.filter_u64.len() {
                     // foreach u64 batch
@@ -758,6 +752,15 @@ impl FilterContext {
                     )))
                 }
             }
+            DataType::FixedSizeList(_, _) => {
+                // the values of the selected lists are contiguous slices of the child
+                // array, which `take` already gathers
+                let indices = (0..self.filter_len)
+                    .filter(|i| self.filter_u64[i / 64] & self.filter_mask[i % 64] != 0)
+                    .map(|i| i as u32)
+                    .collect::<Vec<u32>>();
+                take(&make_array(array.data()), &UInt32Array::from(indices), None)
+            }
             other => Err(ArrowError::ComputeError(format!(
                 "filter not supported for {:?}",
                 other
@@ -847,6 +850,97 @@ pub fn filter_record_batch(
     RecordBatch::try_new(record_batch.schema(), filtered_arrays)
 }
 
+/// Returns the indices of the `true` values of `mask`, in increasing order. Null values
+/// of `mask` are treated as `false`.
+///
+/// This is the inverse of [`indices_to_mask`], and its result can be used with `take`
+/// to select the same values as `filter` with `mask`.
+///
+/// ```
+/// use arrow::array::{BooleanArray, UInt32Array};
+/// use arrow::compute::mask_to_indices;
+///
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+/// assert_eq!(UInt32Array::from(vec![0, 3]), mask_to_indices(&mask).unwrap());
+/// ```
+pub fn mask_to_indices(mask: &BooleanArray) -> Result<UInt32Array> {
+    let len = mask.len();
+    if len as u64 > u64::from(u32::MAX) + 1 {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot represent the indices of a mask of length {} as UInt32",
+            len
+        )));
+    }
+
+    let data = mask.data_ref();
+    let values = &data.buffers()[0];
+    let selected = match data.null_buffer() {
+        Some(nulls) => buffer_bin_and(values, mask.offset(), nulls, mask.offset(), len),
+        None => values.bit_slice(mask.offset(), len),
+    };
+
+    let mut indices = BufferBuilder::<UInt32Type>::new(selected.count_set_bits());
+    let chunks = selected.bit_chunks(0, len);
+    let mut append_word = |base: usize, mut word: u64| -> Result<()> {
+        while word != 0 {
+            indices.append((base + word.trailing_zeros() as usize) as u32)?;
+            // clear the lowest set bit
+            word &= word - 1;
+        }
+        Ok(())
+    };
+    for (i, word) in chunks.iter().enumerate() {
+        append_word(i * 64, word)?;
+    }
+    append_word(len - chunks.remainder_len(), chunks.remainder_bits())?;
+
+    let len = indices.len();
+    Ok(UInt32Array::from(
+        ArrayData::builder(DataType::UInt32)
+            .len(len)
+            .add_buffer(indices.finish())
+            .build(),
+    ))
+}
+
+/// Returns a mask of length `len` that is `true` at the given `indices` and `false`
+/// elsewhere. Null indices are ignored, and the result has no nulls.
+///
+/// This function errors if an index is out of bounds.
+///
+/// ```
+/// use arrow::array::{BooleanArray, UInt32Array};
+/// use arrow::compute::indices_to_mask;
+///
+/// let indices = UInt32Array::from(vec![Some(3), None, Some(0)]);
+/// assert_eq!(
+///     BooleanArray::from(vec![true, false, false, true]),
+///     indices_to_mask(&indices, 4).unwrap()
+/// );
+/// ```
+pub fn indices_to_mask(indices: &UInt32Array, len: usize) -> Result<BooleanArray> {
+    let num_bytes = bit_util::ceil(len, 8);
+    let mut values = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    let values_slice = values.data_mut();
+    for index in indices.iter().flatten() {
+        let index = index as usize;
+        if index >= len {
+            return Err(ArrowError::ComputeError(format!(
+                "Index {} out of bounds for a mask of length {}",
+                index, len
+            )));
+        }
+        bit_util::set_bit(values_slice, index);
+    }
+
+    Ok(BooleanArray::from(
+        ArrayData::builder(DataType::Boolean)
+            .len(len)
+            .add_buffer(values.freeze())
+            .build(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1110,6 +1204,35 @@ mod tests {
         assert_eq!("world", d.value(1));
     }
 
+    #[test]
+    fn test_filter_fixed_size_list_array() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(0), 2);
+        for i in 0..5 {
+            builder.values().append_slice(&[i, i * 10]).unwrap();
+            builder.append(i != 3).unwrap();
+        }
+        let array = builder.finish();
+        let sliced = array.slice(1, 4);
+
+        let mask = BooleanArray::from(vec![true, false, true, true]);
+        let filtered = filter(sliced.as_ref(), &mask).unwrap();
+        let filtered = filtered
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+        assert_eq!(3, filtered.len());
+        assert_eq!(1, filtered.null_count());
+        assert!(filtered.is_null(1));
+        assert_eq!(
+            filtered.value(0).as_ref(),
+            &Int32Array::from(vec![1, 10]) as &Array
+        );
+        assert_eq!(
+            filtered.value(2).as_ref(),
+            &Int32Array::from(vec![4, 40]) as &Array
+        );
+    }
+
     #[test]
     fn test_filter_list_array() {
         let value_data = ArrayData::builder(DataType::Int32)
@@ -1163,4 +1286,56 @@ mod tests {
         assert_eq!(0, inner_list.null_count());
         assert_eq!(inner_list, &Int32Array::from(vec![3, 4, 5]));
     }
+
+    #[test]
+    fn test_mask_to_indices() {
+        let mask: BooleanArray = (0..300)
+            .map(|i| match i % 5 {
+                0 => None,
+                1 | 3 => Some(true),
+                _ => Some(false),
+            })
+            .collect();
+        let expected: Vec<u32> = (0..300).filter(|i| i % 5 == 1 || i % 5 == 3).collect();
+        let indices = mask_to_indices(&mask).unwrap();
+        assert_eq!(UInt32Array::from(expected), indices);
+        assert_eq!(0, indices.null_count());
+
+        // sliced mask, with a remainder
+        let sliced = mask.slice(3, 70);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let expected: Vec<u32> = (3..73)
+            .filter(|i| i % 5 == 1 || i % 5 == 3)
+            .map(|i| i - 3)
+            .collect();
+        assert_eq!(
+            UInt32Array::from(expected),
+            mask_to_indices(sliced).unwrap()
+        );
+
+        // round trip, where nulls become false
+        let mask = indices_to_mask(&indices, 300).unwrap();
+        let expected: BooleanArray =
+            (0..300).map(|i| Some(i % 5 == 1 || i % 5 == 3)).collect();
+        assert_eq!(expected, mask);
+
+        let empty = BooleanArray::from(Vec::<bool>::new());
+        assert_eq!(0, mask_to_indices(&empty).unwrap().len());
+    }
+
+    #[test]
+    fn test_indices_to_mask() {
+        let indices = UInt32Array::from(vec![Some(4), None, Some(1), Some(4)]);
+        assert_eq!(
+            BooleanArray::from(vec![false, true, false, false, true, false]),
+            indices_to_mask(&indices, 6).unwrap()
+        );
+        assert!(indices_to_mask(&indices, 4).is_err());
+        assert_eq!(
+            0,
+            indices_to_mask(&UInt32Array::from(vec![None]), 0)
+                .unwrap()
+                .len()
+        );
+    }
 }
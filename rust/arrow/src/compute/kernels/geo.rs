@@ -0,0 +1,341 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels over arrays of geographic points, such as to filter them by distance
+//! or by bounding box.
+//!
+//! Points are represented either as structs with `Float64` fields named `lat` and `lon`,
+//! or as fixed size lists of two `Float64` values, the latitude and then the longitude.
+//! Coordinates are in degrees. A point is null if it is null or if any of its
+//! coordinates is null.
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// The mean radius of the Earth, in meters
+pub const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// A geographic point, in degrees
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Point {
+    /// The latitude, from -90 to 90
+    pub lat: f64,
+    /// The longitude, from -180 to 180
+    pub lon: f64,
+}
+
+impl Point {
+    /// Creates a point of latitude `lat` and longitude `lon`, in degrees
+    pub fn new(lat: f64, lon: f64) -> Self {
+        Self { lat, lon }
+    }
+
+    /// Returns the great-circle distance in meters between this point and `other`,
+    /// computed with the haversine formula on a sphere of radius [`EARTH_RADIUS_METERS`].
+    pub fn haversine_distance(&self, other: &Point) -> f64 {
+        let (lat1, lat2) = (self.lat.to_radians(), other.lat.to_radians());
+        let half_dlat = (lat2 - lat1) / 2.0;
+        let half_dlon = (other.lon - self.lon).to_radians() / 2.0;
+        let a =
+            half_dlat.sin().powi(2) + lat1.cos() * lat2.cos() * half_dlon.sin().powi(2);
+        // rounding can push `a` slightly above 1 for antipodal points
+        2.0 * EARTH_RADIUS_METERS * a.min(1.0).sqrt().asin()
+    }
+}
+
+/// A bounding box between two corners, inclusive. If the longitude of `min` is greater
+/// than the one of `max`, the box crosses the antimeridian.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    /// The south-west corner
+    pub min: Point,
+    /// The north-east corner
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box between the south-west corner `min` and the north-east
+    /// corner `max`
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// Returns whether `point` is within this box, including its edges
+    pub fn contains(&self, point: &Point) -> bool {
+        let within_lon = if self.min.lon <= self.max.lon {
+            self.min.lon <= point.lon && point.lon <= self.max.lon
+        } else {
+            self.min.lon <= point.lon || point.lon <= self.max.lon
+        };
+        self.min.lat <= point.lat && point.lat <= self.max.lat && within_lon
+    }
+}
+
+/// The coordinates of an array of points
+enum Points<'a> {
+    Struct {
+        array: &'a StructArray,
+        lat: &'a Float64Array,
+        lon: &'a Float64Array,
+    },
+    List {
+        array: &'a FixedSizeListArray,
+        values: &'a Float64Array,
+    },
+}
+
+impl<'a> Points<'a> {
+    fn try_new(array: &'a ArrayRef) -> Result<Self> {
+        let unsupported = || {
+            ArrowError::ComputeError(format!(
+                "Points must be structs of Float64 lat and lon or fixed size lists of 2 \
+                 Float64, got {:?}",
+                array.data_type()
+            ))
+        };
+        let is_float64 = |array: &ArrayRef| array.data_type() == &DataType::Float64;
+        match array.data_type() {
+            DataType::Struct(_) => {
+                let array = array.as_typed::<StructArray>()?;
+                match (array.column_by_name("lat"), array.column_by_name("lon")) {
+                    (Some(lat), Some(lon)) if is_float64(lat) && is_float64(lon) => {
+                        Ok(Points::Struct {
+                            array,
+                            lat: lat.as_typed::<Float64Array>()?,
+                            lon: lon.as_typed::<Float64Array>()?,
+                        })
+                    }
+                    _ => Err(unsupported()),
+                }
+            }
+            DataType::FixedSizeList(field, 2)
+                if field.data_type() == &DataType::Float64 =>
+            {
+                let array = array.as_typed::<FixedSizeListArray>()?;
+                let values = array.values_ref().as_typed::<Float64Array>()?;
+                Ok(Points::List { array, values })
+            }
+            _ => Err(unsupported()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Points::Struct { array, .. } => array.len(),
+            Points::List { array, .. } => array.len(),
+        }
+    }
+
+    /// Returns the `i`-th point, or `None` if it or any of its coordinates is null
+    fn point(&self, i: usize) -> Option<Point> {
+        let (array, lat, lon, lat_index, lon_index): (&Array, _, _, _, _) = match self {
+            Points::Struct { array, lat, lon } => (*array, lat, lon, i, i),
+            Points::List { array, values } => {
+                let offset = array.value_offset(i) as usize;
+                (*array, values, values, offset, offset + 1)
+            }
+        };
+        if array.is_valid(i) && lat.is_valid(lat_index) && lon.is_valid(lon_index) {
+            Some(Point::new(lat.value(lat_index), lon.value(lon_index)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the great-circle distance in meters between each point of `points` and `to`,
+/// as computed by [`Point::haversine_distance`]. The distance of a null point is null.
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use std::sync::Arc;
+/// use arrow::array::*;
+/// use arrow::compute::kernels::geo::{haversine_distance, Point};
+///
+/// let points: ArrayRef = Arc::new(
+///     StructArray::try_from(vec![
+///         ("lat", Arc::new(Float64Array::from(vec![48.8566, 51.5074])) as ArrayRef),
+///         ("lon", Arc::new(Float64Array::from(vec![2.3522, -0.1278])) as ArrayRef),
+///     ])
+///     .unwrap(),
+/// );
+/// let paris = Point::new(48.8566, 2.3522);
+/// let distances = haversine_distance(&points, &paris).unwrap();
+/// assert_eq!(0.0, distances.value(0));
+/// assert!((distances.value(1) - 343_560.0).abs() < 100.0);
+/// ```
+pub fn haversine_distance(points: &ArrayRef, to: &Point) -> Result<Float64Array> {
+    let points = Points::try_new(points)?;
+    Ok((0..points.len())
+        .map(|i| points.point(i).map(|p| p.haversine_distance(to)))
+        .collect())
+}
+
+/// Returns whether each point of `points` is within `bounding_box`, as computed by
+/// [`BoundingBox::contains`], such as to `filter` them. The result is null for null
+/// points.
+pub fn within_bounding_box(
+    points: &ArrayRef,
+    bounding_box: &BoundingBox,
+) -> Result<BooleanArray> {
+    let points = Points::try_new(points)?;
+    Ok((0..points.len())
+        .map(|i| points.point(i).map(|p| bounding_box.contains(&p)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::TryFrom;
+    use std::sync::Arc;
+
+    use crate::datatypes::Field;
+
+    const PARIS: Point = Point {
+        lat: 48.8566,
+        lon: 2.3522,
+    };
+    const LONDON: Point = Point {
+        lat: 51.5074,
+        lon: -0.1278,
+    };
+    const FIJI: Point = Point {
+        lat: -17.7134,
+        lon: 178.065,
+    };
+
+    fn struct_points(points: Vec<Option<Point>>) -> ArrayRef {
+        let lat: Float64Array = points.iter().map(|p| p.map(|p| p.lat)).collect();
+        let lon: Float64Array = points.iter().map(|p| p.map(|p| p.lon)).collect();
+        Arc::new(
+            StructArray::try_from(vec![
+                ("lon", Arc::new(lon) as ArrayRef),
+                ("lat", Arc::new(lat) as ArrayRef),
+            ])
+            .unwrap(),
+        )
+    }
+
+    fn list_points(points: Vec<Option<Point>>) -> ArrayRef {
+        let mut builder = FixedSizeListBuilder::new(Float64Builder::new(0), 2);
+        for point in points {
+            match point {
+                Some(p) => {
+                    builder.values().append_slice(&[p.lat, p.lon]).unwrap();
+                    builder.append(true).unwrap();
+                }
+                None => {
+                    builder.values().append_slice(&[0.0, 0.0]).unwrap();
+                    builder.append(false).unwrap();
+                }
+            }
+        }
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn test_haversine_distance() {
+        assert_eq!(0.0, PARIS.haversine_distance(&PARIS));
+        let d = PARIS.haversine_distance(&LONDON);
+        assert!((d - 343_560.0).abs() < 100.0, "{}", d);
+        assert_eq!(d, LONDON.haversine_distance(&PARIS));
+        // half of the circumference between antipodes
+        let d = Point::new(0.0, 0.0).haversine_distance(&Point::new(0.0, 180.0));
+        assert!((d - std::f64::consts::PI * EARTH_RADIUS_METERS).abs() < 1e-6);
+
+        let points = vec![Some(PARIS), None, Some(LONDON), Some(FIJI)];
+        for array in &[struct_points(points.clone()), list_points(points)] {
+            let distances = haversine_distance(array, &LONDON).unwrap();
+            assert_eq!(4, distances.len());
+            assert!(distances.is_null(1));
+            assert_eq!(0.0, distances.value(2));
+            assert_eq!(PARIS.haversine_distance(&LONDON), distances.value(0));
+
+            // sliced points
+            let sliced = array.slice(2, 2);
+            let distances = haversine_distance(&sliced, &LONDON).unwrap();
+            assert_eq!(0.0, distances.value(0));
+            assert_eq!(FIJI.haversine_distance(&LONDON), distances.value(1));
+        }
+    }
+
+    #[test]
+    fn test_within_bounding_box() {
+        let europe = BoundingBox::new(Point::new(35.0, -10.0), Point::new(60.0, 30.0));
+        // crossing the antimeridian
+        let pacific = BoundingBox::new(Point::new(-30.0, 170.0), Point::new(0.0, -170.0));
+
+        let points = vec![Some(PARIS), Some(FIJI), None, Some(LONDON)];
+        for array in &[struct_points(points.clone()), list_points(points)] {
+            assert_eq!(
+                BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]),
+                within_bounding_box(array, &europe).unwrap()
+            );
+            assert_eq!(
+                BooleanArray::from(vec![Some(false), Some(true), None, Some(false)]),
+                within_bounding_box(array, &pacific).unwrap()
+            );
+        }
+        assert!(pacific.contains(&Point::new(-10.0, -175.0)));
+        assert!(europe.contains(&Point::new(60.0, 30.0)));
+    }
+
+    #[test]
+    fn test_null_coordinates_and_invalid_points() {
+        let array: ArrayRef = Arc::new(
+            StructArray::try_from(vec![
+                (
+                    "lat",
+                    Arc::new(Float64Array::from(vec![Some(1.0), None])) as ArrayRef,
+                ),
+                (
+                    "lon",
+                    Arc::new(Float64Array::from(vec![2.0, 3.0])) as ArrayRef,
+                ),
+            ])
+            .unwrap(),
+        );
+        let distances = haversine_distance(&array, &PARIS).unwrap();
+        assert!(distances.is_valid(0));
+        assert!(distances.is_null(1));
+
+        let array: ArrayRef = Arc::new(
+            StructArray::try_from(vec![
+                ("lat", Arc::new(Float64Array::from(vec![1.0])) as ArrayRef),
+                ("lng", Arc::new(Float64Array::from(vec![2.0])) as ArrayRef),
+            ])
+            .unwrap(),
+        );
+        assert!(haversine_distance(&array, &PARIS).is_err());
+
+        let array: ArrayRef = Arc::new(Float64Array::from(vec![1.0]));
+        assert!(haversine_distance(&array, &PARIS).is_err());
+
+        let data = ArrayData::builder(DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Float64, true)),
+            3,
+        ))
+        .len(1)
+        .add_child_data(Float64Array::from(vec![1.0, 2.0, 3.0]).data())
+        .build();
+        let array: ArrayRef = Arc::new(FixedSizeListArray::from(data));
+        assert!(within_bounding_box(&array, &BoundingBox::new(PARIS, LONDON)).is_err());
+    }
+}
@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to assign the values of numeric arrays to buckets, such as to compute
+//! histograms.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::compute::kernels::aggregate::is_nan;
+use crate::datatypes::{ArrowNativeType, ArrowNumericType, DataType, ToByteSlice};
+use crate::error::{ArrowError, Result};
+
+/// Checks that the boundaries are strictly increasing and not NaN
+fn check_boundaries<T: ArrowNativeType + PartialOrd>(boundaries: &[T]) -> Result<()> {
+    if boundaries.iter().any(|b| is_nan(*b))
+        || boundaries.windows(2).any(|w| w[0] >= w[1])
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Bucket boundaries must be strictly increasing, got {:?}",
+            boundaries
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the bucket of `value`, see `bucketize`
+#[inline]
+fn bucket_of<T: ArrowNativeType + PartialOrd>(value: T, boundaries: &[T]) -> usize {
+    if is_nan(value) {
+        boundaries.len()
+    } else {
+        boundaries.partition_point(|b| *b <= value)
+    }
+}
+
+/// Assigns each value of `array` to a bucket delimited by `boundaries`, which must be
+/// strictly increasing. The `boundaries.len() + 1` buckets are numbered from 0: bucket
+/// `i` holds the values `v` such that `boundaries[i - 1] <= v < boundaries[i]`, where
+/// bucket 0 has no lower bound and the last bucket no upper bound. NaN values are in the
+/// last bucket. If a value is null then its bucket is also null.
+///
+/// Boundaries at quantiles of the values, such as those returned by
+/// `aggregate::quantile`, give buckets of roughly equal counts.
+///
+/// ```
+/// use arrow::array::{Int32Array, UInt32Array};
+/// use arrow::compute::kernels::histogram::bucketize;
+///
+/// let array = Int32Array::from(vec![Some(-3), Some(0), None, Some(5), Some(10)]);
+/// let buckets = bucketize(&array, &[0, 10]).unwrap();
+/// assert_eq!(
+///     UInt32Array::from(vec![Some(0), Some(1), None, Some(1), Some(2)]),
+///     buckets
+/// );
+/// ```
+pub fn bucketize<T>(
+    array: &PrimitiveArray<T>,
+    boundaries: &[T::Native],
+) -> Result<UInt32Array>
+where
+    T: ArrowNumericType,
+{
+    check_boundaries(boundaries)?;
+
+    let buckets: Vec<u32> = (0..array.len())
+        .map(|i| {
+            if array.is_valid(i) {
+                bucket_of(array.value(i), boundaries) as u32
+            } else {
+                0
+            }
+        })
+        .collect();
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        DataType::UInt32,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(buckets.to_byte_slice())],
+        vec![],
+    );
+    Ok(UInt32Array::from(Arc::new(data)))
+}
+
+/// Returns the number of values of `array` in each of the buckets delimited by
+/// `boundaries`, as assigned by [`bucketize`], i.e. the histogram of the values. Null
+/// values are not counted.
+pub fn bucket_counts<T>(
+    array: &PrimitiveArray<T>,
+    boundaries: &[T::Native],
+) -> Result<UInt64Array>
+where
+    T: ArrowNumericType,
+{
+    check_boundaries(boundaries)?;
+
+    let mut counts = vec![0u64; boundaries.len() + 1];
+    (0..array.len())
+        .filter(|i| array.is_valid(*i))
+        .for_each(|i| counts[bucket_of(array.value(i), boundaries)] += 1);
+    Ok(UInt64Array::from(counts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucketize() {
+        let a = Float64Array::from(vec![
+            Some(1.0),
+            Some(-1.5),
+            None,
+            Some(2.0),
+            Some(f64::NAN),
+            Some(7.5),
+            Some(3.0),
+        ]);
+        let boundaries = [0.0, 2.0, 3.0];
+        let expected = UInt32Array::from(vec![
+            Some(1),
+            Some(0),
+            None,
+            Some(2),
+            Some(3),
+            Some(3),
+            Some(3),
+        ]);
+        assert_eq!(expected, bucketize(&a, &boundaries).unwrap());
+        assert_eq!(
+            UInt64Array::from(vec![1, 1, 1, 3]),
+            bucket_counts(&a, &boundaries).unwrap()
+        );
+
+        // a single bucket without boundaries
+        assert_eq!(UInt64Array::from(vec![6]), bucket_counts(&a, &[]).unwrap());
+    }
+
+    #[test]
+    fn test_bucketize_sliced() {
+        let a = UInt8Array::from(vec![Some(200), None, Some(10), Some(100), Some(99)]);
+        let a = a.slice(1, 4);
+        let a = a.as_any().downcast_ref::<UInt8Array>().unwrap();
+
+        let expected = UInt32Array::from(vec![None, Some(0), Some(2), Some(1)]);
+        assert_eq!(expected, bucketize(a, &[50, 100]).unwrap());
+        assert_eq!(
+            UInt64Array::from(vec![1, 1, 1]),
+            bucket_counts(a, &[50, 100]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bucketize_invalid_boundaries() {
+        let a = Int32Array::from(vec![1, 2, 3]);
+        assert!(bucketize(&a, &[1, 1]).is_err());
+        assert!(bucket_counts(&a, &[2, 1]).is_err());
+
+        let a = Float32Array::from(vec![1.0]);
+        assert!(bucketize(&a, &[0.0, f32::NAN]).is_err());
+    }
+}
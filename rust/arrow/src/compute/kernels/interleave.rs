@@ -0,0 +1,170 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the interleave kernel, which gathers values from several arrays.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::util::unify_dictionaries;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// Returns an array of the values of `values` at `indices`, where each index is the
+/// index of an array of `values` followed by the index of a value in that array.
+///
+/// This is a [`take`](crate::compute::kernels::take::take) from multiple arrays, used
+/// e.g. to merge sorted arrays. Consecutive values of an array are copied at once.
+/// Dictionary arrays of different dictionaries are interleaved into an array of the
+/// union of their dictionaries.
+///
+/// # Errors
+/// This function errors if `values` is empty, if the arrays are of different data types
+/// or of a nested type other than lists and structs, or if an index is out of bounds.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::kernels::interleave::interleave;
+/// use arrow::error::Result;
+/// # fn main() -> Result<()> {
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let b: ArrayRef = Arc::new(Int32Array::from(vec![None, Some(5)]));
+/// let interleaved = interleave(&[a, b], &[(0, 0), (1, 1), (0, 2), (1, 0)])?;
+/// assert_eq!(
+///     interleaved.as_typed::<Int32Array>()?,
+///     &Int32Array::from(vec![Some(1), Some(5), Some(3), None])
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn interleave(values: &[ArrayRef], indices: &[(usize, usize)]) -> Result<ArrayRef> {
+    let data_type = match values.first() {
+        Some(array) => array.data_type(),
+        None => {
+            return Err(ArrowError::InvalidArgumentError(
+                "interleave requires input of at least one array".to_string(),
+            ))
+        }
+    };
+    if values.iter().any(|array| array.data_type() != data_type) {
+        return Err(ArrowError::InvalidArgumentError(
+            "It is not possible to interleave arrays of different data types."
+                .to_string(),
+        ));
+    }
+    if let Some((array, index)) = indices
+        .iter()
+        .find(|(array, index)| *array >= values.len() || *index >= values[*array].len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Index ({}, {}) out of bounds of the arrays to interleave",
+            array, index
+        )));
+    }
+    match data_type {
+        DataType::Null => return Ok(Arc::new(NullArray::new(indices.len()))),
+        DataType::Float16 | DataType::FixedSizeList(_, _) | DataType::Union(_) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Interleave is not supported for arrays of type {:?}",
+                data_type
+            )))
+        }
+        _ => {}
+    }
+
+    let values = unify_dictionaries(values)?;
+    let sources = values
+        .iter()
+        .map(|array| array.data_ref().as_ref())
+        .collect::<Vec<_>>();
+    let mut mutable = MutableArrayData::new(sources, false, indices.len());
+
+    let mut runs = indices.iter();
+    if let Some(&(first_array, first_index)) = runs.next() {
+        let (mut array, mut start, mut end) = (first_array, first_index, first_index + 1);
+        for &(next_array, next_index) in runs {
+            if next_array == array && next_index == end {
+                end += 1;
+            } else {
+                mutable.extend(array, start, end);
+                array = next_array;
+                start = next_index;
+                end = next_index + 1;
+            }
+        }
+        mutable.extend(array, start, end);
+    }
+    Ok(make_array(Arc::new(mutable.freeze())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::datatypes::Int16Type;
+
+    #[test]
+    fn test_interleave_strings_and_slices() {
+        let a =
+            StringArray::from(vec![Some("a"), None, Some("c"), Some("d")]).slice(1, 3);
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y"]));
+        let interleaved =
+            interleave(&[a, b], &[(1, 1), (0, 1), (0, 2), (0, 0), (1, 1)]).unwrap();
+        assert_eq!(
+            interleaved.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![Some("y"), Some("c"), Some("d"), None, Some("y")])
+        );
+
+        let empty = interleave(&[interleaved], &[]).unwrap();
+        assert_eq!(0, empty.len());
+        assert_eq!(&DataType::Utf8, empty.data_type());
+    }
+
+    #[test]
+    fn test_interleave_dictionaries() {
+        let a: DictionaryArray<Int16Type> = vec!["a", "b"].into_iter().collect();
+        let b: DictionaryArray<Int16Type> = vec![Some("c"), None].into_iter().collect();
+        let interleaved = interleave(
+            &[Arc::new(a) as ArrayRef, Arc::new(b)],
+            &[(1, 0), (0, 1), (1, 1), (0, 0)],
+        )
+        .unwrap();
+        let interleaved = interleaved
+            .as_typed::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        let values = interleaved.values();
+        let values = values.as_typed::<StringArray>().unwrap();
+        let strings = interleaved
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as usize)))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some("c"), Some("b"), None, Some("a")], strings);
+    }
+
+    #[test]
+    fn test_interleave_errors() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        assert!(interleave(&[], &[]).is_err());
+        assert!(interleave(&[a.clone(), b], &[(0, 0)]).is_err());
+        assert!(interleave(std::slice::from_ref(&a), &[(0, 1)]).is_err());
+        assert!(interleave(&[a], &[(1, 0)]).is_err());
+    }
+}
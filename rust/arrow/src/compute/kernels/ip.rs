@@ -0,0 +1,346 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to parse and format IP addresses stored in FixedSizeBinary(4) (IPv4)
+//! and FixedSizeBinary(16) (IPv6) arrays, and to check whether they belong to a network
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+const IPV4_SIZE: usize = 4;
+const IPV6_SIZE: usize = 16;
+
+fn generic_parse_ip<OffsetSize, F>(
+    array: &GenericStringArray<OffsetSize>,
+    size: usize,
+    parse: F,
+) -> Result<FixedSizeBinaryArray>
+where
+    OffsetSize: StringOffsetSizeTrait,
+    F: Fn(&str, &mut [u8]) -> Option<()>,
+{
+    let mut values = vec![0u8; array.len() * size];
+    for (i, output) in values.chunks_mut(size).enumerate() {
+        if array.is_valid(i) {
+            parse(array.value(i), output).ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Cannot parse '{}' as an IPv{} address",
+                    array.value(i),
+                    if size == IPV4_SIZE { 4 } else { 6 }
+                ))
+            })?;
+        }
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        DataType::FixedSizeBinary(size as i32),
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(&values[..])],
+        vec![],
+    );
+    Ok(FixedSizeBinaryArray::from(Arc::new(data)))
+}
+
+fn parse_ip<F>(array: &Array, size: usize, parse: F) -> Result<FixedSizeBinaryArray>
+where
+    F: Fn(&str, &mut [u8]) -> Option<()>,
+{
+    match array.data_type() {
+        DataType::Utf8 => generic_parse_ip(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+            size,
+            parse,
+        ),
+        DataType::LargeUtf8 => generic_parse_ip(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+            size,
+            parse,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "Parsing IP addresses does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Parses the IPv4 addresses of a \[Large\]String array in dotted-quad notation, such
+/// as `192.168.0.1`, into a FixedSizeBinary(4) array of addresses in network byte
+/// order. Nulls are preserved.
+///
+/// This function errors when a value is not a valid IPv4 address, or when `array` is
+/// not a \[Large\]String array.
+pub fn parse_ipv4(array: &Array) -> Result<FixedSizeBinaryArray> {
+    parse_ip(array, IPV4_SIZE, |value, output| {
+        let address = value.parse::<Ipv4Addr>().ok()?;
+        output.copy_from_slice(&address.octets());
+        Some(())
+    })
+}
+
+/// Parses the IPv6 addresses of a \[Large\]String array, such as `2001:db8::1`, into a
+/// FixedSizeBinary(16) array of addresses in network byte order. Nulls are preserved.
+///
+/// This function errors when a value is not a valid IPv6 address, or when `array` is
+/// not a \[Large\]String array.
+pub fn parse_ipv6(array: &Array) -> Result<FixedSizeBinaryArray> {
+    parse_ip(array, IPV6_SIZE, |value, output| {
+        let address = value.parse::<Ipv6Addr>().ok()?;
+        output.copy_from_slice(&address.octets());
+        Some(())
+    })
+}
+
+/// Returns the IP address of `value`, whose length must be 4 or 16 bytes
+fn ip_addr(value: &[u8]) -> IpAddr {
+    if value.len() == IPV4_SIZE {
+        IpAddr::V4(Ipv4Addr::new(value[0], value[1], value[2], value[3]))
+    } else {
+        let mut octets = [0u8; IPV6_SIZE];
+        octets.copy_from_slice(value);
+        IpAddr::V6(Ipv6Addr::from(octets))
+    }
+}
+
+fn check_ip_array(array: &FixedSizeBinaryArray, kernel: &str) -> Result<()> {
+    match array.value_length() as usize {
+        IPV4_SIZE | IPV6_SIZE => Ok(()),
+        _ => Err(ArrowError::ComputeError(format!(
+            "{} does not support type {:?}",
+            kernel,
+            array.data_type()
+        ))),
+    }
+}
+
+/// Formats the IP addresses of a FixedSizeBinary(4) or FixedSizeBinary(16) array.
+/// IPv4 addresses are formatted in dotted-quad notation, and IPv6 addresses in the
+/// canonical text representation of RFC 5952, such as `2001:db8::1`. Nulls are
+/// preserved.
+///
+/// This function errors when the values of `array` are neither 4 nor 16 bytes long.
+pub fn format_ip(array: &FixedSizeBinaryArray) -> Result<StringArray> {
+    check_ip_array(array, "format_ip")?;
+    Ok((0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                None
+            } else {
+                Some(ip_addr(array.value(i)).to_string())
+            }
+        })
+        .collect())
+}
+
+/// Parses a network in CIDR notation, such as `10.0.0.0/8` or `2001:db8::/32`, into its
+/// address and prefix length
+fn parse_cidr(cidr: &str) -> Result<(IpAddr, u32)> {
+    let invalid = || {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid network in CIDR notation '{}'",
+            cidr
+        ))
+    };
+    let mut parts = cidr.splitn(2, '/');
+    let address = parts
+        .next()
+        .and_then(|address| address.parse::<IpAddr>().ok())
+        .ok_or_else(invalid)?;
+    let prefix_len = parts
+        .next()
+        .and_then(|prefix_len| prefix_len.parse::<u32>().ok())
+        .ok_or_else(invalid)?;
+    let max_prefix_len = match address {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    };
+    if prefix_len > max_prefix_len {
+        return Err(invalid());
+    }
+    Ok((address, prefix_len))
+}
+
+/// Returns whether the first `prefix_len` bits of `left` and `right` are equal
+fn prefix_eq(left: &[u8], right: &[u8], prefix_len: u32) -> bool {
+    let full_bytes = (prefix_len / 8) as usize;
+    if left[..full_bytes] != right[..full_bytes] {
+        return false;
+    }
+    let remaining_bits = prefix_len % 8;
+    if remaining_bits == 0 {
+        return true;
+    }
+    let mask = !(0xffu8 >> remaining_bits);
+    left[full_bytes] & mask == right[full_bytes] & mask
+}
+
+/// Returns whether the IP addresses of a FixedSizeBinary(4) or FixedSizeBinary(16)
+/// array belong to the network `cidr`, in CIDR notation such as `10.0.0.0/8` or
+/// `2001:db8::/32`. Addresses of a different IP version than the network's never belong
+/// to it. Nulls are preserved.
+///
+/// This function errors when `cidr` is invalid, or when the values of `array` are
+/// neither 4 nor 16 bytes long.
+pub fn cidr_contains(array: &FixedSizeBinaryArray, cidr: &str) -> Result<BooleanArray> {
+    check_ip_array(array, "cidr_contains")?;
+    let (network, prefix_len) = parse_cidr(cidr)?;
+    let network = match network {
+        IpAddr::V4(address) => address.octets().to_vec(),
+        IpAddr::V6(address) => address.octets().to_vec(),
+    };
+    let same_version = network.len() == array.value_length() as usize;
+
+    Ok((0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                None
+            } else {
+                Some(same_version && prefix_eq(array.value(i), &network, prefix_len))
+            }
+        })
+        .collect::<Vec<_>>()
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_format_ipv4() {
+        let array = StringArray::from(vec![
+            Some("192.168.0.1"),
+            None,
+            Some("0.0.0.0"),
+            Some("255.255.255.255"),
+        ]);
+        let addresses = parse_ipv4(&array).unwrap();
+        assert_eq!(&DataType::FixedSizeBinary(4), addresses.data_type());
+        assert_eq!(1, addresses.null_count());
+        assert_eq!(&[192, 168, 0, 1], addresses.value(0));
+        assert_eq!(array, format_ip(&addresses).unwrap());
+
+        for value in &["192.168.0", "256.0.0.1", "::1", "a.b.c.d"] {
+            let array = StringArray::from(vec![*value]);
+            assert!(parse_ipv4(&array).is_err(), "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_parse_format_ipv6() {
+        let array = LargeStringArray::from(vec![
+            Some("2001:DB8:0:0:0:0:0:1"),
+            Some("::ffff:192.0.2.1"),
+            None,
+            Some("FE80:0:0:0:1:0:0:0"),
+        ]);
+        let addresses = parse_ipv6(&array).unwrap();
+        assert_eq!(&DataType::FixedSizeBinary(16), addresses.data_type());
+        assert_eq!(
+            &[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            addresses.value(0)
+        );
+
+        let expected = StringArray::from(vec![
+            Some("2001:db8::1"),
+            Some("::ffff:192.0.2.1"),
+            None,
+            Some("fe80::1:0:0:0"),
+        ]);
+        assert_eq!(expected, format_ip(&addresses).unwrap());
+
+        let array = StringArray::from(vec!["2001:db8::1::1"]);
+        assert!(parse_ipv6(&array).is_err());
+        assert!(parse_ipv6(&Int32Array::from(vec![1])).is_err());
+        let array = FixedSizeBinaryArray::from(vec![vec![1; 6]]);
+        assert!(format_ip(&array).is_err());
+    }
+
+    #[test]
+    fn test_cidr_contains() {
+        let array = StringArray::from(vec![
+            Some("10.1.2.3"),
+            Some("10.255.0.0"),
+            Some("11.0.0.0"),
+            None,
+            Some("192.168.1.200"),
+        ]);
+        let addresses = parse_ipv4(&array).unwrap();
+
+        let result = cidr_contains(&addresses, "10.0.0.0/8").unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(false),
+        ]);
+        assert_eq!(expected, result);
+
+        let result = cidr_contains(&addresses, "192.168.1.128/25").unwrap();
+        let expected = BooleanArray::from(vec![
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            Some(true),
+        ]);
+        assert_eq!(expected, result);
+
+        let result = cidr_contains(&addresses, "0.0.0.0/0").unwrap();
+        assert_eq!(4, result.iter().filter(|v| *v == Some(true)).count());
+
+        let result = cidr_contains(&addresses, "::/0").unwrap();
+        assert_eq!(0, result.iter().filter(|v| *v == Some(true)).count());
+
+        for cidr in &["10.0.0.0", "10.0.0.0/33", "10.0.0/8", "::/129"] {
+            assert!(cidr_contains(&addresses, cidr).is_err(), "{}", cidr);
+        }
+    }
+
+    #[test]
+    fn test_cidr_contains_ipv6_sliced() {
+        let array =
+            StringArray::from(vec!["2001:db8::1", "2001:db9::1", "2001:db8:ffff::"]);
+        let addresses = parse_ipv6(&array).unwrap();
+        let addresses = addresses.slice(1, 2);
+        let addresses = addresses
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let result = cidr_contains(addresses, "2001:db8::/32").unwrap();
+        assert_eq!(BooleanArray::from(vec![false, true]), result);
+    }
+}
@@ -0,0 +1,307 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to extract values from \[Large\]StringArrays containing JSON documents
+
+use std::sync::Arc;
+
+use serde_json::Value;
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A step of a JSON path
+#[derive(Debug, PartialEq)]
+enum PathStep {
+    /// The member of an object with the given key
+    Key(String),
+    /// The element of an array at the given index
+    Index(usize),
+}
+
+/// Parses a JSON path such as `$.a.b[0]` or `$['a']["b"][0]` into its steps
+fn parse_path(path: &str) -> Result<Vec<PathStep>> {
+    let invalid = |reason: &str| {
+        ArrowError::InvalidArgumentError(format!(
+            "Invalid JSON path '{}': {}",
+            path, reason
+        ))
+    };
+
+    let mut chars = path.chars().peekable();
+    if chars.next() != Some('$') {
+        return Err(invalid("it must start with '$'"));
+    }
+
+    let mut steps = vec![];
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => {
+                let mut key = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break;
+                    }
+                    key.push(c);
+                    chars.next();
+                }
+                if key.is_empty() {
+                    return Err(invalid("empty key after '.'"));
+                }
+                steps.push(PathStep::Key(key));
+            }
+            '[' => match chars.peek() {
+                Some(&quote) if quote == '\'' || quote == '"' => {
+                    chars.next();
+                    let mut key = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(c) if c == quote => break,
+                            Some(c) => key.push(c),
+                            None => return Err(invalid("unterminated quoted key")),
+                        }
+                    }
+                    if chars.next() != Some(']') {
+                        return Err(invalid("expected ']' after quoted key"));
+                    }
+                    steps.push(PathStep::Key(key));
+                }
+                _ => {
+                    let mut index = String::new();
+                    loop {
+                        match chars.next() {
+                            Some(']') => break,
+                            Some(c) => index.push(c),
+                            None => return Err(invalid("unterminated index")),
+                        }
+                    }
+                    let index = index.trim().parse::<usize>().map_err(|_| {
+                        invalid("array indices must be non-negative integers")
+                    })?;
+                    steps.push(PathStep::Index(index));
+                }
+            },
+            c => return Err(invalid(&format!("unexpected character '{}'", c))),
+        }
+    }
+    Ok(steps)
+}
+
+/// Returns the value at the given path of a JSON document, if the document is valid JSON
+/// and has a value at this path
+fn extract_value(document: &str, path: &[PathStep]) -> Option<Value> {
+    let mut value: Value = serde_json::from_str(document).ok()?;
+    for step in path {
+        value = match (step, value) {
+            (PathStep::Key(key), Value::Object(mut map)) => map.remove(key)?,
+            (PathStep::Index(index), Value::Array(mut array)) if *index < array.len() => {
+                array.swap_remove(*index)
+            }
+            _ => return None,
+        };
+    }
+    Some(value)
+}
+
+fn generic_json_extract<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    path: &[PathStep],
+    data_type: &DataType,
+) -> Result<ArrayRef> {
+    let values = (0..array.len()).map(|i| {
+        if array.is_null(i) {
+            None
+        } else {
+            extract_value(array.value(i), path)
+        }
+    });
+
+    Ok(match data_type {
+        DataType::Utf8 => Arc::new(
+            values
+                .map(|value| match value? {
+                    Value::Null => None,
+                    Value::String(s) => Some(s),
+                    value => Some(value.to_string()),
+                })
+                .collect::<StringArray>(),
+        ),
+        DataType::Boolean => Arc::new(
+            values
+                .map(|value| value?.as_bool())
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect::<BooleanArray>(),
+        ),
+        DataType::Int64 => Arc::new(Int64Array::from(
+            values.map(|value| value?.as_i64()).collect::<Vec<_>>(),
+        )),
+        DataType::Float64 => Arc::new(Float64Array::from(
+            values.map(|value| value?.as_f64()).collect::<Vec<_>>(),
+        )),
+        t => {
+            return Err(ArrowError::ComputeError(format!(
+                "json_extract does not support extracting values as {:?}",
+                t
+            )))
+        }
+    })
+}
+
+/// Extracts the values at `path` of the JSON documents of a \[Large\]String array, as an
+/// array of the given `data_type`.
+///
+/// The path starts with `$`, which refers to the whole document, followed by object keys,
+/// such as `.a` or `['a']`, and array indices, such as `[0]`. For example, `$.a.b[0]`
+/// extracts `1` from `{"a": {"b": [1, 2]}}`.
+///
+/// The supported data types are:
+/// * `Utf8`: strings are extracted as is, while other values, including objects and
+///   arrays, are extracted as their JSON representation
+/// * `Boolean`, `Int64` and `Float64`: values of the corresponding JSON types
+///
+/// The result is null when the document is null or not valid JSON, when it has no value
+/// at `path`, or when that value is `null` or can't be represented as `data_type`.
+///
+/// This function errors when `path` is invalid, when `data_type` is not supported or when
+/// `array` is not a \[Large\]String array.
+pub fn json_extract_as(
+    array: &Array,
+    path: &str,
+    data_type: &DataType,
+) -> Result<ArrayRef> {
+    let path = parse_path(path)?;
+    match array.data_type() {
+        DataType::Utf8 => generic_json_extract(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+            &path,
+            data_type,
+        ),
+        DataType::LargeUtf8 => generic_json_extract(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+            &path,
+            data_type,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "json_extract does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Extracts the values at `path` of the JSON documents of a \[Large\]String array, as a
+/// `Utf8` array. See [`json_extract_as`] for details.
+pub fn json_extract(array: &Array, path: &str) -> Result<ArrayRef> {
+    json_extract_as(array, path, &DataType::Utf8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn documents() -> StringArray {
+        StringArray::from(vec![
+            Some(r#"{"a": {"b": [1, 2.5], "c": "x"}, "d": true}"#),
+            None,
+            Some(r#"{"a": {"b": [false], "c": {"e": null}}}"#),
+            Some("not json"),
+            Some(r#"{"a b": 3}"#),
+        ])
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            vec![
+                PathStep::Key("a".to_string()),
+                PathStep::Key("b c".to_string()),
+                PathStep::Index(2),
+                PathStep::Key("d".to_string()),
+            ],
+            parse_path("$.a['b c'][2].d").unwrap()
+        );
+        assert!(parse_path("$").unwrap().is_empty());
+
+        for path in &["a.b", "$.", "$[-1]", "$['a'", "$[0", "$a"] {
+            assert!(parse_path(path).is_err(), "{}", path);
+        }
+    }
+
+    #[test]
+    fn test_json_extract_utf8() {
+        let array = documents();
+        let result = json_extract(&array, "$.a.c").unwrap();
+        let expected =
+            StringArray::from(vec![Some("x"), None, Some(r#"{"e":null}"#), None, None]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<StringArray>().unwrap()
+        );
+
+        let result = json_extract(&array, "$['a b']").unwrap();
+        let expected = StringArray::from(vec![None, None, None, None, Some("3")]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<StringArray>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_extract_typed() {
+        let array: ArrayRef = Arc::new(documents());
+        let array = array.slice(0, 3);
+
+        let result =
+            json_extract_as(array.as_ref(), "$.a.b[1]", &DataType::Float64).unwrap();
+        let expected = Float64Array::from(vec![Some(2.5), None, None]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<Float64Array>().unwrap()
+        );
+
+        let result =
+            json_extract_as(array.as_ref(), "$.a.b[0]", &DataType::Int64).unwrap();
+        let expected = Int64Array::from(vec![Some(1), None, None]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<Int64Array>().unwrap()
+        );
+
+        let result =
+            json_extract_as(array.as_ref(), "$.a.b[0]", &DataType::Boolean).unwrap();
+        let expected = BooleanArray::from(vec![None, None, Some(false)]);
+        assert_eq!(
+            &expected,
+            result.as_any().downcast_ref::<BooleanArray>().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_json_extract_errors() {
+        let array = documents();
+        assert!(json_extract(&array, "a").is_err());
+        assert!(json_extract_as(&array, "$", &DataType::Int8).is_err());
+        assert!(json_extract(&Int32Array::from(vec![1]), "$").is_err());
+    }
+}
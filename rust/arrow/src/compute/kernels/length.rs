@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel for length of a string array
+//! Defines kernels for the length of the values of string and binary arrays
 
 use crate::datatypes::ToByteSlice;
 use crate::{array::*, buffer::Buffer};
@@ -23,29 +23,23 @@ use crate::{
     datatypes::DataType,
     error::{ArrowError, Result},
 };
+use num::CheckedMul;
 use std::sync::Arc;
 
-fn length_string<OffsetSize>(array: &Array, data_type: DataType) -> Result<ArrayRef>
+/// Returns an array of the lengths in `lengths`, of type `data_type`, with the nulls of
+/// `array`
+fn lengths_array<OffsetSize>(
+    array: &Array,
+    data_type: DataType,
+    lengths: Vec<OffsetSize>,
+) -> ArrayRef
 where
     OffsetSize: OffsetSizeTrait,
 {
-    // note: offsets are stored as u8, but they can be interpreted as OffsetSize
-    let offsets = array.data_ref().clone().buffers()[0].clone();
-    // this is a 30% improvement over iterating over u8s and building OffsetSize, which
-    // justifies the usage of `unsafe`.
-    let slice: &[OffsetSize] =
-        &unsafe { offsets.typed_data::<OffsetSize>() }[array.offset()..];
-
-    let lengths: Vec<OffsetSize> = slice
-        .windows(2)
-        .map(|offset| offset[1] - offset[0])
-        .collect();
-
     let null_bit_buffer = array
         .data_ref()
-        .null_bitmap()
-        .as_ref()
-        .map(|b| b.bits.clone());
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
 
     let data = ArrayData::new(
         data_type,
@@ -56,18 +50,78 @@ where
         vec![Buffer::from(lengths.to_byte_slice())],
         vec![],
     );
-    Ok(make_array(Arc::new(data)))
+    make_array(Arc::new(data))
+}
+
+/// Applies `op` to the length in bytes of each value of a string or binary `array`
+fn unary_offsets<OffsetSize, F>(
+    array: &Array,
+    data_type: DataType,
+    op: F,
+) -> Result<ArrayRef>
+where
+    OffsetSize: OffsetSizeTrait,
+    F: Fn(OffsetSize) -> Result<OffsetSize>,
+{
+    // note: offsets are stored as u8, but they can be interpreted as OffsetSize
+    let offsets = array.data_ref().clone().buffers()[0].clone();
+    // this is a 30% improvement over iterating over u8s and building OffsetSize, which
+    // justifies the usage of `unsafe`.
+    let slice: &[OffsetSize] = &unsafe { offsets.typed_data::<OffsetSize>() }
+        [array.offset()..array.offset() + array.len() + 1];
+
+    let lengths = slice
+        .windows(2)
+        .map(|offset| op(offset[1] - offset[0]))
+        .collect::<Result<Vec<OffsetSize>>>()?;
+
+    Ok(lengths_array(array, data_type, lengths))
 }
 
-/// Returns an array of Int32/Int64 denoting the number of characters in each string in the array.
+/// Returns the number of bits of `len` bytes, or an error if it overflows `T`
+fn bits<T: OffsetSizeTrait + CheckedMul>(len: T) -> Result<T> {
+    len.checked_mul(&T::from_usize(8).unwrap())
+        .ok_or_else(|| ArrowError::ArithmeticOverflow(format!("{:?} * 8", len)))
+}
+
+fn char_length_string<OffsetSize>(array: &Array, data_type: DataType) -> ArrayRef
+where
+    OffsetSize: StringOffsetSizeTrait,
+{
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .expect("A string is expected");
+    let lengths: Vec<OffsetSize> = (0..array.len())
+        .map(|i| {
+            let chars = if array.is_valid(i) {
+                array.value(i).chars().count()
+            } else {
+                0
+            };
+            // a value has at most as many characters as bytes, so that this fits
+            OffsetSize::from_usize(chars).unwrap()
+        })
+        .collect();
+
+    lengths_array(array, data_type, lengths)
+}
+
+/// Returns an array of Int32/Int64 denoting the number of bytes in each value in the
+/// array.
 ///
-/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
+/// * this only accepts StringArray/Utf8, LargeString/LargeUtf8, BinaryArray/Binary and
+///   LargeBinaryArray/LargeBinary
 /// * length of null is null.
-/// * length is in number of bytes
+/// * length is in number of bytes, see [`char_length`] for the number of characters
 pub fn length(array: &Array) -> Result<ArrayRef> {
     match array.data_type() {
-        DataType::Utf8 => length_string::<i32>(array, DataType::Int32),
-        DataType::LargeUtf8 => length_string::<i64>(array, DataType::Int64),
+        DataType::Utf8 | DataType::Binary => {
+            unary_offsets::<i32, _>(array, DataType::Int32, Ok)
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            unary_offsets::<i64, _>(array, DataType::Int64, Ok)
+        }
         _ => Err(ArrowError::ComputeError(format!(
             "length not supported for {:?}",
             array.data_type()
@@ -75,6 +129,44 @@ pub fn length(array: &Array) -> Result<ArrayRef> {
     }
 }
 
+/// Returns an array of Int32/Int64 denoting the number of characters, i.e. of Unicode
+/// scalar values, in each string in the array.
+///
+/// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
+/// * length of null is null.
+pub fn char_length(array: &Array) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(char_length_string::<i32>(array, DataType::Int32)),
+        DataType::LargeUtf8 => Ok(char_length_string::<i64>(array, DataType::Int64)),
+        _ => Err(ArrowError::ComputeError(format!(
+            "char_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Returns an array of Int32/Int64 denoting the number of bits in each value in the
+/// array.
+///
+/// * this only accepts the same types as [`length`]
+/// * length of null is null.
+/// * this errors if the number of bits of a value overflows the Int32/Int64, i.e. for
+///   values of more than `i32::MAX / 8` bytes in Utf8/Binary arrays
+pub fn bit_length(array: &Array) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Utf8 | DataType::Binary => {
+            unary_offsets::<i32, _>(array, DataType::Int32, bits)
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            unary_offsets::<i64, _>(array, DataType::Int64, bits)
+        }
+        _ => Err(ArrowError::ComputeError(format!(
+            "bit_length not supported for {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +265,77 @@ mod tests {
             })
     }
 
+    #[test]
+    fn test_binary() -> Result<()> {
+        let values: Vec<&[u8]> = vec![b"one", b"", b"\x00\xff"];
+        let array = BinaryArray::from(values.clone());
+        let result = length(&array)?;
+        assert_eq!(Int32Array::from(vec![3, 0, 2]).data(), result.data());
+
+        let array = LargeBinaryArray::from(values);
+        let result = length(&array)?;
+        assert_eq!(Int64Array::from(vec![3, 0, 2]).data(), result.data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_length() -> Result<()> {
+        let array =
+            StringArray::from(vec![Some("hello"), None, Some("💖"), Some("héllo")]);
+        let result = char_length(&array)?;
+        let expected = Int32Array::from(vec![Some(5), None, Some(1), Some(5)]);
+        assert_eq!(expected.data(), result.data());
+
+        let array = LargeStringArray::from(vec!["ä", ""]);
+        let result = char_length(&array)?;
+        assert_eq!(Int64Array::from(vec![1, 0]).data(), result.data());
+
+        let array = BinaryArray::from(vec![b"a".as_ref()]);
+        assert!(char_length(&array).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bit_length() -> Result<()> {
+        let array = StringArray::from(vec![Some("hello"), None, Some("💖")]);
+        let result = bit_length(&array)?;
+        let expected = Int32Array::from(vec![Some(40), None, Some(32)]);
+        assert_eq!(expected.data(), result.data());
+
+        let array = LargeBinaryArray::from(vec![b"ab".as_ref()]);
+        let result = bit_length(&array)?;
+        assert_eq!(Int64Array::from(vec![16]).data(), result.data());
+
+        // only the offsets are read, so that the values need not be allocated
+        let len = i32::MAX / 8 + 1;
+        let data = ArrayData::builder(DataType::Binary)
+            .len(1)
+            .add_buffer(Buffer::from([0, len].to_byte_slice()))
+            .add_buffer(Buffer::from(b""))
+            .build();
+        let array = BinaryArray::from(data);
+        assert!(matches!(
+            bit_length(&array),
+            Err(ArrowError::ArithmeticOverflow(_))
+        ));
+        assert!(length(&array).is_ok());
+        Ok(())
+    }
+
+    /// Tests that the nulls of a sliced array are sliced too
+    #[test]
+    fn null_sliced() -> Result<()> {
+        let array = StringArray::from(vec![None, Some("one"), None, Some("three")]);
+        let array = array.slice(1, 3);
+        let result = length(array.as_ref())?;
+        let expected = Int32Array::from(vec![Some(3), None, Some(5)]);
+        assert_eq!(expected.data(), result.data());
+
+        let result = char_length(array.as_ref())?;
+        assert_eq!(expected.data(), result.data());
+        Ok(())
+    }
+
     /// Tests that length is not valid for u64.
     #[test]
     fn wrong_type() -> Result<()> {
@@ -17,7 +17,13 @@
 
 //! Defines miscellaneous array kernels.
 
-use crate::array::ArrayRef;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use super::take::take;
+use crate::array::{ArrayRef, UInt32Array};
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
 
 /// Returns the array, taking only the number of elements specified
 ///
@@ -30,12 +36,65 @@ pub fn limit(array: &ArrayRef, num_elements: usize) -> ArrayRef {
     array.slice(0, lim)
 }
 
+/// Returns a batch of the first `num_rows` rows of `batch`, or all of them if the batch
+/// is shorter. Like [`limit`], the columns are sliced without copying.
+pub fn head(batch: &RecordBatch, num_rows: usize) -> Result<RecordBatch> {
+    let len = num_rows.min(batch.num_rows());
+    slice_batch(batch, 0, len)
+}
+
+/// Returns a batch of the last `num_rows` rows of `batch`, or all of them if the batch
+/// is shorter. Like [`limit`], the columns are sliced without copying.
+pub fn tail(batch: &RecordBatch, num_rows: usize) -> Result<RecordBatch> {
+    let len = num_rows.min(batch.num_rows());
+    slice_batch(batch, batch.num_rows() - len, len)
+}
+
+fn slice_batch(batch: &RecordBatch, offset: usize, len: usize) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| column.slice(offset, len))
+        .collect();
+    RecordBatch::try_new(batch.schema(), columns)
+}
+
+/// Returns a batch of `num_rows` rows of `batch` drawn uniformly at random without
+/// replacement, in their original order, or the whole batch if it is shorter.
+///
+/// The rows are chosen by reservoir sampling with a ChaCha8 generator seeded by `seed`,
+/// whose output is specified, so that the same seed always returns the same sample of a
+/// batch, whatever the version of `rand`.
+pub fn sample(batch: &RecordBatch, num_rows: usize, seed: u64) -> Result<RecordBatch> {
+    if num_rows >= batch.num_rows() {
+        return Ok(batch.clone());
+    }
+
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut indices: Vec<u32> = (0..num_rows as u32).collect();
+    for i in num_rows..batch.num_rows() {
+        let j = rng.gen_range(0, i + 1);
+        if j < num_rows {
+            indices[j] = i as u32;
+        }
+    }
+    indices.sort_unstable();
+
+    let indices = UInt32Array::from(indices);
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| take(column, &indices, None))
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(batch.schema(), columns)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::array::*;
     use crate::buffer::Buffer;
-    use crate::datatypes::{DataType, Field, ToByteSlice};
+    use crate::datatypes::{DataType, Field, Schema, ToByteSlice};
     use crate::util::bit_util;
 
     use std::sync::Arc;
@@ -199,4 +258,83 @@ mod tests {
         assert_eq!(28, sliced_c1.value(1));
         assert_eq!(42, sliced_c1.value(2));
     }
+
+    #[test]
+    fn test_head_tail() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    None,
+                    Some(3),
+                    Some(4),
+                    Some(5),
+                ])),
+                Arc::new(StringArray::from(vec!["1", "2", "3", "4", "5"])),
+            ],
+        )
+        .unwrap();
+
+        let head_batch = head(&batch, 2).unwrap();
+        assert_eq!(batch.schema(), head_batch.schema());
+        let ints = head_batch.column(0).as_typed::<Int32Array>().unwrap();
+        assert_eq!(vec![Some(1), None], ints.iter().collect::<Vec<_>>());
+        let tail_batch = tail(&batch, 2).unwrap();
+        let ints = tail_batch.column(0).as_typed::<Int32Array>().unwrap();
+        assert_eq!(vec![Some(4), Some(5)], ints.iter().collect::<Vec<_>>());
+        let strings = tail_batch.column(1).as_typed::<StringArray>().unwrap();
+        assert_eq!("4", strings.value(0));
+
+        assert_eq!(5, head(&batch, 10).unwrap().num_rows());
+        assert_eq!(5, tail(&batch, 10).unwrap().num_rows());
+        assert_eq!(0, tail(&batch, 0).unwrap().num_rows());
+    }
+
+    #[test]
+    fn test_sample() {
+        let ints: Int32Array = (0..100)
+            .map(|i| if i % 7 == 0 { None } else { Some(i) })
+            .collect();
+        let strings: StringArray =
+            ints.iter().map(|v| v.map(|v| v.to_string())).collect();
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(ints), Arc::new(strings)],
+        )
+        .unwrap();
+
+        let sampled = sample(&batch, 10, 42).unwrap();
+        assert_eq!(10, sampled.num_rows());
+        assert_eq!(batch.schema(), sampled.schema());
+        // the rows are distinct, in their original order, and the columns stay aligned
+        let ints = sampled.column(0).as_typed::<Int32Array>().unwrap();
+        let strings = sampled.column(1).as_typed::<StringArray>().unwrap();
+        let mut previous = -1;
+        for i in 0..sampled.num_rows() {
+            assert_eq!(ints.is_null(i), strings.is_null(i));
+            if ints.is_valid(i) {
+                assert!(ints.value(i) > previous);
+                previous = ints.value(i);
+                assert_eq!(ints.value(i).to_string(), strings.value(i));
+            }
+        }
+
+        // the sample is deterministic for a seed
+        let resampled = sample(&batch, 10, 42).unwrap();
+        assert_eq!(sampled.column(0).data(), resampled.column(0).data());
+        let resampled = sample(&batch, 10, 7).unwrap();
+        assert_ne!(sampled.column(0).data(), resampled.column(0).data());
+
+        assert_eq!(100, sample(&batch, 100, 42).unwrap().num_rows());
+        assert_eq!(0, sample(&batch, 0, 42).unwrap().num_rows());
+    }
 }
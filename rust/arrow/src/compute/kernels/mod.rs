@@ -21,12 +21,32 @@ pub mod aggregate;
 pub mod arithmetic;
 pub mod boolean;
 pub mod cast;
+pub mod coalesce;
 pub mod comparison;
 pub mod concat;
+pub mod concat_elements;
+pub mod cumulative;
+#[cfg(any(feature = "md5", feature = "sha2", feature = "crc32fast"))]
+pub mod digest;
+pub mod encoding;
 pub mod filter;
+pub mod geo;
+pub mod histogram;
+pub mod hll;
+pub mod interleave;
+pub mod ip;
+pub mod json;
 pub mod length;
 pub mod limit;
+pub mod partition;
+pub mod phonetic;
+pub mod regexp;
+pub mod similarity;
 pub mod sort;
+pub mod split;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod unicode;
+pub mod uuid;
+pub mod zip;
@@ -0,0 +1,306 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the partition kernel, which splits a `RecordBatch` by the values of some of
+//! its columns, e.g. to write a dataset partitioned in directories like
+//! `year=2020/month=12`.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::aggregate::for_each_value_bytes;
+use super::take::take;
+use crate::array::{ArrayRef, UInt32Array};
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+use crate::scalar::Scalar;
+
+/// The name of the directory of null partition values, as Hive names it
+const NULL_PARTITION_NAME: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// The values of the key columns shared by the rows of a partition
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartitionKey {
+    names: Vec<String>,
+    values: Vec<Scalar>,
+}
+
+impl PartitionKey {
+    /// The names of the key columns
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// The values of the key columns, in the order of their names
+    pub fn values(&self) -> &[Scalar] {
+        &self.values
+    }
+
+    /// Returns the relative path of the directory of the partition in a Hive-style
+    /// dataset, such as `year=2020/month=12`.
+    ///
+    /// Null values are named `__HIVE_DEFAULT_PARTITION__`, and the characters of values
+    /// that are not allowed in paths are escaped as `%` followed by their hexadecimal
+    /// code.
+    pub fn to_hive_path(&self) -> String {
+        self.names
+            .iter()
+            .zip(&self.values)
+            .map(|(name, value)| {
+                let value = if value.is_null() {
+                    NULL_PARTITION_NAME.to_string()
+                } else {
+                    escape_path_name(&value.to_string())
+                };
+                format!("{}={}", escape_path_name(name), value)
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+impl fmt::Display for PartitionKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hive_path())
+    }
+}
+
+/// Escapes the characters that Hive escapes in the names of partition directories
+fn escape_path_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_control() || "\"#%'*/:=?\\{[]^".contains(c) {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// Splits `batch` into one batch per distinct combination of values of the columns named
+/// `columns`, returning the key of each partition along with its rows, in their original
+/// order. The partitions are ordered by their first row, and the batches keep all the
+/// columns of `batch`, key columns included.
+///
+/// Null key values form their own partition, as do all NaNs and both zeros of floating
+/// point keys.
+///
+/// # Errors
+/// This function errors if a column does not exist, or if its data type is not supported
+/// as a key, such as nested types.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::{Int32Array, StringArray};
+/// use arrow::compute::kernels::partition::partition_by;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::error::Result;
+/// use arrow::record_batch::RecordBatch;
+/// # fn main() -> Result<()> {
+/// let schema = Schema::new(vec![
+///     Field::new("year", DataType::Int32, false),
+///     Field::new("city", DataType::Utf8, false),
+/// ]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema),
+///     vec![
+///         Arc::new(Int32Array::from(vec![2020, 2021, 2020])),
+///         Arc::new(StringArray::from(vec!["Paris", "Lima", "Oslo"])),
+///     ],
+/// )?;
+///
+/// let partitions = partition_by(&batch, &["year"])?;
+/// assert_eq!(partitions.len(), 2);
+/// assert_eq!(partitions[0].0.to_hive_path(), "year=2020");
+/// assert_eq!(partitions[0].1.num_rows(), 2);
+/// assert_eq!(partitions[1].0.to_hive_path(), "year=2021");
+/// # Ok(())
+/// # }
+/// ```
+pub fn partition_by(
+    batch: &RecordBatch,
+    columns: &[&str],
+) -> Result<Vec<(PartitionKey, RecordBatch)>> {
+    let schema = batch.schema();
+    let keys = columns
+        .iter()
+        .map(|name| schema.index_of(name).map(|index| batch.column(index)))
+        .collect::<Result<Vec<&ArrayRef>>>()?;
+
+    let num_rows = batch.num_rows();
+    let mut key_values = Vec::with_capacity(keys.len());
+    for key in &keys {
+        let mut values: Vec<Option<&[u8]>> = vec![None; num_rows];
+        for_each_value_bytes(key, &mut |i, bytes| values[i] = Some(bytes))?;
+        key_values.push(values);
+    }
+
+    // the key values of each row are encoded as in `GroupedAggregator`: a validity byte,
+    // followed by the length and the bytes of valid values
+    let mut partitions: HashMap<Vec<u8>, usize> = HashMap::new();
+    let mut rows: Vec<Vec<u32>> = vec![];
+    let mut key = vec![];
+    for row in 0..num_rows {
+        key.clear();
+        for values in &key_values {
+            match values[row] {
+                None => key.push(0),
+                Some(bytes) => {
+                    key.push(1);
+                    key.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+                    key.extend_from_slice(bytes);
+                }
+            }
+        }
+        match partitions.get(&key) {
+            Some(partition) => rows[*partition].push(row as u32),
+            None => {
+                partitions.insert(key.clone(), rows.len());
+                rows.push(vec![row as u32]);
+            }
+        }
+    }
+
+    let names = columns
+        .iter()
+        .map(|name| name.to_string())
+        .collect::<Vec<_>>();
+    rows.into_iter()
+        .map(|rows| {
+            let values = keys
+                .iter()
+                .map(|key| Scalar::try_from_array(key, rows[0] as usize))
+                .collect::<Result<Vec<_>>>()?;
+            let partition = if rows.len() == num_rows {
+                batch.clone()
+            } else {
+                let indices = UInt32Array::from(rows);
+                let columns = batch
+                    .columns()
+                    .iter()
+                    .map(|column| take(column, &indices, None))
+                    .collect::<Result<Vec<_>>>()?;
+                RecordBatch::try_new(schema.clone(), columns)?
+            };
+            let key = PartitionKey {
+                names: names.clone(),
+                values,
+            };
+            Ok((key, partition))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::array::{Float64Array, Int32Array, StringArray};
+    use crate::datatypes::{DataType, Field, Schema};
+
+    fn batch() -> RecordBatch {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int32, true),
+            Field::new("c", DataType::Float64, false),
+        ]);
+        RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(StringArray::from(vec![
+                    Some("x"),
+                    None,
+                    Some("x"),
+                    Some("y/z"),
+                    None,
+                ])),
+                Arc::new(Int32Array::from(vec![
+                    Some(1),
+                    Some(1),
+                    Some(2),
+                    None,
+                    Some(1),
+                ])),
+                Arc::new(Float64Array::from(vec![0.0, 1.0, 2.0, 3.0, -0.0])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_partition_by_two_columns() {
+        let partitions = partition_by(&batch(), &["a", "b"]).unwrap();
+        let paths = partitions
+            .iter()
+            .map(|(key, _)| key.to_hive_path())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            paths,
+            vec![
+                "a=x/b=1",
+                "a=__HIVE_DEFAULT_PARTITION__/b=1",
+                "a=x/b=2",
+                "a=y%2Fz/b=__HIVE_DEFAULT_PARTITION__",
+            ]
+        );
+        assert_eq!(
+            partitions[1].0.values(),
+            &[Scalar::Utf8(None), Scalar::Int32(Some(1))]
+        );
+
+        let (_, partition) = &partitions[1];
+        assert_eq!(partition.num_columns(), 3);
+        let c = partition
+            .column(2)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(c.len(), 2);
+        assert_eq!(c.value(0), 1.0);
+        assert!(c.value(1) == 0.0 && c.value(1).is_sign_negative());
+    }
+
+    #[test]
+    fn test_partition_by_float_zeros() {
+        let partitions = partition_by(&batch(), &["c"]).unwrap();
+        assert_eq!(partitions.len(), 4);
+        assert_eq!(partitions[0].1.num_rows(), 2);
+    }
+
+    #[test]
+    fn test_partition_by_single_partition() {
+        let batch = batch();
+        let batch = RecordBatch::try_new(
+            batch.schema(),
+            batch.columns().iter().map(|c| c.slice(0, 1)).collect(),
+        )
+        .unwrap();
+        let partitions = partition_by(&batch, &["a"]).unwrap();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions[0].1.num_rows(), 1);
+        assert_eq!(partitions[0].0.names(), &["a".to_string()]);
+    }
+
+    #[test]
+    fn test_partition_by_unknown_column() {
+        assert!(partition_by(&batch(), &["d"]).is_err());
+    }
+}
@@ -0,0 +1,310 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to encode the values of \[Large\]String arrays by how they sound in
+//! English, so that names spelled differently but pronounced alike, such as "Robert"
+//! and "Rupert", have the same code, e.g. to link records of the same person.
+//!
+//! Both encodings only consider the ASCII letters of the values, regardless of their
+//! case: other characters are skipped.
+
+use crate::array::*;
+use crate::compute::util::map_utf8;
+use crate::error::Result;
+
+/// The length of the codes of [`soundex`]
+const SOUNDEX_LEN: usize = 4;
+
+/// The Soundex digit of an uppercase letter, `0` for vowels, which separate letters of
+/// the same digit, and `None` for `H` and `W`, which do not
+fn soundex_digit(letter: u8) -> Option<u8> {
+    match letter {
+        b'B' | b'F' | b'P' | b'V' => Some(b'1'),
+        b'C' | b'G' | b'J' | b'K' | b'Q' | b'S' | b'X' | b'Z' => Some(b'2'),
+        b'D' | b'T' => Some(b'3'),
+        b'L' => Some(b'4'),
+        b'M' | b'N' => Some(b'5'),
+        b'R' => Some(b'6'),
+        b'H' | b'W' => None,
+        _ => Some(b'0'),
+    }
+}
+
+fn soundex_into(value: &str, values: &mut String) {
+    let mut letters = value
+        .bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|b| b.to_ascii_uppercase());
+    let first = match letters.next() {
+        Some(first) => first,
+        None => return,
+    };
+    values.push(first as char);
+
+    let mut len = 1;
+    let mut last = soundex_digit(first).unwrap_or(b'0');
+    for letter in letters {
+        match soundex_digit(letter) {
+            Some(b'0') => last = b'0',
+            Some(digit) if digit != last => {
+                values.push(digit as char);
+                len += 1;
+                if len == SOUNDEX_LEN {
+                    return;
+                }
+                last = digit;
+            }
+            _ => {}
+        }
+    }
+    values.extend(std::iter::repeat('0').take(SOUNDEX_LEN - len));
+}
+
+/// Encodes the values of a \[Large\]String array with American Soundex: their first
+/// letter followed by 3 digits, e.g. `"Robert"` to `"R163"`. Values without letters
+/// are encoded as empty strings, and nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::phonetic::soundex;
+/// let array = StringArray::from(vec![Some("Robert"), Some("Rupert"), None]);
+/// let codes = soundex(&array).unwrap();
+/// let codes = codes.as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(codes, &StringArray::from(vec![Some("R163"), Some("R163"), None]));
+/// ```
+pub fn soundex(array: &Array) -> Result<ArrayRef> {
+    map_utf8(array, "soundex", soundex_into)
+}
+
+fn is_vowel(letter: Option<u8>) -> bool {
+    matches!(
+        letter,
+        Some(b'A') | Some(b'E') | Some(b'I') | Some(b'O') | Some(b'U')
+    )
+}
+
+/// Appends the Metaphone code of the uppercase ASCII letters `word` to `values`
+fn metaphone_into(word: &[u8], max_len: usize, values: &mut String) {
+    let at = |i: usize| word.get(i).copied();
+    let start = values.len();
+
+    // the initial letters pronounced differently
+    let mut i = match (at(0), at(1)) {
+        (Some(b'A'), Some(b'E'))
+        | (Some(b'G'), Some(b'N'))
+        | (Some(b'K'), Some(b'N'))
+        | (Some(b'P'), Some(b'N'))
+        | (Some(b'W'), Some(b'R')) => 1,
+        (Some(b'X'), _) => {
+            values.push('S');
+            1
+        }
+        (Some(b'W'), Some(b'H')) => {
+            values.push('W');
+            2
+        }
+        _ => 0,
+    };
+
+    while i < word.len() && values.len() - start < max_len {
+        let letter = word[i];
+        let prev = if i > 0 { at(i - 1) } else { None };
+        let next = at(i + 1);
+        let after_next = at(i + 2);
+        // doubled letters are encoded once, except for C
+        if letter != b'C' && prev == Some(letter) {
+            i += 1;
+            continue;
+        }
+
+        match letter {
+            b'A' | b'E' | b'I' | b'O' | b'U' => {
+                if i == 0 {
+                    values.push(letter as char);
+                }
+            }
+            b'B' => {
+                // silent in a final MB
+                if !(prev == Some(b'M') && next.is_none()) {
+                    values.push('B');
+                }
+            }
+            b'C' => {
+                if next == Some(b'I') && after_next == Some(b'A') {
+                    values.push('X');
+                } else if next == Some(b'H') {
+                    values.push(if prev == Some(b'S') { 'K' } else { 'X' });
+                } else if matches!(next, Some(b'I') | Some(b'E') | Some(b'Y')) {
+                    // silent in SCI, SCE and SCY
+                    if prev != Some(b'S') {
+                        values.push('S');
+                    }
+                } else {
+                    values.push('K');
+                }
+            }
+            b'D' => {
+                if next == Some(b'G')
+                    && matches!(after_next, Some(b'E') | Some(b'I') | Some(b'Y'))
+                {
+                    values.push('J');
+                    // the G is encoded along with the D
+                    i += 1;
+                } else {
+                    values.push('T');
+                }
+            }
+            b'G' => {
+                let silent_gh =
+                    next == Some(b'H') && after_next.is_some() && !is_vowel(after_next);
+                let silent_gn = next == Some(b'N')
+                    && (i + 2 == word.len() || &word[i + 2..] == b"ED");
+                if !silent_gh && !silent_gn {
+                    let soft = matches!(next, Some(b'I') | Some(b'E') | Some(b'Y'));
+                    values.push(if soft { 'J' } else { 'K' });
+                }
+            }
+            b'H' => {
+                let after_consonant = matches!(
+                    prev,
+                    Some(b'C') | Some(b'S') | Some(b'P') | Some(b'T') | Some(b'G')
+                );
+                if !after_consonant && (!is_vowel(prev) || is_vowel(next)) {
+                    values.push('H');
+                }
+            }
+            b'K' => {
+                if prev != Some(b'C') {
+                    values.push('K');
+                }
+            }
+            b'P' => values.push(if next == Some(b'H') { 'F' } else { 'P' }),
+            b'Q' => values.push('K'),
+            b'S' => {
+                if next == Some(b'H')
+                    || (next == Some(b'I')
+                        && matches!(after_next, Some(b'O') | Some(b'A')))
+                {
+                    values.push('X');
+                } else {
+                    values.push('S');
+                }
+            }
+            b'T' => {
+                if next == Some(b'I') && matches!(after_next, Some(b'O') | Some(b'A')) {
+                    values.push('X');
+                } else if next == Some(b'H') {
+                    // the TH sound is encoded as 0
+                    values.push('0');
+                } else if !(next == Some(b'C') && after_next == Some(b'H')) {
+                    values.push('T');
+                }
+            }
+            b'V' => values.push('F'),
+            b'W' | b'Y' => {
+                if is_vowel(next) {
+                    values.push(letter as char);
+                }
+            }
+            b'X' => values.push_str("KS"),
+            b'Z' => values.push('S'),
+            _ => values.push(letter as char),
+        }
+        i += 1;
+    }
+    // the codes are ASCII, and X may exceed `max_len` by a character
+    values.truncate(start + max_len);
+}
+
+/// Encodes the values of a \[Large\]String array with Metaphone, whose codes of at most
+/// `max_len` characters approximate the pronunciation of English words better than
+/// [`soundex`] does, e.g. `"Knight"` to `"NT"`. Values without letters are encoded
+/// as empty strings, and nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+pub fn metaphone(array: &Array, max_len: usize) -> Result<ArrayRef> {
+    // the letters of each value, reused across values
+    let mut word = vec![];
+    map_utf8(array, "metaphone", |value, values| {
+        word.clear();
+        word.extend(
+            value
+                .bytes()
+                .filter(u8::is_ascii_alphabetic)
+                .map(|b| b.to_ascii_uppercase()),
+        );
+        metaphone_into(&word, max_len, values);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soundex() {
+        let array = StringArray::from(vec![
+            Some("Robert"),
+            Some("Ashcraft"),
+            Some("Tymczak"),
+            Some("Pfister"),
+            Some("Lee"),
+            Some(" 42 "),
+            None,
+        ]);
+        let codes = soundex(&array).unwrap();
+        assert_eq!(
+            codes.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![
+                Some("R163"),
+                Some("A261"),
+                Some("T522"),
+                Some("P236"),
+                Some("L000"),
+                Some(""),
+                None,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_metaphone() {
+        let array = LargeStringArray::from(vec![
+            "Knight", "Xavier", "Wright", "Philip", "thumb", "Judge", "Ax",
+        ]);
+        let codes = metaphone(&array, 4).unwrap();
+        assert_eq!(
+            codes.as_typed::<LargeStringArray>().unwrap(),
+            &LargeStringArray::from(vec!["NT", "SFR", "RT", "FLP", "0M", "JJ", "AKS"])
+        );
+
+        let codes = metaphone(&array, 2).unwrap();
+        let codes = codes.as_typed::<LargeStringArray>().unwrap();
+        assert_eq!(codes.value(1), "SF");
+        assert_eq!(codes.value(6), "AK");
+    }
+
+    #[test]
+    fn test_phonetic_unsupported_type() {
+        let array = Int32Array::from(vec![1]);
+        assert!(soundex(&array).is_err());
+        assert!(metaphone(&array, 4).is_err());
+    }
+}
@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to extract and to replace the matches of regular expressions in
+//! \[Large\]StringArrays.
+//!
+//! The regular expressions are either a scalar, which is compiled once, or the values of
+//! another array, each distinct value of which is compiled once.
+
+use std::borrow::Cow;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::array::*;
+use crate::error::{ArrowError, Result};
+
+fn build_regex(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {}", e))
+    })
+}
+
+/// Compiles `pattern`, checking that it has the capture group `group`
+fn build_extract_regex(pattern: &str, group: usize) -> Result<Regex> {
+    let re = build_regex(pattern)?;
+    if group >= re.captures_len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Regular expression '{}' has no capture group {}",
+            pattern, group
+        )));
+    }
+    Ok(re)
+}
+
+/// Applies `op` to the values of `array` and the compiled patterns of the same index of
+/// `patterns`, compiling each distinct pattern once with `build`. The result is null when
+/// either value is null, or when `op` returns `None`.
+fn map_with_patterns<'a, OffsetSize, B, F>(
+    array: &'a GenericStringArray<OffsetSize>,
+    patterns: &'a GenericStringArray<OffsetSize>,
+    build: B,
+    op: F,
+) -> Result<GenericStringArray<OffsetSize>>
+where
+    OffsetSize: StringOffsetSizeTrait,
+    B: Fn(&str) -> Result<Regex>,
+    F: Fn(&Regex, &'a str) -> Option<Cow<'a, str>>,
+{
+    if array.len() != patterns.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot apply regular expressions of an array of different length"
+                .to_string(),
+        ));
+    }
+
+    let mut regexes: HashMap<&str, Regex> = HashMap::new();
+    let mut values = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) || patterns.is_null(i) {
+            values.push(None);
+            continue;
+        }
+        let pattern = patterns.value(i);
+        let re = match regexes.entry(pattern) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(build(pattern)?),
+        };
+        values.push(op(re, array.value(i)));
+    }
+    Ok(values.into_iter().collect())
+}
+
+/// Extracts the capture group `group` of the first match of `re` in `value`, or `None`
+/// if there is no match or if the group did not participate in it
+fn extract<'a>(re: &Regex, value: &'a str, group: usize) -> Option<Cow<'a, str>> {
+    re.captures(value)
+        .and_then(|captures| captures.get(group))
+        .map(|m| Cow::Borrowed(m.as_str()))
+}
+
+/// Extracts the capture group `group` of the first match in each value of `array` of
+/// the regular expression of the same index of `patterns`. The group 0 is the whole
+/// match. The result is null when either value is null, when the value does not match,
+/// or when the group does not participate in the match.
+///
+/// This function errors if the arrays have different lengths, if a pattern is not a valid
+/// regular expression, or if it does not have the capture group `group`.
+pub fn regexp_extract_utf8<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    patterns: &GenericStringArray<OffsetSize>,
+    group: usize,
+) -> Result<GenericStringArray<OffsetSize>> {
+    map_with_patterns(
+        array,
+        patterns,
+        |pattern| build_extract_regex(pattern, group),
+        |re, value| extract(re, value, group),
+    )
+}
+
+/// Extracts the capture group `group` of the first match of the regular expression
+/// `pattern` in each value of `array`, as [`regexp_extract_utf8`] does.
+///
+/// This function errors if `pattern` is not a valid regular expression, or if it does not
+/// have the capture group `group`.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::regexp::regexp_extract_utf8_scalar;
+/// let array = StringArray::from(vec![Some("id=42"), Some("none"), None]);
+/// let ids = regexp_extract_utf8_scalar(&array, r"id=(\d+)", 1).unwrap();
+/// assert_eq!(ids, StringArray::from(vec![Some("42"), None, None]));
+/// ```
+pub fn regexp_extract_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    pattern: &str,
+    group: usize,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let re = build_extract_regex(pattern, group)?;
+    Ok(array
+        .iter()
+        .map(|value| value.and_then(|value| extract(&re, value, group)))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect())
+}
+
+/// Replaces all the matches in each value of `array` of the regular expression of the
+/// same index of `patterns` with `replacement`, in which `$1` or `${name}` stand for the
+/// capture groups of the match, and `$$` for `$`. The result is null when either value
+/// is null.
+///
+/// This function errors if the arrays have different lengths, or if a pattern is not a
+/// valid regular expression.
+pub fn regexp_replace_utf8<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    patterns: &GenericStringArray<OffsetSize>,
+    replacement: &str,
+) -> Result<GenericStringArray<OffsetSize>> {
+    map_with_patterns(array, patterns, build_regex, |re, value| {
+        Some(re.replace_all(value, replacement))
+    })
+}
+
+/// Replaces all the matches of the regular expression `pattern` in each value of `array`
+/// with `replacement`, as [`regexp_replace_utf8`] does.
+///
+/// This function errors if `pattern` is not a valid regular expression.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::regexp::regexp_replace_utf8_scalar;
+/// let array = StringArray::from(vec![Some("2021-01-31"), None]);
+/// let dates = regexp_replace_utf8_scalar(&array, r"(\d+)-(\d+)-(\d+)", "$3/$2/$1")
+///     .unwrap();
+/// assert_eq!(dates, StringArray::from(vec![Some("31/01/2021"), None]));
+/// ```
+pub fn regexp_replace_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    pattern: &str,
+    replacement: &str,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let re = build_regex(pattern)?;
+    Ok(array
+        .iter()
+        .map(|value| value.map(|value| re.replace_all(value, replacement)))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regexp_extract_utf8() {
+        let array =
+            StringArray::from(vec![Some("a1"), Some("b22"), Some("c"), None, Some("d4")]);
+        let patterns = StringArray::from(vec![
+            Some(r"[a-z](\d)"),
+            Some(r"[a-z](\d+)"),
+            Some(r"[a-z](\d)?"),
+            Some(r"x"),
+            None,
+        ]);
+        let result = regexp_extract_utf8(&array, &patterns, 1).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("1"), Some("22"), None, None, None])
+        );
+
+        let result = regexp_extract_utf8(&array, &patterns, 0).unwrap();
+        assert_eq!(result.value(1), "b22");
+        assert!(regexp_extract_utf8(&array, &patterns, 2).is_err());
+    }
+
+    #[test]
+    fn test_regexp_extract_utf8_scalar_sliced() {
+        let array = LargeStringArray::from(vec!["k=v", "key=value", "novalue"]);
+        let array = array.slice(1, 2);
+        let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        let result = regexp_extract_utf8_scalar(array, r"(?P<key>\w+)=", 1).unwrap();
+        assert_eq!(result, LargeStringArray::from(vec![Some("key"), None]));
+        assert!(regexp_extract_utf8_scalar(array, r"(", 0).is_err());
+    }
+
+    #[test]
+    fn test_regexp_replace_utf8() {
+        let array = StringArray::from(vec![Some("a.b.c"), Some("abc"), None]);
+        let patterns = StringArray::from(vec![r"\.", r"(b)", r"x"]);
+        let result = regexp_replace_utf8(&array, &patterns, "[$1]").unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("a[]b[]c"), Some("a[b]c"), None])
+        );
+
+        let patterns = StringArray::from(vec![r"\.", r"[", r"x"]);
+        assert!(regexp_replace_utf8(&array, &patterns, "").is_err());
+    }
+
+    #[test]
+    fn test_regexp_replace_utf8_scalar() {
+        let array = StringArray::from(vec![Some("a  b   c"), Some(""), None]);
+        let result = regexp_replace_utf8_scalar(&array, r"\s+", " ").unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("a b c"), Some(""), None])
+        );
+    }
+}
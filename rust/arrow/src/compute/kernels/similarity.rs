@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to measure how similar the values of \[Large\]String arrays are,
+//! either pairwise between two arrays or to a scalar, e.g. to find duplicates that
+//! differ by typos.
+//!
+//! Both measures compare the characters of the values, not their bytes.
+
+use crate::array::*;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// The prefix bonus of Jaro-Winkler is only given to values at least this similar
+const WINKLER_THRESHOLD: f64 = 0.7;
+/// The weight of each common prefix character in Jaro-Winkler
+const WINKLER_PREFIX_WEIGHT: f64 = 0.1;
+/// The longest common prefix given a bonus by Jaro-Winkler
+const WINKLER_MAX_PREFIX: usize = 4;
+
+/// Returns an iterator over the values of a \[Large\]String array
+fn utf8_iter<'a>(
+    array: &'a Array,
+    name: &str,
+) -> Result<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+    match array.data_type() {
+        DataType::Utf8 => Ok(Box::new(array.as_typed::<StringArray>()?.iter())),
+        DataType::LargeUtf8 => Ok(Box::new(array.as_typed::<LargeStringArray>()?.iter())),
+        _ => Err(ArrowError::ComputeError(format!(
+            "{} does not support type {:?}",
+            name,
+            array.data_type()
+        ))),
+    }
+}
+
+/// Applies `op` to the characters of the values of `left` and `right` of the same
+/// index, or to those of the values of `left` and the scalar `right`. The buffers of
+/// characters are reused across values.
+fn binary_chars<T, F>(
+    left: &Array,
+    right: Box<dyn Iterator<Item = Option<&str>> + '_>,
+    name: &str,
+    mut op: F,
+) -> Result<Vec<Option<T>>>
+where
+    F: FnMut(&[char], &[char]) -> T,
+{
+    let left_values = utf8_iter(left, name)?;
+    let mut left_chars = vec![];
+    let mut right_chars = vec![];
+    Ok(left_values
+        .zip(right)
+        .map(|(left, right)| match (left, right) {
+            (Some(left), Some(right)) => {
+                left_chars.clear();
+                left_chars.extend(left.chars());
+                right_chars.clear();
+                right_chars.extend(right.chars());
+                Some(op(&left_chars, &right_chars))
+            }
+            _ => None,
+        })
+        .collect())
+}
+
+fn check_same_len(left: &Array, right: &Array, name: &str) -> Result<()> {
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot compute the {} of arrays of different length",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// The Levenshtein distance between `left` and `right`, computed with a single row of
+/// the matrix of the distances between their prefixes
+fn levenshtein_distance(left: &[char], right: &[char], row: &mut Vec<usize>) -> usize {
+    row.clear();
+    row.extend(0..=right.len());
+    for (i, l) in left.iter().enumerate() {
+        // the distance between the previous prefix of `left` and that of `right`
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, r) in right.iter().enumerate() {
+            let substitution = diagonal + if l == r { 0 } else { 1 };
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[right.len()]
+}
+
+/// The Jaro-Winkler similarity of `left` and `right`, between 0 (no similarity) and 1
+/// (equal values)
+fn jaro_winkler_similarity(
+    left: &[char],
+    right: &[char],
+    left_matches: &mut Vec<bool>,
+    right_matches: &mut Vec<bool>,
+) -> f64 {
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+
+    // characters match when they are equal and not farther apart than this
+    let window = (left.len().max(right.len()) / 2).saturating_sub(1);
+    left_matches.clear();
+    left_matches.resize(left.len(), false);
+    right_matches.clear();
+    right_matches.resize(right.len(), false);
+    let mut matches = 0;
+    for (i, l) in left.iter().enumerate() {
+        let end = (i + window + 1).min(right.len());
+        for j in i.saturating_sub(window)..end {
+            if !right_matches[j] && right[j] == *l {
+                left_matches[i] = true;
+                right_matches[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // the matching characters in a different order are transpositions
+    let left_matched = left.iter().zip(left_matches.iter()).filter(|(_, m)| **m);
+    let right_matched = right.iter().zip(right_matches.iter()).filter(|(_, m)| **m);
+    let half_transpositions = left_matched
+        .zip(right_matched)
+        .filter(|((l, _), (r, _))| l != r)
+        .count();
+
+    let matches = matches as f64;
+    let jaro = (matches / left.len() as f64
+        + matches / right.len() as f64
+        + (matches - (half_transpositions / 2) as f64) / matches)
+        / 3.0;
+    if jaro <= WINKLER_THRESHOLD {
+        return jaro;
+    }
+    let prefix = left
+        .iter()
+        .zip(right.iter())
+        .take(WINKLER_MAX_PREFIX)
+        .take_while(|(l, r)| l == r)
+        .count();
+    jaro + prefix as f64 * WINKLER_PREFIX_WEIGHT * (1.0 - jaro)
+}
+
+fn levenshtein_of(
+    left: &Array,
+    right: Box<dyn Iterator<Item = Option<&str>> + '_>,
+) -> Result<UInt32Array> {
+    let mut row = vec![];
+    let distances = binary_chars(left, right, "levenshtein", |left, right| {
+        levenshtein_distance(left, right, &mut row) as u32
+    })?;
+    Ok(distances.into_iter().collect())
+}
+
+fn jaro_winkler_of(
+    left: &Array,
+    right: Box<dyn Iterator<Item = Option<&str>> + '_>,
+) -> Result<Float64Array> {
+    let mut left_matches = vec![];
+    let mut right_matches = vec![];
+    let similarities = binary_chars(left, right, "jaro_winkler", |left, right| {
+        jaro_winkler_similarity(left, right, &mut left_matches, &mut right_matches)
+    })?;
+    Ok(similarities.into_iter().collect())
+}
+
+/// Computes the Levenshtein distance between the values of two \[Large\]String arrays
+/// of the same index: the least number of characters to insert, delete or substitute
+/// to change one value into the other. The distance is null when either value is null.
+///
+/// This function errors when the arrays have different lengths, or when either of them
+/// is not a \[Large\]String array.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::{StringArray, UInt32Array};
+/// use arrow::compute::kernels::similarity::levenshtein;
+/// let left = StringArray::from(vec![Some("kitten"), Some("flaw"), None]);
+/// let right = StringArray::from(vec![Some("sitting"), Some("lawn"), Some("x")]);
+/// let distances = levenshtein(&left, &right).unwrap();
+/// assert_eq!(distances, UInt32Array::from(vec![Some(3), Some(2), None]));
+/// ```
+pub fn levenshtein(left: &Array, right: &Array) -> Result<UInt32Array> {
+    check_same_len(left, right, "levenshtein")?;
+    levenshtein_of(left, utf8_iter(right, "levenshtein")?)
+}
+
+/// Computes the Levenshtein distance, as [`levenshtein`] does, between each value of a
+/// \[Large\]String array and the scalar `right`. The distance is null for null values.
+///
+/// This function errors when `left` is not a \[Large\]String array.
+pub fn levenshtein_scalar(left: &Array, right: &str) -> Result<UInt32Array> {
+    levenshtein_of(left, Box::new(std::iter::repeat(Some(right))))
+}
+
+/// Computes the Jaro-Winkler similarity between the values of two \[Large\]String
+/// arrays of the same index, from 0 for values without common characters to 1 for
+/// equal values. Values whose Jaro similarity is above 0.7 are more similar the longer
+/// their common prefix is, up to 4 characters. The similarity is null when either value
+/// is null.
+///
+/// This function errors when the arrays have different lengths, or when either of them
+/// is not a \[Large\]String array.
+pub fn jaro_winkler(left: &Array, right: &Array) -> Result<Float64Array> {
+    check_same_len(left, right, "jaro_winkler")?;
+    jaro_winkler_of(left, utf8_iter(right, "jaro_winkler")?)
+}
+
+/// Computes the Jaro-Winkler similarity, as [`jaro_winkler`] does, between each value of
+/// a \[Large\]String array and the scalar `right`. The similarity is null for null
+/// values.
+///
+/// This function errors when `left` is not a \[Large\]String array.
+pub fn jaro_winkler_scalar(left: &Array, right: &str) -> Result<Float64Array> {
+    jaro_winkler_of(left, Box::new(std::iter::repeat(Some(right))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein_scalar() {
+        let array =
+            LargeStringArray::from(vec![Some(""), Some("arrow"), None, Some("ärrow")]);
+        let distances = levenshtein_scalar(&array, "arow").unwrap();
+        assert_eq!(
+            distances,
+            UInt32Array::from(vec![Some(4), Some(1), None, Some(2)])
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_errors() {
+        let left = StringArray::from(vec!["a", "b"]);
+        let right = StringArray::from(vec!["a"]);
+        assert!(levenshtein(&left, &right).is_err());
+        let right = Int32Array::from(vec![1, 2]);
+        assert!(levenshtein(&left, &right).is_err());
+    }
+
+    #[test]
+    fn test_jaro_winkler() {
+        let left = StringArray::from(vec![
+            Some("MARTHA"),
+            Some("DIXON"),
+            Some("abc"),
+            Some(""),
+            None,
+        ]);
+        let right = StringArray::from(vec![
+            Some("MARHTA"),
+            Some("DICKSONX"),
+            Some("xyz"),
+            Some(""),
+            Some("a"),
+        ]);
+        let similarities = jaro_winkler(&left, &right).unwrap();
+        assert!((similarities.value(0) - 0.961).abs() < 0.001);
+        assert!((similarities.value(1) - 0.813).abs() < 0.001);
+        assert_eq!(similarities.value(2), 0.0);
+        assert_eq!(similarities.value(3), 1.0);
+        assert!(similarities.is_null(4));
+    }
+
+    #[test]
+    fn test_jaro_winkler_scalar() {
+        let array = StringArray::from(vec!["arrow", "arrows"]);
+        let array = array.slice(1, 1);
+        let similarities = jaro_winkler_scalar(array.as_ref(), "arrows").unwrap();
+        assert_eq!(similarities.len(), 1);
+        assert_eq!(similarities.value(0), 1.0);
+    }
+}
@@ -24,7 +24,7 @@ use crate::compute::take;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
-use crate::buffer::MutableBuffer;
+use crate::buffer::{Buffer, MutableBuffer};
 use num::ToPrimitive;
 use std::sync::Arc;
 use TimeUnit::*;
@@ -160,6 +160,7 @@ pub fn sort_to_indices(
             sort_primitive::<DurationNanosecondType>(values, v, n, vec![], &options)
         }
         DataType::Utf8 => sort_string(values, v, n, &options),
+        DataType::FixedSizeList(_, _) => sort_fixed_size_list(values, v, n, &options),
         DataType::Dictionary(key_type, value_type)
             if *value_type.as_ref() == DataType::Utf8 =>
         {
@@ -465,6 +466,32 @@ where
     Ok(UInt32Array::from(valid_indices))
 }
 
+/// Sort fixed size lists element by element, where null elements are less than any
+/// other value
+fn sort_fixed_size_list(
+    values: &ArrayRef,
+    value_indices: Vec<u32>,
+    null_indices: Vec<u32>,
+    options: &SortOptions,
+) -> Result<UInt32Array> {
+    let compare = build_compare(values.as_ref(), values.as_ref())?;
+    let mut valids = value_indices;
+    let mut nulls = null_indices;
+    if !options.descending {
+        valids.sort_by(|a, b| compare(*a as usize, *b as usize));
+    } else {
+        valids.sort_by(|a, b| compare(*a as usize, *b as usize).reverse());
+        nulls.reverse();
+    }
+
+    if options.nulls_first {
+        nulls.append(&mut valids);
+        return Ok(UInt32Array::from(nulls));
+    }
+    valids.append(&mut nulls);
+    Ok(UInt32Array::from(valids))
+}
+
 /// One column to be used in lexicographical sort
 #[derive(Clone, Debug)]
 pub struct SortColumn {
@@ -613,6 +640,64 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<UInt32Array> {
     ))
 }
 
+/// Sorts the values of a `DictionaryArray` and remaps its keys accordingly, so that the
+/// order of the keys matches the order of the values they reference.
+///
+/// The logical values of the array are unchanged; this allows ordering comparisons on
+/// the keys alone. Null dictionary values, if any, are ordered first.
+///
+/// Returns an `ArrowError::ComputeError(String)` if the dictionary value type is
+/// unsupported by `sort_to_indices`.
+pub fn normalize_dictionary<K>(array: &DictionaryArray<K>) -> Result<DictionaryArray<K>>
+where
+    K: ArrowDictionaryKeyType,
+{
+    let values = array.values();
+    let sorted_indices = sort_to_indices(&values, None)?;
+    let sorted_values = take(&values, &sorted_indices, None)?;
+
+    // `ranks[i]` is the new position of the dictionary value at position `i`
+    let mut ranks = vec![K::Native::default(); values.len()];
+    for (new_index, old_index) in sorted_indices
+        .value_slice(0, sorted_indices.len())
+        .iter()
+        .enumerate()
+    {
+        ranks[*old_index as usize] = K::Native::from_usize(new_index)
+            .ok_or(ArrowError::DictionaryKeyOverflowError)?;
+    }
+
+    let keys = array.keys();
+    let new_keys = (0..keys.len())
+        .map(|i| {
+            if keys.is_valid(i) {
+                let key = keys.value(i).to_usize().ok_or_else(|| {
+                    ArrowError::ComputeError("Cast to usize failed".to_string())
+                })?;
+                Ok(ranks[key])
+            } else {
+                Ok(K::Native::default())
+            }
+        })
+        .collect::<Result<Vec<K::Native>>>()?;
+
+    let null_bit_buffer = keys
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(keys.offset(), keys.len()));
+
+    let data = ArrayData::new(
+        array.data_type().clone(),
+        keys.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(new_keys.to_byte_slice())],
+        vec![sorted_values.data()],
+    );
+    Ok(DictionaryArray::<K>::from(Arc::new(data)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1619,4 +1704,119 @@ mod tests {
         ];
         test_lex_sort_arrays(input, expected);
     }
+
+    #[test]
+    fn test_normalize_dictionary() {
+        let data = vec![Some("b"), None, Some("c"), Some("a"), Some("b"), Some("d")];
+        let array = DictionaryArray::<Int8Type>::from_iter(data);
+        assert_eq!(
+            array.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1), Some(2), Some(0), Some(3)])
+        );
+
+        let normalized = normalize_dictionary(&array).unwrap();
+        assert_eq!(
+            normalized.keys(),
+            &Int8Array::from(vec![Some(1), None, Some(2), Some(0), Some(1), Some(3)])
+        );
+        let values = normalized.values();
+        let values = as_string_array(&values);
+        assert_eq!(values, &StringArray::from(vec!["a", "b", "c", "d"]));
+    }
+
+    #[test]
+    fn test_normalize_dictionary_sliced() {
+        let data = vec![Some("z"), Some("y"), None, Some("x"), Some("y")];
+        let array = DictionaryArray::<UInt16Type>::from_iter(data);
+        let sliced = array.slice(1, 4);
+        let sliced = as_dictionary_array::<UInt16Type>(&sliced);
+
+        let normalized = normalize_dictionary(sliced).unwrap();
+        assert_eq!(4, normalized.len());
+        assert_eq!(
+            normalized.keys(),
+            &UInt16Array::from(vec![Some(1), None, Some(0), Some(1)])
+        );
+        let values = normalized.values();
+        let values = as_string_array(&values);
+        assert_eq!(values, &StringArray::from(vec!["x", "y", "z"]));
+    }
+
+    fn build_fixed_size_list(lists: &[Option<[Option<i32>; 2]>]) -> ArrayRef {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(0), 2);
+        for list in lists {
+            match list {
+                Some(values) => {
+                    for value in values {
+                        match value {
+                            Some(v) => builder.values().append_value(*v).unwrap(),
+                            None => builder.values().append_null().unwrap(),
+                        }
+                    }
+                    builder.append(true).unwrap();
+                }
+                None => {
+                    builder.values().append_null().unwrap();
+                    builder.values().append_null().unwrap();
+                    builder.append(false).unwrap();
+                }
+            }
+        }
+        Arc::new(builder.finish())
+    }
+
+    #[test]
+    fn test_sort_fixed_size_lists() {
+        let lists = [
+            Some([Some(1), Some(2)]),
+            None,
+            Some([Some(0), Some(9)]),
+            Some([Some(1), None]),
+            Some([Some(1), Some(2)]),
+            Some([None, Some(3)]),
+        ];
+        let array = build_fixed_size_list(&lists);
+
+        let sorted = sort_to_indices(&array, None).unwrap();
+        assert_eq!(UInt32Array::from(vec![1, 5, 2, 3, 0, 4]), sorted);
+
+        let options = SortOptions {
+            descending: true,
+            nulls_first: false,
+        };
+        let sorted = sort_to_indices(&array, Some(options)).unwrap();
+        assert_eq!(UInt32Array::from(vec![0, 4, 3, 2, 5, 1]), sorted);
+
+        let sorted = sort(&array, Some(options)).unwrap();
+        let expected = build_fixed_size_list(&[
+            Some([Some(1), Some(2)]),
+            Some([Some(1), Some(2)]),
+            Some([Some(1), None]),
+            Some([Some(0), Some(9)]),
+            Some([None, Some(3)]),
+            None,
+        ]);
+        assert_eq!(expected.len(), sorted.len());
+        let cmp = build_compare(expected.as_ref(), sorted.as_ref()).unwrap();
+        for i in 0..sorted.len() {
+            assert_eq!(expected.is_valid(i), sorted.is_valid(i));
+            if expected.is_valid(i) {
+                assert_eq!(Ordering::Equal, cmp(i, i), "{}", i);
+            }
+        }
+
+        // as a column of a lexicographical sort
+        let sorted = lexsort_to_indices(&[
+            SortColumn {
+                values: array,
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![6, 5, 4, 3, 2, 1])),
+                options: None,
+            },
+        ])
+        .unwrap();
+        assert_eq!(UInt32Array::from(vec![1, 5, 2, 3, 4, 0]), sorted);
+    }
 }
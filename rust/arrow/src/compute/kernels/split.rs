@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernel to split the values of a \[Large\]StringArray into lists of strings
+
+use std::sync::Arc;
+
+use super::concat_elements::offset_from_usize;
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::datatypes::{DataType, Field, ToByteSlice};
+use crate::error::{ArrowError, Result};
+
+fn generic_split<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    list_type: DataType,
+    delimiter: &str,
+    max_splits: Option<usize>,
+) -> Result<ArrayRef> {
+    // `splitn` splits values into at most this number of parts
+    let max_parts = max_splits.map_or(usize::MAX, |n| n.saturating_add(1));
+
+    // the parts are at most as long as the values of the array
+    let capacity = (0..array.len())
+        .map(|i| array.value_length(i).to_usize().unwrap())
+        .sum();
+    let mut values: Vec<u8> = Vec::with_capacity(capacity);
+    let mut value_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    let mut list_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    value_offsets.push(OffsetSize::zero());
+    list_offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            for part in array.value(i).splitn(max_parts, delimiter) {
+                values.extend_from_slice(part.as_bytes());
+                value_offsets.push(offset_from_usize(values.len())?);
+            }
+        }
+        list_offsets.push(offset_from_usize(value_offsets.len() - 1)?);
+    }
+
+    let values = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        value_offsets.len() - 1,
+        Some(0),
+        None,
+        0,
+        vec![
+            Buffer::from(value_offsets.to_byte_slice()),
+            Buffer::from(&values[..]),
+        ],
+        vec![],
+    );
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+    let data = ArrayData::new(
+        list_type,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(list_offsets.to_byte_slice())],
+        vec![Arc::new(values)],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Splits each value of a \[Large\]String array by `delimiter`, returning a
+/// List\<Utf8\> array, or a LargeList\<LargeUtf8\> array for a LargeString array, of
+/// the parts of the values. With `max_splits`, values are split at most that many
+/// times, so that their last part holds the rest of the value. Nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String array, or when
+/// `delimiter` is empty.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::{Array, ListArray, StringArray};
+/// use arrow::compute::kernels::split::split;
+/// let array = StringArray::from(vec![Some("a,b,c"), None, Some("")]);
+/// let lists = split(&array, ",", None).unwrap();
+/// let lists = lists.as_any().downcast_ref::<ListArray>().unwrap();
+/// assert_eq!(lists.value_length(0), 3);
+/// assert!(lists.is_null(1));
+/// // an empty value is a single empty part
+/// assert_eq!(lists.value_length(2), 1);
+///
+/// let lists = split(&array, ",", Some(1)).unwrap();
+/// let lists = lists.as_any().downcast_ref::<ListArray>().unwrap();
+/// let parts = lists.value(0);
+/// let parts = parts.as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(parts, &StringArray::from(vec!["a", "b,c"]));
+/// ```
+pub fn split(
+    array: &Array,
+    delimiter: &str,
+    max_splits: Option<usize>,
+) -> Result<ArrayRef> {
+    if delimiter.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "split requires a non-empty delimiter".to_string(),
+        ));
+    }
+    match array.data_type() {
+        DataType::Utf8 => generic_split(
+            array.as_typed::<StringArray>()?,
+            DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+            delimiter,
+            max_splits,
+        ),
+        DataType::LargeUtf8 => generic_split(
+            array.as_typed::<LargeStringArray>()?,
+            DataType::LargeList(Box::new(Field::new("item", DataType::LargeUtf8, true))),
+            delimiter,
+            max_splits,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "split does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(lists: &ArrayRef, i: usize) -> Vec<String> {
+        let lists = lists.as_any().downcast_ref::<LargeListArray>().unwrap();
+        let values = lists.value(i);
+        let values = values.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        values.iter().map(|v| v.unwrap().to_string()).collect()
+    }
+
+    #[test]
+    fn test_split_large_sliced() {
+        let array = LargeStringArray::from(vec![
+            Some("x"),
+            Some("a::b::"),
+            None,
+            Some("c"),
+            Some("::"),
+        ]);
+        let array = array.slice(1, 4);
+        let lists = split(array.as_ref(), "::", None).unwrap();
+        assert_eq!(lists.len(), 4);
+        assert_eq!(lists.null_count(), 1);
+        assert_eq!(parts(&lists, 0), vec!["a", "b", ""]);
+        assert!(lists.is_null(1));
+        assert_eq!(parts(&lists, 2), vec!["c"]);
+        assert_eq!(parts(&lists, 3), vec!["", ""]);
+    }
+
+    #[test]
+    fn test_split_max_splits() {
+        let array = LargeStringArray::from(vec!["a b c d"]);
+        let lists = split(&array, " ", Some(2)).unwrap();
+        assert_eq!(parts(&lists, 0), vec!["a", "b", "c d"]);
+        let lists = split(&array, " ", Some(0)).unwrap();
+        assert_eq!(parts(&lists, 0), vec!["a b c d"]);
+    }
+
+    #[test]
+    fn test_split_invalid() {
+        let array = StringArray::from(vec!["a"]);
+        assert!(split(&array, "", None).is_err());
+        let array = Int32Array::from(vec![1]);
+        assert!(split(&array, ",", None).is_err());
+    }
+}
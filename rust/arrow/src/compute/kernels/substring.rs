@@ -15,7 +15,8 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Defines kernel to extract a substring of a \[Large\]StringArray
+//! Defines kernels to extract a substring of a \[Large\]StringArray, and to truncate its
+//! values to a number of characters
 
 use crate::{array::*, buffer::Buffer, datatypes::ToByteSlice};
 use crate::{
@@ -29,18 +30,24 @@ fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
     start: OffsetSize,
     length: &Option<OffsetSize>,
 ) -> Result<ArrayRef> {
-    // compute current offsets
+    // compute current offsets, from the offset of the array
     let offsets = array.data_ref().clone().buffers()[0].clone();
     let offsets: &[OffsetSize] = unsafe { offsets.typed_data::<OffsetSize>() };
+    let offsets = &offsets[array.offset()..array.offset() + array.len() + 1];
 
     // compute null bitmap (copy)
-    let null_bit_buffer = array.data_ref().null_buffer().cloned();
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
 
     // compute values
     let values = &array.data_ref().buffers()[1];
     let data = values.data();
 
-    let mut new_values = Vec::new(); // we have no way to estimate how much this will be.
+    // the substrings are at most as long as the values of the array
+    let capacity = (offsets[array.len()] - offsets[0]).to_usize().unwrap();
+    let mut new_values = Vec::with_capacity(capacity);
     let mut new_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
 
     let mut length_so_far = OffsetSize::zero();
@@ -77,7 +84,7 @@ fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
     let data = ArrayData::new(
         <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
         array.len(),
-        None,
+        Some(array.null_count()),
         null_bit_buffer,
         0,
         vec![
@@ -90,6 +97,8 @@ fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
 }
 
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
+/// `start` and `length` are in bytes, and the substrings are computed in a single pass
+/// over the offsets of `array`.
 /// `start` can be negative, in which case the start counts from the end of the string.
 /// this function errors when the passed array is not a \[Large\]String array.
 pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<ArrayRef> {
@@ -117,6 +126,125 @@ pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<Arra
     }
 }
 
+fn generic_truncate_utf8<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    max_chars: usize,
+    ellipsis: &str,
+) -> Result<ArrayRef> {
+    // the number of characters of a truncated value kept before the ellipsis
+    let ellipsis_chars = ellipsis.chars().count();
+    let kept_chars = max_chars - ellipsis_chars;
+
+    let mut new_values = Vec::with_capacity(array.value_data().len());
+    let mut new_offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    new_offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let value = array.value(i);
+            // a value of at most `max_chars` bytes has at most as many characters
+            let truncated = if value.len() <= max_chars {
+                None
+            } else {
+                let mut boundaries = value.char_indices().map(|(index, _)| index);
+                // the value is truncated when it has another character after its first
+                // `max_chars`, i.e. `ellipsis_chars` characters after the kept ones
+                match (boundaries.nth(kept_chars), ellipsis_chars) {
+                    (Some(end), 0) => Some(end),
+                    (Some(end), n) => boundaries.nth(n - 1).map(|_| end),
+                    (None, _) => None,
+                }
+            };
+            match truncated {
+                Some(end) => {
+                    new_values.extend_from_slice(value[..end].as_bytes());
+                    new_values.extend_from_slice(ellipsis.as_bytes());
+                }
+                None => new_values.extend_from_slice(value.as_bytes()),
+            }
+        }
+        new_offsets.push(OffsetSize::from_usize(new_values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(
+                "The truncated values are too large for the offsets of the array"
+                    .to_string(),
+            )
+        })?);
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![
+            Buffer::from(new_offsets.to_byte_slice()),
+            Buffer::from(&new_values[..]),
+        ],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Truncates the values of a \[Large\]String array that have more than `max_chars`
+/// characters to their first characters followed by `ellipsis`, so that they have
+/// exactly `max_chars` characters. The values are cut on character boundaries, and
+/// shorter values and nulls are kept as they are.
+///
+/// This function errors when `array` is not a \[Large\]String array, or when `ellipsis`
+/// has more than `max_chars` characters.
+///
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::substring::truncate_utf8;
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("déjà vu")]);
+/// let truncated = truncate_utf8(&array, 5, "…").unwrap();
+/// let truncated = truncated.as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(
+///     truncated,
+///     &StringArray::from(vec![Some("arrow"), None, Some("déjà…")])
+/// );
+/// ```
+pub fn truncate_utf8(
+    array: &Array,
+    max_chars: usize,
+    ellipsis: &str,
+) -> Result<ArrayRef> {
+    if ellipsis.chars().count() > max_chars {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The ellipsis '{}' is longer than the {} characters of truncated values",
+            ellipsis, max_chars
+        )));
+    }
+    match array.data_type() {
+        DataType::LargeUtf8 => generic_truncate_utf8(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+            max_chars,
+            ellipsis,
+        ),
+        DataType::Utf8 => generic_truncate_utf8(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+            max_chars,
+            ellipsis,
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "truncate_utf8 does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,4 +394,59 @@ mod tests {
     fn without_nulls_large_string() -> Result<()> {
         without_nulls::<LargeStringArray>()
     }
+
+    #[test]
+    fn test_substring_sliced() -> Result<()> {
+        let array = StringArray::from(vec![Some("hello"), None, Some("arrow"), None]);
+        let array = array.slice(1, 3);
+        let result = substring(array.as_ref(), -3, &Some(2))?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(result, &StringArray::from(vec![None, Some("ro"), None]));
+        assert_eq!(result.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate_utf8() -> Result<()> {
+        let array = StringArray::from(vec![
+            Some("short"),
+            Some("exactly"),
+            None,
+            Some("truncated"),
+            Some("naïveté"),
+        ]);
+        let result = truncate_utf8(&array, 7, "...")?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec![
+            Some("short"),
+            Some("exactly"),
+            None,
+            Some("trun..."),
+            Some("naïveté"),
+        ]);
+        assert_eq!(&expected, result);
+
+        let result = truncate_utf8(&array, 4, "")?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(result.value(4), "naïv");
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate_utf8_sliced() -> Result<()> {
+        let array = LargeStringArray::from(vec![Some("xxxxxx"), None, Some("yyyyyy")]);
+        let array = array.slice(1, 2);
+        let result = truncate_utf8(array.as_ref(), 2, "~")?;
+        let result = result.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(result, &LargeStringArray::from(vec![None, Some("y~")]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate_utf8_invalid() {
+        let array = StringArray::from(vec!["abc"]);
+        assert!(truncate_utf8(&array, 2, "...").is_err());
+        let array = Int32Array::from(vec![1]);
+        assert!(truncate_utf8(&array, 2, "").is_err());
+    }
 }
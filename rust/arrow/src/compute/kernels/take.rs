@@ -17,18 +17,19 @@
 
 //! Defines take kernel for `ArrayRef`
 
-use std::{ops::AddAssign, sync::Arc};
+use std::sync::Arc;
 
+use crate::array::*;
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::compute::util::{
-    take_value_indices_from_fixed_size_list, take_value_indices_from_list,
+    combine_option_buffers, take_value_indices_from_fixed_size_list,
+    take_value_indices_from_list,
 };
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::util::bit_util;
-use crate::{array::*, buffer::buffer_bin_and};
 
-use num::{ToPrimitive, Zero};
+use num::ToPrimitive;
 use TimeUnit::*;
 
 /// Take elements from `ArrayRef` by copying the data from `values` at
@@ -62,6 +63,127 @@ pub fn take(
     take_impl::<UInt32Type>(values, indices, options)
 }
 
+/// Take elements from `ArrayRef` using dictionary-encoded `indices`, whose
+/// dictionary values are `UInt32` indices into `values`.
+///
+/// Only the distinct indices of the dictionary are taken from `values`; the
+/// result is then expanded by the keys of `indices`, so the indices are never
+/// materialized as a flat `UInt32Array`.
+///
+/// For example:
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{
+///     Array, ArrayData, ArrayRef, DictionaryArray, StringArray, UInt32Array,
+/// };
+/// use arrow::buffer::Buffer;
+/// use arrow::compute::take_dict_indices;
+/// use arrow::datatypes::{DataType, Int8Type, ToByteSlice};
+///
+/// let values: ArrayRef = Arc::new(StringArray::from(vec!["zero", "one", "two"]));
+///
+/// // indices [2, 2, 0, 2] encoded as a dictionary
+/// let dict_values = UInt32Array::from(vec![2, 0]);
+/// let data = ArrayData::builder(DataType::Dictionary(
+///     Box::new(DataType::Int8),
+///     Box::new(DataType::UInt32),
+/// ))
+/// .len(4)
+/// .add_buffer(Buffer::from([0_i8, 0, 1, 0].to_byte_slice()))
+/// .add_child_data(dict_values.data())
+/// .build();
+/// let indices = DictionaryArray::<Int8Type>::from(data);
+///
+/// let taken = take_dict_indices(&values, &indices, None).unwrap();
+/// let taken = taken.as_any().downcast_ref::<StringArray>().unwrap();
+///
+/// assert_eq!(*taken, StringArray::from(vec!["two", "two", "zero", "two"]));
+/// ```
+///
+/// A null key, or a key referencing a null dictionary value, yields a null value.
+/// Returns an error if the dictionary values of `indices` are not `UInt32`.
+pub fn take_dict_indices<K>(
+    values: &ArrayRef,
+    indices: &DictionaryArray<K>,
+    options: Option<TakeOptions>,
+) -> Result<ArrayRef>
+where
+    K: ArrowDictionaryKeyType + ArrowNumericType,
+    K::Native: ToPrimitive,
+{
+    let dict_values = indices.values();
+    let dict_values = dict_values
+        .as_any()
+        .downcast_ref::<UInt32Array>()
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Take with dictionary indices requires UInt32 dictionary values, \
+                 got {:?}",
+                dict_values.data_type()
+            ))
+        })?;
+    let taken = take(values, dict_values, options.clone())?;
+    take_impl::<K>(&taken, indices.keys(), options)
+}
+
+/// Returns whether [`take`] supports arrays of `data_type`, so that plans can be
+/// validated before execution, as [`can_cast_types`](crate::compute::can_cast_types)
+/// does for `cast`.
+///
+/// ```
+/// use arrow::compute::take_supports;
+/// use arrow::datatypes::DataType;
+///
+/// assert!(take_supports(&DataType::Utf8));
+/// assert!(!take_supports(&DataType::Null));
+/// ```
+pub fn take_supports(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Date32(_)
+        | DataType::Date64(_)
+        | DataType::Time32(Second)
+        | DataType::Time32(Millisecond)
+        | DataType::Time64(Microsecond)
+        | DataType::Time64(Nanosecond)
+        | DataType::Timestamp(_, _)
+        | DataType::Interval(_)
+        | DataType::Duration(_)
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => true,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => take_supports(field.data_type()),
+        DataType::Struct(fields) => {
+            fields.iter().all(|field| take_supports(field.data_type()))
+        }
+        DataType::Dictionary(key_type, _) => matches!(
+            key_type.as_ref(),
+            DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+        ),
+        _ => false,
+    }
+}
+
 fn take_impl<IndexType>(
     values: &ArrayRef,
     indices: &PrimitiveArray<IndexType>,
@@ -145,14 +267,15 @@ where
         }
         DataType::Utf8 => take_string::<i32, _>(values, indices),
         DataType::LargeUtf8 => take_string::<i64, _>(values, indices),
+        DataType::Binary => take_binary::<i32, _>(values, indices),
+        DataType::LargeBinary => take_binary::<i64, _>(values, indices),
         DataType::List(_) => take_list::<_, Int32Type>(values, indices),
         DataType::LargeList(_) => take_list::<_, Int64Type>(values, indices),
         DataType::FixedSizeList(_, length) => {
             take_fixed_size_list(values, indices, *length)
         }
         DataType::Struct(fields) => {
-            let struct_: &StructArray =
-                values.as_any().downcast_ref::<StructArray>().unwrap();
+            let struct_: &StructArray = values.as_typed::<StructArray>()?;
             let arrays: Result<Vec<ArrayRef>> = struct_
                 .columns()
                 .iter()
@@ -216,7 +339,7 @@ where
 {
     let data_len = indices.len();
 
-    let array = values.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let array = values.as_typed::<PrimitiveArray<T>>()?;
 
     let null_count = array.null_count();
 
@@ -235,7 +358,10 @@ where
 
             *elem = array.value(index);
         }
-        nulls = indices.data_ref().null_buffer().cloned();
+        nulls = indices
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(indices.offset(), indices.len()));
     } else {
         let num_bytes = bit_util::ceil(data_len, 8);
         let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, true);
@@ -253,16 +379,13 @@ where
 
             *elem = array.value(index);
         }
-        nulls = match indices.data_ref().null_buffer() {
-            Some(buffer) => Some(buffer_bin_and(
-                buffer,
-                0,
-                &null_buf.freeze(),
-                0,
-                indices.len(),
-            )),
-            None => Some(null_buf.freeze()),
-        };
+        nulls = combine_option_buffers(
+            indices.data_ref().null_buffer(),
+            indices.offset(),
+            Some(&null_buf.freeze()),
+            0,
+            indices.len(),
+        );
     }
 
     let data = ArrayData::new(
@@ -288,7 +411,7 @@ where
 {
     let data_len = indices.len();
 
-    let array = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+    let array = values.as_typed::<BooleanArray>()?;
 
     let num_byte = bit_util::ceil(data_len, 8);
     let mut val_buf = MutableBuffer::new(num_byte).with_bitset(num_byte, false);
@@ -311,7 +434,10 @@ where
             Ok(())
         })?;
 
-        nulls = indices.data_ref().null_buffer().cloned();
+        nulls = indices
+            .data_ref()
+            .null_buffer()
+            .map(|b| b.bit_slice(indices.offset(), indices.len()));
     } else {
         let mut null_buf = MutableBuffer::new(num_byte).with_bitset(num_byte, true);
         let null_slice = null_buf.data_mut();
@@ -330,16 +456,13 @@ where
             Ok(())
         })?;
 
-        nulls = match indices.data_ref().null_buffer() {
-            Some(buffer) => Some(buffer_bin_and(
-                buffer,
-                0,
-                &null_buf.freeze(),
-                0,
-                indices.len(),
-            )),
-            None => Some(null_buf.freeze()),
-        };
+        nulls = combine_option_buffers(
+            indices.data_ref().null_buffer(),
+            indices.offset(),
+            Some(&null_buf.freeze()),
+            0,
+            indices.len(),
+        );
     }
 
     let data = ArrayData::new(
@@ -360,119 +483,136 @@ fn take_string<OffsetSize, IndexType>(
     indices: &PrimitiveArray<IndexType>,
 ) -> Result<ArrayRef>
 where
-    OffsetSize: Zero + AddAssign + StringOffsetSizeTrait,
+    OffsetSize: StringOffsetSizeTrait,
     IndexType: ArrowNumericType,
     IndexType::Native: ToPrimitive,
 {
-    let data_len = indices.len();
-
-    let array = values
-        .as_any()
-        .downcast_ref::<GenericStringArray<OffsetSize>>()
-        .unwrap();
-
-    let bytes_offset = (data_len + 1) * std::mem::size_of::<OffsetSize>();
-    let mut offsets_buffer = MutableBuffer::new(bytes_offset);
-    offsets_buffer.resize(bytes_offset);
-
-    let offsets = offsets_buffer.typed_data_mut();
-    let mut values = Vec::with_capacity(bytes_offset);
-    let mut length_so_far = OffsetSize::zero();
-    offsets[0] = length_so_far;
+    let data = take_bytes::<OffsetSize, _>(
+        values.data_ref(),
+        indices,
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+    )?;
+    Ok(Arc::new(GenericStringArray::<OffsetSize>::from(data)))
+}
 
-    let nulls;
-    if array.null_count() == 0 && indices.null_count() == 0 {
-        for (i, offset) in offsets.iter_mut().skip(1).enumerate() {
-            let index = ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
-                ArrowError::ComputeError("Cast to usize failed".to_string())
-            })?;
+/// `take` implementation for binary arrays
+fn take_binary<OffsetSize, IndexType>(
+    values: &ArrayRef,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<ArrayRef>
+where
+    OffsetSize: BinaryOffsetSizeTrait,
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
+    let data = take_bytes::<OffsetSize, _>(
+        values.data_ref(),
+        indices,
+        <OffsetSize as BinaryOffsetSizeTrait>::DATA_TYPE,
+    )?;
+    Ok(Arc::new(GenericBinaryArray::<OffsetSize>::from(data)))
+}
 
-            let s = array.value(index);
+/// Shared `take` implementation for variable sized string and binary arrays.
+///
+/// The values are copied in two passes: the first pass sums the lengths of the taken
+/// values from the offsets, so that the values buffer is allocated exactly once and an
+/// offset overflow is reported before any data is copied.
+fn take_bytes<OffsetSize, IndexType>(
+    data: &ArrayDataRef,
+    indices: &PrimitiveArray<IndexType>,
+    data_type: DataType,
+) -> Result<ArrayDataRef>
+where
+    OffsetSize: OffsetSizeTrait,
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
+    let data_len = indices.len();
 
-            length_so_far += OffsetSize::from_usize(s.len()).unwrap();
-            values.extend_from_slice(s.as_bytes());
-            *offset = length_so_far;
-        }
-        nulls = None
-    } else if indices.null_count() == 0 {
-        let num_bytes = bit_util::ceil(data_len, 8);
+    // note: offsets are stored as u8, but they can be interpreted as OffsetSize
+    let array_offsets: &[OffsetSize] =
+        &unsafe { data.buffers()[0].typed_data::<OffsetSize>() }[data.offset()..];
+    let array_values = data.buffers()[1].data();
 
-        let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, true);
-        let null_slice = null_buf.data_mut();
+    let has_nulls = data.null_count() > 0 || indices.null_count() > 0;
 
-        for (i, offset) in offsets.iter_mut().skip(1).enumerate() {
+    // resolve the index to take for each slot, `None` for slots that are null
+    let take_indices = (0..data_len)
+        .map(|i| {
+            if has_nulls && indices.is_null(i) {
+                return Ok(None);
+            }
             let index = ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
                 ArrowError::ComputeError("Cast to usize failed".to_string())
             })?;
-
-            if array.is_valid(index) {
-                let s = array.value(index);
-
-                length_so_far += OffsetSize::from_usize(s.len()).unwrap();
-                values.extend_from_slice(s.as_bytes());
+            if has_nulls && data.is_null(index) {
+                Ok(None)
             } else {
-                bit_util::unset_bit(null_slice, i);
+                Ok(Some(index))
             }
-            *offset = length_so_far;
-        }
-        nulls = Some(null_buf.freeze());
-    } else if array.null_count() == 0 {
-        for (i, offset) in offsets.iter_mut().skip(1).enumerate() {
-            if indices.is_valid(i) {
-                let index =
-                    ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
-                        ArrowError::ComputeError("Cast to usize failed".to_string())
-                    })?;
-
-                let s = array.value(index);
-
-                length_so_far += OffsetSize::from_usize(s.len()).unwrap();
-                values.extend_from_slice(s.as_bytes());
-            }
-            *offset = length_so_far;
-        }
-        nulls = indices.data_ref().null_buffer().cloned();
-    } else {
-        let num_bytes = bit_util::ceil(data_len, 8);
+        })
+        .collect::<Result<Vec<Option<usize>>>>()?;
+
+    // first pass: compute the total length of the taken values
+    let values_len = take_indices
+        .iter()
+        .flatten()
+        .map(|index| array_offsets[index + 1] - array_offsets[*index])
+        .try_fold(0usize, |acc, len| {
+            len.to_usize().and_then(|len| acc.checked_add(len))
+        })
+        .ok_or_else(|| ArrowError::ComputeError("Cast to usize failed".to_string()))?;
+    if OffsetSize::from_usize(values_len).is_none() {
+        return Err(ArrowError::ArithmeticOverflow(format!(
+            "taking {} bytes of data overflows the offsets of {:?}",
+            values_len, data_type
+        )));
+    }
 
-        let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, true);
-        let null_slice = null_buf.data_mut();
+    // second pass: copy the values and compute the new offsets
+    let bytes_offset = (data_len + 1) * std::mem::size_of::<OffsetSize>();
+    let mut offsets_buffer = MutableBuffer::new(bytes_offset);
+    offsets_buffer.resize(bytes_offset);
+    let offsets = offsets_buffer.typed_data_mut();
 
-        for (i, offset) in offsets.iter_mut().skip(1).enumerate() {
-            let index = ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
-                ArrowError::ComputeError("Cast to usize failed".to_string())
-            })?;
+    let mut values = MutableBuffer::new(values_len);
 
-            if array.is_valid(index) && indices.is_valid(i) {
-                let s = array.value(index);
+    let num_bytes = bit_util::ceil(data_len, 8);
+    let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, true);
+    let null_slice = null_buf.data_mut();
 
-                length_so_far += OffsetSize::from_usize(s.len()).unwrap();
-                values.extend_from_slice(s.as_bytes());
-            } else {
-                // set null bit
-                bit_util::unset_bit(null_slice, i);
+    let mut length_so_far = OffsetSize::zero();
+    offsets[0] = length_so_far;
+    for (i, (offset, index)) in offsets
+        .iter_mut()
+        .skip(1)
+        .zip(take_indices.iter())
+        .enumerate()
+    {
+        match index {
+            Some(index) => {
+                let start = array_offsets[*index];
+                let end = array_offsets[index + 1];
+                // both conversions were checked by the first pass
+                values.extend_from_slice(
+                    &array_values[start.to_usize().unwrap()..end.to_usize().unwrap()],
+                );
+                length_so_far = length_so_far + (end - start);
             }
-            *offset = length_so_far;
+            None => bit_util::unset_bit(null_slice, i),
         }
-
-        nulls = match indices.data_ref().null_buffer() {
-            Some(buffer) => {
-                Some(buffer_bin_and(buffer, 0, &null_buf.freeze(), 0, data_len))
-            }
-            None => Some(null_buf.freeze()),
-        };
+        *offset = length_so_far;
     }
 
-    let mut data = ArrayData::builder(<OffsetSize as StringOffsetSizeTrait>::DATA_TYPE)
+    let mut data = ArrayData::builder(data_type)
         .len(data_len)
         .add_buffer(offsets_buffer.freeze())
-        .add_buffer(Buffer::from(values));
-    if let Some(null_buffer) = nulls {
-        data = data.null_bit_buffer(null_buffer);
+        .add_buffer(values.freeze());
+    if has_nulls {
+        data = data.null_bit_buffer(null_buf.freeze());
     }
-    Ok(Arc::new(GenericStringArray::<OffsetSize>::from(
-        data.build(),
-    )))
+    Ok(data.build())
 }
 
 /// `take` implementation for list arrays
@@ -493,10 +633,7 @@ where
 {
     // TODO: Some optimizations can be done here such as if it is
     // taking the whole list or a contiguous sublist
-    let list = values
-        .as_any()
-        .downcast_ref::<GenericListArray<OffsetType::Native>>()
-        .unwrap();
+    let list = values.as_typed::<GenericListArray<OffsetType::Native>>()?;
 
     let (list_indices, offsets) =
         take_value_indices_from_list::<IndexType, OffsetType>(list, indices)?;
@@ -547,16 +684,9 @@ where
     IndexType: ArrowNumericType,
     IndexType::Native: ToPrimitive,
 {
-    let indices = indices
-        .as_any()
-        .downcast_ref::<PrimitiveArray<Int32Type>>()
-        .expect("FixedSizeListArray's indices type should be 32-bit signed integer");
-    let list = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .unwrap();
+    let list = values.as_typed::<FixedSizeListArray>()?;
 
-    let list_indices = take_value_indices_from_fixed_size_list(list, indices, length);
+    let list_indices = take_value_indices_from_fixed_size_list(list, indices, length)?;
     let taken = take_impl::<Int32Type>(&list.values(), &list_indices, None)?;
 
     // determine null count and null buffer, which are a function of `values` and `indices`
@@ -566,7 +696,9 @@ where
     let null_slice = null_buf.data_mut();
 
     for i in 0..indices.len() {
-        if !indices.is_valid(i) || list.is_null(indices.value(i) as usize) {
+        if !indices.is_valid(i)
+            || list.is_null(ToPrimitive::to_usize(&indices.value(i)).unwrap())
+        {
             bit_util::unset_bit(null_slice, i);
             null_count += 1;
         }
@@ -586,7 +718,8 @@ where
 /// `take` implementation for dictionary arrays
 ///
 /// applies `take` to the keys of the dictionary array and returns a new dictionary array
-/// with the same dictionary values and reordered keys
+/// with the same dictionary values and reordered keys. The keys carry the offset of the
+/// dictionary array, so that indices refer to the slots of a sliced dictionary array
 fn take_dict<T, I>(values: &ArrayRef, indices: &PrimitiveArray<I>) -> Result<ArrayRef>
 where
     T: ArrowPrimitiveType,
@@ -594,10 +727,7 @@ where
     I: ArrowNumericType,
     I::Native: ToPrimitive,
 {
-    let dict = values
-        .as_any()
-        .downcast_ref::<DictionaryArray<T>>()
-        .unwrap();
+    let dict = values.as_typed::<DictionaryArray<T>>()?;
     let keys: ArrayRef = Arc::new(dict.keys_array());
     let new_keys = take_primitive::<T, I>(&keys, indices)?;
     let new_keys_data = new_keys.data_ref();
@@ -618,7 +748,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::compute::util::tests::build_fixed_size_list;
+    use crate::compute::util::tests::{build_fixed_size_list, dictionary};
 
     fn test_take_boolean_arrays(
         data: Vec<Option<bool>>,
@@ -682,6 +812,167 @@ mod tests {
         Arc::new(struct_array) as ArrayRef
     }
 
+    #[test]
+    fn test_take_sliced_indices_with_nulls() {
+        let index = UInt32Array::from(vec![Some(0), None, Some(2), Some(1)]);
+        let index = index.slice(1, 3);
+        let index = index.as_any().downcast_ref::<UInt32Array>().unwrap();
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(10), None, Some(30)]));
+        let a = take(&values, index, None).unwrap();
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![None, Some(30), None]), a);
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![10, 20, 30]));
+        let a = take(&values, index, None).unwrap();
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![None, Some(30), Some(20)]), a);
+
+        let values: ArrayRef =
+            Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)]));
+        let a = take(&values, index, None).unwrap();
+        let a = a.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![None, Some(false), None]), a);
+    }
+
+    #[test]
+    fn test_take_supports() {
+        let int32 = Box::new(Field::new("item", DataType::Int32, true));
+        let fixed_size_list = DataType::FixedSizeList(int32.clone(), 2);
+        let fixed_size_list_field =
+            Box::new(Field::new("item", fixed_size_list.clone(), true));
+
+        assert!(take_supports(&DataType::Int32));
+        assert!(take_supports(&DataType::Timestamp(TimeUnit::Second, None)));
+        assert!(take_supports(&DataType::List(int32.clone())));
+        assert!(take_supports(&DataType::Struct(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::List(int32), true),
+        ])));
+        assert!(take_supports(&DataType::Dictionary(
+            Box::new(DataType::Int8),
+            Box::new(DataType::Utf8)
+        )));
+        assert!(take_supports(&DataType::List(
+            fixed_size_list_field.clone()
+        )));
+        assert!(take_supports(&fixed_size_list));
+        assert!(take_supports(&DataType::LargeList(fixed_size_list_field)));
+
+        assert!(!take_supports(&DataType::Null));
+        assert!(!take_supports(&DataType::Float16));
+        assert!(!take_supports(&DataType::Time32(TimeUnit::Nanosecond)));
+        assert!(!take_supports(&DataType::Struct(vec![Field::new(
+            "a",
+            DataType::Null,
+            true
+        )])));
+        assert!(!take_supports(&DataType::Dictionary(
+            Box::new(DataType::Utf8),
+            Box::new(DataType::Utf8)
+        )));
+
+        // fixed size lists are taken with any type of indices
+        let values = Int32Array::from(vec![1, 2, 3, 4]);
+        let data = ArrayData::builder(fixed_size_list)
+            .len(2)
+            .add_child_data(values.data())
+            .build();
+        let list: ArrayRef = Arc::new(FixedSizeListArray::from(data));
+        let taken = take(&list, &UInt32Array::from(vec![1, 0]), None).unwrap();
+        let taken = taken.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(
+            taken.values().as_ref(),
+            &Int32Array::from(vec![3, 4, 1, 2]) as &Array
+        );
+
+        // while they are supported within lists
+        let mut builder =
+            ListBuilder::new(FixedSizeListBuilder::new(Int32Builder::new(0), 2));
+        for i in 0..3 {
+            builder.values().values().append_slice(&[i, i]).unwrap();
+            builder.values().append(true).unwrap();
+            builder.append(true).unwrap();
+        }
+        let lists: ArrayRef = Arc::new(builder.finish());
+        let taken = take(&lists, &UInt32Array::from(vec![2, 0]), None).unwrap();
+        let taken = taken.as_any().downcast_ref::<ListArray>().unwrap();
+        let first = taken.value(0);
+        let first = first.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+        assert_eq!(
+            first.value(0).as_ref(),
+            &Int32Array::from(vec![2, 2]) as &Array
+        );
+    }
+
+    #[test]
+    fn test_take_dict_indices() {
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+        ]));
+
+        // dictionary indices [3, 3, null, 1, 0, 3, null]
+        let keys = Int16Array::from(vec![
+            Some(0),
+            Some(0),
+            None,
+            Some(1),
+            Some(3),
+            Some(0),
+            Some(2),
+        ]);
+        let dict_values: ArrayRef =
+            Arc::new(UInt32Array::from(vec![Some(3), Some(1), None, Some(0)]));
+        let indices = dictionary(keys, dict_values);
+
+        let a = take_dict_indices(&values, &indices, None).unwrap();
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            &StringArray::from(vec![
+                Some("d"),
+                Some("d"),
+                None,
+                None,
+                Some("a"),
+                Some("d"),
+                None
+            ]),
+            a
+        );
+
+        // sliced dictionary indices [null, 1, 0]
+        let sliced = indices.slice(2, 3);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        let a = take_dict_indices(&values, sliced, None).unwrap();
+        let a = a.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(&StringArray::from(vec![None, None, Some("a")]), a);
+
+        // out of bounds dictionary values are checked
+        let keys = Int16Array::from(vec![0, 0]);
+        let dict_values: ArrayRef = Arc::new(UInt32Array::from(vec![4]));
+        let indices = dictionary(keys, dict_values);
+        let options = TakeOptions { check_bounds: true };
+        assert!(take_dict_indices(&values, &indices, Some(options.clone())).is_err());
+
+        // as well as out of bounds keys
+        let keys = Int16Array::from(vec![0, 1]);
+        let dict_values: ArrayRef = Arc::new(UInt32Array::from(vec![0]));
+        let indices = dictionary(keys, dict_values);
+        assert!(take_dict_indices(&values, &indices, Some(options)).is_err());
+
+        // only UInt32 dictionary values are supported
+        let keys = Int16Array::from(vec![0, 0]);
+        let dict_values: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let indices = dictionary(keys, dict_values);
+        assert!(take_dict_indices(&values, &indices, None).is_err());
+    }
+
     #[test]
     fn test_take_primitive() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(2)]);
@@ -937,6 +1228,60 @@ mod tests {
         _test_take_string::<LargeStringArray>()
     }
 
+    #[test]
+    fn test_take_string_sliced() {
+        let array = StringArray::from(vec![
+            Some("zero"),
+            Some("one"),
+            None,
+            Some("three"),
+            Some("four"),
+        ]);
+        let array = array.slice(1, 4);
+        let index = UInt32Array::from(vec![3, 0, 1, 0]);
+
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected =
+            StringArray::from(vec![Some("four"), Some("one"), None, Some("one")]);
+        assert_eq!(actual, &expected);
+    }
+
+    fn _test_take_binary<'a, K>()
+    where
+        K: 'static + Array + PartialEq + From<Vec<Option<&'a [u8]>>>,
+    {
+        let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(0)]);
+
+        let array = K::from(vec![
+            Some(b"one"),
+            None,
+            Some(b"three"),
+            Some(b""),
+            Some(b"five"),
+        ]);
+        let array = Arc::new(array) as ArrayRef;
+
+        let actual = take(&array, &index, None).unwrap();
+        assert_eq!(actual.len(), index.len());
+
+        let actual = actual.as_any().downcast_ref::<K>().unwrap();
+
+        let expected = K::from(vec![Some(b""), None, None, Some(b""), Some(b"one")]);
+
+        assert_eq!(actual, &expected);
+    }
+
+    #[test]
+    fn test_take_binary() {
+        _test_take_binary::<BinaryArray>()
+    }
+
+    #[test]
+    fn test_take_large_binary() {
+        _test_take_binary::<LargeBinaryArray>()
+    }
+
     macro_rules! test_take_list {
         ($offset_type:ty, $list_data_type:ident, $list_array_type:ident) => {{
             // Construct a value array, [[0,0,0], [-1,-2,-1], [2,3]]
@@ -1408,4 +1753,41 @@ mod tests {
         ]);
         assert_eq!(result.keys(), &expected_keys);
     }
+
+    #[test]
+    fn test_take_dict_sliced() {
+        let array: DictionaryArray<Int8Type> = vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("c"),
+            Some("a"),
+            None,
+            Some("b"),
+        ]
+        .into_iter()
+        .collect();
+        let array: ArrayRef = Arc::new(array);
+        // ["b", null, "c", "a", null]
+        let sliced = array.slice(1, 5);
+
+        let indices = UInt32Array::from(vec![Some(4), Some(2), None, Some(0), Some(1)]);
+        let result = take(&sliced, &indices, None).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(
+            &Int8Array::from(vec![None, Some(2), None, Some(1), None]),
+            result.keys()
+        );
+        assert_eq!(0, result.offset());
+
+        // the bounds are checked against the sliced array
+        let options = TakeOptions { check_bounds: true };
+        let indices = UInt32Array::from(vec![Some(5)]);
+        assert!(take(&sliced, &indices, Some(options.clone())).is_err());
+        let indices = UInt32Array::from(vec![Some(4)]);
+        assert!(take(&sliced, &indices, Some(options)).is_ok());
+    }
 }
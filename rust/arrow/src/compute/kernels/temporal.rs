@@ -17,34 +17,35 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::Timelike;
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveTime, Timelike, Utc};
 
 use crate::array::*;
+use crate::compute::kernels::cast::cast;
 use crate::datatypes::*;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
-/// Extracts the hours of a given temporal array as an array of integers
-pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+/// Extracts a component of the time of day of the values of a temporal array with `op`
+fn time_component<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<Int32Array>
 where
     T: ArrowTemporalType + ArrowNumericType,
     i64: std::convert::From<T::Native>,
+    F: Fn(NaiveTime) -> u32,
 {
     let mut b = Int32Builder::new(array.len());
     for i in 0..array.len() {
         if array.is_null(i) {
             b.append_null()?;
         } else {
-            match array.data_type() {
-                &DataType::Time32(_) | &DataType::Time64(_) => {
-                    match array.value_as_time(i) {
-                        Some(time) => b.append_value(time.hour() as i32)?,
-                        None => b.append_null()?,
-                    }
-                }
-                _ => match array.value_as_datetime(i) {
-                    Some(dt) => b.append_value(dt.hour() as i32)?,
-                    None => b.append_null()?,
-                },
+            let time = match array.data_type() {
+                &DataType::Time32(_) | &DataType::Time64(_) => array.value_as_time(i),
+                _ => array.value_as_datetime(i).map(|dt| dt.time()),
+            };
+            match time {
+                Some(time) => b.append_value(op(time) as i32)?,
+                None => b.append_null()?,
             }
         }
     }
@@ -52,6 +53,426 @@ where
     Ok(b.finish())
 }
 
+/// Extracts the hours of a given temporal array as an array of integers
+pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    time_component(array, |time| time.hour())
+}
+
+/// Extracts the minutes of a given temporal array as an array of integers
+pub fn minute<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    time_component(array, |time| time.minute())
+}
+
+/// Extracts the seconds of a given temporal array as an array of integers
+pub fn second<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    time_component(array, |time| time.second())
+}
+
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+/// Returns a primitive array of type `T` sharing the buffers of `array`, whose values
+/// must have the same width as `T::Native`
+pub(crate) fn reinterpret<T: ArrowPrimitiveType>(
+    array: &Array,
+    data_type: DataType,
+) -> PrimitiveArray<T> {
+    let data = ArrayData::new(
+        data_type,
+        array.len(),
+        Some(array.null_count()),
+        array.data().null_bitmap().clone().map(|bitmap| bitmap.bits),
+        array.data().offset(),
+        array.data().buffers().to_vec(),
+        vec![],
+    );
+    PrimitiveArray::<T>::from(Arc::new(data))
+}
+
+/// The number of `unit`s in a second
+fn units_per_second(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => 1_000_000_000,
+    }
+}
+
+/// Returns the finer of two time units
+fn finer_unit(left: &TimeUnit, right: &TimeUnit) -> TimeUnit {
+    if units_per_second(left) >= units_per_second(right) {
+        left.clone()
+    } else {
+        right.clone()
+    }
+}
+
+/// Returns the i64 values of `array`, whose values are in `from` units, converted to
+/// `to` units, which must be at least as fine as `from`
+fn values_in_unit(array: &Array, from: &TimeUnit, to: &TimeUnit) -> Result<Int64Array> {
+    let values = reinterpret::<Int64Type>(array, DataType::Int64);
+    let factor = units_per_second(to) / units_per_second(from);
+    if factor == 1 {
+        return Ok(values);
+    }
+    values
+        .iter()
+        .map(|v| {
+            v.map(|v| {
+                v.checked_mul(factor).ok_or_else(|| {
+                    ArrowError::ArithmeticOverflow(format!(
+                        "{:?} {:?} in {:?}",
+                        v, from, to
+                    ))
+                })
+            })
+            .transpose()
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(Int64Array::from)
+}
+
+/// Applies `op` on the non-null values of two arrays of the same length, returning a
+/// null slot when either value is null
+fn binary_op<L, R, O, F>(
+    left: &PrimitiveArray<L>,
+    right: &PrimitiveArray<R>,
+    op: F,
+) -> Result<Vec<Option<O>>>
+where
+    L: ArrowPrimitiveType,
+    R: ArrowPrimitiveType,
+    F: Fn(L::Native, R::Native) -> Result<O>,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+    (0..left.len())
+        .map(|i| {
+            if left.is_null(i) || right.is_null(i) {
+                Ok(None)
+            } else {
+                op(left.value(i), right.value(i)).map(Some)
+            }
+        })
+        .collect()
+}
+
+fn checked_i64_op(
+    left: i64,
+    right: i64,
+    op_name: &str,
+    op: fn(i64, i64) -> Option<i64>,
+) -> Result<i64> {
+    op(left, right).ok_or_else(|| {
+        ArrowError::ArithmeticOverflow(format!("{:?} {} {:?}", left, op_name, right))
+    })
+}
+
+/// Applies `op` on a timestamp array and a duration array, returning a timestamp array
+/// with the finer of their units and the timezone of `timestamps`
+fn timestamp_duration_op(
+    timestamps: &ArrayRef,
+    durations: &ArrayRef,
+    op_name: &str,
+    op: fn(i64, i64) -> Option<i64>,
+) -> Result<ArrayRef> {
+    match (timestamps.data_type(), durations.data_type()) {
+        (DataType::Timestamp(timestamp_unit, tz), DataType::Duration(duration_unit)) => {
+            let unit = finer_unit(timestamp_unit, duration_unit);
+            let left = values_in_unit(timestamps.as_ref(), timestamp_unit, &unit)?;
+            let right = values_in_unit(durations.as_ref(), duration_unit, &unit)?;
+            let values =
+                binary_op(&left, &right, |l, r| checked_i64_op(l, r, op_name, op))?;
+            let values = Int64Array::from(values);
+            let data_type = DataType::Timestamp(unit, tz.clone());
+            Ok(make_array(
+                reinterpret::<Int64Type>(&values, data_type).data(),
+            ))
+        }
+        (l, r) => Err(ArrowError::ComputeError(format!(
+            "Cannot perform timestamp {} duration on {:?} and {:?}",
+            op_name, l, r
+        ))),
+    }
+}
+
+/// Adds a duration array to a timestamp array. The result is a timestamp array with the
+/// timezone of `timestamps` and the finer of the units of both arrays. If either value
+/// is null then the result is also null.
+///
+/// This function errors if the arrays have different lengths or are not a timestamp and
+/// a duration array, or if a value overflows.
+pub fn add_timestamp_duration(
+    timestamps: &ArrayRef,
+    durations: &ArrayRef,
+) -> Result<ArrayRef> {
+    timestamp_duration_op(timestamps, durations, "+", i64::checked_add)
+}
+
+/// Subtracts a duration array from a timestamp array. See `add_timestamp_duration` for
+/// the type of the result and the errors.
+pub fn subtract_timestamp_duration(
+    timestamps: &ArrayRef,
+    durations: &ArrayRef,
+) -> Result<ArrayRef> {
+    timestamp_duration_op(timestamps, durations, "-", i64::checked_sub)
+}
+
+/// Subtracts two timestamp arrays, returning the durations between their values in the
+/// finer of their units. If either value is null then the result is also null.
+///
+/// This function errors if the arrays have different lengths or are not timestamp
+/// arrays, if only one of them has a timezone, or if a value overflows.
+pub fn subtract_timestamps(left: &ArrayRef, right: &ArrayRef) -> Result<ArrayRef> {
+    match (left.data_type(), right.data_type()) {
+        (
+            DataType::Timestamp(left_unit, left_tz),
+            DataType::Timestamp(right_unit, right_tz),
+        ) if left_tz.is_some() == right_tz.is_some() => {
+            let unit = finer_unit(left_unit, right_unit);
+            let left = values_in_unit(left.as_ref(), left_unit, &unit)?;
+            let right = values_in_unit(right.as_ref(), right_unit, &unit)?;
+            let values = binary_op(&left, &right, |l, r| {
+                checked_i64_op(l, r, "-", i64::checked_sub)
+            })?;
+            let values = Int64Array::from(values);
+            Ok(make_array(
+                reinterpret::<Int64Type>(&values, DataType::Duration(unit)).data(),
+            ))
+        }
+        (l, r) => Err(ArrowError::ComputeError(format!(
+            "Cannot subtract {:?} from {:?}",
+            r, l
+        ))),
+    }
+}
+
+/// Multiplies a duration array by an integer array. The result is a duration array with
+/// the unit of `durations`. If either value is null then the result is also null.
+///
+/// This function errors if the arrays have different lengths or are not a duration and
+/// an integer array, or if a value overflows.
+pub fn multiply_duration(durations: &ArrayRef, factors: &ArrayRef) -> Result<ArrayRef> {
+    match (durations.data_type(), factors.data_type()) {
+        (
+            DataType::Duration(_),
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32,
+        ) => {
+            let left = reinterpret::<Int64Type>(durations.as_ref(), DataType::Int64);
+            let factors = cast(factors, &DataType::Int64)?;
+            let right = factors.as_typed::<Int64Array>()?;
+            let values = binary_op(&left, right, |l, r| {
+                checked_i64_op(l, r, "*", i64::checked_mul)
+            })?;
+            let values = Int64Array::from(values);
+            Ok(make_array(
+                reinterpret::<Int64Type>(&values, durations.data_type().clone()).data(),
+            ))
+        }
+        (l, r) => Err(ArrowError::ComputeError(format!(
+            "Cannot multiply {:?} by {:?}",
+            l, r
+        ))),
+    }
+}
+
+/// Adds `months` to `date`, clamping the day to the last day of the resulting month
+fn add_months(date: NaiveDate, months: i32) -> Option<NaiveDate> {
+    let months = (date.year() as i64) * 12 + (date.month0() as i64) + months as i64;
+    let year = months.div_euclid(12) as i32;
+    let month = months.rem_euclid(12) as u32 + 1;
+    (1..=date.day())
+        .rev()
+        .find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
+fn epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
+/// Adds `months` to a number of milliseconds since the epoch, keeping the time of day
+fn add_months_to_millis(millis: i64, months: i32) -> Option<i64> {
+    let days = millis.div_euclid(MILLISECONDS_IN_DAY);
+    let time = millis.rem_euclid(MILLISECONDS_IN_DAY);
+    let date = epoch().checked_add_signed(chrono::Duration::days(days))?;
+    let date = add_months(date, months)?;
+    date.signed_duration_since(epoch())
+        .num_days()
+        .checked_mul(MILLISECONDS_IN_DAY)?
+        .checked_add(time)
+}
+
+/// Splits a day-time interval into its days and milliseconds
+fn split_day_time(interval: i64) -> (i64, i64) {
+    ((interval >> 32) as i32 as i64, interval as i32 as i64)
+}
+
+/// Adds an interval array to a date array, returning a date array of the same type as
+/// `dates`. Year-month intervals add calendar months: the day is clamped to the last day
+/// of the resulting month, so that `2021-01-31 + 1 month` is `2021-02-28`. If either
+/// value is null then the result is also null.
+///
+/// This function errors if the arrays have different lengths or are not a date and an
+/// interval array, if a value overflows, or if a day-time interval with a non-zero
+/// number of milliseconds is added to a `Date32` array.
+pub fn add_date_interval(dates: &ArrayRef, intervals: &ArrayRef) -> Result<ArrayRef> {
+    let overflow = |date: &dyn std::fmt::Debug, interval: &dyn std::fmt::Debug| {
+        ArrowError::ArithmeticOverflow(format!("{:?} + {:?}", date, interval))
+    };
+    let values: ArrayRef = match (dates.data_type(), intervals.data_type()) {
+        (DataType::Date32(_), DataType::Interval(IntervalUnit::YearMonth)) => {
+            let dates = reinterpret::<Int32Type>(dates.as_ref(), DataType::Int32);
+            let intervals = reinterpret::<Int32Type>(intervals.as_ref(), DataType::Int32);
+            Arc::new(Int32Array::from(binary_op(&dates, &intervals, |d, m| {
+                epoch()
+                    .checked_add_signed(chrono::Duration::days(d as i64))
+                    .and_then(|date| add_months(date, m))
+                    .map(|date| date.signed_duration_since(epoch()).num_days())
+                    .and_then(|days| i32::try_from(days).ok())
+                    .ok_or_else(|| overflow(&d, &m))
+            })?))
+        }
+        (DataType::Date32(_), DataType::Interval(IntervalUnit::DayTime)) => {
+            let dates = reinterpret::<Int32Type>(dates.as_ref(), DataType::Int32);
+            let intervals = reinterpret::<Int64Type>(intervals.as_ref(), DataType::Int64);
+            Arc::new(Int32Array::from(binary_op(&dates, &intervals, |d, i| {
+                let (days, millis) = split_day_time(i);
+                if millis != 0 {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Cannot add an interval of {} days and {} milliseconds to a \
+                         Date32",
+                        days, millis
+                    )));
+                }
+                d.checked_add(days as i32)
+                    .ok_or_else(|| overflow(&d, &(days, millis)))
+            })?))
+        }
+        (DataType::Date64(_), DataType::Interval(IntervalUnit::YearMonth)) => {
+            let dates = reinterpret::<Int64Type>(dates.as_ref(), DataType::Int64);
+            let intervals = reinterpret::<Int32Type>(intervals.as_ref(), DataType::Int32);
+            Arc::new(Int64Array::from(binary_op(&dates, &intervals, |d, m| {
+                add_months_to_millis(d, m).ok_or_else(|| overflow(&d, &m))
+            })?))
+        }
+        (DataType::Date64(_), DataType::Interval(IntervalUnit::DayTime)) => {
+            let dates = reinterpret::<Int64Type>(dates.as_ref(), DataType::Int64);
+            let intervals = reinterpret::<Int64Type>(intervals.as_ref(), DataType::Int64);
+            Arc::new(Int64Array::from(binary_op(&dates, &intervals, |d, i| {
+                let (days, millis) = split_day_time(i);
+                days.checked_mul(MILLISECONDS_IN_DAY)
+                    .and_then(|days| d.checked_add(days))
+                    .and_then(|d| d.checked_add(millis))
+                    .ok_or_else(|| overflow(&d, &(days, millis)))
+            })?))
+        }
+        (l, r) => {
+            return Err(ArrowError::ComputeError(format!(
+                "Cannot add {:?} to {:?}",
+                r, l
+            )))
+        }
+    };
+
+    let data = values.data();
+    let data = ArrayData::new(
+        dates.data_type().clone(),
+        data.len(),
+        Some(data.null_count()),
+        data.null_buffer().cloned(),
+        0,
+        data.buffers().to_vec(),
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// A source of the current time, which can be injected into [`now`] and [`current_date`]
+/// to evaluate them deterministically, e.g. in tests.
+pub trait Clock {
+    /// Returns the current time
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The [`Clock`] of the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A [`Clock`] stopped at a given time
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+/// Returns a `Timestamp(Nanosecond, Some("UTC"))` array of `len` slots, all set to the
+/// current time of `clock`, as in SQL's `NOW()`.
+///
+/// This function errors if the current time is not representable as nanoseconds since
+/// the epoch, i.e. outside of the years 1677 to 2262.
+///
+/// ```
+/// use arrow::array::Array;
+/// use arrow::compute::{now, FixedClock};
+/// use chrono::{TimeZone, Utc};
+///
+/// let clock = FixedClock(Utc.timestamp_opt(1_600_000_000, 0).unwrap());
+/// let array = now(&clock, 3).unwrap();
+/// assert_eq!(array.len(), 3);
+/// assert_eq!(array.value(2), 1_600_000_000_000_000_000);
+/// ```
+pub fn now(clock: &dyn Clock, len: usize) -> Result<TimestampNanosecondArray> {
+    let now = clock.now();
+    let nanoseconds = now
+        .timestamp()
+        .checked_mul(1_000_000_000)
+        .and_then(|v| v.checked_add(i64::from(now.timestamp_subsec_nanos())))
+        .ok_or_else(|| {
+            ArrowError::ArithmeticOverflow(format!("{:?} in nanoseconds", now))
+        })?;
+    Ok(TimestampNanosecondArray::from_vec(
+        vec![nanoseconds; len],
+        Some("UTC".to_string()),
+    ))
+}
+
+/// Returns a `Date32` array of `len` slots, all set to the current date in UTC of
+/// `clock`, as in SQL's `CURRENT_DATE`.
+pub fn current_date(clock: &dyn Clock, len: usize) -> Date32Array {
+    let days = clock.now().timestamp().div_euclid(86_400) as i32;
+    Date32Array::from(vec![days; len])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +498,204 @@ mod tests {
         assert_eq!(10, b.value(0));
         assert_eq!(23, b.value(1));
     }
+
+    #[test]
+    fn test_temporal_array_minute_second() {
+        let a: PrimitiveArray<Time64MicrosecondType> =
+            vec![Some(45_296_789_000), None, Some(86_399_999_999)].into();
+        let b = minute(&a).unwrap();
+        assert_eq!(34, b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!(59, b.value(2));
+        let b = second(&a).unwrap();
+        assert_eq!(56, b.value(0));
+        assert_eq!(59, b.value(2));
+
+        // 2019-02-20T04:23:45
+        let a: PrimitiveArray<TimestampMillisecondType> =
+            vec![Some(1550636625000)].into();
+        assert_eq!(23, minute(&a).unwrap().value(0));
+        assert_eq!(45, second(&a).unwrap().value(0));
+    }
+
+    fn timestamps(
+        values: Vec<Option<i64>>,
+        unit: TimeUnit,
+        tz: Option<&str>,
+    ) -> ArrayRef {
+        let array = Int64Array::from(values);
+        make_array(
+            reinterpret::<Int64Type>(
+                &array,
+                DataType::Timestamp(unit, tz.map(String::from)),
+            )
+            .data(),
+        )
+    }
+
+    fn durations(values: Vec<Option<i64>>, unit: TimeUnit) -> ArrayRef {
+        let array = Int64Array::from(values);
+        make_array(reinterpret::<Int64Type>(&array, DataType::Duration(unit)).data())
+    }
+
+    #[test]
+    fn test_timestamp_duration_arithmetic() {
+        let ts = timestamps(
+            vec![Some(10), None, Some(-5), Some(7)],
+            TimeUnit::Second,
+            Some("+01:00"),
+        );
+        let d = durations(
+            vec![Some(1500), Some(1), None, Some(-7000)],
+            TimeUnit::Millisecond,
+        );
+
+        let result = add_timestamp_duration(&ts, &d).unwrap();
+        let expected = timestamps(
+            vec![Some(11500), None, None, Some(0)],
+            TimeUnit::Millisecond,
+            Some("+01:00"),
+        );
+        assert_eq!(&expected, &result);
+
+        let result = subtract_timestamp_duration(&ts, &d).unwrap();
+        let expected = timestamps(
+            vec![Some(8500), None, None, Some(14000)],
+            TimeUnit::Millisecond,
+            Some("+01:00"),
+        );
+        assert_eq!(&expected, &result);
+
+        assert!(add_timestamp_duration(&d, &ts).is_err());
+        let d = durations(vec![Some(i64::MAX); 4], TimeUnit::Second);
+        assert!(matches!(
+            add_timestamp_duration(&ts, &d),
+            Err(ArrowError::ArithmeticOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn test_subtract_timestamps() {
+        let left =
+            timestamps(vec![Some(2000), Some(3), None], TimeUnit::Millisecond, None);
+        let right = timestamps(vec![Some(1), Some(4), Some(1)], TimeUnit::Second, None);
+        let result = subtract_timestamps(&left, &right).unwrap();
+        let expected =
+            durations(vec![Some(1000), Some(-3997), None], TimeUnit::Millisecond);
+        assert_eq!(&expected, &result);
+
+        let right = timestamps(vec![Some(1); 3], TimeUnit::Second, Some("UTC"));
+        assert!(subtract_timestamps(&left, &right).is_err());
+    }
+
+    #[test]
+    fn test_multiply_duration() {
+        let d = durations(
+            vec![Some(3), None, Some(-2), Some(5)],
+            TimeUnit::Microsecond,
+        );
+        let factors: ArrayRef =
+            Arc::new(Int8Array::from(vec![Some(2), Some(1), None, Some(-1)]));
+        let result = multiply_duration(&d, &factors).unwrap();
+        let expected =
+            durations(vec![Some(6), None, None, Some(-5)], TimeUnit::Microsecond);
+        assert_eq!(&expected, &result);
+
+        let factors: ArrayRef = Arc::new(Float64Array::from(vec![1.0; 4]));
+        assert!(multiply_duration(&d, &factors).is_err());
+    }
+
+    #[test]
+    fn test_add_date_interval() {
+        // 2020-01-31, 2021-03-15, null
+        let dates: ArrayRef =
+            Arc::new(Date32Array::from(vec![Some(18292), Some(18701), None]));
+        let months: ArrayRef = Arc::new(IntervalYearMonthArray::from(vec![
+            Some(1),
+            Some(-14),
+            Some(1),
+        ]));
+        let result = add_date_interval(&dates, &months).unwrap();
+        let result = result.as_any().downcast_ref::<Date32Array>().unwrap();
+        // 2020-02-29, 2020-01-15, null
+        assert_eq!(
+            &Date32Array::from(vec![Some(18321), Some(18276), None]),
+            result
+        );
+
+        let day_time: ArrayRef = Arc::new(IntervalDayTimeArray::from(vec![
+            Some(2 << 32),
+            Some(-1 << 32),
+            Some(0),
+        ]));
+        let result = add_date_interval(&dates, &day_time).unwrap();
+        let result = result.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(
+            &Date32Array::from(vec![Some(18294), Some(18700), None]),
+            result
+        );
+
+        // 2020-01-31T12:00:00
+        let dates: ArrayRef =
+            Arc::new(Date64Array::from(vec![1580472000000, 1580472000000]));
+        let intervals: ArrayRef = Arc::new(IntervalYearMonthArray::from(vec![1, 13]));
+        let result = add_date_interval(&dates, &intervals).unwrap();
+        let result = result.as_any().downcast_ref::<Date64Array>().unwrap();
+        // 2020-02-29T12:00:00, 2021-02-28T12:00:00
+        assert_eq!(
+            &Date64Array::from(vec![1582977600000, 1614513600000]),
+            result
+        );
+
+        // 1 day and 1000 milliseconds, -1 millisecond
+        let intervals: ArrayRef = Arc::new(IntervalDayTimeArray::from(vec![
+            (1 << 32) + 1000,
+            u32::MAX as i64,
+        ]));
+        let result = add_date_interval(&dates, &intervals).unwrap();
+        let result = result.as_any().downcast_ref::<Date64Array>().unwrap();
+        assert_eq!(
+            &Date64Array::from(vec![1580558401000, 1580471999999]),
+            result
+        );
+
+        let dates: ArrayRef = Arc::new(Date32Array::from(vec![0]));
+        let intervals: ArrayRef = Arc::new(IntervalDayTimeArray::from(vec![1]));
+        assert!(add_date_interval(&dates, &intervals).is_err());
+        assert!(add_date_interval(&intervals, &dates).is_err());
+    }
+
+    #[test]
+    fn test_now_and_current_date() {
+        use chrono::TimeZone;
+
+        // 2021-03-04T05:06:07.000000008Z
+        let clock = FixedClock(Utc.timestamp_opt(1_614_834_367, 8).unwrap());
+        let a = now(&clock, 2).unwrap();
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string())),
+            a.data_type()
+        );
+        assert_eq!(0, a.null_count());
+        assert_eq!(&[1_614_834_367_000_000_008; 2], a.value_slice(0, 2));
+        assert_eq!(
+            &Date32Array::from(vec![18_690, 18_690, 18_690]),
+            &current_date(&clock, 3)
+        );
+
+        // dates before the epoch
+        let clock = FixedClock(Utc.with_ymd_and_hms(1969, 12, 31, 23, 0, 0).unwrap());
+        assert_eq!(-1, current_date(&clock, 1).value(0));
+        assert_eq!(-3_600_000_000_000, now(&clock, 1).unwrap().value(0));
+
+        // times not representable as nanoseconds
+        let clock = FixedClock(Utc.with_ymd_and_hms(2300, 1, 1, 0, 0, 0).unwrap());
+        assert!(matches!(
+            now(&clock, 1),
+            Err(ArrowError::ArithmeticOverflow(_))
+        ));
+
+        assert_eq!(0, now(&SystemClock, 0).unwrap().len());
+        assert!(current_date(&SystemClock, 1).value(0) > 18_690);
+    }
 }
@@ -0,0 +1,200 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to map the case of the values of \[Large\]String arrays, and to
+//! normalize and to case fold them so that text entered differently by users compares
+//! equal, e.g. before it is joined or grouped by.
+//!
+//! Normalization is only available with the `unicode-normalization` feature, and case
+//! folding with the `caseless` feature. Both are usually applied together: values
+//! case folded and then normalized with NFKC compare equal regardless of their case,
+//! their composition and their compatibility variants.
+
+use crate::array::*;
+use crate::compute::util::map_utf8;
+use crate::error::Result;
+
+/// Maps the values of a \[Large\]String array to upper case, like SQL `UPPER` does, with
+/// the full case mapping of Unicode: the case of a character can map to several, e.g.
+/// `"ß"` to `"SS"`. Nulls are preserved, and ASCII values are mapped byte by byte.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+pub fn upper(array: &Array) -> Result<ArrayRef> {
+    map_utf8(array, "upper", |value, values| {
+        if value.is_ascii() {
+            values.extend(value.bytes().map(|b| b.to_ascii_uppercase() as char));
+        } else {
+            values.extend(value.chars().flat_map(char::to_uppercase));
+        }
+    })
+}
+
+/// Maps the values of a \[Large\]String array to lower case, like SQL `LOWER` does, with
+/// the full case mapping of Unicode. Nulls are preserved, and ASCII values are mapped
+/// byte by byte.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+pub fn lower(array: &Array) -> Result<ArrayRef> {
+    map_utf8(array, "lower", |value, values| {
+        if value.is_ascii() {
+            values.extend(value.bytes().map(|b| b.to_ascii_lowercase() as char));
+        } else if value.contains('\u{3a3}') {
+            // the lower case of a capital sigma depends on whether it ends a word
+            values.push_str(&value.to_lowercase());
+        } else {
+            values.extend(value.chars().flat_map(char::to_lowercase));
+        }
+    })
+}
+
+/// The Unicode normalization forms of [`normalize`]
+#[cfg(feature = "unicode-normalization")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NormalizationForm {
+    /// Canonical decomposition followed by canonical composition
+    Nfc,
+    /// Canonical decomposition
+    Nfd,
+    /// Compatibility decomposition followed by canonical composition
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd,
+}
+
+/// Normalizes the values of a \[Large\]String array to the Unicode normalization form
+/// `form`. Nulls are preserved, and values that are already normalized, such as ASCII
+/// values, are copied as they are.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+#[cfg(feature = "unicode-normalization")]
+pub fn normalize(array: &Array, form: NormalizationForm) -> Result<ArrayRef> {
+    use unicode_normalization::{
+        is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized,
+        UnicodeNormalization,
+    };
+
+    map_utf8(array, "normalize", |value, values| {
+        let is_normalized = match form {
+            NormalizationForm::Nfc => is_nfc_quick(value.chars()),
+            NormalizationForm::Nfd => is_nfd_quick(value.chars()),
+            NormalizationForm::Nfkc => is_nfkc_quick(value.chars()),
+            NormalizationForm::Nfkd => is_nfkd_quick(value.chars()),
+        };
+        if is_normalized == IsNormalized::Yes {
+            values.push_str(value);
+        } else {
+            match form {
+                NormalizationForm::Nfc => values.extend(value.nfc()),
+                NormalizationForm::Nfd => values.extend(value.nfd()),
+                NormalizationForm::Nfkc => values.extend(value.nfkc()),
+                NormalizationForm::Nfkd => values.extend(value.nfkd()),
+            }
+        }
+    })
+}
+
+/// Case folds the values of a \[Large\]String array with the default, locale-independent
+/// case folding of Unicode, e.g. `"Straße"` to `"strasse"`. Nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String array.
+#[cfg(feature = "caseless")]
+pub fn case_fold(array: &Array) -> Result<ArrayRef> {
+    use caseless::Caseless;
+
+    map_utf8(array, "case_fold", |value, values| {
+        if value.is_ascii() {
+            values.extend(value.bytes().map(|b| b.to_ascii_lowercase() as char));
+        } else {
+            values.extend(value.chars().default_case_fold());
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upper() {
+        let array =
+            StringArray::from(vec![Some("arrow"), None, Some("straße"), Some("")]);
+        let result = upper(&array).unwrap();
+        assert_eq!(
+            result.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![Some("ARROW"), None, Some("STRASSE"), Some("")])
+        );
+    }
+
+    #[test]
+    fn test_lower() {
+        let array =
+            LargeStringArray::from(vec![Some("X"), Some("ÀB"), Some("ΟΔΟΣ"), None]);
+        let array = array.slice(1, 3);
+        let result = lower(array.as_ref()).unwrap();
+        assert_eq!(
+            result.as_typed::<LargeStringArray>().unwrap(),
+            &LargeStringArray::from(vec![Some("àb"), Some("οδος"), None])
+        );
+        assert!(lower(&Int32Array::from(vec![1])).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "unicode-normalization")]
+    fn test_normalize() {
+        // "é" composed, decomposed, and the "ﬁ" ligature
+        let array = StringArray::from(vec![
+            Some("caf\u{e9}"),
+            Some("cafe\u{301}"),
+            None,
+            Some("\u{fb01}x"),
+        ]);
+        let nfc = normalize(&array, NormalizationForm::Nfc).unwrap();
+        assert_eq!(
+            nfc.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![
+                Some("caf\u{e9}"),
+                Some("caf\u{e9}"),
+                None,
+                Some("\u{fb01}x"),
+            ])
+        );
+        let nfkd = normalize(&array, NormalizationForm::Nfkd).unwrap();
+        let nfkd = nfkd.as_typed::<StringArray>().unwrap();
+        assert_eq!(nfkd.value(0), "cafe\u{301}");
+        assert_eq!(nfkd.value(3), "fix");
+    }
+
+    #[test]
+    #[cfg(feature = "caseless")]
+    fn test_case_fold() {
+        let array =
+            LargeStringArray::from(vec![Some("x"), Some("Straße"), None, Some("ABC")]);
+        let array = array.slice(1, 3);
+        let folded = case_fold(array.as_ref()).unwrap();
+        assert_eq!(
+            folded.as_typed::<LargeStringArray>().unwrap(),
+            &LargeStringArray::from(vec![Some("strasse"), None, Some("abc")])
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "caseless")]
+    fn test_case_fold_unsupported_type() {
+        let array = Int32Array::from(vec![1]);
+        assert!(case_fold(&array).is_err());
+    }
+}
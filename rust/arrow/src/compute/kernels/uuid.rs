@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to parse, format and generate UUIDs stored in FixedSizeBinary(16)
+//! arrays
+
+use std::sync::Arc;
+
+use rand::RngCore;
+
+use super::encoding::Encoding;
+use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::{DataType, ToByteSlice};
+use crate::error::{ArrowError, Result};
+
+/// The number of bytes of a UUID
+const UUID_SIZE: usize = 16;
+/// The length of the canonical string representation of a UUID
+const UUID_STRING_LEN: usize = 36;
+/// The positions of the dashes in the canonical string representation of a UUID
+const DASH_POSITIONS: [usize; 4] = [8, 13, 18, 23];
+
+/// Parses a UUID in its canonical form, such as `67e55044-10b1-426f-9247-bb680e5fe0c8`,
+/// into `output`
+fn parse_uuid_into(value: &str, output: &mut [u8]) -> Option<()> {
+    let value = value.as_bytes();
+    if value.len() != UUID_STRING_LEN
+        || DASH_POSITIONS.iter().any(|pos| value[*pos] != b'-')
+    {
+        return None;
+    }
+    let mut hex = [0u8; UUID_SIZE * 2];
+    let digits = value
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !DASH_POSITIONS.contains(i))
+        .map(|(_, c)| *c);
+    hex.iter_mut().zip(digits).for_each(|(h, c)| *h = c);
+    Encoding::Hex.decode_into(&hex, output).map(|_| ())
+}
+
+fn generic_parse_uuid<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+) -> Result<FixedSizeBinaryArray> {
+    let mut values = vec![0u8; array.len() * UUID_SIZE];
+    for (i, output) in values.chunks_mut(UUID_SIZE).enumerate() {
+        if array.is_valid(i) {
+            parse_uuid_into(array.value(i), output).ok_or_else(|| {
+                ArrowError::ComputeError(format!(
+                    "Cannot parse '{}' as a UUID",
+                    array.value(i)
+                ))
+            })?;
+        }
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        DataType::FixedSizeBinary(UUID_SIZE as i32),
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(&values[..])],
+        vec![],
+    );
+    Ok(FixedSizeBinaryArray::from(Arc::new(data)))
+}
+
+/// Parses the UUIDs of a \[Large\]String array in their canonical form, such as
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8`, into a FixedSizeBinary(16) array. Both
+/// lowercase and uppercase hexadecimal digits are accepted. Nulls are preserved.
+///
+/// This function errors when a value is not a valid UUID, or when `array` is not a
+/// \[Large\]String array.
+pub fn parse_uuid(array: &Array) -> Result<FixedSizeBinaryArray> {
+    match array.data_type() {
+        DataType::Utf8 => generic_parse_uuid(
+            array
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .expect("A string is expected"),
+        ),
+        DataType::LargeUtf8 => generic_parse_uuid(
+            array
+                .as_any()
+                .downcast_ref::<LargeStringArray>()
+                .expect("A large string is expected"),
+        ),
+        _ => Err(ArrowError::ComputeError(format!(
+            "parse_uuid does not support type {:?}",
+            array.data_type()
+        ))),
+    }
+}
+
+/// Formats the UUIDs of a FixedSizeBinary(16) array in their canonical form, with
+/// lowercase hexadecimal digits. Nulls are preserved.
+///
+/// This function errors when the values of `array` are not 16 bytes long.
+pub fn format_uuid(array: &FixedSizeBinaryArray) -> Result<StringArray> {
+    if array.value_length() as usize != UUID_SIZE {
+        return Err(ArrowError::ComputeError(format!(
+            "format_uuid does not support type {:?}",
+            array.data_type()
+        )));
+    }
+
+    // all the valid values have the same length
+    let mut offsets: Vec<i32> = Vec::with_capacity(array.len() + 1);
+    let mut length_so_far = 0;
+    offsets.push(0);
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            length_so_far += UUID_STRING_LEN as i32;
+        }
+        offsets.push(length_so_far);
+    }
+
+    let mut values = MutableBuffer::new(length_so_far as usize);
+    values.resize(length_so_far as usize);
+    let data = values.data_mut();
+    let mut hex = [0u8; UUID_SIZE * 2];
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            Encoding::Hex.encode_into(array.value(i), &mut hex);
+            let output = &mut data[offsets[i] as usize..offsets[i + 1] as usize];
+            let mut digits = hex.iter();
+            for (j, c) in output.iter_mut().enumerate() {
+                *c = if DASH_POSITIONS.contains(&j) {
+                    b'-'
+                } else {
+                    *digits.next().unwrap()
+                };
+            }
+        }
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        DataType::Utf8,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![Buffer::from(offsets.to_byte_slice()), values.freeze()],
+        vec![],
+    );
+    Ok(StringArray::from(Arc::new(data)))
+}
+
+/// Returns a FixedSizeBinary(16) array of `len` random (version 4) UUIDs, without nulls.
+pub fn random_uuid(len: usize) -> FixedSizeBinaryArray {
+    let mut values = vec![0u8; len * UUID_SIZE];
+    rand::thread_rng().fill_bytes(&mut values);
+    for uuid in values.chunks_mut(UUID_SIZE) {
+        // version 4, variant 1 (RFC 4122)
+        uuid[6] = (uuid[6] & 0x0f) | 0x40;
+        uuid[8] = (uuid[8] & 0x3f) | 0x80;
+    }
+
+    let data = ArrayData::new(
+        DataType::FixedSizeBinary(UUID_SIZE as i32),
+        len,
+        Some(0),
+        None,
+        0,
+        vec![Buffer::from(&values[..])],
+        vec![],
+    );
+    FixedSizeBinaryArray::from(Arc::new(data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_format_uuid() {
+        let array = StringArray::from(vec![
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("00000000-0000-0000-0000-000000000000"),
+            Some("FFFFFFFF-FFFF-4FFF-BFFF-FFFFFFFFFFFF"),
+        ]);
+        let uuids = parse_uuid(&array).unwrap();
+        assert_eq!(4, uuids.len());
+        assert_eq!(1, uuids.null_count());
+        assert_eq!(
+            &[
+                0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68,
+                0x0e, 0x5f, 0xe0, 0xc8
+            ],
+            uuids.value(0)
+        );
+        assert_eq!(&[0; 16], uuids.value(2));
+
+        let formatted = format_uuid(&uuids).unwrap();
+        let expected = StringArray::from(vec![
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("00000000-0000-0000-0000-000000000000"),
+            Some("ffffffff-ffff-4fff-bfff-ffffffffffff"),
+        ]);
+        assert_eq!(expected, formatted);
+    }
+
+    #[test]
+    fn test_parse_uuid_invalid() {
+        for value in &[
+            "67e55044-10b1-426f-9247-bb680e5fe0c",
+            "67e5504410b1426f9247bb680e5fe0c8",
+            "67e55044-10b1-426f-9247_bb680e5fe0c8",
+            "67e55044-10b1-426f-9247-bb680e5fe0cg",
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+        ] {
+            let array = LargeStringArray::from(vec![*value]);
+            assert!(parse_uuid(&array).is_err(), "{}", value);
+        }
+        assert!(parse_uuid(&Int32Array::from(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_format_uuid_sliced() {
+        let array = FixedSizeBinaryArray::from(vec![
+            Some(vec![1; 16]),
+            None,
+            Some(vec![0xab; 16]),
+        ]);
+        let array = array.slice(1, 2);
+        let array = array
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        let expected =
+            StringArray::from(vec![None, Some("abababab-abab-abab-abab-abababababab")]);
+        assert_eq!(expected, format_uuid(array).unwrap());
+
+        let array = FixedSizeBinaryArray::from(vec![vec![1; 4]]);
+        assert!(format_uuid(&array).is_err());
+    }
+
+    #[test]
+    fn test_random_uuid() {
+        let uuids = random_uuid(100);
+        assert_eq!(100, uuids.len());
+        assert_eq!(0, uuids.null_count());
+        for i in 0..uuids.len() {
+            assert_eq!(0x40, uuids.value(i)[6] & 0xf0);
+            assert_eq!(0x80, uuids.value(i)[8] & 0xc0);
+        }
+        let formatted = format_uuid(&uuids).unwrap();
+        let parsed = parse_uuid(&formatted).unwrap();
+        assert_eq!(uuids.value_data(), parsed.value_data());
+        assert_ne!(uuids.value(0), uuids.value(1));
+    }
+}
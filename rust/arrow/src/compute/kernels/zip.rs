@@ -0,0 +1,303 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the zip kernel, which selects the values of two arrays based on a mask, as a
+//! `CASE WHEN` expression does.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::util::unify_dictionaries;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::scalar::Scalar;
+
+/// The values selected by [`zip`] for one of the sides of the mask: either an array of
+/// the length of the mask, or a scalar repeated for each of its slots
+#[derive(Debug, Clone, Copy)]
+pub enum ZipValues<'a> {
+    /// the values of an array
+    Array(&'a ArrayRef),
+    /// a value repeated for each slot
+    Scalar(&'a Scalar),
+}
+
+impl<'a> From<&'a ArrayRef> for ZipValues<'a> {
+    fn from(array: &'a ArrayRef) -> Self {
+        ZipValues::Array(array)
+    }
+}
+
+impl<'a> From<&'a Scalar> for ZipValues<'a> {
+    fn from(scalar: &'a Scalar) -> Self {
+        ZipValues::Scalar(scalar)
+    }
+}
+
+/// Returns an array whose values are those of `truthy` where `mask` is true, and those of
+/// `falsy` where it is false or null.
+///
+/// `truthy` and `falsy` are either arrays of the length of the mask, or scalars. They
+/// must be of the same data type, except for a null scalar, which is of any type.
+/// Dictionary arrays of different dictionaries are zipped into an array of the union of
+/// their dictionaries.
+///
+/// # Errors
+/// This function errors if the arrays are of different lengths or data types, or of a
+/// nested type other than lists and structs.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, BooleanArray, Int32Array};
+/// use arrow::compute::kernels::zip::zip;
+/// use arrow::error::Result;
+/// use arrow::scalar::Scalar;
+/// # fn main() -> Result<()> {
+/// let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let zero = Scalar::Int32(Some(0));
+/// let zipped = zip(&mask, &values, &zero)?;
+/// assert_eq!(
+///     zipped.as_typed::<Int32Array>()?,
+///     &Int32Array::from(vec![1, 0, 0])
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn zip<'a, T, F>(mask: &BooleanArray, truthy: T, falsy: F) -> Result<ArrayRef>
+where
+    T: Into<ZipValues<'a>>,
+    F: Into<ZipValues<'a>>,
+{
+    let len = mask.len();
+    let truthy = resolve(truthy.into(), len)?;
+    let falsy = resolve(falsy.into(), len)?;
+
+    let data_type = match (&truthy, &falsy) {
+        (Some(t), Some(f)) if t.data_type() != f.data_type() => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot zip arrays of different data types {:?} and {:?}",
+                t.data_type(),
+                f.data_type()
+            )))
+        }
+        (Some(array), _) | (None, Some(array)) => array.data_type().clone(),
+        // both sides are null
+        (None, None) => return Ok(Arc::new(NullArray::new(len))),
+    };
+    match data_type {
+        DataType::Null => return Ok(Arc::new(NullArray::new(len))),
+        DataType::Float16 | DataType::FixedSizeList(_, _) | DataType::Union(_) => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Zip is not supported for arrays of type {:?}",
+                data_type
+            )))
+        }
+        _ => {}
+    }
+    let (truthy, falsy) = match (truthy, falsy) {
+        (Some(t), Some(f)) => {
+            let mut unified = unify_dictionaries(&[t, f])?.into_iter();
+            (unified.next(), unified.next())
+        }
+        sides => sides,
+    };
+
+    // the sources of the values of the result, where a missing side is null
+    let sources = truthy
+        .iter()
+        .chain(falsy.iter())
+        .map(|array| array.data_ref().as_ref())
+        .collect::<Vec<_>>();
+    let truthy_index = truthy.as_ref().map(|_| 0);
+    let falsy_index = falsy.as_ref().map(|_| sources.len() - 1);
+    let mut mutable = MutableArrayData::new(sources, true, len);
+    let mut extend = |index: Option<usize>, start: usize, end: usize| match index {
+        Some(index) => mutable.extend(index, start, end),
+        None => mutable.extend_nulls(end - start),
+    };
+
+    let mut start = 0;
+    let mut selected = false;
+    for i in 0..len {
+        let is_truthy = mask.is_valid(i) && mask.value(i);
+        if is_truthy != selected {
+            if i > start {
+                extend(if selected { truthy_index } else { falsy_index }, start, i);
+            }
+            start = i;
+            selected = is_truthy;
+        }
+    }
+    if len > start {
+        extend(
+            if selected { truthy_index } else { falsy_index },
+            start,
+            len,
+        );
+    }
+    Ok(make_array(Arc::new(mutable.freeze())))
+}
+
+/// Returns the array of the values of `values` for a mask of `len` slots, or `None` if
+/// they are all null
+fn resolve(values: ZipValues, len: usize) -> Result<Option<ArrayRef>> {
+    match values {
+        ZipValues::Array(array) if array.len() != len => {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Cannot zip an array of length {} with a mask of length {}",
+                array.len(),
+                len
+            )))
+        }
+        ZipValues::Array(array) => Ok(Some(array.clone())),
+        ZipValues::Scalar(scalar) if scalar.is_null() => Ok(None),
+        ZipValues::Scalar(scalar) => Ok(Some(scalar.to_array_of_size(len))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_flat_types() {
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+            Some(true),
+        ]);
+        let truthy: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            None,
+            Some("e"),
+        ]));
+        let falsy: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("v"),
+            None,
+            Some("x"),
+            Some("y"),
+            None,
+        ]));
+        let zipped = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(
+            zipped.as_typed::<StringArray>().unwrap(),
+            &StringArray::from(vec![Some("a"), None, Some("x"), None, Some("e")])
+        );
+
+        let truthy: ArrayRef =
+            Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]));
+        let zipped = zip(&mask, &Scalar::Float64(None), &truthy).unwrap();
+        assert_eq!(
+            zipped.as_typed::<Float64Array>().unwrap(),
+            &Float64Array::from(vec![None, Some(2.0), Some(3.0), None, None])
+        );
+        let zipped = zip(&mask, &truthy, &Scalar::Null).unwrap();
+        assert_eq!(
+            zipped.as_typed::<Float64Array>().unwrap(),
+            &Float64Array::from(vec![Some(1.0), None, None, Some(4.0), Some(5.0)])
+        );
+
+        let zipped =
+            zip(&mask, &Scalar::Boolean(Some(true)), &Scalar::Boolean(None)).unwrap();
+        assert_eq!(
+            zipped.as_typed::<BooleanArray>().unwrap(),
+            &BooleanArray::from(vec![Some(true), None, None, Some(true), Some(true)])
+        );
+        assert_eq!(
+            5,
+            zip(&mask, &Scalar::Null, &Scalar::Null)
+                .unwrap()
+                .null_count()
+        );
+    }
+
+    #[test]
+    fn test_zip_sliced_and_long() {
+        let mask: BooleanArray = (0..300)
+            .map(|i| Some(i % 3 != 1))
+            .collect::<Vec<_>>()
+            .into();
+        let mask = mask.slice(5, 200);
+        let mask = mask.as_typed::<BooleanArray>().unwrap();
+        let truthy = Int64Array::from((0..250).collect::<Vec<i64>>()).slice(50, 200);
+        let falsy = Int64Array::from((0..200).map(|i| -i).collect::<Vec<i64>>());
+        let falsy: ArrayRef = Arc::new(falsy);
+
+        let zipped = zip(mask, &truthy, &falsy).unwrap();
+        let expected: Int64Array = (0..200)
+            .map(|i| {
+                if (i + 5) % 3 != 1 {
+                    i as i64 + 50
+                } else {
+                    -(i as i64)
+                }
+            })
+            .collect::<Vec<_>>()
+            .into();
+        assert_eq!(zipped.as_typed::<Int64Array>().unwrap(), &expected);
+    }
+
+    #[test]
+    fn test_zip_dictionaries() {
+        let mask = BooleanArray::from(vec![true, false, false, true]);
+        let truthy: DictionaryArray<Int8Type> =
+            vec![Some("a"), Some("b"), None, Some("a")]
+                .into_iter()
+                .collect();
+        let falsy: DictionaryArray<Int8Type> =
+            vec![Some("c"), Some("a"), Some("d"), None]
+                .into_iter()
+                .collect();
+        let truthy: ArrayRef = Arc::new(truthy);
+        let falsy: ArrayRef = Arc::new(falsy);
+
+        let zipped = zip(&mask, &truthy, &falsy).unwrap();
+        assert_eq!(truthy.data_type(), zipped.data_type());
+        let zipped = zipped.as_typed::<DictionaryArray<Int8Type>>().unwrap();
+        let values = zipped.values();
+        let values = values.as_typed::<StringArray>().unwrap();
+        let strings = zipped
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as usize)))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some("a"), Some("a"), Some("d"), Some("a")], strings);
+
+        // dictionaries that are shared are not concatenated
+        let zipped = zip(&mask, &truthy, &truthy).unwrap();
+        let zipped = zipped.as_typed::<DictionaryArray<Int8Type>>().unwrap();
+        assert_eq!(2, zipped.values().len());
+    }
+
+    #[test]
+    fn test_zip_errors() {
+        let mask = BooleanArray::from(vec![true, false]);
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let longs: ArrayRef = Arc::new(Int64Array::from(vec![1, 2]));
+        let short: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        assert!(zip(&mask, &ints, &longs).is_err());
+        assert!(zip(&mask, &ints, &Scalar::Int64(Some(1))).is_err());
+        assert!(zip(&mask, &ints, &short).is_err());
+        assert!(zip(&mask, &short, &Scalar::Null).is_err());
+    }
+}
@@ -0,0 +1,469 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the merge of streams of `RecordBatch`es that are sorted by the same columns
+//! into a single sorted stream, as in the final step of an external sort.
+//!
+//! The merge holds a single batch of each stream at a time, so that its memory does not
+//! grow with the lengths of the streams. Each merged batch has at most `batch_size`
+//! rows, and ends when the current batch of a stream has been merged.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::*;
+//! use arrow::compute::merge::{MergeColumn, SortPreservingMerge};
+//! use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+//! use arrow::error::Result;
+//! use arrow::record_batch::{RecordBatch, RecordBatchReader};
+//!
+//! struct MemoryReader(SchemaRef, Vec<RecordBatch>);
+//!
+//! impl Iterator for MemoryReader {
+//!     type Item = Result<RecordBatch>;
+//!     fn next(&mut self) -> Option<Self::Item> {
+//!         self.1.pop().map(Ok)
+//!     }
+//! }
+//!
+//! impl RecordBatchReader for MemoryReader {
+//!     fn schema(&self) -> SchemaRef {
+//!         self.0.clone()
+//!     }
+//! }
+//!
+//! let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+//! let reader = |values: Vec<i32>| -> Box<dyn RecordBatchReader> {
+//!     let array = Arc::new(Int32Array::from(values));
+//!     let batch = RecordBatch::try_new(schema.clone(), vec![array]).unwrap();
+//!     Box::new(MemoryReader(schema.clone(), vec![batch]))
+//! };
+//!
+//! let merge = SortPreservingMerge::try_new(
+//!     vec![reader(vec![1, 4, 5]), reader(vec![2, 3, 6])],
+//!     vec![MergeColumn { index: 0, options: None }],
+//!     1024,
+//! )
+//! .unwrap();
+//! let batches = merge.collect::<Result<Vec<_>>>().unwrap();
+//! let values = batches[0].column(0).as_typed::<Int32Array>().unwrap();
+//! assert_eq!(&Int32Array::from(vec![1, 2, 3, 4, 5]), values);
+//! let values = batches[1].column(0).as_typed::<Int32Array>().unwrap();
+//! assert_eq!(&Int32Array::from(vec![6]), values);
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::array::*;
+use crate::compute::kernels::interleave::interleave;
+use crate::compute::kernels::sort::SortOptions;
+use crate::datatypes::SchemaRef;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+/// A column by which the streams of a [`SortPreservingMerge`] are sorted
+#[derive(Clone, Debug)]
+pub struct MergeColumn {
+    /// The index of the column in the schema of the streams
+    pub index: usize,
+    /// How the column is sorted, as by the sort kernels
+    pub options: Option<SortOptions>,
+}
+
+/// The current batch of a stream, and the index of its next row to merge
+struct Cursor {
+    batch: RecordBatch,
+    row: usize,
+}
+
+/// Merges streams of batches that are sorted by the same columns into a stream of
+/// sorted batches. Rows that are equal are merged in the order of the streams.
+pub struct SortPreservingMerge {
+    schema: SchemaRef,
+    columns: Vec<(usize, SortOptions)>,
+    batch_size: usize,
+    readers: Vec<Box<dyn RecordBatchReader>>,
+    /// The cursor of each stream, which is `None` once the stream is exhausted
+    cursors: Vec<Option<Cursor>>,
+    started: bool,
+}
+
+impl fmt::Debug for SortPreservingMerge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SortPreservingMerge")
+            .field("schema", &self.schema)
+            .field("columns", &self.columns)
+            .field("batch_size", &self.batch_size)
+            .field("num_streams", &self.readers.len())
+            .finish()
+    }
+}
+
+impl SortPreservingMerge {
+    /// Creates the merge of the streams of `readers`, which must have the same schema
+    /// and be sorted by `columns`, into batches of at most `batch_size` rows.
+    ///
+    /// The order of the streams is not checked: the merged stream is not sorted if they
+    /// are not.
+    pub fn try_new(
+        readers: Vec<Box<dyn RecordBatchReader>>,
+        columns: Vec<MergeColumn>,
+        batch_size: usize,
+    ) -> Result<Self> {
+        let schema = match readers.first() {
+            Some(reader) => reader.schema(),
+            None => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Merge requires at least one stream".to_string(),
+                ))
+            }
+        };
+        if readers.iter().any(|reader| reader.schema() != schema) {
+            return Err(ArrowError::InvalidArgumentError(
+                "Merged streams must have the same schema".to_string(),
+            ));
+        }
+        if columns.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Merge requires at least one column".to_string(),
+            ));
+        }
+        if let Some(column) = columns
+            .iter()
+            .find(|column| column.index >= schema.fields().len())
+        {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Merge column index {} out of bounds for {} columns",
+                column.index,
+                schema.fields().len()
+            )));
+        }
+        if batch_size == 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "The batch size of a merge must be positive".to_string(),
+            ));
+        }
+
+        let cursors = readers.iter().map(|_| None).collect();
+        Ok(Self {
+            schema,
+            columns: columns
+                .into_iter()
+                .map(|column| (column.index, column.options.unwrap_or_default()))
+                .collect(),
+            batch_size,
+            readers,
+            cursors,
+            started: false,
+        })
+    }
+
+    /// Reads the next non-empty batch of each stream whose current batch has been
+    /// merged, or marks the stream as exhausted
+    fn advance_cursors(&mut self) -> Result<()> {
+        for (i, reader) in self.readers.iter_mut().enumerate() {
+            let cursor = &mut self.cursors[i];
+            let merged = match cursor {
+                Some(cursor) => cursor.row == cursor.batch.num_rows(),
+                None => !self.started,
+            };
+            if !merged {
+                continue;
+            }
+            *cursor = None;
+            for batch in reader.by_ref() {
+                let batch = batch?;
+                if batch.num_rows() > 0 {
+                    *cursor = Some(Cursor { batch, row: 0 });
+                    break;
+                }
+            }
+        }
+        self.started = true;
+        Ok(())
+    }
+
+    /// Merges the current batches of the streams until one of them or the output is
+    /// exhausted, and returns the merged rows
+    fn merge_batches(&mut self) -> Result<RecordBatch> {
+        let streams = self
+            .cursors
+            .iter()
+            .enumerate()
+            .filter_map(|(i, cursor)| cursor.as_ref().map(|_| i))
+            .collect::<Vec<_>>();
+        let batches = streams
+            .iter()
+            .map(|i| self.cursors[*i].as_ref().unwrap().batch.clone())
+            .collect::<Vec<_>>();
+        let mut rows = streams
+            .iter()
+            .map(|i| self.cursors[*i].as_ref().unwrap().row)
+            .collect::<Vec<_>>();
+
+        // the comparators of the sort columns of each pair of streams, built when the
+        // streams are first compared
+        let num_streams = streams.len();
+        let sort_columns = &self.columns;
+        let mut comparators: Vec<Option<Vec<DynComparator>>> =
+            (0..num_streams * num_streams).map(|_| None).collect();
+        let mut compare = |a: usize, a_row: usize, b: usize, b_row: usize| -> Result<_> {
+            let pair = &mut comparators[a * num_streams + b];
+            if pair.is_none() {
+                *pair = Some(
+                    sort_columns
+                        .iter()
+                        .map(|(index, _)| {
+                            build_compare(
+                                batches[a].column(*index).as_ref(),
+                                batches[b].column(*index).as_ref(),
+                            )
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                );
+            }
+            let pair = pair.as_ref().unwrap();
+            for ((index, options), comparator) in sort_columns.iter().zip(pair) {
+                let a_valid = batches[a].column(*index).is_valid(a_row);
+                let b_valid = batches[b].column(*index).is_valid(b_row);
+                let order = match (a_valid, b_valid) {
+                    (true, true) if options.descending => {
+                        comparator(a_row, b_row).reverse()
+                    }
+                    (true, true) => comparator(a_row, b_row),
+                    (false, true) if options.nulls_first => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (true, false) if options.nulls_first => Ordering::Greater,
+                    (true, false) => Ordering::Less,
+                    (false, false) => Ordering::Equal,
+                };
+                if order != Ordering::Equal {
+                    return Ok(order);
+                }
+            }
+            Ok(Ordering::Equal)
+        };
+
+        let mut indices = Vec::with_capacity(self.batch_size);
+        while indices.len() < self.batch_size {
+            let mut min = 0;
+            for stream in 1..num_streams {
+                if compare(stream, rows[stream], min, rows[min])? == Ordering::Less {
+                    min = stream;
+                }
+            }
+            indices.push((min, rows[min]));
+            rows[min] += 1;
+            if rows[min] == batches[min].num_rows() {
+                break;
+            }
+        }
+
+        let columns = (0..self.schema.fields().len())
+            .map(|column| {
+                let values = batches
+                    .iter()
+                    .map(|batch| batch.column(column).clone())
+                    .collect::<Vec<_>>();
+                interleave(&values, &indices)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (stream, row) in streams.iter().zip(rows) {
+            self.cursors[*stream].as_mut().unwrap().row = row;
+        }
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+}
+
+impl Iterator for SortPreservingMerge {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Err(e) = self.advance_cursors() {
+            return Some(Err(e));
+        }
+        if self.cursors.iter().all(|cursor| cursor.is_none()) {
+            return None;
+        }
+        Some(self.merge_batches())
+    }
+}
+
+impl RecordBatchReader for SortPreservingMerge {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use crate::datatypes::{DataType, Field, Schema};
+
+    struct MemoryReader {
+        schema: SchemaRef,
+        batches: std::vec::IntoIter<Result<RecordBatch>>,
+    }
+
+    impl Iterator for MemoryReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.batches.next()
+        }
+    }
+
+    impl RecordBatchReader for MemoryReader {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+    }
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("k", DataType::Utf8, true),
+            Field::new("n", DataType::Int32, true),
+        ]))
+    }
+
+    fn batch(k: Vec<Option<&str>>, n: Vec<i32>) -> RecordBatch {
+        RecordBatch::try_new(
+            schema(),
+            vec![
+                Arc::new(StringArray::from(k)),
+                Arc::new(Int32Array::from(n)),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn reader(batches: Vec<RecordBatch>) -> Box<dyn RecordBatchReader> {
+        Box::new(MemoryReader {
+            schema: schema(),
+            batches: batches.into_iter().map(Ok).collect::<Vec<_>>().into_iter(),
+        })
+    }
+
+    fn rows(batches: Vec<RecordBatch>) -> Vec<(Option<String>, i32)> {
+        batches
+            .iter()
+            .flat_map(|batch| {
+                let k = batch.column(0).as_typed::<StringArray>().unwrap();
+                let n = batch.column(1).as_typed::<Int32Array>().unwrap();
+                (0..batch.num_rows())
+                    .map(|i| {
+                        let key = if k.is_null(i) {
+                            None
+                        } else {
+                            Some(k.value(i).to_string())
+                        };
+                        (key, n.value(i))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_streams() {
+        // sorted by `k` descending with nulls last, then by `n`
+        let streams = vec![
+            reader(vec![
+                batch(vec![Some("c"), Some("b")], vec![1, 1]),
+                batch(vec![], vec![]),
+                batch(vec![Some("b"), None], vec![3, 0]),
+            ]),
+            reader(vec![]),
+            reader(vec![batch(
+                vec![Some("d"), Some("b"), Some("a"), None],
+                vec![0, 2, 0, 0],
+            )]),
+            reader(vec![batch(vec![Some("b")], vec![1])]),
+        ];
+        let columns = vec![
+            MergeColumn {
+                index: 0,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: false,
+                }),
+            },
+            MergeColumn {
+                index: 1,
+                options: None,
+            },
+        ];
+        let merge = SortPreservingMerge::try_new(streams, columns, 3).unwrap();
+        assert_eq!(schema(), merge.schema());
+        let batches = merge.collect::<Result<Vec<_>>>().unwrap();
+        assert!(batches.iter().all(|batch| batch.num_rows() <= 3));
+
+        let expected = vec![
+            (Some("d"), 0),
+            (Some("c"), 1),
+            (Some("b"), 1),
+            (Some("b"), 1),
+            (Some("b"), 2),
+            (Some("b"), 3),
+            (Some("a"), 0),
+            (None, 0),
+            (None, 0),
+        ];
+        let expected = expected
+            .into_iter()
+            .map(|(k, n)| (k.map(|k| k.to_string()), n))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, rows(batches));
+    }
+
+    #[test]
+    fn test_merge_errors() {
+        let columns = || {
+            vec![MergeColumn {
+                index: 1,
+                options: None,
+            }]
+        };
+        assert!(SortPreservingMerge::try_new(vec![], columns(), 10).is_err());
+        assert!(SortPreservingMerge::try_new(vec![reader(vec![])], vec![], 10).is_err());
+        assert!(
+            SortPreservingMerge::try_new(vec![reader(vec![])], columns(), 0).is_err()
+        );
+        let out_of_bounds = vec![MergeColumn {
+            index: 2,
+            options: None,
+        }];
+        assert!(
+            SortPreservingMerge::try_new(vec![reader(vec![])], out_of_bounds, 10)
+                .is_err()
+        );
+
+        let failing = Box::new(MemoryReader {
+            schema: schema(),
+            batches: vec![Err(ArrowError::IoError("failed".to_string()))].into_iter(),
+        });
+        let mut merge =
+            SortPreservingMerge::try_new(vec![reader(vec![]), failing], columns(), 10)
+                .unwrap();
+        assert!(merge.next().unwrap().is_err());
+    }
+}
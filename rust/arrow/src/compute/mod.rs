@@ -17,7 +17,12 @@
 
 //! Computation kernels on Arrow Arrays
 
+pub mod expression;
+pub mod group_by;
 pub mod kernels;
+pub mod merge;
+pub mod registry;
+pub mod unify;
 
 mod util;
 
@@ -25,10 +30,15 @@ pub use self::kernels::aggregate::*;
 pub use self::kernels::arithmetic::*;
 pub use self::kernels::boolean::*;
 pub use self::kernels::cast::*;
+pub use self::kernels::coalesce::*;
 pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
+pub use self::kernels::cumulative::*;
 pub use self::kernels::filter::*;
+pub use self::kernels::interleave::*;
 pub use self::kernels::limit::*;
 pub use self::kernels::sort::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::zip::*;
+pub use self::util::{combine_option_bitmap, combine_option_buffers};
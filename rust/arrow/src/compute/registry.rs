@@ -0,0 +1,311 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines a registry of kernels, which maps an operation name and the data types of its
+//! inputs to a kernel, so that kernels can be looked up at runtime.
+//!
+//! Embedders can register their own kernels, or override the built-in ones, and generic
+//! code such as expression evaluators can invoke them without matching on data types.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::*;
+//! use arrow::compute::registry::KernelRegistry;
+//! use arrow::datatypes::DataType;
+//!
+//! let mut registry = KernelRegistry::with_builtins();
+//! registry.register(
+//!     "double",
+//!     vec![DataType::Int32],
+//!     Arc::new(|args: &[ArrayRef]| {
+//!         let array = args[0].as_typed::<Int32Array>()?;
+//!         Ok(Arc::new(array.iter().map(|v| v.map(|v| v * 2)).collect::<Int32Array>())
+//!             as ArrayRef)
+//!     }),
+//! );
+//!
+//! let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+//! let b = registry.invoke("double", &[a.clone()]).unwrap();
+//! let c = registry.invoke("add", &[a, b]).unwrap();
+//! let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+//! assert_eq!(&Int32Array::from(vec![3, 6]), c);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+use std::sync::Arc;
+
+use num::{One, Zero};
+
+use crate::array::*;
+use crate::compute::kernels::{arithmetic, boolean, comparison};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// A kernel that can be registered in a `KernelRegistry`. It is invoked with arrays
+/// whose data types are the ones it was registered for.
+pub type KernelRef = Arc<dyn Fn(&[ArrayRef]) -> Result<ArrayRef> + Send + Sync>;
+
+/// A registry of kernels, indexed by operation name and input data types
+#[derive(Clone, Default)]
+pub struct KernelRegistry {
+    kernels: HashMap<(String, Vec<DataType>), KernelRef>,
+}
+
+impl fmt::Debug for KernelRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut signatures = self.kernels.keys().collect::<Vec<_>>();
+        signatures.sort();
+        f.debug_struct("KernelRegistry")
+            .field("kernels", &signatures)
+            .finish()
+    }
+}
+
+/// Downcasts the `i`-th argument of a kernel
+fn arg<T: 'static>(args: &[ArrayRef], i: usize) -> Result<&T> {
+    args.get(i)
+        .and_then(|array| array.as_any().downcast_ref::<T>())
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Kernel argument {} is missing or has an unexpected type",
+                i
+            ))
+        })
+}
+
+/// Wraps a kernel on two arrays of type `A` into a `KernelRef`
+fn binary_kernel<A, O, F>(kernel: F) -> KernelRef
+where
+    A: Array + 'static,
+    O: Array + 'static,
+    F: Fn(&A, &A) -> Result<O> + Send + Sync + 'static,
+{
+    Arc::new(move |args: &[ArrayRef]| {
+        Ok(Arc::new(kernel(arg::<A>(args, 0)?, arg::<A>(args, 1)?)?) as ArrayRef)
+    })
+}
+
+impl KernelRegistry {
+    /// Creates an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry containing the built-in kernels:
+    /// * `add`, `subtract`, `multiply`, `divide` and `modulus` on two numeric arrays of
+    ///   the same type
+    /// * `eq`, `neq`, `lt`, `lt_eq`, `gt` and `gt_eq` on two numeric or
+    ///   \[Large\]String arrays of the same type
+    /// * `eq`, `neq`, `lt`, `lt_eq`, `gt` and `gt_eq` on two boolean arrays
+    /// * `and` and `or` on two boolean arrays, and `not` on a boolean array
+    pub fn with_builtins() -> Self {
+        let mut registry = Self::new();
+        registry.register_numeric::<Int8Type>();
+        registry.register_numeric::<Int16Type>();
+        registry.register_numeric::<Int32Type>();
+        registry.register_numeric::<Int64Type>();
+        registry.register_numeric::<UInt8Type>();
+        registry.register_numeric::<UInt16Type>();
+        registry.register_numeric::<UInt32Type>();
+        registry.register_numeric::<UInt64Type>();
+        registry.register_numeric::<Float32Type>();
+        registry.register_numeric::<Float64Type>();
+        registry.register_utf8::<i32>();
+        registry.register_utf8::<i64>();
+
+        let boolean = vec![DataType::Boolean, DataType::Boolean];
+        registry.register("and", boolean.clone(), binary_kernel(boolean::and));
+        registry.register("or", boolean.clone(), binary_kernel(boolean::or));
+        let comparisons: Vec<(&str, KernelRef)> = vec![
+            ("eq", binary_kernel(comparison::eq_bool)),
+            ("neq", binary_kernel(comparison::neq_bool)),
+            ("lt", binary_kernel(comparison::lt_bool)),
+            ("lt_eq", binary_kernel(comparison::lt_eq_bool)),
+            ("gt", binary_kernel(comparison::gt_bool)),
+            ("gt_eq", binary_kernel(comparison::gt_eq_bool)),
+        ];
+        for (name, kernel) in comparisons {
+            registry.register(name, boolean.clone(), kernel);
+        }
+        registry.register(
+            "not",
+            vec![DataType::Boolean],
+            Arc::new(|args: &[ArrayRef]| {
+                Ok(Arc::new(boolean::not(arg::<BooleanArray>(args, 0)?)?) as ArrayRef)
+            }),
+        );
+        registry
+    }
+
+    fn register_numeric<T>(&mut self)
+    where
+        T: ArrowNumericType,
+        T::Native: Add<Output = T::Native>
+            + Sub<Output = T::Native>
+            + Mul<Output = T::Native>
+            + Div<Output = T::Native>
+            + Rem<Output = T::Native>
+            + Zero
+            + One,
+    {
+        let types = vec![T::DATA_TYPE, T::DATA_TYPE];
+        let kernels: Vec<(&str, KernelRef)> = vec![
+            ("add", binary_kernel(arithmetic::add::<T>)),
+            ("subtract", binary_kernel(arithmetic::subtract::<T>)),
+            ("multiply", binary_kernel(arithmetic::multiply::<T>)),
+            ("divide", binary_kernel(arithmetic::divide::<T>)),
+            ("modulus", binary_kernel(arithmetic::modulus::<T>)),
+            ("eq", binary_kernel(comparison::eq::<T>)),
+            ("neq", binary_kernel(comparison::neq::<T>)),
+            ("lt", binary_kernel(comparison::lt::<T>)),
+            ("lt_eq", binary_kernel(comparison::lt_eq::<T>)),
+            ("gt", binary_kernel(comparison::gt::<T>)),
+            ("gt_eq", binary_kernel(comparison::gt_eq::<T>)),
+        ];
+        for (name, kernel) in kernels {
+            self.register(name, types.clone(), kernel);
+        }
+    }
+
+    fn register_utf8<OffsetSize: StringOffsetSizeTrait>(&mut self) {
+        let data_type = <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE;
+        let types = vec![data_type.clone(), data_type];
+        let kernels: Vec<(&str, KernelRef)> = vec![
+            ("eq", binary_kernel(comparison::eq_utf8::<OffsetSize>)),
+            ("neq", binary_kernel(comparison::neq_utf8::<OffsetSize>)),
+            ("lt", binary_kernel(comparison::lt_utf8::<OffsetSize>)),
+            ("lt_eq", binary_kernel(comparison::lt_eq_utf8::<OffsetSize>)),
+            ("gt", binary_kernel(comparison::gt_utf8::<OffsetSize>)),
+            ("gt_eq", binary_kernel(comparison::gt_eq_utf8::<OffsetSize>)),
+        ];
+        for (name, kernel) in kernels {
+            self.register(name, types.clone(), kernel);
+        }
+    }
+
+    /// Registers `kernel` for the operation `name` on inputs of types `input_types`,
+    /// returning the kernel previously registered for this signature, if any.
+    pub fn register(
+        &mut self,
+        name: &str,
+        input_types: Vec<DataType>,
+        kernel: KernelRef,
+    ) -> Option<KernelRef> {
+        self.kernels.insert((name.to_string(), input_types), kernel)
+    }
+
+    /// Returns the kernel registered for the operation `name` on inputs of types
+    /// `input_types`, if any.
+    pub fn get(&self, name: &str, input_types: &[DataType]) -> Option<&KernelRef> {
+        self.kernels.get(&(name.to_string(), input_types.to_vec()))
+    }
+
+    /// Invokes the kernel registered for the operation `name` on the data types of
+    /// `args`.
+    ///
+    /// This function errors if no kernel is registered for this signature, or if the
+    /// kernel errors.
+    pub fn invoke(&self, name: &str, args: &[ArrayRef]) -> Result<ArrayRef> {
+        let input_types = args
+            .iter()
+            .map(|array| array.data_type().clone())
+            .collect::<Vec<_>>();
+        let kernel = self.get(name, &input_types).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "No kernel registered for {}{:?}",
+                name, input_types
+            ))
+        })?;
+        kernel(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtins() {
+        let registry = KernelRegistry::with_builtins();
+
+        let a: ArrayRef = Arc::new(Float64Array::from(vec![Some(1.0), None, Some(3.0)]));
+        let b: ArrayRef = Arc::new(Float64Array::from(vec![2.0, 2.0, 2.0]));
+        let c = registry
+            .invoke("multiply", &[a.clone(), b.clone()])
+            .unwrap();
+        let expected = Float64Array::from(vec![Some(2.0), None, Some(6.0)]);
+        assert_eq!(
+            &expected,
+            c.as_any().downcast_ref::<Float64Array>().unwrap()
+        );
+
+        let c = registry.invoke("gt", &[a, b]).unwrap();
+        assert_eq!(
+            &BooleanArray::from(vec![Some(false), None, Some(true)]),
+            c.as_any().downcast_ref::<BooleanArray>().unwrap()
+        );
+
+        let c = registry.invoke("not", &[c]).unwrap();
+        let d = registry.invoke("eq", &[c.clone(), c.clone()]).unwrap();
+        let c = c.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![Some(true), None, Some(false)]), c);
+        let d = d.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![Some(true), None, Some(true)]), d);
+
+        let a: ArrayRef = Arc::new(LargeStringArray::from(vec!["a", "b"]));
+        let b: ArrayRef = Arc::new(LargeStringArray::from(vec!["b", "b"]));
+        let c = registry.invoke("lt_eq", &[a, b]).unwrap();
+        let c = c.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(&BooleanArray::from(vec![true, true]), c);
+    }
+
+    #[test]
+    fn test_missing_kernel() {
+        let registry = KernelRegistry::with_builtins();
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        assert!(registry.invoke("add", &[a.clone(), b]).is_err());
+        assert!(registry.invoke("unknown", &[a.clone(), a.clone()]).is_err());
+        assert!(registry.invoke("add", &[a]).is_err());
+        assert!(KernelRegistry::new()
+            .get("add", &[DataType::Int32, DataType::Int32])
+            .is_none());
+    }
+
+    #[test]
+    fn test_register_overrides() {
+        let mut registry = KernelRegistry::with_builtins();
+        let types = vec![DataType::Int32, DataType::Int32];
+        let previous = registry.register(
+            "add",
+            types.clone(),
+            Arc::new(|args: &[ArrayRef]| Ok(args[0].clone())),
+        );
+        assert!(previous.is_some());
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![2]));
+        let c = registry.invoke("add", &[a, b]).unwrap();
+        let c = c.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&Int32Array::from(vec![1]), c);
+        assert!(registry.get("add", &types).is_some());
+    }
+}
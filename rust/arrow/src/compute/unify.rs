@@ -0,0 +1,470 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the unification of `RecordBatch`es of different schemas, e.g. of chunks of
+//! JSON whose schemas were inferred separately, into batches of a single schema.
+//!
+//! The schemas are folded into one whose columns are those of all the schemas, with
+//! data types wide enough for the values of all of them: integers are widened to larger
+//! integers and to floats, dates to `Date64`, timestamps to their finest unit, lists and
+//! structs to lists and structs of widened types, and other conflicting types to
+//! `Utf8`. Each batch is then conformed to that schema by casting its columns, and by
+//! adding null columns for the columns it does not have.
+//!
+//! Example:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::*;
+//! use arrow::compute::unify::unify_batches;
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::record_batch::RecordBatch;
+//!
+//! let batch = |field: Field, column: ArrayRef| {
+//!     RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![column]).unwrap()
+//! };
+//! let batches = vec![
+//!     batch(
+//!         Field::new("a", DataType::Int64, false),
+//!         Arc::new(Int64Array::from(vec![1, 2])),
+//!     ),
+//!     batch(
+//!         Field::new("a", DataType::Float64, false),
+//!         Arc::new(Float64Array::from(vec![0.5])),
+//!     ),
+//! ];
+//!
+//! let (schema, batches) = unify_batches(&batches).unwrap();
+//! assert_eq!(schema.field(0).data_type(), &DataType::Float64);
+//! let a = batches[0].column(0).as_typed::<Float64Array>().unwrap();
+//! assert_eq!(a.value(1), 2.0);
+//! ```
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::kernels::cast::{can_cast_types, cast};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// Returns the bit width and signedness of an integer type
+fn integer_type(data_type: &DataType) -> Option<(usize, bool)> {
+    match data_type {
+        DataType::Int8 => Some((8, true)),
+        DataType::Int16 => Some((16, true)),
+        DataType::Int32 => Some((32, true)),
+        DataType::Int64 => Some((64, true)),
+        DataType::UInt8 => Some((8, false)),
+        DataType::UInt16 => Some((16, false)),
+        DataType::UInt32 => Some((32, false)),
+        DataType::UInt64 => Some((64, false)),
+        _ => None,
+    }
+}
+
+fn integer_of(bits: usize, signed: bool) -> DataType {
+    match (bits, signed) {
+        (8, true) => DataType::Int8,
+        (16, true) => DataType::Int16,
+        (32, true) => DataType::Int32,
+        (64, true) => DataType::Int64,
+        (8, false) => DataType::UInt8,
+        (16, false) => DataType::UInt16,
+        (32, false) => DataType::UInt32,
+        _ => DataType::UInt64,
+    }
+}
+
+/// The finest of two time units
+fn finest_unit(left: &TimeUnit, right: &TimeUnit) -> TimeUnit {
+    let rank = |unit: &TimeUnit| match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 1,
+        TimeUnit::Microsecond => 2,
+        TimeUnit::Nanosecond => 3,
+    };
+    if rank(left) >= rank(right) {
+        left.clone()
+    } else {
+        right.clone()
+    }
+}
+
+/// Returns whether the values of type `from` can be conformed to the type `to`, by
+/// [`conform_batch`]
+fn can_conform(from: &DataType, to: &DataType) -> bool {
+    match (from, to) {
+        (DataType::Null, _) => true,
+        (DataType::Struct(from_fields), DataType::Struct(to_fields)) => {
+            from_fields.iter().all(|from_field| {
+                to_fields
+                    .iter()
+                    .find(|to_field| to_field.name() == from_field.name())
+                    .map_or(false, |to_field| {
+                        can_conform(from_field.data_type(), to_field.data_type())
+                    })
+            }) && to_fields.iter().all(|to_field| {
+                to_field.is_nullable()
+                    || from_fields.iter().any(|f| f.name() == to_field.name())
+            })
+        }
+        _ => can_cast_types(from, to),
+    }
+}
+
+/// Returns a data type that the values of both `left` and `right` can be cast to, as
+/// described in the [module documentation](self).
+///
+/// # Errors
+/// This function errors if the types are incompatible, e.g. when a type cannot be cast
+/// to `Utf8`.
+pub fn widen_data_types(left: &DataType, right: &DataType) -> Result<DataType> {
+    use DataType::*;
+
+    let widened = match (left, right) {
+        _ if left == right => return Ok(left.clone()),
+        (Null, other) | (other, Null) => return Ok(other.clone()),
+        (Struct(left_fields), Struct(right_fields)) => {
+            Struct(widen_fields(left_fields, right_fields)?)
+        }
+        (List(left_field), List(right_field)) => {
+            List(Box::new(widen_field(left_field, right_field)?))
+        }
+        (Float32, Float64) | (Float64, Float32) => Float64,
+        (Date32(_), Date64(_)) | (Date64(_), Date32(_)) => Date64(DateUnit::Millisecond),
+        (Timestamp(left_unit, left_tz), Timestamp(right_unit, right_tz))
+            if left_tz == right_tz =>
+        {
+            Timestamp(finest_unit(left_unit, right_unit), left_tz.clone())
+        }
+        _ => match (integer_type(left), integer_type(right)) {
+            (Some((left_bits, left_signed)), Some((right_bits, right_signed))) => {
+                if left_signed == right_signed {
+                    integer_of(left_bits.max(right_bits), left_signed)
+                } else {
+                    // the signed type must be wider than the unsigned one
+                    let unsigned_bits = if left_signed { right_bits } else { left_bits };
+                    let bits = left_bits.max(right_bits).max(unsigned_bits * 2);
+                    if bits <= 64 {
+                        integer_of(bits, true)
+                    } else {
+                        Float64
+                    }
+                }
+            }
+            (Some((bits, _)), None) if right == &Float32 && bits <= 16 => Float32,
+            (None, Some((bits, _))) if left == &Float32 && bits <= 16 => Float32,
+            (Some(_), None) if DataType::is_numeric(right) => Float64,
+            (None, Some(_)) if DataType::is_numeric(left) => Float64,
+            _ => Utf8,
+        },
+    };
+
+    if can_conform(left, &widened) && can_conform(right, &widened) {
+        Ok(widened)
+    } else {
+        Err(ArrowError::SchemaError(format!(
+            "Cannot unify the data types {:?} and {:?}",
+            left, right
+        )))
+    }
+}
+
+/// Widens fields of the same name, which are nullable if either of them is
+fn widen_field(left: &Field, right: &Field) -> Result<Field> {
+    let data_type = widen_data_types(left.data_type(), right.data_type())?;
+    let nullable = left.is_nullable() || right.is_nullable();
+    if &data_type == left.data_type() && nullable == left.is_nullable() {
+        Ok(left.clone())
+    } else {
+        Ok(Field::new(left.name(), data_type, nullable))
+    }
+}
+
+/// Widens the fields of the same names of `left` and `right`, which are followed by
+/// the other fields of `right`. The fields of only one side become nullable.
+fn widen_fields(left: &[Field], right: &[Field]) -> Result<Vec<Field>> {
+    let mut fields = Vec::with_capacity(left.len());
+    for field in left {
+        fields.push(match right.iter().find(|f| f.name() == field.name()) {
+            Some(other) => widen_field(field, other)?,
+            None => Field::new(field.name(), field.data_type().clone(), true),
+        });
+    }
+    for field in right {
+        if !left.iter().any(|f| f.name() == field.name()) {
+            fields.push(Field::new(field.name(), field.data_type().clone(), true));
+        }
+    }
+    Ok(fields)
+}
+
+/// Returns a schema that the batches of both `left` and `right` can be conformed to with
+/// [`conform_batch`]. Its fields are the fields of `left`, followed by the fields of
+/// `right` that `left` does not have, with widened data types. The fields that only one
+/// of the schemas has are nullable.
+///
+/// # Errors
+/// This function errors if the data types of fields of the same name are incompatible,
+/// or if the schemas have conflicting metadata.
+pub fn widen_schema(left: &Schema, right: &Schema) -> Result<Schema> {
+    let mut metadata = left.metadata().clone();
+    for (key, value) in right.metadata() {
+        match metadata.get(key) {
+            Some(existing) if existing != value => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Cannot unify schemas with conflicting metadata for key {}",
+                    key
+                )))
+            }
+            _ => {
+                metadata.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    let fields = widen_fields(left.fields(), right.fields())?;
+    Ok(Schema::new_with_metadata(fields, metadata))
+}
+
+/// Folds `schemas` into a single schema with [`widen_schema`].
+///
+/// # Errors
+/// This function errors if `schemas` is empty, or if two schemas cannot be unified.
+pub fn widen_schemas(schemas: &[SchemaRef]) -> Result<Schema> {
+    let (first, rest) = schemas.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(
+            "Unifying schemas requires at least one schema".to_string(),
+        )
+    })?;
+    rest.iter()
+        .try_fold(first.as_ref().clone(), |unified, schema| {
+            widen_schema(&unified, schema)
+        })
+}
+
+/// Returns an array of `len` nulls of type `data_type`
+fn null_array(data_type: &DataType, len: usize) -> ArrayRef {
+    let empty = new_empty_array(data_type);
+    let mut mutable = MutableArrayData::new(vec![empty.data_ref().as_ref()], true, len);
+    mutable.extend_nulls(len);
+    make_array(Arc::new(mutable.freeze()))
+}
+
+fn conform_array(array: &ArrayRef, data_type: &DataType) -> Result<ArrayRef> {
+    match (array.data_type(), data_type) {
+        (from, to) if from == to => Ok(array.clone()),
+        (DataType::Null, _) => Ok(null_array(data_type, array.len())),
+        (DataType::Struct(_), DataType::Struct(fields)) => {
+            let array = array.as_typed::<StructArray>()?;
+            let children = conform_columns(
+                array.column_names().into_iter().zip(array.columns_ref()),
+                fields,
+                array.len(),
+            )?;
+            let null_buffer = array
+                .data_ref()
+                .null_buffer()
+                .map(|buffer| buffer.bit_slice(array.offset(), array.len()));
+            Ok(make_array(Arc::new(ArrayData::new(
+                data_type.clone(),
+                array.len(),
+                None,
+                null_buffer,
+                0,
+                vec![],
+                children.iter().map(|child| child.data()).collect(),
+            ))))
+        }
+        _ => cast(array, data_type),
+    }
+}
+
+/// Returns the columns of `fields` from the named `columns` of length `len`
+fn conform_columns<'a>(
+    columns: impl Iterator<Item = (&'a str, ArrayRef)> + Clone,
+    fields: &[Field],
+    len: usize,
+) -> Result<Vec<ArrayRef>> {
+    fields
+        .iter()
+        .map(
+            |field| match columns.clone().find(|(name, _)| *name == field.name()) {
+                Some((_, column)) => conform_array(&column, field.data_type()),
+                None if field.is_nullable() => Ok(null_array(field.data_type(), len)),
+                None => Err(ArrowError::SchemaError(format!(
+                    "Cannot conform to the non-nullable field {} without a column",
+                    field.name()
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Returns `batch` conformed to `schema`: its columns are cast to the data types of the
+/// fields of the same names, and the fields it does not have are null. Its columns that
+/// are not in `schema` are dropped.
+///
+/// # Errors
+/// This function errors if a column cannot be cast, or if a non-nullable field of
+/// `schema` is not in `batch`.
+pub fn conform_batch(batch: &RecordBatch, schema: SchemaRef) -> Result<RecordBatch> {
+    let batch_schema = batch.schema();
+    let names = batch_schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str());
+    let columns = conform_columns(
+        names.zip(batch.columns().iter().cloned()),
+        schema.fields(),
+        batch.num_rows(),
+    )?;
+    RecordBatch::try_new(schema, columns)
+}
+
+/// Unifies the schemas of `batches` with [`widen_schemas`], and returns the unified
+/// schema along with the batches conformed to it.
+///
+/// # Errors
+/// This function errors if `batches` is empty, or if their schemas cannot be unified.
+pub fn unify_batches(batches: &[RecordBatch]) -> Result<(SchemaRef, Vec<RecordBatch>)> {
+    let schemas = batches
+        .iter()
+        .map(|batch| batch.schema())
+        .collect::<Vec<_>>();
+    let schema = Arc::new(widen_schemas(&schemas)?);
+    let batches = batches
+        .iter()
+        .map(|batch| conform_batch(batch, schema.clone()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((schema, batches))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widen_data_types() {
+        use DataType::*;
+
+        let cases = vec![
+            (Int8, Int32, Int32),
+            (UInt8, UInt64, UInt64),
+            (UInt8, Int8, Int16),
+            (UInt32, Int16, Int64),
+            (UInt64, Int64, Float64),
+            (Int16, Float32, Float32),
+            (Int32, Float32, Float64),
+            (Float32, Float64, Float64),
+            (Null, Boolean, Boolean),
+            (Boolean, Int32, Utf8),
+            (Int64, Utf8, Utf8),
+            (
+                Date32(DateUnit::Day),
+                Date64(DateUnit::Millisecond),
+                Date64(DateUnit::Millisecond),
+            ),
+            (
+                Timestamp(TimeUnit::Second, None),
+                Timestamp(TimeUnit::Microsecond, None),
+                Timestamp(TimeUnit::Microsecond, None),
+            ),
+            (
+                List(Box::new(Field::new("item", Int32, true))),
+                List(Box::new(Field::new("item", Float64, false))),
+                List(Box::new(Field::new("item", Float64, true))),
+            ),
+        ];
+        for (left, right, expected) in cases {
+            assert_eq!(widen_data_types(&left, &right).unwrap(), expected);
+            assert_eq!(widen_data_types(&right, &left).unwrap(), expected);
+        }
+
+        let list = List(Box::new(Field::new("item", Int32, true)));
+        assert!(widen_data_types(&list, &Int32).is_err());
+    }
+
+    #[test]
+    fn test_unify_batches() {
+        let first = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new(
+                    "s",
+                    DataType::Struct(vec![Field::new("x", DataType::Int8, false)]),
+                    false,
+                ),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2])),
+                Arc::new(StructArray::from(vec![(
+                    Field::new("x", DataType::Int8, false),
+                    Arc::new(Int8Array::from(vec![3, 4])) as ArrayRef,
+                )])),
+            ],
+        )
+        .unwrap();
+        let second = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("b", DataType::Utf8, true),
+                Field::new("a", DataType::UInt32, false),
+                Field::new("s", DataType::Null, true),
+            ])),
+            vec![
+                Arc::new(StringArray::from(vec![Some("x")])),
+                Arc::new(UInt32Array::from(vec![5])),
+                Arc::new(NullArray::new(1)),
+            ],
+        )
+        .unwrap();
+
+        let (schema, batches) = unify_batches(&[first, second]).unwrap();
+        assert_eq!(
+            schema.as_ref(),
+            &Schema::new(vec![
+                Field::new("a", DataType::Int64, false),
+                Field::new(
+                    "s",
+                    DataType::Struct(vec![Field::new("x", DataType::Int8, false)]),
+                    true,
+                ),
+                Field::new("b", DataType::Utf8, true),
+            ])
+        );
+
+        let a = batches[0].column(0).as_typed::<Int64Array>().unwrap();
+        assert_eq!(a, &Int64Array::from(vec![1, 2]));
+        assert_eq!(batches[0].column(2).null_count(), 2);
+        let s = batches[1].column(1).as_typed::<StructArray>().unwrap();
+        assert_eq!(s.null_count(), 1);
+        let b = batches[1].column(2).as_typed::<StringArray>().unwrap();
+        assert_eq!(b.value(0), "x");
+    }
+
+    #[test]
+    fn test_conform_batch_missing_non_nullable() {
+        let batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)])),
+            vec![Arc::new(Int32Array::from(vec![1]))],
+        )
+        .unwrap();
+        let schema = Arc::new(Schema::new(vec![Field::new("b", DataType::Int32, false)]));
+        assert!(conform_batch(&batch, schema).is_err());
+        assert!(widen_schemas(&[]).is_err());
+    }
+}
@@ -20,45 +20,60 @@
 use crate::array::*;
 #[cfg(feature = "simd")]
 use crate::bitmap::Bitmap;
-use crate::buffer::{buffer_bin_and, buffer_bin_or, Buffer};
+use crate::buffer::{buffer_bin_and, buffer_bin_or, Buffer, MutableBuffer};
+use crate::compute::kernels::concat::concat;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use num::{One, ToPrimitive, Zero};
 #[cfg(feature = "simd")]
 use std::cmp::min;
 use std::ops::Add;
+use std::sync::Arc;
 
-/// Combines the null bitmaps of two arrays using a bitwise `and` operation.
+/// Combines two optional null bitmaps using a bitwise `and` operation, where `left` and
+/// `right` start at the bit offsets `left_offset_in_bits` and `right_offset_in_bits`.
 ///
-/// This function is useful when implementing operations on higher level arrays.
-pub(super) fn combine_option_bitmap(
+/// The returned bitmap has `len_in_bits` bits and starts at offset 0, or is `None` when
+/// both inputs are `None`.
+pub fn combine_option_buffers(
+    left: Option<&Buffer>,
+    left_offset_in_bits: usize,
+    right: Option<&Buffer>,
+    right_offset_in_bits: usize,
+    len_in_bits: usize,
+) -> Option<Buffer> {
+    match (left, right) {
+        (None, None) => None,
+        (Some(l), None) => Some(l.bit_slice(left_offset_in_bits, len_in_bits)),
+        (None, Some(r)) => Some(r.bit_slice(right_offset_in_bits, len_in_bits)),
+        (Some(l), Some(r)) => Some(buffer_bin_and(
+            l,
+            left_offset_in_bits,
+            r,
+            right_offset_in_bits,
+            len_in_bits,
+        )),
+    }
+}
+
+/// Combines the null bitmaps of two arrays using a bitwise `and` operation, taking
+/// the offsets of the arrays into account.
+///
+/// This function is useful when implementing operations on higher level arrays: a slot
+/// of the result is null when the slot of either input is null. The returned bitmap
+/// starts at offset 0.
+pub fn combine_option_bitmap(
     left_data: &ArrayDataRef,
     right_data: &ArrayDataRef,
     len_in_bits: usize,
 ) -> Result<Option<Buffer>> {
-    let left_offset_in_bits = left_data.offset();
-    let right_offset_in_bits = right_data.offset();
-
-    let left = left_data.null_buffer();
-    let right = right_data.null_buffer();
-
-    match left {
-        None => match right {
-            None => Ok(None),
-            Some(r) => Ok(Some(r.bit_slice(right_offset_in_bits, len_in_bits))),
-        },
-        Some(l) => match right {
-            None => Ok(Some(l.bit_slice(left_offset_in_bits, len_in_bits))),
-
-            Some(r) => Ok(Some(buffer_bin_and(
-                &l,
-                left_offset_in_bits,
-                &r,
-                right_offset_in_bits,
-                len_in_bits,
-            ))),
-        },
-    }
+    Ok(combine_option_buffers(
+        left_data.null_buffer(),
+        left_data.offset(),
+        right_data.null_buffer(),
+        right_data.offset(),
+        len_in_bits,
+    ))
 }
 
 /// Compares the null bitmaps of two arrays using a bitwise `or` operation.
@@ -147,23 +162,181 @@ where
 }
 
 /// Takes/filters a fixed size list array's inner data using the offsets of the list array.
-pub(super) fn take_value_indices_from_fixed_size_list(
+pub(super) fn take_value_indices_from_fixed_size_list<IndexType>(
     list: &FixedSizeListArray,
-    indices: &PrimitiveArray<Int32Type>,
+    indices: &PrimitiveArray<IndexType>,
     length: <Int32Type as ArrowPrimitiveType>::Native,
-) -> PrimitiveArray<Int32Type> {
+) -> Result<PrimitiveArray<Int32Type>>
+where
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
     let mut values = vec![];
 
     for i in 0..indices.len() {
         if indices.is_valid(i) {
-            let index = indices.value(i) as usize;
+            let index = ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
+                ArrowError::ComputeError("Cast to usize failed".to_string())
+            })?;
             let start = list.value_offset(index);
 
             values.extend(start..start + length);
         }
     }
 
-    PrimitiveArray::<Int32Type>::from(values)
+    Ok(PrimitiveArray::<Int32Type>::from(values))
+}
+
+/// Returns the dictionary `arrays`, which are all of the same data type, rewritten to
+/// share the concatenation of their dictionaries if these differ, so that their keys
+/// can be copied from one array to another
+pub(super) fn unify_dictionaries(arrays: &[ArrayRef]) -> Result<Vec<ArrayRef>> {
+    match arrays.first().map(|array| array.data_type()) {
+        Some(DataType::Dictionary(key_type, _)) => match key_type.as_ref() {
+            DataType::Int8 => unify_dictionaries_of::<Int8Type>(arrays),
+            DataType::Int16 => unify_dictionaries_of::<Int16Type>(arrays),
+            DataType::Int32 => unify_dictionaries_of::<Int32Type>(arrays),
+            DataType::Int64 => unify_dictionaries_of::<Int64Type>(arrays),
+            DataType::UInt8 => unify_dictionaries_of::<UInt8Type>(arrays),
+            DataType::UInt16 => unify_dictionaries_of::<UInt16Type>(arrays),
+            DataType::UInt32 => unify_dictionaries_of::<UInt32Type>(arrays),
+            DataType::UInt64 => unify_dictionaries_of::<UInt64Type>(arrays),
+            t => Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid dictionary key type {:?}",
+                t
+            ))),
+        },
+        _ => Ok(arrays.to_vec()),
+    }
+}
+
+fn unify_dictionaries_of<K: ArrowDictionaryKeyType>(
+    arrays: &[ArrayRef],
+) -> Result<Vec<ArrayRef>> {
+    let dictionaries = arrays
+        .iter()
+        .map(|array| array.as_typed::<DictionaryArray<K>>())
+        .collect::<Result<Vec<_>>>()?;
+    let values = dictionaries
+        .iter()
+        .map(|dictionary| dictionary.values())
+        .collect::<Vec<_>>();
+    // the dictionaries are only compared by pointer, as comparing their values would
+    // cost as much as concatenating them
+    let first = values[0].data_ref();
+    if values.iter().all(|v| Arc::ptr_eq(v.data_ref(), first)) {
+        return Ok(arrays.to_vec());
+    }
+
+    let all_values = concat(&values.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+    let mut shift = 0;
+    dictionaries
+        .iter()
+        .zip(values.iter())
+        .map(|(dictionary, values)| {
+            // the keys are shifted past the values of the previous dictionaries
+            let keys = dictionary
+                .keys()
+                .iter()
+                .map(|key| {
+                    key.map(|key| {
+                        key.to_usize()
+                            .and_then(|key| K::Native::from_usize(key + shift))
+                            .ok_or_else(|| {
+                                ArrowError::ComputeError(format!(
+                                    "The union of the dictionaries has more values \
+                                     than keys of type {:?} can index",
+                                    K::DATA_TYPE
+                                ))
+                            })
+                    })
+                    .transpose()
+                })
+                .collect::<Result<PrimitiveArray<K>>>()?;
+            shift += values.len();
+
+            let keys = keys.data_ref();
+            let data = ArrayData::new(
+                dictionary.data_type().clone(),
+                keys.len(),
+                None,
+                keys.null_buffer().cloned(),
+                keys.offset(),
+                keys.buffers().to_vec(),
+                vec![all_values.data()],
+            );
+            Ok(make_array(Arc::new(data)))
+        })
+        .collect()
+}
+
+fn generic_map_utf8<OffsetSize, F>(
+    array: &GenericStringArray<OffsetSize>,
+    op: &mut F,
+) -> Result<ArrayRef>
+where
+    OffsetSize: StringOffsetSizeTrait,
+    F: FnMut(&str, &mut String),
+{
+    let capacity = (0..array.len())
+        .map(|i| array.value_length(i).to_usize().unwrap())
+        .sum();
+    let mut values = String::with_capacity(capacity);
+    let mut offsets: Vec<OffsetSize> = Vec::with_capacity(array.len() + 1);
+    offsets.push(OffsetSize::zero());
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            op(array.value(i), &mut values);
+        }
+        offsets.push(OffsetSize::from_usize(values.len()).ok_or_else(|| {
+            ArrowError::ComputeError(
+                "The mapped values are too large for the offsets of the array"
+                    .to_string(),
+            )
+        })?);
+    }
+
+    let null_bit_buffer = array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()));
+
+    let data = ArrayData::new(
+        <OffsetSize as StringOffsetSizeTrait>::DATA_TYPE,
+        array.len(),
+        Some(array.null_count()),
+        null_bit_buffer,
+        0,
+        vec![
+            Buffer::from(offsets.to_byte_slice()),
+            Buffer::from(values.as_bytes()),
+        ],
+        vec![],
+    );
+    Ok(make_array(Arc::new(data)))
+}
+
+/// Returns the \[Large\]String array of the values of the \[Large\]String `array`
+/// mapped by `op`, which appends the mapped value of its first argument to its second.
+/// Nulls are preserved.
+///
+/// This function errors when `array` is not a \[Large\]String array, naming the kernel
+/// `name`, or when the mapped values do not fit in the offsets of the returned array.
+pub(super) fn map_utf8<F>(array: &Array, name: &str, mut op: F) -> Result<ArrayRef>
+where
+    F: FnMut(&str, &mut String),
+{
+    match array.data_type() {
+        DataType::Utf8 => generic_map_utf8(array.as_typed::<StringArray>()?, &mut op),
+        DataType::LargeUtf8 => {
+            generic_map_utf8(array.as_typed::<LargeStringArray>()?, &mut op)
+        }
+        _ => Err(ArrowError::ComputeError(format!(
+            "{} does not support type {:?}",
+            name,
+            array.data_type()
+        ))),
+    }
 }
 
 /// Creates a new SIMD mask, i.e. `packed_simd::m32x16` or similar. that indicates if the
@@ -230,6 +403,66 @@ where
     )
 }
 
+/// The values of an array of fixed width values whose memory can be mutated in place
+pub(super) struct MutableValues {
+    data_type: DataType,
+    pub(super) len: usize,
+    pub(super) null_bit_buffer: Option<Buffer>,
+    pub(super) values: MutableBuffer,
+}
+
+impl MutableValues {
+    pub(super) fn finish(self) -> ArrayDataRef {
+        Arc::new(ArrayData::new(
+            self.data_type,
+            self.len,
+            None,
+            self.null_bit_buffer,
+            0,
+            vec![self.values.freeze()],
+            vec![],
+        ))
+    }
+}
+
+/// Takes the values of `array` when it is the only reference to them and is not sliced,
+/// or returns the data of `array` otherwise.
+pub(super) fn into_mutable_values<A: Array>(
+    array: A,
+) -> std::result::Result<MutableValues, ArrayDataRef> {
+    let data = array.data();
+    drop(array);
+    if data.offset() != 0 {
+        return Err(data);
+    }
+
+    let data_type = data.data_type().clone();
+    let len = data.len();
+    let null_count = data.null_count();
+    let null_bit_buffer = data.null_buffer().cloned();
+    let values = data.buffers()[0].clone();
+    // release the reference of `data` to the values
+    drop(data);
+
+    match values.into_mutable() {
+        Ok(values) => Ok(MutableValues {
+            data_type,
+            len,
+            null_bit_buffer,
+            values,
+        }),
+        Err(values) => Err(Arc::new(ArrayData::new(
+            data_type,
+            len,
+            Some(null_count),
+            null_bit_buffer,
+            0,
+            vec![values],
+            vec![],
+        ))),
+    }
+}
+
 #[cfg(test)]
 pub(super) mod tests {
     use super::*;
@@ -286,6 +519,32 @@ pub(super) mod tests {
         );
     }
 
+    #[test]
+    fn test_combine_option_buffers_with_offsets() {
+        let left = Buffer::from([0b01101101, 0b00000001]);
+        let right = Buffer::from([0b11110111]);
+        assert_eq!(None, combine_option_buffers(None, 3, None, 0, 6));
+        assert_eq!(
+            Some(Buffer::from([0b00011011])),
+            combine_option_buffers(Some(&left), 2, None, 0, 6)
+        );
+        assert_eq!(
+            Some(Buffer::from([0b00111101])),
+            combine_option_buffers(None, 0, Some(&right), 2, 6)
+        );
+        assert_eq!(
+            Some(Buffer::from([0b00011001])),
+            combine_option_buffers(Some(&left), 2, Some(&right), 2, 6)
+        );
+
+        let left = make_data_with_null_bit_buffer(6, 2, Some(left));
+        let right = make_data_with_null_bit_buffer(6, 2, Some(right));
+        assert_eq!(
+            Some(Buffer::from([0b00011001])),
+            combine_option_bitmap(&left, &right, 6).unwrap()
+        );
+    }
+
     #[test]
     fn test_compare_option_bitmap() {
         let none_bitmap = make_data_with_null_bit_buffer(8, 0, None);
@@ -335,6 +594,24 @@ pub(super) mod tests {
         GenericListArray::<S>::from(list_data)
     }
 
+    pub(crate) fn dictionary<K: ArrowDictionaryKeyType>(
+        keys: PrimitiveArray<K>,
+        values: ArrayRef,
+    ) -> DictionaryArray<K> {
+        let keys = keys.data();
+        let mut builder = ArrayData::builder(DataType::Dictionary(
+            Box::new(K::DATA_TYPE),
+            Box::new(values.data_type().clone()),
+        ))
+        .len(keys.len())
+        .add_buffer(keys.buffers()[0].clone())
+        .add_child_data(values.data());
+        if let Some(nulls) = keys.null_buffer() {
+            builder = builder.null_bit_buffer(nulls.clone());
+        }
+        DictionaryArray::from(builder.build())
+    }
+
     pub(crate) fn build_fixed_size_list<T>(
         list_values: Vec<Option<Vec<Option<T::Native>>>>,
         length: <Int32Type as ArrowPrimitiveType>::Native,
@@ -426,12 +703,14 @@ pub(super) mod tests {
         );
 
         let indices = Int32Array::from(vec![2, 1, 0]);
-        let indexed = take_value_indices_from_fixed_size_list(&list, &indices, 3);
+        let indexed =
+            take_value_indices_from_fixed_size_list(&list, &indices, 3).unwrap();
 
         assert_eq!(indexed, Int32Array::from(vec![6, 7, 8, 3, 4, 5, 0, 1, 2]));
 
         let indices = Int32Array::from(vec![3, 2, 1, 2, 0]);
-        let indexed = take_value_indices_from_fixed_size_list(&list, &indices, 3);
+        let indexed =
+            take_value_indices_from_fixed_size_list(&list, &indices, 3).unwrap();
 
         assert_eq!(
             indexed,
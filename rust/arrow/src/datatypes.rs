@@ -28,7 +28,7 @@ use std::fmt;
 use std::mem::size_of;
 use std::ops::Neg;
 #[cfg(feature = "simd")]
-use std::ops::{Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, Div, Mul, Not, Sub};
+use std::ops::{Add, BitAnd, BitAndAssign, BitOr, BitOrAssign, Div, Mul, Not, Rem, Sub};
 use std::slice::from_raw_parts;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -512,6 +512,7 @@ where
         + Sub<Output = Self::Simd>
         + Mul<Output = Self::Simd>
         + Div<Output = Self::Simd>
+        + Rem<Output = Self::Simd>
         + Copy,
     Self::SimdMask: BitAnd<Output = Self::SimdMask>
         + BitOr<Output = Self::SimdMask>
@@ -891,6 +892,11 @@ make_signed_numeric_type!(Int32Type, i32x16);
 make_signed_numeric_type!(Int64Type, i64x8);
 make_signed_numeric_type!(Float32Type, f32x16);
 make_signed_numeric_type!(Float64Type, f64x8);
+make_signed_numeric_type!(IntervalYearMonthType, i32x16);
+make_signed_numeric_type!(DurationSecondType, i64x8);
+make_signed_numeric_type!(DurationMillisecondType, i64x8);
+make_signed_numeric_type!(DurationMicrosecondType, i64x8);
+make_signed_numeric_type!(DurationNanosecondType, i64x8);
 
 /// A subtype of primitive type that represents temporal values.
 pub trait ArrowTemporalType: ArrowPrimitiveType {}
@@ -30,6 +30,8 @@ pub enum ArrowError {
     SchemaError(String),
     ComputeError(String),
     DivideByZero,
+    /// The result of an arithmetic operation does not fit into its native type
+    ArithmeticOverflow(String),
     CsvError(String),
     JsonError(String),
     IoError(String),
@@ -96,6 +98,9 @@ impl Display for ArrowError {
             ArrowError::SchemaError(desc) => write!(f, "Schema error: {}", desc),
             ArrowError::ComputeError(desc) => write!(f, "Compute error: {}", desc),
             ArrowError::DivideByZero => write!(f, "Divide by zero error"),
+            ArrowError::ArithmeticOverflow(desc) => {
+                write!(f, "Arithmetic overflow: {}", desc)
+            }
             ArrowError::CsvError(desc) => write!(f, "Csv error: {}", desc),
             ArrowError::JsonError(desc) => write!(f, "Json error: {}", desc),
             ArrowError::IoError(desc) => write!(f, "Io error: {}", desc),
@@ -20,6 +20,7 @@
 
 pub mod convert;
 pub mod reader;
+pub mod spill;
 pub mod writer;
 
 #[allow(clippy::redundant_closure)]
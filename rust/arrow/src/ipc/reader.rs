@@ -447,6 +447,17 @@ pub fn read_record_batch(
     let field_nodes = batch.nodes().ok_or_else(|| {
         ArrowError::IoError("Unable to get field nodes from IPC RecordBatch".to_string())
     })?;
+    // every dictionary field needs its dictionary to have been read beforehand
+    for (field, dictionary) in schema.fields().iter().zip(dictionaries) {
+        if let (Some(dict_id), None) = (field.dict_id(), dictionary) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "No dictionary with id {} was read for field {}",
+                dict_id,
+                field.name()
+            )));
+        }
+    }
+
     // keep track of buffer and node index, the functions that create arrays mutate these
     let mut buffer_index = 0;
     let mut node_index = 0;
@@ -491,6 +502,20 @@ fn read_dictionary(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    // fields sharing a dictionary must agree on its type, otherwise the dictionary ids
+    // were not assigned consistently by the writer
+    if let Some(field) = fields_using_this_dictionary
+        .iter()
+        .find(|f| f.data_type() != first_field.data_type())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Fields {} and {} share dictionary id {} but have different data types",
+            first_field.name(),
+            field.name(),
+            id
+        )));
+    }
+
     // As the dictionary batch does not contain the type of the
     // values array, we need to retrieve this from the schema.
     // Get an array representing this dictionary's values.
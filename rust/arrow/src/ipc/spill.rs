@@ -0,0 +1,359 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Spilling of record batches to temporary files
+//!
+//! Operators that run out of memory, such as sorts and joins, can write the batches they
+//! cannot hold to a [`SpillWriter`], and read them back later from the [`SpillFile`] it
+//! returns. The batches are written in the IPC streaming format, optionally compressed,
+//! and the files are deleted when they are no longer used.
+
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rand::Rng;
+
+use crate::datatypes::{Schema, SchemaRef};
+use crate::error::Result;
+use crate::ipc::reader::StreamReader;
+use crate::ipc::writer::StreamWriter;
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+/// The compression of the spilled batches
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SpillCompression {
+    /// The batches are written uncompressed
+    None,
+    /// The batches are compressed with DEFLATE, which requires the `flate2` feature
+    #[cfg(feature = "flate2")]
+    Deflate,
+}
+
+impl Default for SpillCompression {
+    fn default() -> Self {
+        SpillCompression::None
+    }
+}
+
+/// Writes record batches to a new temporary file, which is deleted if the writer is
+/// dropped before it is finished.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use arrow::array::Int32Array;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::error::Result;
+/// use arrow::ipc::spill::{SpillCompression, SpillWriter};
+/// use arrow::record_batch::RecordBatch;
+/// # fn main() -> Result<()> {
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+/// let batch = RecordBatch::try_new(
+///     Arc::new(schema.clone()),
+///     vec![Arc::new(Int32Array::from(vec![1, 2, 3]))],
+/// )?;
+///
+/// let mut writer = SpillWriter::try_new(&schema, SpillCompression::None)?;
+/// writer.write(&batch)?;
+/// let spill = writer.finish()?;
+/// assert_eq!(spill.num_rows(), 3);
+///
+/// let batches = spill.into_reader()?.collect::<Result<Vec<_>>>()?;
+/// assert_eq!(batches.len(), 1);
+/// assert_eq!(batches[0].num_rows(), 3);
+/// # Ok(())
+/// # }
+/// ```
+pub struct SpillWriter {
+    writer: StreamWriter<Box<dyn Write + Send>>,
+    path: PathBuf,
+    schema: SchemaRef,
+    compression: SpillCompression,
+    num_batches: usize,
+    num_rows: usize,
+    finished: bool,
+}
+
+impl fmt::Debug for SpillWriter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpillWriter")
+            .field("path", &self.path)
+            .field("schema", &self.schema)
+            .field("compression", &self.compression)
+            .field("num_batches", &self.num_batches)
+            .field("num_rows", &self.num_rows)
+            .finish()
+    }
+}
+
+impl SpillWriter {
+    /// Creates a writer of batches of `schema` to a new file of the temporary directory
+    /// of the system
+    pub fn try_new(schema: &Schema, compression: SpillCompression) -> Result<Self> {
+        Self::try_new_in(&std::env::temp_dir(), schema, compression)
+    }
+
+    /// Creates a writer of batches of `schema` to a new file of the directory `dir`
+    pub fn try_new_in(
+        dir: &Path,
+        schema: &Schema,
+        compression: SpillCompression,
+    ) -> Result<Self> {
+        // retry on the unlikely collision of the random names
+        let (file, path) = loop {
+            let path = dir.join(format!(
+                "arrow-spill-{}-{:016x}.arrows",
+                std::process::id(),
+                rand::thread_rng().gen::<u64>()
+            ));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (file, path),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        let sink: Box<dyn Write + Send> = match compression {
+            SpillCompression::None => Box::new(file),
+            #[cfg(feature = "flate2")]
+            SpillCompression::Deflate => Box::new(flate2::write::DeflateEncoder::new(
+                file,
+                flate2::Compression::fast(),
+            )),
+        };
+        let writer = match StreamWriter::try_new(sink, schema) {
+            Ok(writer) => writer,
+            Err(e) => {
+                let _ = fs::remove_file(&path);
+                return Err(e);
+            }
+        };
+        Ok(Self {
+            writer,
+            path,
+            schema: Arc::new(schema.clone()),
+            compression,
+            num_batches: 0,
+            num_rows: 0,
+            finished: false,
+        })
+    }
+
+    /// Writes a record batch to the file
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.writer.write(batch)?;
+        self.num_batches += 1;
+        self.num_rows += batch.num_rows();
+        Ok(())
+    }
+
+    /// Finishes writing the file, returning it to be read back
+    pub fn finish(mut self) -> Result<SpillFile> {
+        // all the compressed data is flushed along with the end of the stream, which is
+        // where the reader stops
+        self.writer.finish()?;
+        self.finished = true;
+        Ok(SpillFile {
+            path: self.path.clone(),
+            schema: self.schema.clone(),
+            compression: self.compression,
+            num_batches: self.num_batches,
+            num_rows: self.num_rows,
+        })
+    }
+}
+
+impl Drop for SpillWriter {
+    fn drop(&mut self) {
+        if !self.finished {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// A temporary file of spilled record batches, written by a [`SpillWriter`]. The file
+/// is deleted when this is dropped.
+#[derive(Debug)]
+pub struct SpillFile {
+    path: PathBuf,
+    schema: SchemaRef,
+    compression: SpillCompression,
+    num_batches: usize,
+    num_rows: usize,
+}
+
+impl SpillFile {
+    /// The path of the file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The schema of the spilled batches
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// The number of spilled batches
+    pub fn num_batches(&self) -> usize {
+        self.num_batches
+    }
+
+    /// The total number of rows of the spilled batches
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns a reader of the spilled batches, in the order they were written. The file
+    /// is deleted when the reader is dropped.
+    pub fn into_reader(self) -> Result<SpillReader> {
+        let file = File::open(&self.path)?;
+        let source: Box<dyn Read + Send> = match self.compression {
+            SpillCompression::None => Box::new(file),
+            #[cfg(feature = "flate2")]
+            SpillCompression::Deflate => {
+                Box::new(flate2::read::DeflateDecoder::new(file))
+            }
+        };
+        Ok(SpillReader {
+            reader: StreamReader::try_new(source)?,
+            _file: self,
+        })
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Reads back the record batches of a [`SpillFile`], deleting it when dropped
+pub struct SpillReader {
+    reader: StreamReader<Box<dyn Read + Send>>,
+    // dropped after the reader, so that the file is closed before it is deleted
+    _file: SpillFile,
+}
+
+impl fmt::Debug for SpillReader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SpillReader")
+            .field("file", &self._file)
+            .finish()
+    }
+}
+
+impl Iterator for SpillReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next()
+    }
+}
+
+impl RecordBatchReader for SpillReader {
+    fn schema(&self) -> SchemaRef {
+        self.reader.schema()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::array::{Int32Array, StringArray};
+    use crate::datatypes::{DataType, Field};
+
+    fn batches() -> (Schema, Vec<RecordBatch>) {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let batches = (0..3)
+            .map(|i| {
+                RecordBatch::try_new(
+                    Arc::new(schema.clone()),
+                    vec![
+                        Arc::new(Int32Array::from(vec![i; 100])),
+                        Arc::new(StringArray::from(vec![Some("spill"); 100])),
+                    ],
+                )
+                .unwrap()
+            })
+            .collect();
+        (schema, batches)
+    }
+
+    fn roundtrip(compression: SpillCompression) {
+        let dir = tempfile::tempdir().unwrap();
+        let (schema, batches) = batches();
+
+        let mut writer =
+            SpillWriter::try_new_in(dir.path(), &schema, compression).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let spill = writer.finish().unwrap();
+        assert_eq!(spill.num_batches(), 3);
+        assert_eq!(spill.num_rows(), 300);
+        let path = spill.path().to_path_buf();
+        assert!(path.exists());
+
+        let reader = spill.into_reader().unwrap();
+        assert_eq!(reader.schema().as_ref(), &schema);
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read.len(), batches.len());
+        for (read, batch) in read.iter().zip(&batches) {
+            for (read, column) in read.columns().iter().zip(batch.columns()) {
+                assert_eq!(read.data(), column.data());
+            }
+        }
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_spill_roundtrip() {
+        roundtrip(SpillCompression::None);
+    }
+
+    #[test]
+    #[cfg(feature = "flate2")]
+    fn test_spill_roundtrip_deflate() {
+        roundtrip(SpillCompression::Deflate);
+    }
+
+    #[test]
+    fn test_spill_deleted_when_dropped() {
+        let dir = tempfile::tempdir().unwrap();
+        let (schema, batches) = batches();
+
+        let mut writer =
+            SpillWriter::try_new_in(dir.path(), &schema, SpillCompression::None).unwrap();
+        writer.write(&batches[0]).unwrap();
+        drop(writer);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+
+        let writer =
+            SpillWriter::try_new_in(dir.path(), &schema, SpillCompression::None).unwrap();
+        let spill = writer.finish().unwrap();
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+        drop(spill);
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+}
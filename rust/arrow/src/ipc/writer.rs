@@ -22,10 +22,13 @@
 
 use std::collections::HashMap;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 
 use flatbuffers::FlatBufferBuilder;
 
-use crate::array::{ArrayDataRef, ArrayRef};
+use crate::array::{
+    make_array, new_empty_array, ArrayData, ArrayDataRef, ArrayRef, MutableArrayData,
+};
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
@@ -45,6 +48,11 @@ pub struct IpcWriteOptions {
     write_legacy_ipc_format: bool,
     /// The metadata version to write. The Rust IPC writer supports V4+
     metadata_version: ipc::MetadataVersion,
+    /// Write the dictionary ids of the schema's fields as they are, instead of assigning
+    /// a unique id to each dictionary field. Defaults to true
+    preserve_dict_id: bool,
+    /// Flush the underlying writer after each message. Defaults to true
+    flush_per_batch: bool,
 }
 
 impl IpcWriteOptions {
@@ -69,6 +77,8 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                preserve_dict_id: true,
+                flush_per_batch: true,
             }),
             ipc::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -81,12 +91,38 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        preserve_dict_id: true,
+                        flush_per_batch: true,
                     })
                 }
             }
             z => panic!("Unsupported ipc::MetadataVersion {:?}", z),
         }
     }
+
+    /// Set whether the writers should keep the dictionary ids of the schema's fields.
+    ///
+    /// By default, the ids are preserved, and fields sharing an id must have the same
+    /// data type, as they share their dictionary. Otherwise, writers assign a unique id
+    /// to each dictionary field of the schema, including those nested in struct and list
+    /// fields, in the order of the fields, and ignore the ids of the written batches'
+    /// fields.
+    pub fn with_preserve_dict_id(mut self, preserve_dict_id: bool) -> Self {
+        self.preserve_dict_id = preserve_dict_id;
+        self
+    }
+
+    /// Set whether the writers should flush the underlying writer after writing the
+    /// schema and each record batch, so that they reach the reader without delay, which
+    /// is the default.
+    ///
+    /// Otherwise, messages are buffered, and only flushed when the buffer is full, on
+    /// `StreamWriter::flush` and when the writer is finished, which favours throughput
+    /// over latency.
+    pub fn with_flush_per_batch(mut self, flush_per_batch: bool) -> Self {
+        self.flush_per_batch = flush_per_batch;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -95,6 +131,8 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: true,
             metadata_version: ipc::MetadataVersion::V4,
+            preserve_dict_id: true,
+            flush_per_batch: true,
         }
     }
 }
@@ -157,12 +195,12 @@ impl IpcDataGenerator {
                         dict_id,
                         dict_values,
                         write_options,
-                    ));
+                    )?);
                 }
             }
         }
 
-        let encoded_message = self.record_batch_to_bytes(batch, write_options);
+        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
 
         Ok((encoded_dictionaries, encoded_message))
     }
@@ -173,7 +211,7 @@ impl IpcDataGenerator {
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
-    ) -> EncodedData {
+    ) -> Result<EncodedData> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<ipc::FieldNode> = vec![];
@@ -190,7 +228,7 @@ impl IpcDataGenerator {
                 offset,
                 array.len(),
                 array.null_count(),
-            );
+            )?;
         }
 
         // write data
@@ -215,10 +253,10 @@ impl IpcDataGenerator {
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        EncodedData {
+        Ok(EncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
-        }
+        })
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (ipc::Message) and the
@@ -228,7 +266,7 @@ impl IpcDataGenerator {
         dict_id: i64,
         array_data: &ArrayDataRef,
         write_options: &IpcWriteOptions,
-    ) -> EncodedData {
+    ) -> Result<EncodedData> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<ipc::FieldNode> = vec![];
@@ -243,7 +281,7 @@ impl IpcDataGenerator {
             0,
             array_data.len(),
             array_data.null_count(),
-        );
+        )?;
 
         // write data
         let buffers = fbb.create_vector(&buffers);
@@ -276,10 +314,10 @@ impl IpcDataGenerator {
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        EncodedData {
+        Ok(EncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
-        }
+        })
     }
 }
 
@@ -332,13 +370,159 @@ impl DictionaryTracker {
     }
 }
 
+/// Returns the fields nested in fields of type `data_type`
+fn child_fields(data_type: &DataType) -> &[Field] {
+    match data_type {
+        DataType::Struct(fields) | DataType::Union(fields) => fields,
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => std::slice::from_ref(field.as_ref()),
+        _ => &[],
+    }
+}
+
+/// Checks that the dictionary fields of `fields`, and those nested in them, that share a
+/// dictionary id have the same data type
+fn check_dict_ids<'a>(
+    fields: &'a [Field],
+    data_types: &mut HashMap<i64, &'a DataType>,
+) -> Result<()> {
+    for field in fields {
+        if let Some(dict_id) = field.dict_id() {
+            let data_type = data_types.entry(dict_id).or_insert(field.data_type());
+            if *data_type != field.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Fields with dictionary id {} have different data types: \
+                     {:?} and {:?}",
+                    dict_id,
+                    data_type,
+                    field.data_type()
+                )));
+            }
+        }
+        check_dict_ids(child_fields(field.data_type()), data_types)?;
+    }
+    Ok(())
+}
+
+/// Returns `field` with a unique dictionary id assigned to it, if it is a dictionary
+/// field, and to the dictionary fields nested in it
+fn assign_dict_ids(field: &Field, next_dict_id: &mut i64) -> Field {
+    fn assign_all(fields: &[Field], next_dict_id: &mut i64) -> Vec<Field> {
+        fields
+            .iter()
+            .map(|field| assign_dict_ids(field, next_dict_id))
+            .collect()
+    }
+    let data_type = match field.data_type() {
+        DataType::Struct(fields) => DataType::Struct(assign_all(fields, next_dict_id)),
+        DataType::Union(fields) => DataType::Union(assign_all(fields, next_dict_id)),
+        DataType::List(child) => {
+            DataType::List(Box::new(assign_dict_ids(child, next_dict_id)))
+        }
+        DataType::LargeList(child) => {
+            DataType::LargeList(Box::new(assign_dict_ids(child, next_dict_id)))
+        }
+        DataType::FixedSizeList(child, size) => {
+            DataType::FixedSizeList(Box::new(assign_dict_ids(child, next_dict_id)), *size)
+        }
+        data_type => data_type.clone(),
+    };
+    match field.dict_id() {
+        Some(_) => {
+            let field = Field::new_dict(
+                field.name(),
+                data_type,
+                field.is_nullable(),
+                *next_dict_id,
+                field.dict_is_ordered().unwrap_or(false),
+            );
+            *next_dict_id += 1;
+            field
+        }
+        None => Field::new(field.name(), data_type, field.is_nullable()),
+    }
+}
+
+/// Returns the schema that the writers write, according to the write options.
+///
+/// When `preserve_dict_id` is set, the ids are kept, and this function errors if fields
+/// sharing a dictionary id have different data types. Otherwise each dictionary field,
+/// including those nested in other fields, is assigned a unique id, in the order of the
+/// fields.
+fn schema_with_dict_ids(
+    schema: &Schema,
+    write_options: &IpcWriteOptions,
+) -> Result<Schema> {
+    if write_options.preserve_dict_id {
+        check_dict_ids(schema.fields(), &mut HashMap::new())?;
+        return Ok(schema.clone());
+    }
+
+    let mut next_dict_id = 0;
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| assign_dict_ids(field, &mut next_dict_id))
+        .collect();
+    Ok(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Returns `data` with the type `data_type`, which only differs from its own by the
+/// dictionary ids of nested fields
+fn with_data_type(data: &ArrayDataRef, data_type: &DataType) -> ArrayDataRef {
+    if data.data_type() == data_type {
+        return data.clone();
+    }
+    let fields = child_fields(data_type);
+    let child_data = if fields.is_empty() {
+        data.child_data().to_vec()
+    } else {
+        data.child_data()
+            .iter()
+            .zip(fields)
+            .map(|(child, field)| with_data_type(child, field.data_type()))
+            .collect()
+    };
+    Arc::new(ArrayData::new(
+        data_type.clone(),
+        data.len(),
+        Some(data.null_count()),
+        data.null_buffer().cloned(),
+        data.offset(),
+        data.buffers().to_vec(),
+        child_data,
+    ))
+}
+
+/// Returns a batch with the columns of `batch` and the schema of the writer, so that the
+/// dictionary ids assigned by the writer are used to write its dictionaries.
+fn batch_with_dict_ids(
+    batch: &RecordBatch,
+    schema: &SchemaRef,
+    write_options: &IpcWriteOptions,
+) -> Result<RecordBatch> {
+    if write_options.preserve_dict_id {
+        return Ok(batch.clone());
+    }
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(schema.fields())
+        .map(|(column, field)| {
+            make_array(with_data_type(&column.data(), field.data_type()))
+        })
+        .collect();
+    RecordBatch::try_new(schema.clone(), columns)
+}
+
 pub struct FileWriter<W: Write> {
     /// The object to write to
     writer: BufWriter<W>,
     /// IPC write options
     write_options: IpcWriteOptions,
     /// A reference to the schema, used in validating record batches
-    schema: Schema,
+    schema: SchemaRef,
     /// The number of bytes between each block of bytes, as an offset for random access
     block_offsets: usize,
     /// Dictionary blocks that will be written as part of the IPC footer
@@ -367,18 +551,22 @@ impl<W: Write> FileWriter<W> {
         write_options: IpcWriteOptions,
     ) -> Result<Self> {
         let data_gen = IpcDataGenerator::default();
+        let schema = schema_with_dict_ids(schema, &write_options)?;
         let mut writer = BufWriter::new(writer);
         // write magic to header
         writer.write_all(&super::ARROW_MAGIC[..])?;
         // create an 8-byte boundary after the header
         writer.write_all(&[0, 0])?;
         // write the schema, set the written bytes to the schema + header
-        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        let encoded_message = data_gen.schema_to_bytes(&schema, &write_options);
         let (meta, data) = write_message(&mut writer, encoded_message, &write_options)?;
+        if write_options.flush_per_batch {
+            writer.flush()?;
+        }
         Ok(Self {
             writer,
             write_options,
-            schema: schema.clone(),
+            schema: Arc::new(schema),
             block_offsets: meta + data + 8,
             dictionary_blocks: vec![],
             record_blocks: vec![],
@@ -396,8 +584,9 @@ impl<W: Write> FileWriter<W> {
             ));
         }
 
+        let batch = batch_with_dict_ids(batch, &self.schema, &self.write_options)?;
         let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
-            batch,
+            &batch,
             &mut self.dictionary_tracker,
             &self.write_options,
         )?;
@@ -422,6 +611,9 @@ impl<W: Write> FileWriter<W> {
         );
         self.record_blocks.push(block);
         self.block_offsets += meta + data;
+        if self.write_options.flush_per_batch {
+            self.writer.flush()?;
+        }
         Ok(())
     }
 
@@ -471,7 +663,7 @@ pub struct StreamWriter<W: Write> {
     /// IPC write options
     write_options: IpcWriteOptions,
     /// A reference to the schema, used in validating record batches
-    schema: Schema,
+    schema: SchemaRef,
     /// Whether the writer footer has been written, and the writer is finished
     finished: bool,
     /// Keeps track of dictionaries that have been written
@@ -493,14 +685,18 @@ impl<W: Write> StreamWriter<W> {
         write_options: IpcWriteOptions,
     ) -> Result<Self> {
         let data_gen = IpcDataGenerator::default();
+        let schema = schema_with_dict_ids(schema, &write_options)?;
         let mut writer = BufWriter::new(writer);
         // write the schema, set the written bytes to the schema
-        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        let encoded_message = data_gen.schema_to_bytes(&schema, &write_options);
         write_message(&mut writer, encoded_message, &write_options)?;
+        if write_options.flush_per_batch {
+            writer.flush()?;
+        }
         Ok(Self {
             writer,
             write_options,
-            schema: schema.clone(),
+            schema: Arc::new(schema),
             finished: false,
             dictionary_tracker: DictionaryTracker::new(false),
             data_gen,
@@ -515,22 +711,90 @@ impl<W: Write> StreamWriter<W> {
             ));
         }
 
+        let batch = batch_with_dict_ids(batch, &self.schema, &self.write_options)?;
         let (encoded_dictionaries, encoded_message) = self
             .data_gen
-            .encoded_batch(batch, &mut self.dictionary_tracker, &self.write_options)
+            .encoded_batch(&batch, &mut self.dictionary_tracker, &self.write_options)
             .expect("StreamWriter is configured to not error on dictionary replacement");
 
+        self.write_encoded(encoded_dictionaries, encoded_message)
+    }
+
+    /// Write a record batch without rows to the stream, e.g. to keep a long-lived
+    /// connection alive while no data is produced. The stream is flushed afterwards.
+    ///
+    /// Readers receive an empty record batch, which references the dictionaries that
+    /// were last written, so these are not written again.
+    pub fn write_heartbeat(&mut self) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write heartbeat to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let columns = self
+            .schema
+            .fields()
+            .iter()
+            .map(|field| new_empty_array(field.data_type()))
+            .collect();
+        let batch = RecordBatch::try_new(self.schema.clone(), columns)?;
+
+        let written = &self.dictionary_tracker.written;
+        let dictionaries_written = self
+            .schema
+            .fields()
+            .iter()
+            .filter_map(|field| field.dict_id())
+            .all(|dict_id| written.contains_key(&dict_id));
+        let (encoded_dictionaries, encoded_message) = if dictionaries_written {
+            let encoded_message = self
+                .data_gen
+                .record_batch_to_bytes(&batch, &self.write_options)?;
+            (vec![], encoded_message)
+        } else {
+            // readers need a dictionary for each dictionary field, so the empty ones
+            // are written before the first batch
+            self.data_gen
+                .encoded_batch(&batch, &mut self.dictionary_tracker, &self.write_options)
+                .expect(
+                    "StreamWriter is configured to not error on dictionary replacement",
+                )
+        };
+
+        self.write_encoded(encoded_dictionaries, encoded_message)?;
+        if !self.write_options.flush_per_batch {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn write_encoded(
+        &mut self,
+        encoded_dictionaries: Vec<EncodedData>,
+        encoded_message: EncodedData,
+    ) -> Result<()> {
         for encoded_dictionary in encoded_dictionaries {
             write_message(&mut self.writer, encoded_dictionary, &self.write_options)?;
         }
 
         write_message(&mut self.writer, encoded_message, &self.write_options)?;
+        if self.write_options.flush_per_batch {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Flush the messages written so far to the underlying writer
+    pub fn flush(&mut self) -> Result<()> {
+        self.writer.flush()?;
         Ok(())
     }
 
-    /// Write continuation bytes, and mark the stream as done
+    /// Write continuation bytes, flush the stream, and mark it as done
     pub fn finish(&mut self) -> Result<()> {
         write_continuation(&mut self.writer, &self.write_options, 0)?;
+        self.writer.flush()?;
 
         self.finished = true;
 
@@ -547,6 +811,121 @@ impl<W: Write> Drop for StreamWriter<W> {
     }
 }
 
+/// Splits `batch` into contiguous sub-batches whose IPC message bodies are each at most
+/// `max_bytes` long, e.g. to respect the message size limits of gRPC and Flight.
+///
+/// The number of rows of each window is estimated from the cost per row of the whole
+/// batch, and shrunk until the window fits the budget, so that skewed variable-size data
+/// is handled without serializing the batch. The windows are copied into new arrays, as
+/// the IPC writers serialize whole buffers. If the batch already fits, it is returned as
+/// is.
+///
+/// The budget covers the message body only; the flatbuffer metadata of each message
+/// depends on the schema, not the number of rows, and should be accounted for by the
+/// caller.
+///
+/// Returns an error if a single row does not fit in `max_bytes`.
+pub fn split_batch_by_size(
+    batch: &RecordBatch,
+    max_bytes: usize,
+) -> Result<Vec<RecordBatch>> {
+    let num_rows = batch.num_rows();
+    let total_bytes = batch_body_size(batch)?;
+    if num_rows == 0 || total_bytes <= max_bytes {
+        return Ok(vec![batch.clone()]);
+    }
+    let bytes_per_row = bit_util::ceil(total_bytes, num_rows);
+
+    let mut batches = vec![];
+    let mut start = 0;
+    while start < num_rows {
+        let mut len = (max_bytes / bytes_per_row).max(1).min(num_rows - start);
+        loop {
+            let window = batch_window(batch, start, len)?;
+            let window_bytes = batch_body_size(&window)?;
+            if window_bytes <= max_bytes {
+                batches.push(window);
+                start += len;
+                break;
+            }
+            if len == 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Row {} requires {} bytes, which exceeds the budget of {} bytes",
+                    start, window_bytes, max_bytes
+                )));
+            }
+            // shrink the window in proportion to the overshoot
+            len = (len * max_bytes / window_bytes).max(1).min(len - 1);
+        }
+    }
+    Ok(batches)
+}
+
+/// Copies `len` rows of `batch` starting at `offset` into a new `RecordBatch`
+fn batch_window(batch: &RecordBatch, offset: usize, len: usize) -> Result<RecordBatch> {
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| {
+            let data = column.data_ref();
+            let mut mutable = MutableArrayData::new(vec![data.as_ref()], false, len);
+            mutable.extend(0, offset, offset + len);
+            make_array(Arc::new(mutable.freeze()))
+        })
+        .collect();
+    RecordBatch::try_new(batch.schema(), columns)
+}
+
+/// Returns the length of the message body `record_batch_to_bytes` writes for `batch`
+fn batch_body_size(batch: &RecordBatch) -> Result<usize> {
+    batch
+        .columns()
+        .iter()
+        .map(|array| array_body_size(array.data_ref(), array.len()))
+        .sum()
+}
+
+/// Returns the number of bytes `write_array_data` writes for `array_data`
+fn array_body_size(array_data: &ArrayData, num_rows: usize) -> Result<usize> {
+    let padded = |len: usize| len + pad_to_8(len as u32);
+    let data_offset = match array_data.data_type() {
+        DataType::Dictionary(_, _) => array_data.offset(),
+        _ => 0,
+    };
+    let mut size = 0;
+    if array_data.data_type() != &DataType::Null {
+        size += match array_data.null_buffer() {
+            Some(buffer) if data_offset != 0 => {
+                padded(buffer.bit_slice(data_offset, num_rows).len())
+            }
+            Some(buffer) => padded(buffer.len()),
+            None => padded(bit_util::ceil(num_rows, 8)),
+        };
+    }
+
+    match array_data.data_type() {
+        DataType::Dictionary(key_type, _) if data_offset != 0 => {
+            size += padded(num_rows * dictionary_key_width(key_type)?);
+        }
+        _ => {
+            size += array_data
+                .buffers()
+                .iter()
+                .map(|buffer| padded(buffer.len()))
+                .sum::<usize>()
+        }
+    }
+
+    if !matches!(array_data.data_type(), DataType::Dictionary(_, _)) {
+        size += array_data
+            .child_data()
+            .iter()
+            .map(|data_ref| array_body_size(data_ref, data_ref.len()))
+            .sum::<Result<usize>>()?;
+    }
+    Ok(size)
+}
+
 /// Stores the encoded data, which is an ipc::Message, and optional Arrow data
 pub struct EncodedData {
     /// An encoded ipc::Message
@@ -613,7 +992,6 @@ fn write_body_buffers<W: Write>(writer: &mut BufWriter<W>, data: &[u8]) -> Resul
         writer.write_all(&vec![0u8; pad_len as usize][..])?;
     }
 
-    writer.flush()?;
     Ok(total_len as usize)
 }
 
@@ -649,8 +1027,6 @@ fn write_continuation<W: Write>(
         z => panic!("Unsupported ipc::MetadataVersion {:?}", z),
     };
 
-    writer.flush()?;
-
     Ok(written)
 }
 
@@ -663,9 +1039,15 @@ fn write_array_data(
     offset: i64,
     num_rows: usize,
     null_count: usize,
-) -> i64 {
+) -> Result<i64> {
     let mut offset = offset;
     nodes.push(ipc::FieldNode::new(num_rows as i64, null_count as i64));
+    // the keys of dictionaries are fixed-width, so sliced dictionary arrays are written
+    // by slicing their null and keys buffers
+    let data_offset = match array_data.data_type() {
+        DataType::Dictionary(_, _) => array_data.offset(),
+        _ => 0,
+    };
     // NullArray does not have any buffers, thus the null buffer is not generated
     if array_data.data_type() != &DataType::Null {
         // write null buffer if exists
@@ -677,19 +1059,28 @@ fn write_array_data(
                 let buffer = buffer.with_bitset(num_bytes, true);
                 buffer.freeze()
             }
+            Some(buffer) if data_offset != 0 => buffer.bit_slice(data_offset, num_rows),
             Some(buffer) => buffer.clone(),
         };
 
         offset = write_buffer(&null_buffer, &mut buffers, &mut arrow_data, offset);
     }
 
-    array_data.buffers().iter().for_each(|buffer| {
-        offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset);
-    });
+    match array_data.data_type() {
+        DataType::Dictionary(key_type, _) if data_offset != 0 => {
+            let width = dictionary_key_width(key_type)?;
+            let keys = &array_data.buffers()[0].data()
+                [data_offset * width..(data_offset + num_rows) * width];
+            offset = write_buffer(&Buffer::from(keys), buffers, arrow_data, offset);
+        }
+        _ => array_data.buffers().iter().for_each(|buffer| {
+            offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset);
+        }),
+    }
 
     if !matches!(array_data.data_type(), DataType::Dictionary(_, _)) {
         // recursively write out nested structures
-        array_data.child_data().iter().for_each(|data_ref| {
+        for data_ref in array_data.child_data() {
             // write the nested data (e.g list data)
             offset = write_array_data(
                 data_ref,
@@ -699,11 +1090,25 @@ fn write_array_data(
                 offset,
                 data_ref.len(),
                 data_ref.null_count(),
-            );
-        });
+            )?;
+        }
     }
 
-    offset
+    Ok(offset)
+}
+
+/// Returns the width in bytes of the keys of a dictionary of `key_type`
+fn dictionary_key_width(key_type: &DataType) -> Result<usize> {
+    match key_type {
+        DataType::Int8 | DataType::UInt8 => Ok(1),
+        DataType::Int16 | DataType::UInt16 => Ok(2),
+        DataType::Int32 | DataType::UInt32 => Ok(4),
+        DataType::Int64 | DataType::UInt64 => Ok(8),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "Dictionary key type {:?} is not an integer type",
+            key_type
+        ))),
+    }
 }
 
 /// Write a buffer to a vector of bytes, and add its ipc::Buffer to a vector
@@ -738,6 +1143,7 @@ mod tests {
     use crate::array::*;
     use crate::datatypes::Field;
     use crate::ipc::reader::*;
+    use crate::record_batch::RecordBatchReader;
     use crate::util::integration_util::*;
     use std::env;
     use std::fs::File;
@@ -791,6 +1197,391 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_write_dictionaries_assigns_dict_ids() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        // both fields have the default dictionary id
+        let schema = Schema::new(vec![
+            Field::new("dict1", dict_type.clone(), true),
+            Field::new("dict2", dict_type, true),
+        ]);
+        let dict1: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b")].into_iter().collect();
+        let dict2: DictionaryArray<Int8Type> =
+            vec![Some("c"), Some("d"), Some("c")].into_iter().collect();
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(dict1) as ArrayRef, Arc::new(dict2) as ArrayRef],
+        )
+        .unwrap();
+
+        let mut file = vec![];
+        {
+            let mut writer = FileWriter::try_new(&mut file, &schema).unwrap();
+            writer.write(&batch).unwrap();
+        }
+        let mut stream = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut stream, &schema).unwrap();
+            writer.write(&batch).unwrap();
+        }
+
+        let file_reader = FileReader::try_new(std::io::Cursor::new(file)).unwrap();
+        let stream_reader = StreamReader::try_new(stream.as_slice()).unwrap();
+        let readers: Vec<Box<dyn RecordBatchReader>> =
+            vec![Box::new(file_reader), Box::new(stream_reader)];
+        for mut reader in readers {
+            let schema = reader.schema();
+            assert_eq!(Some(0), schema.field(0).dict_id());
+            assert_eq!(Some(1), schema.field(1).dict_id());
+
+            let read_batch = reader.next().unwrap().unwrap();
+            for (read, expected) in read_batch.columns().iter().zip(batch.columns()) {
+                let read = read
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<Int8Type>>()
+                    .unwrap();
+                let expected = expected
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<Int8Type>>()
+                    .unwrap();
+                assert_eq!(expected.keys(), read.keys());
+                assert_eq!(&expected.values(), &read.values());
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_sliced_dictionary() {
+        check_write_sliced_dictionary::<Int8Type>();
+        check_write_sliced_dictionary::<Int16Type>();
+        check_write_sliced_dictionary::<Int32Type>();
+        check_write_sliced_dictionary::<Int64Type>();
+    }
+
+    fn check_write_sliced_dictionary<K: ArrowDictionaryKeyType>() {
+        let dict_type =
+            DataType::Dictionary(Box::new(K::DATA_TYPE), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![Field::new("dict", dict_type, true)]);
+        let values = vec![
+            Some("a"),
+            None,
+            Some("b"),
+            Some("c"),
+            None,
+            Some("a"),
+            Some("d"),
+            Some("b"),
+            Some("e"),
+            None,
+            Some("c"),
+        ];
+        let dict: DictionaryArray<K> = values.into_iter().collect();
+        let dict = Arc::new(dict) as ArrayRef;
+
+        for &(offset, len) in &[(0, 11), (3, 5), (8, 3), (1, 0)] {
+            let sliced = dict.slice(offset, len);
+            let batch =
+                RecordBatch::try_new(Arc::new(schema.clone()), vec![sliced.clone()])
+                    .unwrap();
+
+            let mut file = vec![];
+            {
+                let mut writer = FileWriter::try_new(&mut file, &schema).unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+            }
+            let mut stream = vec![];
+            {
+                let mut writer = StreamWriter::try_new(&mut stream, &schema).unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+            }
+
+            let file_reader = FileReader::try_new(std::io::Cursor::new(file)).unwrap();
+            let stream_reader = StreamReader::try_new(stream.as_slice()).unwrap();
+            let readers: Vec<Box<dyn RecordBatchReader>> =
+                vec![Box::new(file_reader), Box::new(stream_reader)];
+            for mut reader in readers {
+                let read_batch = reader.next().unwrap().unwrap();
+                let read = read_batch.column(0);
+                assert_eq!(len, read.len());
+                assert_eq!(sliced.null_count(), read.null_count());
+                let read = read.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+                let expected = sliced
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<K>>()
+                    .unwrap();
+                assert_eq!(expected.keys(), read.keys());
+                assert_eq!(&expected.values(), &read.values());
+            }
+        }
+    }
+
+    /// A sink whose bytes can be inspected while a writer owns it
+    #[derive(Clone, Default)]
+    struct SharedSink(std::rc::Rc<std::cell::RefCell<Vec<u8>>>);
+
+    impl Write for SharedSink {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedSink {
+        fn len(&self) -> usize {
+            self.0.borrow().len()
+        }
+    }
+
+    #[test]
+    fn test_stream_writer_flush() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))],
+        )
+        .unwrap();
+
+        // messages are buffered until flushed
+        let sink = SharedSink::default();
+        let options = IpcWriteOptions::default().with_flush_per_batch(false);
+        let mut writer =
+            StreamWriter::try_new_with_options(sink.clone(), &schema, options).unwrap();
+        writer.write(&batch).unwrap();
+        assert_eq!(0, sink.len());
+        writer.flush().unwrap();
+        let flushed = sink.len();
+        assert!(flushed > 0);
+        writer.write_heartbeat().unwrap();
+        assert!(sink.len() > flushed);
+        writer.finish().unwrap();
+        assert!(writer.write_heartbeat().is_err());
+
+        let bytes = sink.0.borrow().clone();
+        let reader = StreamReader::try_new(bytes.as_slice()).unwrap();
+        let batches = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(2, batches.len());
+        assert_eq!(3, batches[0].num_rows());
+        assert_eq!(0, batches[1].num_rows());
+        assert_eq!(batches[1].schema(), batch.schema());
+
+        // each message is flushed by default
+        let sink = SharedSink::default();
+        let mut writer = StreamWriter::try_new(sink.clone(), &schema).unwrap();
+        let flushed = sink.len();
+        assert!(flushed > 0);
+        writer.write(&batch).unwrap();
+        assert!(sink.len() > flushed);
+    }
+
+    #[test]
+    fn test_stream_writer_heartbeat_nested_and_dictionary() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let list_type =
+            DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+        let struct_type = DataType::Struct(vec![
+            Field::new("s", DataType::LargeUtf8, true),
+            Field::new("b", DataType::Boolean, true),
+        ]);
+        let schema = Schema::new(vec![
+            Field::new("dict", dict_type, true),
+            Field::new("list", list_type, true),
+            Field::new("struct", struct_type, true),
+        ]);
+
+        let dict: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+        let mut builder = ListBuilder::new(Int64Builder::new(3));
+        builder.values().append_value(1).unwrap();
+        builder.append(true).unwrap();
+        builder.append(false).unwrap();
+        builder.values().append_slice(&[2, 3]).unwrap();
+        builder.append(true).unwrap();
+        let list = builder.finish();
+        let structs = StructArray::from(vec![
+            (
+                Field::new("s", DataType::LargeUtf8, true),
+                Arc::new(LargeStringArray::from(vec![Some("x"), None, Some("z")]))
+                    as ArrayRef,
+            ),
+            (
+                Field::new("b", DataType::Boolean, true),
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(dict), Arc::new(list), Arc::new(structs)],
+        )
+        .unwrap();
+
+        let mut stream = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut stream, &schema).unwrap();
+            // the dictionary is not written yet
+            writer.write_heartbeat().unwrap();
+            writer.write(&batch).unwrap();
+            writer.write_heartbeat().unwrap();
+            writer.finish().unwrap();
+        }
+
+        let reader = StreamReader::try_new(stream.as_slice()).unwrap();
+        let batches = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(
+            vec![0, 3, 0],
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>()
+        );
+        for (i, column) in batch.columns().iter().enumerate() {
+            assert_eq!(column.data_type(), batches[0].column(i).data_type());
+            assert_eq!(column.data_type(), batches[2].column(i).data_type());
+            assert_eq!(&column.data(), &batches[1].column(i).data());
+        }
+    }
+
+    #[test]
+    fn test_split_batch_by_size() {
+        let schema = Schema::new(vec![
+            Field::new("int", DataType::Int32, true),
+            Field::new("str", DataType::Utf8, true),
+        ]);
+        let ints: Int32Array = (0..1000)
+            .map(|i| if i % 7 == 0 { None } else { Some(i) })
+            .collect();
+        // skew the string lengths towards the end of the batch
+        let strings: Vec<Option<String>> = (0..1000)
+            .map(|i| match i {
+                i if i % 11 == 0 => None,
+                i if i >= 900 => Some("x".repeat(64)),
+                i => Some(i.to_string()),
+            })
+            .collect();
+        let strings: StringArray = strings.iter().map(|s| s.as_deref()).collect();
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(ints), Arc::new(strings)],
+        )
+        .unwrap();
+        let options = IpcWriteOptions::default();
+        let generator = IpcDataGenerator::default();
+
+        let batches = split_batch_by_size(&batch, 2048).unwrap();
+        assert!(batches.len() > 1);
+        let mut stream = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut stream, &schema).unwrap();
+            for b in &batches {
+                let body = generator.record_batch_to_bytes(b, &options).unwrap();
+                let body = body.arrow_data;
+                assert_eq!(body.len(), batch_body_size(b).unwrap());
+                assert!(body.len() <= 2048);
+                writer.write(b).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let reader = StreamReader::try_new(stream.as_slice()).unwrap();
+        let mut offset = 0;
+        for read in reader {
+            let read = read.unwrap();
+            for i in 0..batch.num_columns() {
+                let expected = batch.column(i).slice(offset, read.num_rows());
+                assert_eq!(expected.as_ref(), read.column(i).as_ref());
+            }
+            offset += read.num_rows();
+        }
+        assert_eq!(batch.num_rows(), offset);
+
+        // a batch within the budget is not copied
+        let batches = split_batch_by_size(&batch, usize::MAX).unwrap();
+        assert_eq!(1, batches.len());
+        assert!(Arc::ptr_eq(batch.column(0), batches[0].column(0)));
+
+        // a single row exceeds the budget
+        assert!(split_batch_by_size(&batch, 16).is_err());
+    }
+
+    #[test]
+    fn test_write_preserved_dict_ids_with_different_types() {
+        let schema = Schema::new(vec![
+            Field::new_dict(
+                "dict1",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+                1,
+                false,
+            ),
+            Field::new_dict(
+                "dict2",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32)),
+                true,
+                1,
+                false,
+            ),
+        ]);
+        assert!(StreamWriter::try_new(vec![], &schema).is_err());
+
+        // the ids are unique once reassigned
+        let options = IpcWriteOptions::default().with_preserve_dict_id(false);
+        assert!(StreamWriter::try_new_with_options(vec![], &schema, options).is_ok());
+    }
+
+    /// Returns the schema of a stream written with `options`
+    fn written_schema(schema: &Schema, options: IpcWriteOptions) -> SchemaRef {
+        let mut bytes = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut bytes, schema, options).unwrap();
+            writer.finish().unwrap();
+        }
+        StreamReader::try_new(bytes.as_slice()).unwrap().schema()
+    }
+
+    #[test]
+    fn test_write_nested_dict_ids() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let nested = Field::new_dict("nested", dict_type.clone(), true, 7, false);
+        let schema = Schema::new(vec![
+            Field::new_dict("dict", dict_type.clone(), true, 5, false),
+            Field::new("struct", DataType::Struct(vec![nested.clone()]), true),
+        ]);
+        let nested_dict_id = |schema: &Schema| match schema.field(1).data_type() {
+            DataType::Struct(fields) => fields[0].dict_id(),
+            data_type => panic!("unexpected data type {:?}", data_type),
+        };
+
+        // the ids are preserved by default
+        let written = written_schema(&schema, IpcWriteOptions::default());
+        assert_eq!(Some(5), written.field(0).dict_id());
+        assert_eq!(Some(7), nested_dict_id(&written));
+
+        let options = IpcWriteOptions::default().with_preserve_dict_id(false);
+        let written = written_schema(&schema, options);
+        assert_eq!(Some(0), written.field(0).dict_id());
+        assert_eq!(Some(1), nested_dict_id(&written));
+
+        // a nested field sharing the id of a field of another type
+        let nested = Field::new_dict(
+            "nested",
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32)),
+            true,
+            5,
+            false,
+        );
+        let schema = Schema::new(vec![
+            Field::new_dict("dict", dict_type, true, 5, false),
+            Field::new("list", DataType::List(Box::new(nested)), true),
+        ]);
+        assert!(StreamWriter::try_new(vec![], &schema).is_err());
+    }
+
     #[test]
     fn test_write_null_file() {
         let schema = Schema::new(vec![
@@ -121,6 +121,7 @@
 //! * json: [reader](json::reader::Reader)
 //! * csv: [reader](csv::reader::Reader) and [writer](csv::writer::Writer)
 //! * ipc: [reader](ipc::reader::StreamReader) and [writer](ipc::writer::FileWriter)
+//! * postgres: [writer](postgres::writer::Writer) of the binary format of `COPY`
 //!
 //! The parquet implementation is on a [separate crate](https://crates.io/crates/parquet)
 
@@ -148,7 +149,9 @@ pub mod ffi;
 pub mod ipc;
 pub mod json;
 pub mod memory;
+pub mod postgres;
 pub mod record_batch;
+pub mod scalar;
 pub mod tensor;
 pub mod util;
 mod zz_memory_check;
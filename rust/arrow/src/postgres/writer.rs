@@ -0,0 +1,680 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! PostgreSQL `COPY` Writer
+//!
+//! This writer serializes record batches in the binary format of the `COPY` command of
+//! PostgreSQL, so that they can be loaded with `COPY table FROM STDIN (FORMAT binary)`
+//! while keeping the types of their values, which is faster than loading them as CSV.
+//!
+//! The columns of the table must be of the types returned by [`type_name`] for the
+//! fields of the schema, in the same order. The writer does not support nested types.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow::array::*;
+//! use arrow::datatypes::*;
+//! use arrow::postgres;
+//! use arrow::record_batch::RecordBatch;
+//! use std::sync::Arc;
+//!
+//! let schema = Schema::new(vec![
+//!     Field::new("id", DataType::Int64, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]);
+//! let batch = RecordBatch::try_new(
+//!     Arc::new(schema.clone()),
+//!     vec![
+//!         Arc::new(Int64Array::from(vec![1, 2])),
+//!         Arc::new(StringArray::from(vec![Some("a"), None])),
+//!     ],
+//! )
+//! .unwrap();
+//!
+//! assert_eq!("text", postgres::type_name(schema.field(1).data_type()).unwrap());
+//!
+//! let mut buffer = Vec::new();
+//! let mut writer = postgres::Writer::try_new(&mut buffer, &schema).unwrap();
+//! writer.write(&batch).unwrap();
+//! writer.finish().unwrap();
+//! drop(writer);
+//! assert_eq!(&b"PGCOPY\n\xff\r\n\0"[..], &buffer[..11]);
+//! ```
+
+use std::convert::TryFrom;
+use std::io::{BufWriter, Write};
+
+use crate::array::*;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::bit_util::ceil;
+
+/// The signature that starts the binary format of `COPY`
+const SIGNATURE: &[u8] = b"PGCOPY\n\xff\r\n\0";
+
+/// The days between the UNIX epoch and the PostgreSQL epoch, 2000-01-01
+const EPOCH_DAYS: i64 = 10_957;
+
+/// The microseconds between the UNIX epoch and the PostgreSQL epoch, 2000-01-01
+const EPOCH_MICROSECONDS: i64 = EPOCH_DAYS * 86_400_000_000;
+
+/// The largest scale of a PostgreSQL `numeric`
+const MAX_NUMERIC_SCALE: usize = 1000;
+
+/// Returns the PostgreSQL type to which the values of `data_type` are written, as its
+/// OID and its name
+fn postgres_type(data_type: &DataType) -> Result<(u32, &'static str)> {
+    Ok(match data_type {
+        DataType::Boolean => (16, "bool"),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => (21, "int2"),
+        DataType::Int32 | DataType::UInt16 => (23, "int4"),
+        DataType::Int64 | DataType::UInt32 => (20, "int8"),
+        DataType::UInt64 | DataType::Decimal(_, _) => (1700, "numeric"),
+        DataType::Float32 => (700, "float4"),
+        DataType::Float64 => (701, "float8"),
+        DataType::Utf8 | DataType::LargeUtf8 => (25, "text"),
+        DataType::Binary | DataType::LargeBinary | DataType::FixedSizeBinary(_) => {
+            (17, "bytea")
+        }
+        DataType::Date32(_) | DataType::Date64(_) => (1082, "date"),
+        DataType::Time32(_) | DataType::Time64(_) => (1083, "time"),
+        DataType::Timestamp(_, None) => (1114, "timestamp"),
+        DataType::Timestamp(_, Some(_)) => (1184, "timestamptz"),
+        DataType::Duration(_) | DataType::Interval(_) => (1186, "interval"),
+        DataType::Dictionary(_, value_type) => postgres_type(value_type)?,
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Writing values of type {:?} to PostgreSQL is not supported",
+                t
+            )))
+        }
+    })
+}
+
+/// Returns the OID of the PostgreSQL type to which the values of `data_type` are
+/// written, or an error if they cannot be written.
+pub fn type_oid(data_type: &DataType) -> Result<u32> {
+    postgres_type(data_type).map(|(oid, _)| oid)
+}
+
+/// Returns the name of the PostgreSQL type to which the values of `data_type` are
+/// written, e.g. `int8` for `Int64`, or an error if they cannot be written.
+///
+/// Unsigned integers are written to the smallest signed type that holds their values,
+/// and `UInt64` to `numeric`.
+pub fn type_name(data_type: &DataType) -> Result<&'static str> {
+    postgres_type(data_type).map(|(_, name)| name)
+}
+
+/// Appends the field `bytes` to `buffer`, preceded by its length
+#[inline]
+fn write_field(buffer: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+    if bytes.len() > i32::MAX as usize {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Value of {} bytes is too large for PostgreSQL",
+            bytes.len()
+        )));
+    }
+    buffer.extend_from_slice(&(bytes.len() as i32).to_be_bytes());
+    buffer.extend_from_slice(bytes);
+    Ok(())
+}
+
+/// Appends the `numeric` field of the decimal `value * 10^-scale` to `buffer`.
+///
+/// A `numeric` is written as its number of base 10000 digits, the weight of its first
+/// digit, its sign, its scale and its digits, where leading and trailing zero digits
+/// are omitted.
+fn write_numeric(buffer: &mut Vec<u8>, value: i128, scale: usize) -> Result<()> {
+    if scale > MAX_NUMERIC_SCALE {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Scale {} is too large for PostgreSQL",
+            scale
+        )));
+    }
+    let digits = format!("{:0>width$}", value.unsigned_abs(), width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    let integer = integer.trim_start_matches('0');
+
+    // pad the integer part on the left and the fraction on the right to whole base
+    // 10000 digits
+    let integer_groups = ceil(integer.len(), 4);
+    let padded = format!(
+        "{:0>int_width$}{:0<fraction_width$}",
+        integer,
+        fraction,
+        int_width = integer_groups * 4,
+        fraction_width = ceil(fraction.len(), 4) * 4
+    );
+    let mut groups = padded
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0i16, |group, digit| group * 10 + (digit - b'0') as i16)
+        })
+        .collect::<Vec<_>>();
+    let mut weight = integer_groups as i16 - 1;
+    let leading_zeros = groups.iter().take_while(|group| **group == 0).count();
+    groups.drain(..leading_zeros);
+    weight -= leading_zeros as i16;
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    if groups.is_empty() {
+        weight = 0;
+    }
+    let sign: i16 = if value < 0 { 0x4000 } else { 0 };
+
+    buffer.extend_from_slice(&(8 + 2 * groups.len() as i32).to_be_bytes());
+    for header in &[groups.len() as i16, weight, sign, scale as i16] {
+        buffer.extend_from_slice(&header.to_be_bytes());
+    }
+    groups
+        .iter()
+        .for_each(|group| buffer.extend_from_slice(&group.to_be_bytes()));
+    Ok(())
+}
+
+/// Appends the `interval` field of `microseconds`, `days` and `months` to `buffer`
+fn write_interval(buffer: &mut Vec<u8>, microseconds: i64, days: i32, months: i32) {
+    buffer.extend_from_slice(&16i32.to_be_bytes());
+    buffer.extend_from_slice(&microseconds.to_be_bytes());
+    buffer.extend_from_slice(&days.to_be_bytes());
+    buffer.extend_from_slice(&months.to_be_bytes());
+}
+
+/// Returns `value` of `unit` as microseconds, rounded down, or an error if it overflows
+fn to_microseconds(value: i64, unit: &TimeUnit) -> Result<i64> {
+    let microseconds = match unit {
+        TimeUnit::Second => value.checked_mul(1_000_000),
+        TimeUnit::Millisecond => value.checked_mul(1_000),
+        TimeUnit::Microsecond => Some(value),
+        TimeUnit::Nanosecond => Some(value.div_euclid(1_000)),
+    };
+    microseconds.ok_or_else(|| {
+        ArrowError::ComputeError(format!(
+            "Value {} of unit {:?} is out of the range of PostgreSQL",
+            value, unit
+        ))
+    })
+}
+
+/// Returns the number of days since 2000-01-01 of `days` since the Unix epoch
+fn to_postgres_days(days: i64) -> Result<i32> {
+    days.checked_sub(EPOCH_DAYS)
+        .and_then(|days| i32::try_from(days).ok())
+        .ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Date of {} days is out of the range of PostgreSQL",
+                days
+            ))
+        })
+}
+
+macro_rules! write_be {
+    ($buffer:expr, $array:expr, $index:expr, $array_type:ty, $native:ty) => {{
+        let value = $array.as_typed::<$array_type>()?.value($index) as $native;
+        write_field($buffer, &value.to_be_bytes())
+    }};
+}
+
+macro_rules! value_of {
+    ($array:expr, $index:expr, $array_type:ty) => {
+        $array.as_typed::<$array_type>()?.value($index) as i64
+    };
+}
+
+/// Appends the field of the value of `array` at `index` to `buffer`
+fn write_value(buffer: &mut Vec<u8>, array: &ArrayRef, index: usize) -> Result<()> {
+    if array.is_null(index) {
+        buffer.extend_from_slice(&(-1i32).to_be_bytes());
+        return Ok(());
+    }
+    match array.data_type() {
+        DataType::Boolean => {
+            let value = array.as_typed::<BooleanArray>()?.value(index);
+            write_field(buffer, &[value as u8])
+        }
+        DataType::Int8 => write_be!(buffer, array, index, Int8Array, i16),
+        DataType::Int16 => write_be!(buffer, array, index, Int16Array, i16),
+        DataType::Int32 => write_be!(buffer, array, index, Int32Array, i32),
+        DataType::Int64 => write_be!(buffer, array, index, Int64Array, i64),
+        DataType::UInt8 => write_be!(buffer, array, index, UInt8Array, i16),
+        DataType::UInt16 => write_be!(buffer, array, index, UInt16Array, i32),
+        DataType::UInt32 => write_be!(buffer, array, index, UInt32Array, i64),
+        DataType::UInt64 => {
+            let value = array.as_typed::<UInt64Array>()?.value(index);
+            write_numeric(buffer, value as i128, 0)
+        }
+        DataType::Float32 => write_be!(buffer, array, index, Float32Array, f32),
+        DataType::Float64 => write_be!(buffer, array, index, Float64Array, f64),
+        DataType::Decimal(_, scale) => {
+            let value = array.as_typed::<DecimalArray>()?.value(index);
+            write_numeric(buffer, value, *scale)
+        }
+        DataType::Utf8 => write_field(
+            buffer,
+            array.as_typed::<StringArray>()?.value(index).as_bytes(),
+        ),
+        DataType::LargeUtf8 => write_field(
+            buffer,
+            array
+                .as_typed::<LargeStringArray>()?
+                .value(index)
+                .as_bytes(),
+        ),
+        DataType::Binary => {
+            write_field(buffer, array.as_typed::<BinaryArray>()?.value(index))
+        }
+        DataType::LargeBinary => {
+            write_field(buffer, array.as_typed::<LargeBinaryArray>()?.value(index))
+        }
+        DataType::FixedSizeBinary(_) => write_field(
+            buffer,
+            array.as_typed::<FixedSizeBinaryArray>()?.value(index),
+        ),
+        DataType::Date32(_) => {
+            let days = to_postgres_days(value_of!(array, index, Date32Array))?;
+            write_field(buffer, &days.to_be_bytes())
+        }
+        DataType::Date64(_) => {
+            let days = value_of!(array, index, Date64Array).div_euclid(86_400_000);
+            write_field(buffer, &to_postgres_days(days)?.to_be_bytes())
+        }
+        DataType::Time32(unit) => {
+            let value = match unit {
+                TimeUnit::Second => value_of!(array, index, Time32SecondArray),
+                _ => value_of!(array, index, Time32MillisecondArray),
+            };
+            write_field(buffer, &to_microseconds(value, unit)?.to_be_bytes())
+        }
+        DataType::Time64(unit) => {
+            let value = match unit {
+                TimeUnit::Microsecond => value_of!(array, index, Time64MicrosecondArray),
+                _ => value_of!(array, index, Time64NanosecondArray),
+            };
+            write_field(buffer, &to_microseconds(value, unit)?.to_be_bytes())
+        }
+        DataType::Timestamp(unit, _) => {
+            let value = match unit {
+                TimeUnit::Second => value_of!(array, index, TimestampSecondArray),
+                TimeUnit::Millisecond => {
+                    value_of!(array, index, TimestampMillisecondArray)
+                }
+                TimeUnit::Microsecond => {
+                    value_of!(array, index, TimestampMicrosecondArray)
+                }
+                TimeUnit::Nanosecond => value_of!(array, index, TimestampNanosecondArray),
+            };
+            let value = to_microseconds(value, unit)?
+                .checked_sub(EPOCH_MICROSECONDS)
+                .ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Timestamp {} is out of the range of PostgreSQL",
+                        value
+                    ))
+                })?;
+            write_field(buffer, &value.to_be_bytes())
+        }
+        DataType::Duration(unit) => {
+            let value = match unit {
+                TimeUnit::Second => value_of!(array, index, DurationSecondArray),
+                TimeUnit::Millisecond => {
+                    value_of!(array, index, DurationMillisecondArray)
+                }
+                TimeUnit::Microsecond => {
+                    value_of!(array, index, DurationMicrosecondArray)
+                }
+                TimeUnit::Nanosecond => value_of!(array, index, DurationNanosecondArray),
+            };
+            write_interval(buffer, to_microseconds(value, unit)?, 0, 0);
+            Ok(())
+        }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            let months = array.as_typed::<IntervalYearMonthArray>()?.value(index);
+            write_interval(buffer, 0, 0, months);
+            Ok(())
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            // the days are the high 32 bits, and the milliseconds the low ones
+            let value = array.as_typed::<IntervalDayTimeArray>()?.value(index);
+            let (days, milliseconds) = ((value >> 32) as i32, value as i32);
+            write_interval(buffer, milliseconds as i64 * 1_000, days, 0);
+            Ok(())
+        }
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => write_dictionary_value::<Int8Type>(buffer, array, index),
+            DataType::Int16 => write_dictionary_value::<Int16Type>(buffer, array, index),
+            DataType::Int32 => write_dictionary_value::<Int32Type>(buffer, array, index),
+            DataType::Int64 => write_dictionary_value::<Int64Type>(buffer, array, index),
+            DataType::UInt8 => write_dictionary_value::<UInt8Type>(buffer, array, index),
+            DataType::UInt16 => {
+                write_dictionary_value::<UInt16Type>(buffer, array, index)
+            }
+            DataType::UInt32 => {
+                write_dictionary_value::<UInt32Type>(buffer, array, index)
+            }
+            DataType::UInt64 => {
+                write_dictionary_value::<UInt64Type>(buffer, array, index)
+            }
+            t => Err(ArrowError::InvalidArgumentError(format!(
+                "Invalid dictionary key type {:?}",
+                t
+            ))),
+        },
+        t => Err(ArrowError::InvalidArgumentError(format!(
+            "Writing values of type {:?} to PostgreSQL is not supported",
+            t
+        ))),
+    }
+}
+
+/// Appends the field of the value of the dictionary `array` at `index`, which is valid,
+/// to `buffer`
+fn write_dictionary_value<K: ArrowDictionaryKeyType>(
+    buffer: &mut Vec<u8>,
+    array: &ArrayRef,
+    index: usize,
+) -> Result<()> {
+    let array = array.as_typed::<DictionaryArray<K>>()?;
+    let key = array.keys().value(index).to_usize().ok_or_else(|| {
+        ArrowError::ComputeError(format!("Invalid dictionary key at index {}", index))
+    })?;
+    write_value(buffer, &array.values(), key)
+}
+
+/// A writer of record batches in the binary format of the PostgreSQL `COPY` command
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    /// The object to write to
+    writer: BufWriter<W>,
+    /// A reference to the schema, used in validating record batches
+    schema: SchemaRef,
+    /// Whether the trailer has been written, and the writer is finished
+    finished: bool,
+}
+
+impl<W: Write> Writer<W> {
+    /// Try create a new writer of batches of `schema`, and write the header of the
+    /// format.
+    ///
+    /// This function errors if a field is of a type that cannot be written.
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self> {
+        for field in schema.fields() {
+            postgres_type(field.data_type())?;
+        }
+        if schema.fields().len() > i16::MAX as usize {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "PostgreSQL does not support {} columns",
+                schema.fields().len()
+            )));
+        }
+
+        let mut writer = BufWriter::new(writer);
+        writer.write_all(SIGNATURE)?;
+        // no flags, and no header extension
+        writer.write_all(&0i32.to_be_bytes())?;
+        writer.write_all(&0i32.to_be_bytes())?;
+        Ok(Self {
+            writer,
+            schema: SchemaRef::new(schema.clone()),
+            finished: false,
+        })
+    }
+
+    /// Write the rows of a record batch
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write record batch to PostgreSQL writer as it is finished"
+                    .to_string(),
+            ));
+        }
+        if batch.schema().fields() != self.schema.fields() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Cannot write record batch to PostgreSQL writer of a different schema"
+                    .to_string(),
+            ));
+        }
+
+        let num_columns = (batch.num_columns() as i16).to_be_bytes();
+        let mut buffer = Vec::new();
+        for row in 0..batch.num_rows() {
+            buffer.extend_from_slice(&num_columns);
+            for column in batch.columns() {
+                write_value(&mut buffer, column, row)?;
+            }
+        }
+        self.writer.write_all(&buffer)?;
+        Ok(())
+    }
+
+    /// Write the trailer of the format, then mark the writer as done
+    pub fn finish(&mut self) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot finish PostgreSQL writer as it is already finished".to_string(),
+            ));
+        }
+        self.writer.write_all(&(-1i16).to_be_bytes())?;
+        self.writer.flush()?;
+        self.finished = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    fn write_batch(batch: &RecordBatch) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::try_new(&mut buffer, &batch.schema()).unwrap();
+        writer.write(batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+        buffer
+    }
+
+    /// Returns the fields of the single row written in `buffer`
+    fn read_row(buffer: &[u8]) -> Vec<Option<Vec<u8>>> {
+        assert_eq!(SIGNATURE, &buffer[..11]);
+        assert_eq!(&[0; 8], &buffer[11..19]);
+        assert_eq!(&[0xff, 0xff], &buffer[buffer.len() - 2..]);
+        let num_fields = i16::from_be_bytes([buffer[19], buffer[20]]);
+        let mut position = 21;
+        let fields = (0..num_fields)
+            .map(|_| {
+                let mut length = [0; 4];
+                length.copy_from_slice(&buffer[position..position + 4]);
+                let length = i32::from_be_bytes(length);
+                position += 4;
+                if length < 0 {
+                    None
+                } else {
+                    let field = buffer[position..position + length as usize].to_vec();
+                    position += length as usize;
+                    Some(field)
+                }
+            })
+            .collect();
+        assert_eq!(buffer.len() - 2, position);
+        fields
+    }
+
+    fn numeric(value: i128, scale: usize) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_numeric(&mut buffer, value, scale).unwrap();
+        buffer[4..].to_vec()
+    }
+
+    fn be_i16s(values: &[i16]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|v| v.to_be_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_write_numeric() {
+        // ndigits, weight, sign, dscale, then the base 10000 digits
+        assert_eq!(be_i16s(&[2, 0, 0, 2, 123, 4500]), numeric(12345, 2));
+        assert_eq!(be_i16s(&[1, -1, 0x4000, 2, 500]), numeric(-5, 2));
+        assert_eq!(be_i16s(&[1, 1, 0, 0, 1]), numeric(10_000, 0));
+        assert_eq!(be_i16s(&[1, -2, 0, 8, 1]), numeric(1, 8));
+        assert_eq!(be_i16s(&[0, 0, 0, 3]), numeric(0, 3));
+        assert_eq!(
+            be_i16s(&[5, 4, 0, 0, 1844, 6744, 737, 955, 1615]),
+            numeric(u64::MAX as i128, 0)
+        );
+        assert!(write_numeric(&mut Vec::new(), 1, MAX_NUMERIC_SCALE).is_ok());
+        assert!(write_numeric(&mut Vec::new(), 1, MAX_NUMERIC_SCALE + 1).is_err());
+    }
+
+    #[test]
+    fn test_postgres_days() {
+        assert_eq!(0, to_postgres_days(EPOCH_DAYS).unwrap());
+        assert_eq!(
+            i32::MIN,
+            to_postgres_days(i32::MIN as i64 + EPOCH_DAYS).unwrap()
+        );
+        assert!(to_postgres_days(i32::MIN as i64).is_err());
+        assert!(to_postgres_days(i64::MIN).is_err());
+    }
+
+    #[test]
+    fn test_write_types() {
+        let mut decimals = DecimalBuilder::new(1, 10, 2);
+        decimals.append_value(-12345).unwrap();
+        let dictionary: DictionaryArray<Int8Type> = vec!["x"].into_iter().collect();
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(BooleanArray::from(vec![true])),
+            Arc::new(Int8Array::from(vec![-2])),
+            Arc::new(UInt32Array::from(vec![u32::MAX])),
+            Arc::new(Float64Array::from(vec![1.5])),
+            Arc::new(StringArray::from(vec!["héllo"])),
+            Arc::new(BinaryArray::from(vec![&b"\x00\x01"[..]])),
+            Arc::new(decimals.finish()),
+            Arc::new(Date32Array::from(vec![EPOCH_DAYS as i32 + 1])),
+            Arc::new(Time32MillisecondArray::from(vec![1_500])),
+            Arc::new(TimestampNanosecondArray::from_opt_vec(
+                vec![Some(EPOCH_MICROSECONDS * 1_000 - 1_500)],
+                Some("UTC".to_string()),
+            )),
+            Arc::new(IntervalDayTimeArray::from(vec![(3i64 << 32) + 250])),
+            Arc::new(dictionary),
+            Arc::new(Int32Array::from(vec![None])),
+        ];
+        let fields = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Field::new(&format!("c{}", i), c.data_type().clone(), true))
+            .collect::<Vec<_>>();
+        let type_names = fields
+            .iter()
+            .map(|f| type_name(f.data_type()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "bool",
+                "int2",
+                "int8",
+                "float8",
+                "text",
+                "bytea",
+                "numeric",
+                "date",
+                "time",
+                "timestamptz",
+                "interval",
+                "text",
+                "int4"
+            ],
+            type_names
+        );
+        assert_eq!(1184, type_oid(fields[9].data_type()).unwrap());
+
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).unwrap();
+        let mut interval = 250_000i64.to_be_bytes().to_vec();
+        interval.extend_from_slice(&3i32.to_be_bytes());
+        interval.extend_from_slice(&0i32.to_be_bytes());
+        assert_eq!(
+            vec![
+                Some(vec![1]),
+                Some((-2i16).to_be_bytes().to_vec()),
+                Some((u32::MAX as i64).to_be_bytes().to_vec()),
+                Some(1.5f64.to_be_bytes().to_vec()),
+                Some("héllo".as_bytes().to_vec()),
+                Some(vec![0, 1]),
+                Some(be_i16s(&[2, 0, 0x4000, 2, 123, 4500])),
+                Some(1i32.to_be_bytes().to_vec()),
+                Some(1_500_000i64.to_be_bytes().to_vec()),
+                Some((-2i64).to_be_bytes().to_vec()),
+                Some(interval),
+                Some(b"x".to_vec()),
+                None,
+            ],
+            read_row(&write_batch(&batch))
+        );
+    }
+
+    #[test]
+    fn test_write_batches() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(Int32Array::from(vec![Some(1), None]))],
+        )
+        .unwrap();
+
+        let mut buffer = Vec::new();
+        let mut writer = Writer::try_new(&mut buffer, &schema).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+
+        let other = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let other = RecordBatch::try_new(
+            Arc::new(other),
+            vec![Arc::new(Int64Array::from(vec![1]))],
+        )
+        .unwrap();
+        assert!(writer.write(&other).is_err());
+
+        writer.finish().unwrap();
+        assert!(writer.write(&batch).is_err());
+        assert!(writer.finish().is_err());
+        drop(writer);
+
+        let row = [
+            &[0, 1, 0, 0, 0, 4, 0, 0, 0, 1][..],
+            &[0, 1, 0xff, 0xff, 0xff, 0xff],
+        ]
+        .concat();
+        let expected = [SIGNATURE, &[0; 8], &row, &row, &[0xff, 0xff]].concat();
+        assert_eq!(expected, buffer);
+
+        let list = DataType::List(Box::new(Field::new("item", DataType::Int32, true)));
+        let schema = Schema::new(vec![Field::new("l", list, true)]);
+        assert!(Writer::try_new(Vec::new(), &schema).is_err());
+    }
+}
@@ -23,6 +23,7 @@ use std::sync::Arc;
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::scalar::Scalar;
 
 /// A two-dimensional batch of column-oriented data with a defined
 /// [schema](crate::datatypes::Schema).
@@ -185,6 +186,313 @@ impl RecordBatch {
     pub fn columns(&self) -> &[ArrayRef] {
         &self.columns[..]
     }
+
+    /// Get the value at `row` of the column at index `column` as a dynamically typed
+    /// [`Scalar`](crate::scalar::Scalar).
+    ///
+    /// This is meant for the inspection of individual values, e.g. when debugging or
+    /// sampling; use the typed arrays to access values in bulk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is outside of `0..num_columns`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::Int32Array;
+    /// use arrow::datatypes::{Schema, Field, DataType};
+    /// use arrow::record_batch::RecordBatch;
+    /// use arrow::scalar::Scalar;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let id_array = Int32Array::from(vec![Some(1), None, Some(3)]);
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int32, true)
+    /// ]);
+    ///
+    /// let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(id_array)])?;
+    ///
+    /// assert_eq!(batch.get(0, 2)?, Scalar::Int32(Some(3)));
+    /// assert_eq!(batch.get(0, 1)?, Scalar::Int32(None));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get(&self, column: usize, row: usize) -> Result<Scalar> {
+        Scalar::try_from_array(&self.columns[column], row)
+    }
+
+    /// Get the values of all columns at `row` as dynamically typed
+    /// [`Scalars`](crate::scalar::Scalar), in the order of the schema's fields.
+    pub fn row(&self, row: usize) -> Result<Vec<Scalar>> {
+        self.columns
+            .iter()
+            .map(|column| Scalar::try_from_array(column, row))
+            .collect()
+    }
+
+    /// Returns an iterator over the rows of this batch, whose values can be read with the
+    /// typed getters of [`Row`], e.g. to write them to a row-oriented format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{Int32Array, StringArray};
+    /// use arrow::datatypes::{DataType, Field, Int32Type, Schema};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let schema = Schema::new(vec![
+    ///     Field::new("id", DataType::Int32, false),
+    ///     Field::new("name", DataType::Utf8, true),
+    /// ]);
+    /// let batch = RecordBatch::try_new(
+    ///     Arc::new(schema),
+    ///     vec![
+    ///         Arc::new(Int32Array::from(vec![1, 2])),
+    ///         Arc::new(StringArray::from(vec![Some("a"), None])),
+    ///     ],
+    /// )?;
+    ///
+    /// let mut rows = Vec::new();
+    /// for row in batch.rows() {
+    ///     rows.push((row.primitive::<Int32Type, _>(0)?, row.str("name")?));
+    /// }
+    /// assert_eq!(vec![(Some(1), Some("a")), (Some(2), None)], rows);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn rows(&self) -> Rows<'_> {
+        Rows {
+            batch: self,
+            columns: self.columns.iter().map(TypedColumn::new).collect(),
+            row: 0,
+        }
+    }
+}
+
+/// A column of a [`RecordBatch`], downcast once by [`Rows`] for all of its rows.
+///
+/// The type of primitive columns is only known by [`Row::primitive`], which downcasts
+/// them on access.
+#[derive(Debug, Clone, Copy)]
+enum TypedColumn<'a> {
+    Boolean(&'a BooleanArray),
+    Utf8(&'a StringArray),
+    LargeUtf8(&'a LargeStringArray),
+    Binary(&'a BinaryArray),
+    LargeBinary(&'a LargeBinaryArray),
+    FixedSizeBinary(&'a FixedSizeBinaryArray),
+    Other(&'a dyn Array),
+}
+
+impl<'a> TypedColumn<'a> {
+    fn new(column: &'a ArrayRef) -> Self {
+        let any = column.as_any();
+        match column.data_type() {
+            DataType::Boolean => TypedColumn::Boolean(any.downcast_ref().unwrap()),
+            DataType::Utf8 => TypedColumn::Utf8(any.downcast_ref().unwrap()),
+            DataType::LargeUtf8 => TypedColumn::LargeUtf8(any.downcast_ref().unwrap()),
+            DataType::Binary => TypedColumn::Binary(any.downcast_ref().unwrap()),
+            DataType::LargeBinary => {
+                TypedColumn::LargeBinary(any.downcast_ref().unwrap())
+            }
+            DataType::FixedSizeBinary(_) => {
+                TypedColumn::FixedSizeBinary(any.downcast_ref().unwrap())
+            }
+            _ => TypedColumn::Other(column.as_ref()),
+        }
+    }
+
+    fn array(&self) -> &'a dyn Array {
+        match *self {
+            TypedColumn::Boolean(array) => array,
+            TypedColumn::Utf8(array) => array,
+            TypedColumn::LargeUtf8(array) => array,
+            TypedColumn::Binary(array) => array,
+            TypedColumn::LargeBinary(array) => array,
+            TypedColumn::FixedSizeBinary(array) => array,
+            TypedColumn::Other(array) => array,
+        }
+    }
+
+    fn type_error(&self, expected: &str) -> ArrowError {
+        ArrowError::InvalidArgumentError(format!(
+            "Expected array of type {}, got array of data type {:?}",
+            expected,
+            self.array().data_type()
+        ))
+    }
+}
+
+/// Iterator over the rows of a [`RecordBatch`], created by [`RecordBatch::rows`]
+#[derive(Debug, Clone)]
+pub struct Rows<'a> {
+    batch: &'a RecordBatch,
+    columns: Arc<[TypedColumn<'a>]>,
+    row: usize,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.batch.num_rows() {
+            let row = Row {
+                batch: self.batch,
+                columns: self.columns.clone(),
+                row: self.row,
+            };
+            self.row += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.batch.num_rows() - self.row;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Rows<'a> {}
+
+/// A column of a [`RecordBatch`], referred to by its index or by its name
+pub trait ColumnIndex {
+    /// Returns the index of the column in `schema`, or an error if it has no such column
+    fn index_in(&self, schema: &Schema) -> Result<usize>;
+}
+
+impl ColumnIndex for usize {
+    fn index_in(&self, schema: &Schema) -> Result<usize> {
+        if *self < schema.fields().len() {
+            Ok(*self)
+        } else {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "Column index {} out of bounds for {} columns",
+                self,
+                schema.fields().len()
+            )))
+        }
+    }
+}
+
+impl ColumnIndex for &str {
+    fn index_in(&self, schema: &Schema) -> Result<usize> {
+        schema.index_of(self)
+    }
+}
+
+/// A row of a [`RecordBatch`], whose values are read from the columns of the batch
+/// without copying them.
+///
+/// The getters take the index or the name of a column, and return an error if the
+/// column does not exist or is not of the requested type. Looking columns up by name
+/// scans the fields of the schema, so prefer indices in hot loops.
+#[derive(Debug, Clone)]
+pub struct Row<'a> {
+    batch: &'a RecordBatch,
+    columns: Arc<[TypedColumn<'a>]>,
+    row: usize,
+}
+
+impl<'a> Row<'a> {
+    /// Returns the index of this row in its batch
+    pub fn index(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the number of columns of the batch
+    pub fn num_columns(&self) -> usize {
+        self.batch.num_columns()
+    }
+
+    /// Returns the column `column` of the batch
+    pub fn column<I: ColumnIndex>(&self, column: I) -> Result<&'a ArrayRef> {
+        let index = column.index_in(&self.batch.schema)?;
+        Ok(&self.batch.columns[index])
+    }
+
+    /// Returns whether the value of column `column` is null
+    pub fn is_null<I: ColumnIndex>(&self, column: I) -> Result<bool> {
+        Ok(self.typed_column(column)?.array().is_null(self.row))
+    }
+
+    /// Returns the value of column `column`, which must be a `PrimitiveArray<T>`
+    pub fn primitive<T: ArrowPrimitiveType, I: ColumnIndex>(
+        &self,
+        column: I,
+    ) -> Result<Option<T::Native>> {
+        let array = self.typed_column(column)?.array();
+        let array = array.as_typed::<PrimitiveArray<T>>()?;
+        Ok(self.value(array, |array, i| array.value(i)))
+    }
+
+    /// Returns the value of column `column`, which must be a `BooleanArray`
+    pub fn bool<I: ColumnIndex>(&self, column: I) -> Result<Option<bool>> {
+        match self.typed_column(column)? {
+            TypedColumn::Boolean(array) => {
+                Ok(self.value(array, |array, i| array.value(i)))
+            }
+            column => Err(column.type_error("BooleanArray")),
+        }
+    }
+
+    /// Returns the value of column `column`, which must be a `StringArray` or a
+    /// `LargeStringArray`
+    pub fn str<I: ColumnIndex>(&self, column: I) -> Result<Option<&'a str>> {
+        match self.typed_column(column)? {
+            TypedColumn::Utf8(array) => Ok(self.value(array, |array, i| array.value(i))),
+            TypedColumn::LargeUtf8(array) => {
+                Ok(self.value(array, |array, i| array.value(i)))
+            }
+            column => Err(column.type_error("StringArray")),
+        }
+    }
+
+    /// Returns the value of column `column`, which must be a `BinaryArray`, a
+    /// `LargeBinaryArray` or a `FixedSizeBinaryArray`
+    pub fn binary<I: ColumnIndex>(&self, column: I) -> Result<Option<&'a [u8]>> {
+        match self.typed_column(column)? {
+            TypedColumn::Binary(array) => {
+                Ok(self.value(array, |array, i| array.value(i)))
+            }
+            TypedColumn::LargeBinary(array) => {
+                Ok(self.value(array, |array, i| array.value(i)))
+            }
+            TypedColumn::FixedSizeBinary(array) => {
+                Ok(self.value(array, |array, i| array.value(i)))
+            }
+            column => Err(column.type_error("BinaryArray")),
+        }
+    }
+
+    /// Returns the value of column `column` as a dynamically typed
+    /// [`Scalar`](crate::scalar::Scalar), for columns of other types
+    pub fn scalar<I: ColumnIndex>(&self, column: I) -> Result<Scalar> {
+        Scalar::try_from_array(self.column(column)?, self.row)
+    }
+
+    fn typed_column<I: ColumnIndex>(&self, column: I) -> Result<TypedColumn<'a>> {
+        let index = column.index_in(&self.batch.schema)?;
+        Ok(self.columns[index])
+    }
+
+    #[inline]
+    fn value<A: Array, T>(
+        &self,
+        array: &'a A,
+        value: impl Fn(&'a A, usize) -> T,
+    ) -> Option<T> {
+        if array.is_null(self.row) {
+            None
+        } else {
+            Some(value(array, self.row))
+        }
+    }
 }
 
 impl From<&StructArray> for RecordBatch {
@@ -330,4 +638,86 @@ mod tests {
         assert_eq!(batch.column(0).data(), boolean_data);
         assert_eq!(batch.column(1).data(), int_data);
     }
+
+    #[test]
+    fn record_batch_get_and_row() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+        ]);
+        let a = Int32Array::from(vec![Some(1), None]);
+        let b = StringArray::from(vec!["x", "y"]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        assert_eq!(
+            Scalar::Utf8(Some("y".to_string())),
+            batch.get(1, 1).unwrap()
+        );
+        assert_eq!(
+            vec![Scalar::Int32(None), Scalar::Utf8(Some("y".to_string()))],
+            batch.row(1).unwrap()
+        );
+        assert!(batch.row(2).is_err());
+    }
+
+    #[test]
+    fn record_batch_rows() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, false),
+            Field::new("c", DataType::Boolean, true),
+            Field::new("d", DataType::LargeBinary, false),
+        ]);
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = StringArray::from(vec!["x", "y", "z"]);
+        let c = BooleanArray::from(vec![None, Some(true), Some(false)]);
+        let d = LargeBinaryArray::from(vec![&b"1"[..], b"22", b"333"]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(a), Arc::new(b), Arc::new(c), Arc::new(d)],
+        )
+        .unwrap();
+
+        let rows = batch.rows();
+        assert_eq!(3, rows.len());
+        let rows: Vec<_> = rows
+            .map(|row| {
+                (
+                    row.index(),
+                    row.primitive::<Int32Type, _>(0).unwrap(),
+                    row.str("b").unwrap(),
+                    row.bool(2).unwrap(),
+                    row.binary("d").unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (0, Some(1), Some("x"), None, Some(&b"1"[..])),
+                (1, None, Some("y"), Some(true), Some(&b"22"[..])),
+                (2, Some(3), Some("z"), Some(false), Some(&b"333"[..])),
+            ],
+            rows
+        );
+
+        let row = batch.rows().nth(1).unwrap();
+        assert!(row.is_null("a").unwrap());
+        assert_eq!(Scalar::Utf8(Some("y".to_string())), row.scalar(1).unwrap());
+        assert!(row.primitive::<Int64Type, _>(0).is_err());
+        assert!(row.str(0).is_err());
+        assert!(row.bool(4).is_err());
+        assert!(row.bool("e").is_err());
+    }
+
+    #[test]
+    fn record_batch_rows_of_sliced_columns() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
+        let a = StringArray::from(vec![Some("x"), None, Some("z")]).slice(1, 2);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![a]).unwrap();
+
+        let values: Vec<_> = batch.rows().map(|row| row.str(0).unwrap()).collect();
+        assert_eq!(vec![None, Some("z")], values);
+    }
 }
@@ -0,0 +1,604 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`Scalar`](crate::scalar::Scalar), a dynamically typed, nullable single value.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::kernels::temporal::reinterpret;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+
+/// A dynamically typed, nullable single value.
+/// This is the single-valued counter-part of [`Array`](crate::array::Array).
+///
+/// Every variant but `Null` carries an `Option`, so that a null value keeps its data
+/// type.
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::scalar::Scalar;
+///
+/// let array: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None]));
+/// assert_eq!(Scalar::try_from_array(&array, 0).unwrap(), Scalar::Int32(Some(1)));
+/// assert!(Scalar::try_from_array(&array, 1).unwrap().is_null());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Scalar {
+    /// a value of the null data type
+    Null,
+    /// true or false value
+    Boolean(Option<bool>),
+    /// signed 8bit int
+    Int8(Option<i8>),
+    /// signed 16bit int
+    Int16(Option<i16>),
+    /// signed 32bit int
+    Int32(Option<i32>),
+    /// signed 64bit int
+    Int64(Option<i64>),
+    /// unsigned 8bit int
+    UInt8(Option<u8>),
+    /// unsigned 16bit int
+    UInt16(Option<u16>),
+    /// unsigned 32bit int
+    UInt32(Option<u32>),
+    /// unsigned 64bit int
+    UInt64(Option<u64>),
+    /// 32bit float
+    Float32(Option<f32>),
+    /// 64bit float
+    Float64(Option<f64>),
+    /// utf-8 encoded string
+    Utf8(Option<String>),
+    /// utf-8 encoded string with 64bit offsets
+    LargeUtf8(Option<String>),
+    /// opaque binary data
+    Binary(Option<Vec<u8>>),
+    /// opaque binary data with 64bit offsets
+    LargeBinary(Option<Vec<u8>>),
+    /// opaque binary data of the given fixed byte width
+    FixedSizeBinary(Option<Vec<u8>>, i32),
+    /// days since the UNIX epoch
+    Date32(Option<i32>),
+    /// milliseconds since the UNIX epoch
+    Date64(Option<i64>),
+    /// time since midnight as a 32bit int of the given unit
+    Time32(Option<i32>, TimeUnit),
+    /// time since midnight as a 64bit int of the given unit
+    Time64(Option<i64>, TimeUnit),
+    /// time since the UNIX epoch of the given unit and optional timezone
+    Timestamp(Option<i64>, TimeUnit, Option<String>),
+    /// elapsed time of the given unit
+    Duration(Option<i64>, TimeUnit),
+    /// number of elapsed whole months
+    IntervalYearMonth(Option<i32>),
+    /// number of elapsed days and milliseconds, packed into a 64bit int
+    IntervalDayTime(Option<i64>),
+}
+
+macro_rules! typed_value {
+    ($array:expr, $index:expr, $array_type:ty) => {{
+        let array = $array.as_any().downcast_ref::<$array_type>().unwrap();
+        if array.is_null($index) {
+            None
+        } else {
+            Some(array.value($index).into())
+        }
+    }};
+}
+
+impl Scalar {
+    /// Returns the data type of this scalar.
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Scalar::Null => DataType::Null,
+            Scalar::Boolean(_) => DataType::Boolean,
+            Scalar::Int8(_) => DataType::Int8,
+            Scalar::Int16(_) => DataType::Int16,
+            Scalar::Int32(_) => DataType::Int32,
+            Scalar::Int64(_) => DataType::Int64,
+            Scalar::UInt8(_) => DataType::UInt8,
+            Scalar::UInt16(_) => DataType::UInt16,
+            Scalar::UInt32(_) => DataType::UInt32,
+            Scalar::UInt64(_) => DataType::UInt64,
+            Scalar::Float32(_) => DataType::Float32,
+            Scalar::Float64(_) => DataType::Float64,
+            Scalar::Utf8(_) => DataType::Utf8,
+            Scalar::LargeUtf8(_) => DataType::LargeUtf8,
+            Scalar::Binary(_) => DataType::Binary,
+            Scalar::LargeBinary(_) => DataType::LargeBinary,
+            Scalar::FixedSizeBinary(_, size) => DataType::FixedSizeBinary(*size),
+            Scalar::Date32(_) => DataType::Date32(DateUnit::Day),
+            Scalar::Date64(_) => DataType::Date64(DateUnit::Millisecond),
+            Scalar::Time32(_, unit) => DataType::Time32(unit.clone()),
+            Scalar::Time64(_, unit) => DataType::Time64(unit.clone()),
+            Scalar::Timestamp(_, unit, tz) => {
+                DataType::Timestamp(unit.clone(), tz.clone())
+            }
+            Scalar::Duration(_, unit) => DataType::Duration(unit.clone()),
+            Scalar::IntervalYearMonth(_) => DataType::Interval(IntervalUnit::YearMonth),
+            Scalar::IntervalDayTime(_) => DataType::Interval(IntervalUnit::DayTime),
+        }
+    }
+
+    /// Returns whether this scalar is null.
+    pub fn is_null(&self) -> bool {
+        match self {
+            Scalar::Null => true,
+            Scalar::Boolean(v) => v.is_none(),
+            Scalar::Int8(v) => v.is_none(),
+            Scalar::Int16(v) => v.is_none(),
+            Scalar::Int32(v) => v.is_none(),
+            Scalar::Int64(v) => v.is_none(),
+            Scalar::UInt8(v) => v.is_none(),
+            Scalar::UInt16(v) => v.is_none(),
+            Scalar::UInt32(v) => v.is_none(),
+            Scalar::UInt64(v) => v.is_none(),
+            Scalar::Float32(v) => v.is_none(),
+            Scalar::Float64(v) => v.is_none(),
+            Scalar::Utf8(v) | Scalar::LargeUtf8(v) => v.is_none(),
+            Scalar::Binary(v)
+            | Scalar::LargeBinary(v)
+            | Scalar::FixedSizeBinary(v, _) => v.is_none(),
+            Scalar::Date32(v) | Scalar::Time32(v, _) | Scalar::IntervalYearMonth(v) => {
+                v.is_none()
+            }
+            Scalar::Date64(v)
+            | Scalar::Time64(v, _)
+            | Scalar::Timestamp(v, _, _)
+            | Scalar::Duration(v, _)
+            | Scalar::IntervalDayTime(v) => v.is_none(),
+        }
+    }
+
+    /// Returns the value at `index` of `array` as a `Scalar`.
+    ///
+    /// Values of dictionary arrays are returned decoded, i.e. as a scalar of the
+    /// dictionary's value type.
+    ///
+    /// Note this function is quite inefficient and is meant for the inspection of
+    /// individual values, not for converting whole arrays.
+    ///
+    /// # Errors
+    ///
+    /// This function errors if `index` is out of bounds, or if the data type of `array`
+    /// is not supported.
+    pub fn try_from_array(array: &ArrayRef, index: usize) -> Result<Self> {
+        if index >= array.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Index {} out of bounds for array of length {}",
+                index,
+                array.len()
+            )));
+        }
+        Ok(match array.data_type() {
+            DataType::Null => Scalar::Null,
+            DataType::Boolean => {
+                Scalar::Boolean(typed_value!(array, index, BooleanArray))
+            }
+            DataType::Int8 => Scalar::Int8(typed_value!(array, index, Int8Array)),
+            DataType::Int16 => Scalar::Int16(typed_value!(array, index, Int16Array)),
+            DataType::Int32 => Scalar::Int32(typed_value!(array, index, Int32Array)),
+            DataType::Int64 => Scalar::Int64(typed_value!(array, index, Int64Array)),
+            DataType::UInt8 => Scalar::UInt8(typed_value!(array, index, UInt8Array)),
+            DataType::UInt16 => Scalar::UInt16(typed_value!(array, index, UInt16Array)),
+            DataType::UInt32 => Scalar::UInt32(typed_value!(array, index, UInt32Array)),
+            DataType::UInt64 => Scalar::UInt64(typed_value!(array, index, UInt64Array)),
+            DataType::Float32 => {
+                Scalar::Float32(typed_value!(array, index, Float32Array))
+            }
+            DataType::Float64 => {
+                Scalar::Float64(typed_value!(array, index, Float64Array))
+            }
+            DataType::Utf8 => Scalar::Utf8(typed_value!(array, index, StringArray)),
+            DataType::LargeUtf8 => {
+                Scalar::LargeUtf8(typed_value!(array, index, LargeStringArray))
+            }
+            DataType::Binary => Scalar::Binary(typed_value!(array, index, BinaryArray)),
+            DataType::LargeBinary => {
+                Scalar::LargeBinary(typed_value!(array, index, LargeBinaryArray))
+            }
+            DataType::FixedSizeBinary(size) => Scalar::FixedSizeBinary(
+                typed_value!(array, index, FixedSizeBinaryArray),
+                *size,
+            ),
+            DataType::Date32(_) => {
+                Scalar::Date32(typed_value!(array, index, Date32Array))
+            }
+            DataType::Date64(_) => {
+                Scalar::Date64(typed_value!(array, index, Date64Array))
+            }
+            DataType::Time32(TimeUnit::Second) => Scalar::Time32(
+                typed_value!(array, index, Time32SecondArray),
+                TimeUnit::Second,
+            ),
+            DataType::Time32(TimeUnit::Millisecond) => Scalar::Time32(
+                typed_value!(array, index, Time32MillisecondArray),
+                TimeUnit::Millisecond,
+            ),
+            DataType::Time64(TimeUnit::Microsecond) => Scalar::Time64(
+                typed_value!(array, index, Time64MicrosecondArray),
+                TimeUnit::Microsecond,
+            ),
+            DataType::Time64(TimeUnit::Nanosecond) => Scalar::Time64(
+                typed_value!(array, index, Time64NanosecondArray),
+                TimeUnit::Nanosecond,
+            ),
+            DataType::Timestamp(unit, tz) => {
+                let value = match unit {
+                    TimeUnit::Second => {
+                        typed_value!(array, index, TimestampSecondArray)
+                    }
+                    TimeUnit::Millisecond => {
+                        typed_value!(array, index, TimestampMillisecondArray)
+                    }
+                    TimeUnit::Microsecond => {
+                        typed_value!(array, index, TimestampMicrosecondArray)
+                    }
+                    TimeUnit::Nanosecond => {
+                        typed_value!(array, index, TimestampNanosecondArray)
+                    }
+                };
+                Scalar::Timestamp(value, unit.clone(), tz.clone())
+            }
+            DataType::Duration(unit) => {
+                let value = match unit {
+                    TimeUnit::Second => typed_value!(array, index, DurationSecondArray),
+                    TimeUnit::Millisecond => {
+                        typed_value!(array, index, DurationMillisecondArray)
+                    }
+                    TimeUnit::Microsecond => {
+                        typed_value!(array, index, DurationMicrosecondArray)
+                    }
+                    TimeUnit::Nanosecond => {
+                        typed_value!(array, index, DurationNanosecondArray)
+                    }
+                };
+                Scalar::Duration(value, unit.clone())
+            }
+            DataType::Interval(IntervalUnit::YearMonth) => Scalar::IntervalYearMonth(
+                typed_value!(array, index, IntervalYearMonthArray),
+            ),
+            DataType::Interval(IntervalUnit::DayTime) => {
+                Scalar::IntervalDayTime(typed_value!(array, index, IntervalDayTimeArray))
+            }
+            DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+                DataType::Int8 => dictionary_value::<Int8Type>(array, index)?,
+                DataType::Int16 => dictionary_value::<Int16Type>(array, index)?,
+                DataType::Int32 => dictionary_value::<Int32Type>(array, index)?,
+                DataType::Int64 => dictionary_value::<Int64Type>(array, index)?,
+                DataType::UInt8 => dictionary_value::<UInt8Type>(array, index)?,
+                DataType::UInt16 => dictionary_value::<UInt16Type>(array, index)?,
+                DataType::UInt32 => dictionary_value::<UInt32Type>(array, index)?,
+                DataType::UInt64 => dictionary_value::<UInt64Type>(array, index)?,
+                t => {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Scalar not supported for dictionary key type {:?}",
+                        t
+                    )))
+                }
+            },
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Scalar not supported for data type {:?}",
+                    t
+                )))
+            }
+        })
+    }
+
+    /// Creates a null scalar of the given data type.
+    ///
+    /// # Errors
+    ///
+    /// This function errors if the data type is not supported.
+    pub fn new_null(data_type: &DataType) -> Result<Self> {
+        Ok(match data_type {
+            DataType::Null => Scalar::Null,
+            DataType::Boolean => Scalar::Boolean(None),
+            DataType::Int8 => Scalar::Int8(None),
+            DataType::Int16 => Scalar::Int16(None),
+            DataType::Int32 => Scalar::Int32(None),
+            DataType::Int64 => Scalar::Int64(None),
+            DataType::UInt8 => Scalar::UInt8(None),
+            DataType::UInt16 => Scalar::UInt16(None),
+            DataType::UInt32 => Scalar::UInt32(None),
+            DataType::UInt64 => Scalar::UInt64(None),
+            DataType::Float32 => Scalar::Float32(None),
+            DataType::Float64 => Scalar::Float64(None),
+            DataType::Utf8 => Scalar::Utf8(None),
+            DataType::LargeUtf8 => Scalar::LargeUtf8(None),
+            DataType::Binary => Scalar::Binary(None),
+            DataType::LargeBinary => Scalar::LargeBinary(None),
+            DataType::FixedSizeBinary(size) => Scalar::FixedSizeBinary(None, *size),
+            DataType::Date32(_) => Scalar::Date32(None),
+            DataType::Date64(_) => Scalar::Date64(None),
+            DataType::Time32(unit) => Scalar::Time32(None, unit.clone()),
+            DataType::Time64(unit) => Scalar::Time64(None, unit.clone()),
+            DataType::Timestamp(unit, tz) => {
+                Scalar::Timestamp(None, unit.clone(), tz.clone())
+            }
+            DataType::Duration(unit) => Scalar::Duration(None, unit.clone()),
+            DataType::Interval(IntervalUnit::YearMonth) => {
+                Scalar::IntervalYearMonth(None)
+            }
+            DataType::Interval(IntervalUnit::DayTime) => Scalar::IntervalDayTime(None),
+            DataType::Dictionary(_, value_type) => Scalar::new_null(value_type)?,
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Scalar not supported for data type {:?}",
+                    t
+                )))
+            }
+        })
+    }
+
+    /// Returns an array of length `size` whose slots all hold this scalar.
+    ///
+    /// ```
+    /// use arrow::array::{Array, Int32Array};
+    /// use arrow::scalar::Scalar;
+    ///
+    /// let array = Scalar::Int32(Some(7)).to_array_of_size(2);
+    /// let array = array.as_any().downcast_ref::<Int32Array>().unwrap();
+    /// assert_eq!(array, &Int32Array::from(vec![7, 7]));
+    /// ```
+    pub fn to_array_of_size(&self, size: usize) -> ArrayRef {
+        macro_rules! repeated {
+            ($array_type:ty, $value:expr) => {
+                Arc::new(<$array_type>::from(vec![$value; size])) as ArrayRef
+            };
+        }
+
+        match self {
+            Scalar::Null => Arc::new(NullArray::new(size)),
+            Scalar::Boolean(v) => repeated!(BooleanArray, *v),
+            Scalar::Int8(v) => repeated!(Int8Array, *v),
+            Scalar::Int16(v) => repeated!(Int16Array, *v),
+            Scalar::Int32(v) => repeated!(Int32Array, *v),
+            Scalar::Int64(v) => repeated!(Int64Array, *v),
+            Scalar::UInt8(v) => repeated!(UInt8Array, *v),
+            Scalar::UInt16(v) => repeated!(UInt16Array, *v),
+            Scalar::UInt32(v) => repeated!(UInt32Array, *v),
+            Scalar::UInt64(v) => repeated!(UInt64Array, *v),
+            Scalar::Float32(v) => repeated!(Float32Array, *v),
+            Scalar::Float64(v) => repeated!(Float64Array, *v),
+            Scalar::Utf8(v) => repeated!(StringArray, v.as_deref()),
+            Scalar::LargeUtf8(v) => repeated!(LargeStringArray, v.as_deref()),
+            Scalar::Binary(v) => repeated!(BinaryArray, v.as_deref()),
+            Scalar::LargeBinary(v) => repeated!(LargeBinaryArray, v.as_deref()),
+            Scalar::FixedSizeBinary(v, byte_width) => {
+                let (values, null_bit_buffer) = match v {
+                    Some(v) => (v.repeat(size), None),
+                    None => {
+                        let num_bytes = bit_util::ceil(size, 8);
+                        let nulls = MutableBuffer::new(num_bytes)
+                            .with_bitset(num_bytes, false)
+                            .freeze();
+                        (vec![0; *byte_width as usize * size], Some(nulls))
+                    }
+                };
+                let data = ArrayData::new(
+                    self.data_type(),
+                    size,
+                    None,
+                    null_bit_buffer,
+                    0,
+                    vec![Buffer::from(&values[..])],
+                    vec![],
+                );
+                Arc::new(FixedSizeBinaryArray::from(Arc::new(data)))
+            }
+            Scalar::Date32(v) | Scalar::Time32(v, _) | Scalar::IntervalYearMonth(v) => {
+                let values = repeated!(Int32Array, *v);
+                make_array(
+                    reinterpret::<Int32Type>(values.as_ref(), self.data_type()).data(),
+                )
+            }
+            Scalar::Date64(v)
+            | Scalar::Time64(v, _)
+            | Scalar::Timestamp(v, _, _)
+            | Scalar::Duration(v, _)
+            | Scalar::IntervalDayTime(v) => {
+                let values = repeated!(Int64Array, *v);
+                make_array(
+                    reinterpret::<Int64Type>(values.as_ref(), self.data_type()).data(),
+                )
+            }
+        }
+    }
+}
+
+/// Returns the decoded value at `index` of a dictionary array.
+fn dictionary_value<K: ArrowPrimitiveType>(
+    array: &ArrayRef,
+    index: usize,
+) -> Result<Scalar> {
+    let dict = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let keys = dict.keys();
+    if keys.is_null(index) {
+        return Scalar::new_null(&dict.value_type());
+    }
+    let key = keys.value(index).to_usize().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Can not convert dictionary key {:?} at index {} to usize",
+            keys.value(index),
+            index
+        ))
+    })?;
+    Scalar::try_from_array(&dict.values(), key)
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        macro_rules! format_option {
+            ($value:expr) => {
+                match $value {
+                    Some(v) => write!(f, "{}", v),
+                    None => write!(f, "NULL"),
+                }
+            };
+        }
+
+        match self {
+            Scalar::Null => write!(f, "NULL"),
+            Scalar::Boolean(v) => format_option!(v),
+            Scalar::Int8(v) => format_option!(v),
+            Scalar::Int16(v) => format_option!(v),
+            Scalar::Int32(v) => format_option!(v),
+            Scalar::Int64(v) => format_option!(v),
+            Scalar::UInt8(v) => format_option!(v),
+            Scalar::UInt16(v) => format_option!(v),
+            Scalar::UInt32(v) => format_option!(v),
+            Scalar::UInt64(v) => format_option!(v),
+            Scalar::Float32(v) => format_option!(v),
+            Scalar::Float64(v) => format_option!(v),
+            Scalar::Utf8(v) | Scalar::LargeUtf8(v) => format_option!(v),
+            Scalar::Binary(v)
+            | Scalar::LargeBinary(v)
+            | Scalar::FixedSizeBinary(v, _) => {
+                format_option!(v.as_ref().map(hex::encode))
+            }
+            Scalar::Date32(v) | Scalar::Time32(v, _) | Scalar::IntervalYearMonth(v) => {
+                format_option!(v)
+            }
+            Scalar::Date64(v)
+            | Scalar::Time64(v, _)
+            | Scalar::Timestamp(v, _, _)
+            | Scalar::Duration(v, _)
+            | Scalar::IntervalDayTime(v) => format_option!(v),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::iter::FromIterator;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_scalar_from_primitive_array() {
+        let array: ArrayRef = Arc::new(Int64Array::from(vec![Some(1), None, Some(3)]));
+        let array = array.slice(1, 2);
+
+        let scalar = Scalar::try_from_array(&array, 0).unwrap();
+        assert_eq!(Scalar::Int64(None), scalar);
+        assert!(scalar.is_null());
+        assert_eq!(DataType::Int64, scalar.data_type());
+
+        let scalar = Scalar::try_from_array(&array, 1).unwrap();
+        assert_eq!(Scalar::Int64(Some(3)), scalar);
+        assert!(!scalar.is_null());
+        assert_eq!("3", scalar.to_string());
+    }
+
+    #[test]
+    fn test_scalar_from_string_and_binary_arrays() {
+        let array: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None]));
+        assert_eq!(
+            Scalar::Utf8(Some("a".to_string())),
+            Scalar::try_from_array(&array, 0).unwrap()
+        );
+        assert_eq!(
+            "NULL",
+            Scalar::try_from_array(&array, 1).unwrap().to_string()
+        );
+
+        let array: ArrayRef = Arc::new(BinaryArray::from(vec![b"\x01\xff".as_ref()]));
+        let scalar = Scalar::try_from_array(&array, 0).unwrap();
+        assert_eq!(Scalar::Binary(Some(vec![1, 255])), scalar);
+        assert_eq!("01ff", scalar.to_string());
+    }
+
+    #[test]
+    fn test_scalar_from_temporal_array() {
+        let array: ArrayRef = Arc::new(TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1000)],
+            Some("UTC".to_string()),
+        ));
+        let scalar = Scalar::try_from_array(&array, 0).unwrap();
+        assert_eq!(
+            Scalar::Timestamp(Some(1000), TimeUnit::Millisecond, Some("UTC".to_string())),
+            scalar
+        );
+        assert_eq!(array.data_type(), &scalar.data_type());
+    }
+
+    #[test]
+    fn test_scalar_from_dictionary_array() {
+        let array =
+            DictionaryArray::<Int8Type>::from_iter(vec![Some("a"), None, Some("b")]);
+        let array: ArrayRef = Arc::new(array);
+        assert_eq!(
+            Scalar::Utf8(Some("b".to_string())),
+            Scalar::try_from_array(&array, 2).unwrap()
+        );
+        assert_eq!(
+            Scalar::Utf8(None),
+            Scalar::try_from_array(&array, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_scalar_from_array_out_of_bounds() {
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        assert!(Scalar::try_from_array(&array, 1).is_err());
+    }
+
+    #[test]
+    fn test_scalar_to_array_of_size() {
+        let scalars = vec![
+            Scalar::Null,
+            Scalar::Boolean(Some(true)),
+            Scalar::UInt16(None),
+            Scalar::Float64(Some(1.5)),
+            Scalar::LargeUtf8(Some("a".to_string())),
+            Scalar::Binary(None),
+            Scalar::FixedSizeBinary(Some(vec![1, 2]), 2),
+            Scalar::FixedSizeBinary(None, 2),
+            Scalar::Date32(Some(1)),
+            Scalar::Time64(None, TimeUnit::Nanosecond),
+            Scalar::Timestamp(Some(2), TimeUnit::Second, Some("UTC".to_string())),
+            Scalar::IntervalDayTime(Some(3)),
+        ];
+        for scalar in scalars {
+            let array = scalar.to_array_of_size(3);
+            assert_eq!(3, array.len());
+            assert_eq!(&scalar.data_type(), array.data_type());
+            for i in 0..3 {
+                assert_eq!(scalar, Scalar::try_from_array(&array, i).unwrap());
+            }
+        }
+        assert_eq!(0, Scalar::Int8(Some(1)).to_array_of_size(0).len());
+    }
+
+    #[test]
+    fn test_scalar_new_null() {
+        let data_type = DataType::Time32(TimeUnit::Second);
+        let scalar = Scalar::new_null(&data_type).unwrap();
+        assert!(scalar.is_null());
+        assert_eq!(data_type, scalar.data_type());
+    }
+}
@@ -44,6 +44,23 @@ macro_rules! make_string {
     }};
 }
 
+macro_rules! make_string_time {
+    ($array_type:ty, $column: ident, $row: ident) => {{
+        let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
+
+        let s = if array.is_null($row) {
+            "".to_string()
+        } else {
+            array
+                .value_as_time($row)
+                .map(|time| time.to_string())
+                .unwrap_or_else(|| "ERROR CONVERTING TIME".to_string())
+        };
+
+        Ok(s)
+    }};
+}
+
 macro_rules! make_string_from_list {
     ($column: ident, $row: ident) => {{
         let list = $column
@@ -94,16 +111,16 @@ pub fn array_value_to_string(column: &array::ArrayRef, row: usize) -> Result<Str
         DataType::Date32(_) => make_string!(array::Date32Array, column, row),
         DataType::Date64(_) => make_string!(array::Date64Array, column, row),
         DataType::Time32(unit) if *unit == TimeUnit::Second => {
-            make_string!(array::Time32SecondArray, column, row)
+            make_string_time!(array::Time32SecondArray, column, row)
         }
         DataType::Time32(unit) if *unit == TimeUnit::Millisecond => {
-            make_string!(array::Time32MillisecondArray, column, row)
+            make_string_time!(array::Time32MillisecondArray, column, row)
         }
-        DataType::Time32(unit) if *unit == TimeUnit::Microsecond => {
-            make_string!(array::Time64MicrosecondArray, column, row)
+        DataType::Time64(unit) if *unit == TimeUnit::Microsecond => {
+            make_string_time!(array::Time64MicrosecondArray, column, row)
         }
         DataType::Time64(unit) if *unit == TimeUnit::Nanosecond => {
-            make_string!(array::Time64NanosecondArray, column, row)
+            make_string_time!(array::Time64NanosecondArray, column, row)
         }
         DataType::List(_) => make_string_from_list!(column, row),
         DataType::Dictionary(index_type, _value_type) => match **index_type {
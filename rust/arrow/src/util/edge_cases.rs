@@ -0,0 +1,185 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Constructors of arrays with edge-case layouts, such as non-zero offsets, empty slices
+//! or large dictionaries, which kernels are expected to handle.
+//!
+//! This module is available with the `test_utils` feature, so that kernels implemented
+//! outside of this crate can be tested against the same arrays as the crate's own tests.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
+use crate::datatypes::*;
+use crate::util::bit_util;
+
+/// Returns a slice of `array` with the largest possible offset, which only contains its
+/// last element. Returns an empty slice if `array` is empty.
+pub fn max_offset_slice(array: &ArrayRef) -> ArrayRef {
+    let offset = array.len().saturating_sub(1);
+    array.slice(offset, array.len() - offset)
+}
+
+/// Returns the empty slices of `array` at its start, middle and end.
+pub fn empty_slices(array: &ArrayRef) -> Vec<ArrayRef> {
+    vec![
+        array.slice(0, 0),
+        array.slice(array.len() / 2, 0),
+        array.slice(array.len(), 0),
+    ]
+}
+
+/// Returns a string array with the given values, whose first offset is `prefix_len`
+/// instead of `0`: the first `prefix_len` bytes of its values buffer are not part of any
+/// value.
+pub fn string_array_with_unused_prefix(
+    values: &[Option<&str>],
+    prefix_len: usize,
+) -> StringArray {
+    let mut offsets = Vec::with_capacity(values.len() + 1);
+    let mut data = vec![b'#'; prefix_len];
+    offsets.push(prefix_len as i32);
+    for value in values {
+        data.extend_from_slice(value.unwrap_or_default().as_bytes());
+        offsets.push(data.len() as i32);
+    }
+    let num_bytes = bit_util::ceil(values.len(), 8);
+    let mut null_buffer = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    for (i, value) in values.iter().enumerate() {
+        if value.is_some() {
+            bit_util::set_bit(null_buffer.data_mut(), i);
+        }
+    }
+
+    let data = ArrayData::builder(DataType::Utf8)
+        .len(values.len())
+        .null_bit_buffer(null_buffer.freeze())
+        .add_buffer(Buffer::from(offsets.to_byte_slice()))
+        .add_buffer(Buffer::from(&data[..]))
+        .build();
+    StringArray::from(data)
+}
+
+/// Returns a dictionary array of length `len` whose dictionary contains as many distinct
+/// string values as the key type can index, up to `2^16` values. The keys only refer to
+/// the first and the last values of the dictionary.
+pub fn huge_dictionary<K: ArrowDictionaryKeyType>(len: usize) -> DictionaryArray<K> {
+    let num_values = (0..1usize << 16)
+        .take_while(|i| K::Native::from_usize(*i).is_some())
+        .count();
+    let values = (0..num_values)
+        .map(|i| Some(format!("value{}", i)))
+        .collect::<StringArray>();
+    let keys = (0..len)
+        .map(|i| {
+            let key = if i % 2 == 0 { 0 } else { num_values - 1 };
+            K::Native::from_usize(key).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let data = ArrayData::builder(DataType::Dictionary(
+        Box::new(K::DATA_TYPE),
+        Box::new(DataType::Utf8),
+    ))
+    .len(len)
+    .add_buffer(Buffer::from(keys.to_byte_slice()))
+    .add_child_data(values.data())
+    .build();
+    DictionaryArray::from(data)
+}
+
+/// Returns a corpus of arrays with edge-case layouts: arrays with non-zero offsets,
+/// empty slices, string arrays whose offsets do not start at zero and large dictionaries.
+pub fn edge_case_arrays() -> Vec<ArrayRef> {
+    let int32: ArrayRef = Arc::new(Int32Array::from(vec![
+        Some(1),
+        None,
+        Some(i32::MIN),
+        Some(i32::MAX),
+        None,
+        Some(0),
+    ]));
+    let float64: ArrayRef = Arc::new(Float64Array::from(vec![
+        Some(f64::NAN),
+        None,
+        Some(-0.0),
+        Some(f64::INFINITY),
+    ]));
+    let boolean: ArrayRef = Arc::new(BooleanArray::from(vec![
+        Some(true),
+        None,
+        Some(false),
+        Some(true),
+        None,
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(true),
+    ]));
+    let string: ArrayRef = Arc::new(string_array_with_unused_prefix(
+        &[Some("hello"), None, Some(""), Some("arrow")],
+        3,
+    ));
+    let dictionary: ArrayRef = Arc::new(huge_dictionary::<Int16Type>(5));
+
+    let mut arrays = vec![];
+    for array in [int32, float64, boolean, string, dictionary].iter() {
+        arrays.push(array.slice(1, array.len() - 1));
+        arrays.push(max_offset_slice(array));
+        arrays.extend(empty_slices(array));
+        arrays.push(array.clone());
+    }
+    arrays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_string_array_with_unused_prefix() {
+        let array = string_array_with_unused_prefix(&[Some("a"), None, Some("bc")], 4);
+        assert_eq!(3, array.len());
+        assert_eq!(4, array.value_offset(0));
+        assert_eq!("a", array.value(0));
+        assert!(array.is_null(1));
+        assert_eq!("bc", array.value(2));
+    }
+
+    #[test]
+    fn test_huge_dictionary() {
+        let array = huge_dictionary::<UInt8Type>(3);
+        assert_eq!(256, array.values().len());
+        assert_eq!(&UInt8Array::from(vec![0, 255, 0]), array.keys());
+
+        let array = huge_dictionary::<Int32Type>(1);
+        assert_eq!(1 << 16, array.values().len());
+    }
+
+    #[test]
+    fn test_edge_case_arrays() {
+        let arrays = edge_case_arrays();
+        assert_eq!(30, arrays.len());
+        for array in arrays {
+            assert_eq!(
+                array.null_count(),
+                (0..array.len()).filter(|i| array.is_null(*i)).count()
+            );
+        }
+    }
+}
@@ -18,8 +18,11 @@
 pub mod bit_chunk_iterator;
 pub mod bit_util;
 pub mod display;
+#[cfg(any(test, feature = "test_utils"))]
+pub mod edge_cases;
 pub mod integration_util;
 #[cfg(feature = "prettyprint")]
 pub mod pretty;
+pub mod sql;
 pub mod string_writer;
 pub mod test_util;
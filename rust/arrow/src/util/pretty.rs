@@ -74,7 +74,7 @@ mod tests {
     use crate::array::{self, PrimitiveBuilder, StringBuilder, StringDictionaryBuilder};
 
     use super::*;
-    use crate::datatypes::{DataType, Field, Int32Type, Schema};
+    use crate::datatypes::{DataType, Field, Int32Type, Schema, TimeUnit};
     use std::sync::Arc;
 
     #[test]
@@ -160,4 +160,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pretty_format_time() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("t32", DataType::Time32(TimeUnit::Millisecond), true),
+            Field::new("t64", DataType::Time64(TimeUnit::Microsecond), true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(array::Time32MillisecondArray::from(vec![
+                    Some(45_045_123),
+                    None,
+                ])),
+                Arc::new(array::Time64MicrosecondArray::from(vec![
+                    Some(3_600_000_000),
+                    Some(86_399_999_999),
+                ])),
+            ],
+        )?;
+
+        let table = pretty_format_batches(&[batch])?;
+
+        let expected = vec![
+            "+--------------+-----------------+",
+            "| t32          | t64             |",
+            "+--------------+-----------------+",
+            "| 12:30:45.123 | 01:00:00        |",
+            "|              | 23:59:59.999999 |",
+            "+--------------+-----------------+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,523 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Functions to export record batches to relational databases, either as the values of
+//! the parameters of prepared statements, or as `INSERT` statements of SQL literals.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::{Int32Array, StringArray};
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::record_batch::RecordBatch;
+//! use arrow::util::sql::{insert_statements, SqlValue};
+//!
+//! # fn main() -> arrow::error::Result<()> {
+//! let schema = Schema::new(vec![
+//!     Field::new("id", DataType::Int32, false),
+//!     Field::new("name", DataType::Utf8, true),
+//! ]);
+//! let batch = RecordBatch::try_new(
+//!     Arc::new(schema),
+//!     vec![
+//!         Arc::new(Int32Array::from(vec![1, 2])),
+//!         Arc::new(StringArray::from(vec![Some("O'Brien"), None])),
+//!     ],
+//! )?;
+//!
+//! let statements = insert_statements("people", &batch, None)?;
+//! assert_eq!(
+//!     vec![r#"INSERT INTO "people" ("id", "name") VALUES (1, 'O''Brien'), (2, NULL)"#],
+//!     statements
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::array::*;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, Row};
+
+/// A value of a row, as bound to a parameter of a prepared statement.
+///
+/// Its `Display` implementation renders it as a SQL literal.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    /// the null value of any type
+    Null,
+    /// a boolean
+    Boolean(bool),
+    /// a signed integer
+    Int(i64),
+    /// an unsigned integer
+    UInt(u64),
+    /// a floating point number
+    Float(f64),
+    /// a decimal number, `value * 10^-scale`
+    Decimal(i128, usize),
+    /// a string
+    Text(String),
+    /// a binary string
+    Bytes(Vec<u8>),
+    /// a date
+    Date(NaiveDate),
+    /// a time of day
+    Time(NaiveTime),
+    /// a timestamp without time zone
+    Timestamp(NaiveDateTime),
+    /// a timestamp with time zone, as its UTC date and time
+    TimestampTz(NaiveDateTime),
+}
+
+/// Formats the decimal `value * 10^-scale`, e.g. `-0.05` for `-5` of scale `2`
+fn format_decimal(value: i128, scale: usize) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Writes `s` as a quoted string literal, doubling the quotes it contains
+fn write_quoted(f: &mut fmt::Formatter, s: &str, quote: char) -> fmt::Result {
+    write!(f, "{}", quote)?;
+    for c in s.chars() {
+        if c == quote {
+            write!(f, "{}", quote)?;
+        }
+        write!(f, "{}", c)?;
+    }
+    write!(f, "{}", quote)
+}
+
+impl fmt::Display for SqlValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SqlValue::Null => write!(f, "NULL"),
+            SqlValue::Boolean(v) => write!(f, "{}", if *v { "TRUE" } else { "FALSE" }),
+            SqlValue::Int(v) => write!(f, "{}", v),
+            SqlValue::UInt(v) => write!(f, "{}", v),
+            SqlValue::Float(v) if v.is_nan() => write!(f, "'NaN'"),
+            SqlValue::Float(v) if v.is_infinite() => {
+                write!(f, "'{}Infinity'", if *v < 0.0 { "-" } else { "" })
+            }
+            // `Debug` keeps a fractional part and never uses an exponent without one,
+            // so that the literal is not read back as an integer
+            SqlValue::Float(v) => write!(f, "{:?}", v),
+            SqlValue::Decimal(v, scale) => write!(f, "{}", format_decimal(*v, *scale)),
+            SqlValue::Text(v) => write_quoted(f, v, '\''),
+            SqlValue::Bytes(v) => {
+                write!(f, "X'")?;
+                v.iter().try_for_each(|b| write!(f, "{:02X}", b))?;
+                write!(f, "'")
+            }
+            SqlValue::Date(v) => write!(f, "DATE '{}'", v),
+            SqlValue::Time(v) => write!(f, "TIME '{}'", v),
+            SqlValue::Timestamp(v) => write!(f, "TIMESTAMP '{}'", v),
+            SqlValue::TimestampTz(v) => {
+                write!(f, "TIMESTAMP WITH TIME ZONE '{}+00:00'", v)
+            }
+        }
+    }
+}
+
+macro_rules! primitive_value {
+    ($array:expr, $index:expr, $array_type:ty, $variant:ident, $native:ty) => {{
+        let array = $array.as_typed::<$array_type>()?;
+        SqlValue::$variant(array.value($index) as $native)
+    }};
+}
+
+macro_rules! temporal_value {
+    ($array:expr, $index:expr, $array_type:ty, $convert:ident, $variant:ident) => {{
+        let array = $array.as_typed::<$array_type>()?;
+        let value = array.$convert($index).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "Value {} of type {:?} is out of the range of SQL values",
+                array.value($index),
+                array.data_type()
+            ))
+        })?;
+        SqlValue::$variant(value)
+    }};
+}
+
+/// Returns the value of `array` at `index` as a [`SqlValue`]
+fn sql_value(array: &ArrayRef, index: usize) -> Result<SqlValue> {
+    if array.is_null(index) {
+        return Ok(SqlValue::Null);
+    }
+    Ok(match array.data_type() {
+        DataType::Null => SqlValue::Null,
+        DataType::Boolean => {
+            SqlValue::Boolean(array.as_typed::<BooleanArray>()?.value(index))
+        }
+        DataType::Int8 => primitive_value!(array, index, Int8Array, Int, i64),
+        DataType::Int16 => primitive_value!(array, index, Int16Array, Int, i64),
+        DataType::Int32 => primitive_value!(array, index, Int32Array, Int, i64),
+        DataType::Int64 => primitive_value!(array, index, Int64Array, Int, i64),
+        DataType::UInt8 => primitive_value!(array, index, UInt8Array, UInt, u64),
+        DataType::UInt16 => primitive_value!(array, index, UInt16Array, UInt, u64),
+        DataType::UInt32 => primitive_value!(array, index, UInt32Array, UInt, u64),
+        DataType::UInt64 => primitive_value!(array, index, UInt64Array, UInt, u64),
+        DataType::Float32 => primitive_value!(array, index, Float32Array, Float, f64),
+        DataType::Float64 => primitive_value!(array, index, Float64Array, Float, f64),
+        DataType::Decimal(_, scale) => {
+            SqlValue::Decimal(array.as_typed::<DecimalArray>()?.value(index), *scale)
+        }
+        DataType::Utf8 => {
+            SqlValue::Text(array.as_typed::<StringArray>()?.value(index).to_string())
+        }
+        DataType::LargeUtf8 => SqlValue::Text(
+            array
+                .as_typed::<LargeStringArray>()?
+                .value(index)
+                .to_string(),
+        ),
+        DataType::Binary => {
+            SqlValue::Bytes(array.as_typed::<BinaryArray>()?.value(index).to_vec())
+        }
+        DataType::LargeBinary => {
+            SqlValue::Bytes(array.as_typed::<LargeBinaryArray>()?.value(index).to_vec())
+        }
+        DataType::FixedSizeBinary(_) => SqlValue::Bytes(
+            array
+                .as_typed::<FixedSizeBinaryArray>()?
+                .value(index)
+                .to_vec(),
+        ),
+        DataType::Date32(_) => {
+            temporal_value!(array, index, Date32Array, value_as_date, Date)
+        }
+        DataType::Date64(_) => {
+            temporal_value!(array, index, Date64Array, value_as_date, Date)
+        }
+        DataType::Time32(TimeUnit::Second) => {
+            temporal_value!(array, index, Time32SecondArray, value_as_time, Time)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            temporal_value!(array, index, Time32MillisecondArray, value_as_time, Time)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            temporal_value!(array, index, Time64MicrosecondArray, value_as_time, Time)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            temporal_value!(array, index, Time64NanosecondArray, value_as_time, Time)
+        }
+        DataType::Timestamp(unit, tz) => {
+            let value = match unit {
+                TimeUnit::Second => temporal_value!(
+                    array,
+                    index,
+                    TimestampSecondArray,
+                    value_as_datetime,
+                    Timestamp
+                ),
+                TimeUnit::Millisecond => temporal_value!(
+                    array,
+                    index,
+                    TimestampMillisecondArray,
+                    value_as_datetime,
+                    Timestamp
+                ),
+                TimeUnit::Microsecond => temporal_value!(
+                    array,
+                    index,
+                    TimestampMicrosecondArray,
+                    value_as_datetime,
+                    Timestamp
+                ),
+                TimeUnit::Nanosecond => temporal_value!(
+                    array,
+                    index,
+                    TimestampNanosecondArray,
+                    value_as_datetime,
+                    Timestamp
+                ),
+            };
+            match (value, tz) {
+                (SqlValue::Timestamp(v), Some(_)) => SqlValue::TimestampTz(v),
+                (value, _) => value,
+            }
+        }
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => dictionary_value::<Int8Type>(array, index)?,
+            DataType::Int16 => dictionary_value::<Int16Type>(array, index)?,
+            DataType::Int32 => dictionary_value::<Int32Type>(array, index)?,
+            DataType::Int64 => dictionary_value::<Int64Type>(array, index)?,
+            DataType::UInt8 => dictionary_value::<UInt8Type>(array, index)?,
+            DataType::UInt16 => dictionary_value::<UInt16Type>(array, index)?,
+            DataType::UInt32 => dictionary_value::<UInt32Type>(array, index)?,
+            DataType::UInt64 => dictionary_value::<UInt64Type>(array, index)?,
+            t => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Invalid dictionary key type {:?}",
+                    t
+                )))
+            }
+        },
+        t => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Exporting values of type {:?} to SQL is not supported",
+                t
+            )))
+        }
+    })
+}
+
+/// Returns the value of the dictionary `array` at `index`, which is valid
+fn dictionary_value<K: ArrowDictionaryKeyType>(
+    array: &ArrayRef,
+    index: usize,
+) -> Result<SqlValue> {
+    let array = array.as_typed::<DictionaryArray<K>>()?;
+    let key = array.keys().value(index).to_usize().ok_or_else(|| {
+        ArrowError::ComputeError(format!("Invalid dictionary key at index {}", index))
+    })?;
+    sql_value(&array.values(), key)
+}
+
+/// Returns the values of `row` as [`SqlValue`]s, in the order of the columns
+pub fn row_values(row: &Row) -> Result<Vec<SqlValue>> {
+    (0..row.num_columns())
+        .map(|i| sql_value(row.column(i)?, row.index()))
+        .collect()
+}
+
+/// Returns the values of each row of `batch`, to be bound to the parameters of a
+/// prepared statement.
+///
+/// This function errors if a column is of a type that has no SQL equivalent, such as
+/// lists or structs, or if a temporal value is out of the range of `chrono`.
+pub fn batch_values(batch: &RecordBatch) -> Result<Vec<Vec<SqlValue>>> {
+    batch.rows().map(|row| row_values(&row)).collect()
+}
+
+/// Returns `name` quoted as a SQL identifier, e.g. `"my ""table"""` for `my "table"`
+pub fn quote_identifier(name: &str) -> String {
+    SqlIdentifier(name).to_string()
+}
+
+struct SqlIdentifier<'a>(&'a str);
+
+impl fmt::Display for SqlIdentifier<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_quoted(f, self.0, '"')
+    }
+}
+
+/// Options that define the `INSERT` statements of [`insert_statements`]
+#[derive(Debug, Clone)]
+pub struct InsertOptions {
+    /// the maximum number of rows inserted by a statement
+    pub rows_per_statement: usize,
+}
+
+impl Default for InsertOptions {
+    fn default() -> Self {
+        Self {
+            rows_per_statement: 1000,
+        }
+    }
+}
+
+/// Returns `INSERT` statements of the rows of `batch` into `table`, whose columns are
+/// named after the fields of the schema of the batch.
+///
+/// The values are rendered as SQL literals: strings are quoted with their quotes
+/// doubled, binaries are hexadecimal literals, and temporal values are typed literals,
+/// e.g. `DATE '2020-01-31'`. No statement is returned for an empty batch.
+///
+/// This function errors under the same conditions as [`batch_values`], or if
+/// `rows_per_statement` is `0`.
+pub fn insert_statements(
+    table: &str,
+    batch: &RecordBatch,
+    options: Option<InsertOptions>,
+) -> Result<Vec<String>> {
+    let options = options.unwrap_or_default();
+    if options.rows_per_statement == 0 {
+        return Err(ArrowError::InvalidArgumentError(
+            "The number of rows per INSERT statement must be positive".to_string(),
+        ));
+    }
+
+    let schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| quote_identifier(field.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let prefix = format!(
+        "INSERT INTO {} ({}) VALUES ",
+        quote_identifier(table),
+        columns
+    );
+
+    let rows = batch
+        .rows()
+        .map(|row| {
+            let values = row_values(&row)?
+                .iter()
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>();
+            Ok(format!("({})", values.join(", ")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(rows
+        .chunks(options.rows_per_statement)
+        .map(|rows| format!("{}{}", prefix, rows.join(", ")))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    #[test]
+    fn test_sql_literals() {
+        let date = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+        let time = NaiveTime::from_hms_milli_opt(13, 5, 7, 250).unwrap();
+        let cases = vec![
+            (SqlValue::Null, "NULL"),
+            (SqlValue::Boolean(false), "FALSE"),
+            (SqlValue::Int(-3), "-3"),
+            (SqlValue::UInt(u64::MAX), "18446744073709551615"),
+            (SqlValue::Float(1.0), "1.0"),
+            (SqlValue::Float(-2.5e-10), "-2.5e-10"),
+            (SqlValue::Float(f64::NAN), "'NaN'"),
+            (SqlValue::Float(f64::NEG_INFINITY), "'-Infinity'"),
+            (SqlValue::Decimal(12345, 2), "123.45"),
+            (SqlValue::Decimal(-5, 2), "-0.05"),
+            (
+                SqlValue::Decimal(i128::MIN, 0),
+                "-170141183460469231731687303715884105728",
+            ),
+            (SqlValue::Text("it's \"ok\"".to_string()), "'it''s \"ok\"'"),
+            (SqlValue::Bytes(vec![0, 0xab, 0x1f]), "X'00AB1F'"),
+            (SqlValue::Date(date), "DATE '2020-01-31'"),
+            (SqlValue::Time(time), "TIME '13:05:07.250'"),
+            (
+                SqlValue::Timestamp(date.and_time(time)),
+                "TIMESTAMP '2020-01-31 13:05:07.250'",
+            ),
+            (
+                SqlValue::TimestampTz(date.and_time(time)),
+                "TIMESTAMP WITH TIME ZONE '2020-01-31 13:05:07.250+00:00'",
+            ),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(expected, value.to_string());
+        }
+        assert_eq!("\"my \"\"table\"\"\"", quote_identifier("my \"table\""));
+    }
+
+    #[test]
+    fn test_batch_values() {
+        let mut decimals = DecimalBuilder::new(2, 10, 3);
+        decimals.append_value(1_500).unwrap();
+        decimals.append_null().unwrap();
+        let dictionary: DictionaryArray<Int8Type> =
+            vec![Some("b"), None].into_iter().collect();
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(decimals.finish()),
+            Arc::new(Date32Array::from(vec![Some(1), None])),
+            Arc::new(TimestampMillisecondArray::from_opt_vec(
+                vec![Some(1_500), None],
+                Some("UTC".to_string()),
+            )),
+            Arc::new(Time64MicrosecondArray::from(vec![Some(1), None])),
+            Arc::new(dictionary),
+            Arc::new(UInt8Array::from(vec![Some(7), None])),
+        ];
+        let fields = columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| Field::new(&format!("c{}", i), c.data_type().clone(), true))
+            .collect();
+        let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns).unwrap();
+
+        let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        assert_eq!(
+            vec![
+                vec![
+                    SqlValue::Decimal(1_500, 3),
+                    SqlValue::Date(NaiveDate::from_ymd_opt(1970, 1, 2).unwrap()),
+                    SqlValue::TimestampTz(epoch.and_hms_milli_opt(0, 0, 1, 500).unwrap()),
+                    SqlValue::Time(NaiveTime::from_hms_micro_opt(0, 0, 0, 1).unwrap()),
+                    SqlValue::Text("b".to_string()),
+                    SqlValue::UInt(7),
+                ],
+                vec![SqlValue::Null; 6],
+            ],
+            batch_values(&batch).unwrap()
+        );
+
+        let statements = insert_statements(
+            "t",
+            &batch,
+            Some(InsertOptions {
+                rows_per_statement: 1,
+            }),
+        )
+        .unwrap();
+        let prefix = r#"INSERT INTO "t" ("c0", "c1", "c2", "c3", "c4", "c5") VALUES "#;
+        assert_eq!(
+            vec![
+                format!(
+                    "{}(1.500, DATE '1970-01-02', \
+                     TIMESTAMP WITH TIME ZONE '1970-01-01 00:00:01.500+00:00', \
+                     TIME '00:00:00.000001', 'b', 7)",
+                    prefix
+                ),
+                format!("{}(NULL, NULL, NULL, NULL, NULL, NULL)", prefix),
+            ],
+            statements
+        );
+    }
+
+    #[test]
+    fn test_insert_statements_errors() {
+        let mut list = ListBuilder::new(Int32Builder::new(1));
+        list.values().append_value(1).unwrap();
+        list.append(true).unwrap();
+        let list = list.finish();
+        let schema = Schema::new(vec![Field::new("l", list.data_type().clone(), true)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(list)]).unwrap();
+        assert!(insert_statements("t", &batch, None).is_err());
+
+        let options = InsertOptions {
+            rows_per_statement: 0,
+        };
+        assert!(insert_statements("t", &batch, Some(options)).is_err());
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let empty = Int32Array::from(Vec::<i32>::new());
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(empty)]).unwrap();
+        assert!(insert_statements("t", &batch, None).unwrap().is_empty());
+    }
+}